@@ -8,7 +8,7 @@ This module supports both:
 from datetime import datetime
 from typing import Optional
 
-from fastapi import APIRouter, Depends, HTTPException
+from fastapi import APIRouter, Depends, HTTPException, Query
 from pydantic import BaseModel, Field
 from sqlalchemy import select
 from sqlalchemy.ext.asyncio import AsyncSession
@@ -37,6 +37,11 @@ class ExecuteTradeRequest(BaseModel):
         default=None, ge=1, le=99, description="Limit price in cents (1-99)"
     )
     signal_id: Optional[str] = Field(default=None, description="Associated signal ID")
+    client_order_id: Optional[str] = Field(
+        default=None,
+        max_length=100,
+        description="Idempotency key; a repeat returns the order it already placed",
+    )
 
 
 class ExecuteFromSignalRequest(BaseModel):
@@ -44,6 +49,11 @@ class ExecuteFromSignalRequest(BaseModel):
 
     signal_id: int = Field(..., description="Signal ID to trade")
     contracts: int = Field(..., ge=1, le=1000, description="Number of contracts")
+    client_order_id: Optional[str] = Field(
+        default=None,
+        max_length=100,
+        description="Idempotency key; a repeat returns the order it already placed",
+    )
 
 
 class TradeResponseModel(BaseModel):
@@ -128,6 +138,7 @@ async def execute_trade(
         order_type=request.order_type,
         limit_price=request.limit_price,
         signal_id=request.signal_id,
+        client_order_id=request.client_order_id,
     )
 
     result = await executor.execute_trade(trade_request)
@@ -158,6 +169,7 @@ async def execute_from_signal(
     result = await executor.execute_from_signal(
         signal_id=request.signal_id,
         contracts=request.contracts,
+        client_order_id=request.client_order_id,
     )
 
     return TradeResponseModel(
@@ -260,15 +272,19 @@ async def get_position(
 @router.delete("/positions/{trade_id}", response_model=TradeResponseModel)
 async def close_position(
     trade_id: int,
+    contracts: Optional[int] = Query(
+        default=None, ge=1, description="Contracts to sell (default: the whole position)"
+    ),
     db: AsyncSession = Depends(get_db),
 ) -> TradeResponseModel:
     """
-    Close an open position.
+    Close an open position, or part of it.
 
-    Places a market sell order to close the position.
+    Places a market sell order for `contracts` (or the whole position). A
+    partial close is recorded as its own closed trade and the rest stays open.
     """
     executor = TradeExecutor(db)
-    result = await executor.close_position(trade_id)
+    result = await executor.close_position(trade_id, contracts)
 
     return TradeResponseModel(
         success=result.success,
@@ -6,6 +6,7 @@ from datetime import datetime
 from typing import Optional
 
 from sqlalchemy import select
+from sqlalchemy.exc import IntegrityError
 from sqlalchemy.ext.asyncio import AsyncSession
 
 from app.core.config import settings
@@ -25,6 +26,7 @@ class TradeRequest:
     order_type: str = "market"  # market or limit
     limit_price: Optional[int] = None  # Price in cents for limit orders
     signal_id: Optional[str] = None
+    client_order_id: Optional[str] = None  # Caller's idempotency key
 
 
 @dataclass
@@ -78,8 +80,17 @@ class TradeExecutor:
         Returns:
             TradeResponse with execution results
         """
-        # Generate client order ID for idempotency
-        client_order_id = f"basilisk_{uuid.uuid4().hex[:16]}"
+        # A repeated client order ID gets the order it already placed, so a
+        # caller can resend after a timeout without placing a second one
+        if request.client_order_id:
+            existing = await self.db.execute(
+                select(Trade).where(Trade.client_order_id == request.client_order_id)
+            )
+            previous = existing.scalar_one_or_none()
+            if previous is not None:
+                return self._replay(previous)
+
+        client_order_id = request.client_order_id or f"basilisk_{uuid.uuid4().hex[:16]}"
 
         # Map direction to OrderSide
         side = OrderSide.YES if request.direction.upper() == "YES" else OrderSide.NO
@@ -99,7 +110,16 @@ class TradeExecutor:
             builder_code_used=self.builder_code if self.builder_code else None,
         )
         self.db.add(trade)
-        await self.db.flush()  # Get the trade ID
+        try:
+            await self.db.flush()  # Get the trade ID
+        except IntegrityError:
+            # Another request with this client order ID is still being placed
+            await self.db.rollback()
+            return TradeResponse(
+                success=False,
+                client_order_id=client_order_id,
+                error=f"duplicate client_order_id {client_order_id}: already being placed",
+            )
 
         # Place order on Kalshi
         result = await self.kalshi.place_order(
@@ -159,10 +179,28 @@ class TradeExecutor:
                 error=result.error,
             )
 
+    @staticmethod
+    def _replay(trade: Trade) -> TradeResponse:
+        """Answer a repeated client order ID with the trade it created."""
+        success = trade.status != "CANCELLED"
+        price = trade.entry_price or None
+        filled = trade.filled_contracts or 0
+        return TradeResponse(
+            success=success,
+            trade_id=trade.id,
+            order_id=trade.kalshi_order_id,
+            client_order_id=trade.client_order_id,
+            filled=filled,
+            price=price,
+            cost=price * filled if price and filled else None,
+            error=None if success else "order with this client_order_id was rejected",
+        )
+
     async def execute_from_signal(
         self,
         signal_id: int,
         contracts: int,
+        client_order_id: Optional[str] = None,
     ) -> TradeResponse:
         """
         Execute a trade from a signal.
@@ -170,6 +208,7 @@ class TradeExecutor:
         Args:
             signal_id: ID of the TradeSignal
             contracts: Number of contracts to trade
+            client_order_id: Caller's idempotency key (see execute_trade)
 
         Returns:
             TradeResponse with execution results
@@ -207,16 +246,22 @@ class TradeExecutor:
             strike=strike,
             contracts=contracts,
             signal_id=str(signal_id),
+            client_order_id=client_order_id,
         )
 
         return await self.execute_trade(request)
 
-    async def close_position(self, trade_id: int) -> TradeResponse:
+    async def close_position(
+        self,
+        trade_id: int,
+        contracts: Optional[int] = None,
+    ) -> TradeResponse:
         """
-        Close an open position by selling.
+        Close an open position, or part of it, by selling.
 
         Args:
             trade_id: ID of the Trade to close
+            contracts: Contracts to sell (default: the whole position)
 
         Returns:
             TradeResponse with close results
@@ -236,6 +281,14 @@ class TradeExecutor:
                 error=f"Trade is not open (status: {trade.status})",
             )
 
+        count = trade.filled_contracts if contracts is None else contracts
+        if count < 1 or count > trade.filled_contracts:
+            return TradeResponse(
+                success=False,
+                trade_id=trade.id,
+                error=f"Can't close {count} contracts: {trade.filled_contracts} are open",
+            )
+
         # Place sell order
         side = OrderSide.YES if trade.direction == "YES" else OrderSide.NO
         client_order_id = f"basilisk_close_{uuid.uuid4().hex[:12]}"
@@ -244,28 +297,49 @@ class TradeExecutor:
             ticker=trade.ticker,
             side=side,
             action=OrderAction.SELL,
-            count=trade.filled_contracts,
+            count=count,
             order_type=OrderType.MARKET,
             client_order_id=client_order_id,
         )
 
         if close_result.success and close_result.filled_count > 0:
-            # Calculate P&L
             exit_price = close_result.avg_price / 100.0 if close_result.avg_price else 0
-            trade.exit_price = exit_price
-            trade.status = "CLOSED"
-            trade.closed_at = datetime.utcnow()
-
-            # P&L calculation
-            gross_pnl = (exit_price - trade.entry_price) * trade.filled_contracts
-            if gross_pnl > 0:
-                # Apply 7% fee on profits
-                fee = gross_pnl * settings.kalshi_fee_rate
-                trade.fees = fee
-                trade.pnl = gross_pnl - fee
+            sold = min(close_result.filled_count, trade.filled_contracts)
+            fees, pnl = self._realize(trade.entry_price, exit_price, sold)
+            now = datetime.utcnow()
+
+            if sold >= trade.filled_contracts:
+                trade.exit_price = exit_price
+                trade.status = "CLOSED"
+                trade.closed_at = now
+                trade.fees = fees
+                trade.pnl = pnl
             else:
-                trade.fees = 0
-                trade.pnl = gross_pnl
+                # The sold part becomes its own closed trade; the rest stays open
+                self.db.add(
+                    Trade(
+                        signal_id=trade.signal_id,
+                        asset=trade.asset,
+                        ticker=trade.ticker,
+                        direction=trade.direction,
+                        strike=trade.strike,
+                        contracts=sold,
+                        entry_price=trade.entry_price,
+                        exit_price=exit_price,
+                        fees=fees,
+                        pnl=pnl,
+                        status="CLOSED",
+                        client_order_id=client_order_id,
+                        builder_code_used=trade.builder_code_used,
+                        filled_contracts=sold,
+                        avg_fill_price=trade.avg_fill_price,
+                        opened_at=trade.opened_at,
+                        closed_at=now,
+                        expiry_at=trade.expiry_at,
+                    )
+                )
+                trade.contracts -= sold
+                trade.filled_contracts -= sold
 
             await self.db.commit()
 
@@ -273,9 +347,9 @@ class TradeExecutor:
                 success=True,
                 trade_id=trade.id,
                 order_id=close_result.order_id,
-                filled=close_result.filled_count,
+                filled=sold,
                 price=exit_price,
-                cost=trade.pnl,  # Using cost field for P&L in close
+                cost=pnl,  # Using cost field for P&L in close
             )
         else:
             return TradeResponse(
@@ -284,6 +358,16 @@ class TradeExecutor:
                 error=close_result.error or "Failed to close position",
             )
 
+    @staticmethod
+    def _realize(entry_price: float, exit_price: float, contracts: int) -> tuple[float, float]:
+        """Fees and net P&L of selling `contracts` bought at `entry_price`."""
+        gross_pnl = (exit_price - entry_price) * contracts
+        if gross_pnl > 0:
+            # Apply 7% fee on profits
+            fee = gross_pnl * settings.kalshi_fee_rate
+            return fee, gross_pnl - fee
+        return 0, gross_pnl
+
     async def get_open_positions(self) -> list[PositionSummary]:
         """
         Get all open positions with live data.
@@ -0,0 +1,20 @@
+//! Terminal price distributions implied by a strip of binary prices.
+
+/// Probability mass in each bucket between strikes, from the probability of
+/// finishing above each strike (strikes ascending).
+///
+/// Returns one more bucket than strikes: below the first strike, between each
+/// adjacent pair, and above the last. A noisy strip can price a higher strike
+/// as more likely than a lower one; those probabilities are capped at the one
+/// below so no bucket goes negative, and the masses always sum to 1.
+pub fn bucket_masses(prob_above: &[f64]) -> Vec<f64> {
+    let mut masses = Vec::with_capacity(prob_above.len() + 1);
+    let mut previous = 1.0;
+    for p in prob_above {
+        let p = p.clamp(0.0, 1.0).min(previous);
+        masses.push(previous - p);
+        previous = p;
+    }
+    masses.push(previous);
+    masses
+}
@@ -0,0 +1,22 @@
+//! Pricing and volatility math for Kalshi Bitcoin hourly contracts.
+//!
+//! The numerical core of Basilisk, kept free of UI, network and storage
+//! dependencies so it can be tested, benchmarked and reused on its own (the
+//! CLI's dashboard, stress tests and backtester all build on it).
+//!
+//! - [`pricing`]: binary option probabilities, fair values, deltas and
+//!   vol-adjusted distance under GBM, mirroring the backend's DVOL +
+//!   Black-Scholes model
+//! - [`volatility`]: realized vol from a price series and regime
+//!   classification
+//! - [`monte_carlo`]: terminal BTC price simulation and percentiles
+//! - [`distribution`]: the distribution of BTC at expiry implied by binary
+//!   prices across strikes
+//!
+//! Volatilities are annualized fractions (0.45 for 45%), horizons are in
+//! hours and prices in dollars throughout.
+
+pub mod distribution;
+pub mod monte_carlo;
+pub mod pricing;
+pub mod volatility;
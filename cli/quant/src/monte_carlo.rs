@@ -0,0 +1,86 @@
+//! Monte Carlo simulation of BTC at expiry.
+
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+use crate::pricing;
+
+/// BTC at expiry for one standard normal shock `z` under driftless GBM.
+///
+/// Taking the shock as an argument lets several positions share one draw per
+/// path, so strikes on the same underlying win and lose together.
+pub fn terminal_price(spot: f64, annual_vol: f64, hours: f64, z: f64) -> f64 {
+    let sigma_t = pricing::horizon_vol(annual_vol, hours);
+    spot * (-0.5 * sigma_t * sigma_t + sigma_t * z).exp()
+}
+
+/// `paths` terminal BTC prices under driftless GBM, sorted ascending
+pub fn simulate_terminal<R: Rng>(
+    rng: &mut R,
+    spot: f64,
+    annual_vol: f64,
+    hours: f64,
+    paths: usize,
+) -> Vec<f64> {
+    let mut prices: Vec<f64> = (0..paths)
+        .map(|_| terminal_price(spot, annual_vol, hours, rng.sample(StandardNormal)))
+        .collect();
+    prices.sort_by(|a, b| a.total_cmp(b));
+    prices
+}
+
+/// Value at quantile `p` (0.0 - 1.0) of ascending `sorted` samples,
+/// nearest rank. Panics on an empty slice.
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    sorted[((sorted.len() - 1) as f64 * p.clamp(0.0, 1.0)).round() as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn percentile_takes_the_nearest_rank() {
+        let sorted = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(percentile(&sorted, 0.0), 1.0);
+        assert_eq!(percentile(&sorted, 0.5), 3.0);
+        assert_eq!(percentile(&sorted, 0.6), 3.0);
+        assert_eq!(percentile(&sorted, 0.65), 4.0);
+        assert_eq!(percentile(&sorted, 1.0), 5.0);
+    }
+
+    #[test]
+    fn percentile_clamps_the_quantile() {
+        let sorted = [10.0, 20.0];
+        assert_eq!(percentile(&sorted, -0.5), 10.0);
+        assert_eq!(percentile(&sorted, 1.5), 20.0);
+        assert_eq!(percentile(&[7.0], 0.95), 7.0);
+    }
+
+    #[test]
+    fn a_zero_shock_loses_only_the_vol_drag() {
+        let price = terminal_price(95_000.0, 0.5, 1.0, 0.0);
+        assert!(price < 95_000.0 && price > 94_990.0, "{}", price);
+        assert_eq!(terminal_price(95_000.0, 0.5, 0.0, 2.0), 95_000.0);
+    }
+
+    #[test]
+    fn simulated_prices_are_sorted_and_centred_on_spot() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let prices = simulate_terminal(&mut rng, 95_000.0, 0.5, 1.0, 20_000);
+        assert_eq!(prices.len(), 20_000);
+        assert!(prices.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        // Driftless GBM keeps the mean at spot
+        let mean = prices.iter().sum::<f64>() / prices.len() as f64;
+        assert!((mean / 95_000.0 - 1.0).abs() < 0.001, "{}", mean);
+
+        // The simulated share above a strike matches the closed form
+        let strike = 95_500.0;
+        let above = prices.iter().filter(|&&p| p > strike).count() as f64 / prices.len() as f64;
+        let expected = pricing::prob_above(95_000.0, strike, 0.5, 1.0);
+        assert!((above - expected).abs() < 0.01, "{} vs {}", above, expected);
+    }
+}
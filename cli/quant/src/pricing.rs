@@ -0,0 +1,191 @@
+//! Pricing engine for hourly binary contracts.
+//!
+//! Mirrors the backend's DVOL + Black-Scholes approach closely enough for
+//! client-side what-if analysis (exports, stress tests, backtests) without
+//! another round-trip to the API. Spot is modelled as driftless GBM unless a
+//! drift is given.
+
+/// Hours in a (crypto, 24/7) year, used to annualize volatility.
+const HOURS_PER_YEAR: f64 = 365.0 * 24.0;
+
+/// Standard normal cumulative distribution function
+pub fn norm_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+/// Standard normal probability density function
+pub fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Error function (Abramowitz & Stegun 7.1.26, max error ~1.5e-7)
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
+/// Volatility scaled to the remaining contract lifetime (sigma * sqrt(T))
+pub fn horizon_vol(annual_vol: f64, hours: f64) -> f64 {
+    annual_vol * (hours.max(0.0) / HOURS_PER_YEAR).sqrt()
+}
+
+/// Probability that spot finishes above strike at expiry (driftless GBM)
+pub fn prob_above(spot: f64, strike: f64, annual_vol: f64, hours: f64) -> f64 {
+    prob_above_with_drift(spot, strike, annual_vol, hours, 0.0)
+}
+
+/// As [`prob_above`], with spot expected to drift by `drift_per_hour` (a
+/// fraction, e.g. 0.001 for +0.1%/h) on top of the random walk
+pub fn prob_above_with_drift(
+    spot: f64,
+    strike: f64,
+    annual_vol: f64,
+    hours: f64,
+    drift_per_hour: f64,
+) -> f64 {
+    if spot <= 0.0 || strike <= 0.0 {
+        return 0.5;
+    }
+
+    let sigma_t = horizon_vol(annual_vol, hours);
+    if sigma_t <= 0.0 {
+        // At (or past) expiry the outcome is already decided
+        return if spot > strike { 1.0 } else { 0.0 };
+    }
+
+    let drift = drift_per_hour * hours.max(0.0);
+    let d2 = ((spot / strike).ln() + drift - 0.5 * sigma_t * sigma_t) / sigma_t;
+    norm_cdf(d2)
+}
+
+/// Fair value of one side of a binary contract in dollars (0.00 - 1.00)
+pub fn binary_value(direction: &str, spot: f64, strike: f64, annual_vol: f64, hours: f64) -> f64 {
+    let p_yes = prob_above(spot, strike, annual_vol, hours);
+    if direction.eq_ignore_ascii_case("NO") {
+        1.0 - p_yes
+    } else {
+        p_yes
+    }
+}
+
+/// Change in one side's fair value per $1 move in spot (per contract).
+///
+/// Positive for YES, negative for NO; zero at expiry or without inputs.
+pub fn binary_delta(direction: &str, spot: f64, strike: f64, annual_vol: f64, hours: f64) -> f64 {
+    let sigma_t = horizon_vol(annual_vol, hours);
+    if spot <= 0.0 || strike <= 0.0 || sigma_t <= 0.0 {
+        return 0.0;
+    }
+
+    let d2 = ((spot / strike).ln() - 0.5 * sigma_t * sigma_t) / sigma_t;
+    let delta_yes = norm_pdf(d2) / (spot * sigma_t);
+    if direction.eq_ignore_ascii_case("NO") {
+        -delta_yes
+    } else {
+        delta_yes
+    }
+}
+
+/// Distance to strike in standard deviations of the remaining move.
+///
+/// Positive when the given side is currently winning, so the number reads the
+/// same way for YES and NO positions.
+pub fn vol_adjusted_distance(
+    direction: &str,
+    spot: f64,
+    strike: f64,
+    annual_vol: f64,
+    hours: f64,
+) -> Option<f64> {
+    let sigma_t = horizon_vol(annual_vol, hours);
+    if spot <= 0.0 || strike <= 0.0 || sigma_t <= 0.0 {
+        return None;
+    }
+
+    let distance = (spot / strike).ln() / sigma_t;
+    if direction.eq_ignore_ascii_case("NO") {
+        Some(-distance)
+    } else {
+        Some(distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-6;
+
+    #[test]
+    fn norm_cdf_matches_known_values() {
+        assert!((norm_cdf(0.0) - 0.5).abs() < EPS);
+        assert!((norm_cdf(1.96) - 0.975_002).abs() < 1e-5);
+        assert!((norm_cdf(-1.0) + norm_cdf(1.0) - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn at_the_money_is_a_coin_flip_less_the_vol_drag() {
+        let p = prob_above(95_000.0, 95_000.0, 0.5, 1.0);
+        assert!(p < 0.5 && p > 0.49, "{}", p);
+    }
+
+    #[test]
+    fn prob_above_falls_as_the_strike_rises() {
+        let low = prob_above(95_000.0, 94_000.0, 0.5, 1.0);
+        let high = prob_above(95_000.0, 96_000.0, 0.5, 1.0);
+        assert!(low > 0.5 && high < 0.5 && low > high);
+    }
+
+    #[test]
+    fn expired_contracts_are_decided() {
+        assert_eq!(prob_above(95_000.0, 94_000.0, 0.5, 0.0), 1.0);
+        assert_eq!(prob_above(95_000.0, 96_000.0, 0.5, -1.0), 0.0);
+        assert_eq!(prob_above(0.0, 96_000.0, 0.5, 1.0), 0.5);
+    }
+
+    #[test]
+    fn positive_drift_raises_the_yes_probability() {
+        let flat = prob_above(95_000.0, 95_500.0, 0.5, 2.0);
+        let drifting = prob_above_with_drift(95_000.0, 95_500.0, 0.5, 2.0, 0.002);
+        assert!(drifting > flat);
+    }
+
+    #[test]
+    fn yes_and_no_values_sum_to_a_dollar() {
+        let yes = binary_value("YES", 95_000.0, 95_800.0, 0.6, 0.5);
+        let no = binary_value("no", 95_000.0, 95_800.0, 0.6, 0.5);
+        assert!((yes + no - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn delta_matches_a_finite_difference() {
+        let (spot, strike, vol, hours) = (95_000.0, 95_500.0, 0.5, 1.0);
+        let bumped = (prob_above(spot + 1.0, strike, vol, hours)
+            - prob_above(spot - 1.0, strike, vol, hours))
+            / 2.0;
+        let delta = binary_delta("YES", spot, strike, vol, hours);
+        assert!((delta - bumped).abs() < 1e-7, "{} vs {}", delta, bumped);
+        assert_eq!(binary_delta("NO", spot, strike, vol, hours), -delta);
+        assert_eq!(binary_delta("YES", spot, strike, vol, 0.0), 0.0);
+    }
+
+    #[test]
+    fn distance_reads_positive_for_the_winning_side() {
+        let yes = vol_adjusted_distance("YES", 96_000.0, 95_000.0, 0.5, 1.0).unwrap_or(0.0);
+        let no = vol_adjusted_distance("NO", 96_000.0, 95_000.0, 0.5, 1.0).unwrap_or(0.0);
+        assert!(yes > 0.0);
+        assert_eq!(no, -yes);
+        assert_eq!(
+            vol_adjusted_distance("YES", 96_000.0, 95_000.0, 0.5, 0.0),
+            None
+        );
+    }
+}
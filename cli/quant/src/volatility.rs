@@ -0,0 +1,90 @@
+//! Realized volatility and regime classification.
+
+/// Seconds in a (crypto, 24/7) year, used to annualize volatility
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 3600.0;
+
+/// Realized-vol ceilings for each regime, as the backend classifies them
+const REGIME_RV_CEILINGS: [(&str, f64); 3] = [("CALM", 0.30), ("NORMAL", 0.50), ("ELEVATED", 0.75)];
+
+/// Annualized realized vol of `(seconds, price)` samples, oldest first.
+///
+/// Squared log returns are summed and scaled by the time the samples span,
+/// so irregularly spaced ticks are fine. None for fewer than two samples or
+/// when they span less than `min_span_secs`.
+pub fn realized_vol<I>(samples: I, min_span_secs: f64) -> Option<f64>
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let mut samples = samples.into_iter();
+    let (first_at, mut previous) = samples.next()?;
+    let mut last_at = first_at;
+    let mut sum_squares = 0.0;
+    for (at, price) in samples {
+        let log_return = (price / previous).ln();
+        sum_squares += log_return * log_return;
+        previous = price;
+        last_at = at;
+    }
+
+    let span = last_at - first_at;
+    if span <= 0.0 || span < min_span_secs {
+        return None;
+    }
+    Some((sum_squares / span * SECONDS_PER_YEAR).sqrt())
+}
+
+/// Regime for a realized vol: CALM, NORMAL, ELEVATED or CRISIS.
+///
+/// The backend also weighs implied vol; this is the RV-only half of its
+/// thresholds, for when there is no IV to go on.
+pub fn regime(realized_vol: f64) -> &'static str {
+    REGIME_RV_CEILINGS
+        .iter()
+        .find(|(_, ceiling)| realized_vol <= *ceiling)
+        .map(|(regime, _)| *regime)
+        .unwrap_or("CRISIS")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn realized_vol_needs_two_samples_and_the_minimum_span() {
+        assert_eq!(realized_vol(Vec::new(), 0.0), None);
+        assert_eq!(realized_vol(vec![(0.0, 100.0)], 0.0), None);
+        assert_eq!(realized_vol(vec![(0.0, 100.0), (30.0, 101.0)], 60.0), None);
+        assert_eq!(realized_vol(vec![(10.0, 100.0), (10.0, 101.0)], 0.0), None);
+    }
+
+    #[test]
+    fn a_flat_series_has_no_vol() {
+        let samples = (0..10).map(|i| (i as f64 * 60.0, 95_000.0));
+        assert_eq!(realized_vol(samples, 60.0), Some(0.0));
+    }
+
+    #[test]
+    fn realized_vol_annualizes_over_the_span() {
+        // One 1% move over an hour: ln(1.01)² per hour, scaled to a year
+        let vol = realized_vol(vec![(0.0, 100.0), (3600.0, 101.0)], 60.0).unwrap_or(0.0);
+        let expected = (1.01_f64.ln().powi(2) * 365.0 * 24.0).sqrt();
+        assert!((vol - expected).abs() < 1e-9, "{} vs {}", vol, expected);
+    }
+
+    #[test]
+    fn irregular_spacing_only_depends_on_the_total_span() {
+        let even = realized_vol(vec![(0.0, 100.0), (60.0, 101.0), (120.0, 100.0)], 0.0);
+        let uneven = realized_vol(vec![(0.0, 100.0), (5.0, 101.0), (120.0, 100.0)], 0.0);
+        assert_eq!(even, uneven);
+    }
+
+    #[test]
+    fn regime_uses_inclusive_ceilings() {
+        assert_eq!(regime(0.10), "CALM");
+        assert_eq!(regime(0.30), "CALM");
+        assert_eq!(regime(0.31), "NORMAL");
+        assert_eq!(regime(0.50), "NORMAL");
+        assert_eq!(regime(0.75), "ELEVATED");
+        assert_eq!(regime(0.76), "CRISIS");
+    }
+}
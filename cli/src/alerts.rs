@@ -0,0 +1,361 @@
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, Utc};
+use clap::Subcommand;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::api::{Contract, VolatilityData};
+use crate::mute::MuteList;
+use crate::scripting::ScriptHooks;
+use crate::store;
+
+const ALERTS_FILE: &str = "alerts.jsonl";
+
+/// EV at or above this fires a new-opportunity alert (5%)
+const HIGH_EV_THRESHOLD: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "INFO",
+            Severity::Warning => "WARN",
+            Severity::Critical => "CRIT",
+        }
+    }
+}
+
+/// A triggered alert as persisted in the data directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    pub id: u64,
+    pub timestamp: DateTime<Utc>,
+    pub severity: Severity,
+    pub kind: String,
+    pub message: String,
+    #[serde(default)]
+    pub context: serde_json::Value,
+    #[serde(default)]
+    pub acknowledged_at: Option<DateTime<Utc>>,
+}
+
+impl Alert {
+    /// Critical alerts stay active until someone acknowledges them
+    pub fn needs_ack(&self) -> bool {
+        self.severity == Severity::Critical && self.acknowledged_at.is_none()
+    }
+}
+
+/// Watches market updates, fires alerts, and persists every one of them
+pub struct AlertEngine {
+    history: Vec<Alert>,
+    fired: HashSet<String>, // Dedupe keys so a condition alerts once per session
+    last_regime: Option<String>,
+}
+
+impl AlertEngine {
+    pub fn load() -> Self {
+        Self {
+            history: store::read_jsonl(ALERTS_FILE).unwrap_or_default(),
+            fired: HashSet::new(),
+            last_regime: None,
+        }
+    }
+
+    /// Check a fresh contracts/volatility snapshot for alert conditions
+    pub fn evaluate(
+        &mut self,
+        contracts: &[Contract],
+        volatility: &VolatilityData,
+        script: Option<&ScriptHooks>,
+        mutes: &MuteList,
+    ) -> Result<()> {
+        // Regime escalation
+        if !volatility.regime.is_empty() {
+            let changed = self.last_regime.as_deref() != Some(volatility.regime.as_str());
+            if changed {
+                let severity = match volatility.regime.as_str() {
+                    "CRISIS" => Some(Severity::Critical),
+                    "ELEVATED" => Some(Severity::Warning),
+                    _ => None,
+                };
+                // Only alert on transitions, not on the first reading after launch
+                if let (Some(severity), Some(previous)) = (severity, self.last_regime.as_deref()) {
+                    // Without IV the regime was estimated locally from RV
+                    let readings = if volatility.implied_vol > 0.0 {
+                        format!(
+                            "RV {:.0}%, IV {:.0}%",
+                            volatility.realized_vol * 100.0,
+                            volatility.implied_vol * 100.0
+                        )
+                    } else {
+                        format!(
+                            "RV {:.0}% from ticks, est.",
+                            volatility.realized_vol * 100.0
+                        )
+                    };
+                    let message = format!(
+                        "Volatility regime changed {} → {} ({})",
+                        previous, volatility.regime, readings
+                    );
+                    let context = serde_json::json!({
+                        "from": previous,
+                        "to": volatility.regime,
+                        "realized_vol": volatility.realized_vol,
+                        "implied_vol": volatility.implied_vol,
+                    });
+                    self.trigger(severity, "regime_change", message, context)?;
+                }
+                self.last_regime = Some(volatility.regime.clone());
+            }
+        }
+
+        // New high-EV opportunities
+        for contract in contracts {
+            if contract.signal_type == "HOLD" || contract.expected_value < HIGH_EV_THRESHOLD {
+                continue;
+            }
+            // Muted contracts stay eligible, so they can still alert once the mute ends
+            if mutes.is_muted(contract) {
+                continue;
+            }
+            // The user's script filter can veto an opportunity (script errors still alert)
+            if let Some(false) = script.and_then(|s| s.filter(contract).ok().flatten()) {
+                continue;
+            }
+            let key = format!("high_ev:{}", contract.ticker);
+            if !self.fired.insert(key) {
+                continue;
+            }
+
+            let message = format!(
+                "{} {} at {} ({} EV)",
+                contract.signal_type,
+                contract.ticker,
+                contract.strike_display(),
+                contract.ev_display()
+            );
+            let context = serde_json::json!({
+                "ticker": contract.ticker,
+                "signal_id": contract.id,
+                "signal_type": contract.signal_type,
+                "expected_value": contract.expected_value,
+                "implied_probability": contract.implied_probability,
+                "model_probability": contract.model_probability,
+            });
+            self.trigger(Severity::Info, "high_ev", message, context)?;
+        }
+
+        Ok(())
+    }
+
+    /// Record a lost data connection
+    pub fn connection_lost(&mut self, reason: &str) -> Result<()> {
+        // One alert per outage: re-armed by connection_restored()
+        if !self.fired.insert("connection_lost".to_string()) {
+            return Ok(());
+        }
+        let context = serde_json::json!({ "reason": reason });
+        self.trigger(
+            Severity::Warning,
+            "connection_lost",
+            format!("Live data connection lost: {}", reason),
+            context,
+        )
+    }
+
+    /// Record that `basilisk auto` suspended itself under `[auto.pause]`
+    pub fn auto_paused(&mut self, reason: &str) -> Result<()> {
+        let context = serde_json::json!({ "reason": reason });
+        self.trigger(
+            Severity::Critical,
+            "auto_paused",
+            format!("Auto-trader paused: {}", reason),
+            context,
+        )
+    }
+
+    /// Record a position `basilisk monitor` closed (or failed to close) at its loss limit
+    pub fn stop_loss(&mut self, message: String, context: serde_json::Value) -> Result<()> {
+        self.trigger(Severity::Critical, "stop_loss", message, context)
+    }
+
+    pub fn connection_restored(&mut self) {
+        self.fired.remove("connection_lost");
+    }
+
+    /// Oldest critical alert still waiting for acknowledgement
+    pub fn pending_critical(&self) -> Option<&Alert> {
+        self.history.iter().find(|alert| alert.needs_ack())
+    }
+
+    pub fn pending_critical_count(&self) -> usize {
+        self.history
+            .iter()
+            .filter(|alert| alert.needs_ack())
+            .count()
+    }
+
+    /// Pick up alerts other processes appended since the last read
+    pub fn reload(&mut self) -> Result<()> {
+        self.history = store::read_jsonl(ALERTS_FILE)?;
+        Ok(())
+    }
+
+    /// Acknowledge every outstanding alert (clears the critical banner).
+    /// Starts from the file, not memory: `monitor`, `auto` and `brackets`
+    /// append their own alerts, and a rewrite from memory would drop them.
+    pub fn acknowledge_all(&mut self) -> Result<usize> {
+        self.history = store::read_jsonl(ALERTS_FILE)?;
+        let count = acknowledge(&mut self.history, None)?;
+        if count > 0 {
+            store::write_jsonl(ALERTS_FILE, &self.history)?;
+        }
+        Ok(count)
+    }
+
+    fn trigger(
+        &mut self,
+        severity: Severity,
+        kind: &str,
+        message: String,
+        context: serde_json::Value,
+    ) -> Result<()> {
+        // Other processes append too; number after whatever they wrote
+        if let Ok(history) = store::read_jsonl(ALERTS_FILE) {
+            self.history = history;
+        }
+        let alert = Alert {
+            id: self.history.iter().map(|a| a.id).max().unwrap_or(0) + 1,
+            timestamp: Utc::now(),
+            severity,
+            kind: kind.to_string(),
+            message,
+            context,
+            acknowledged_at: None,
+        };
+
+        store::append_jsonl(ALERTS_FILE, &alert)?;
+        self.history.push(alert);
+        Ok(())
+    }
+}
+
+/// Mark one alert (or all unacknowledged ones) as acknowledged
+fn acknowledge(history: &mut [Alert], id: Option<u64>) -> Result<usize> {
+    let now = Utc::now();
+    let mut count = 0;
+
+    for alert in history.iter_mut() {
+        let selected = match id {
+            Some(id) => alert.id == id,
+            None => true,
+        };
+        if selected && alert.acknowledged_at.is_none() {
+            alert.acknowledged_at = Some(now);
+            count += 1;
+        }
+    }
+
+    if let Some(id) = id {
+        if count == 0 && !history.iter().any(|a| a.id == id) {
+            bail!("No alert with ID {}", id);
+        }
+    }
+    Ok(count)
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AlertsCommands {
+    /// Show alert history (newest first)
+    #[command(name = "list")]
+    List {
+        /// Only show alerts that haven't been acknowledged
+        #[arg(long)]
+        unacked: bool,
+        /// Number of alerts to show
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// Acknowledge an alert by ID, or all with --all
+    #[command(name = "ack")]
+    Ack {
+        /// Alert ID to acknowledge
+        #[arg(required_unless_present = "all")]
+        id: Option<u64>,
+        /// Acknowledge every outstanding alert
+        #[arg(long, conflicts_with = "id")]
+        all: bool,
+    },
+}
+
+pub fn handle_alerts_command(cmd: AlertsCommands) -> Result<()> {
+    let mut history: Vec<Alert> = store::read_jsonl(ALERTS_FILE)?;
+
+    match cmd {
+        AlertsCommands::List { unacked, limit } => {
+            let alerts: Vec<&Alert> = history
+                .iter()
+                .rev()
+                .filter(|alert| !unacked || alert.acknowledged_at.is_none())
+                .take(limit)
+                .collect();
+
+            if alerts.is_empty() {
+                println!("🔕 No alerts.");
+                return Ok(());
+            }
+
+            println!("🔔 Alerts ({}):", alerts.len());
+            println!("{}", "─".repeat(90));
+            println!(
+                "{:<6} {:<20} {:<5} {:<4} Message",
+                "ID", "Time", "Sev", "Ack"
+            );
+            println!("{}", "─".repeat(90));
+
+            for alert in alerts {
+                let color = match alert.severity {
+                    Severity::Critical => "\x1b[31m",
+                    Severity::Warning => "\x1b[33m",
+                    Severity::Info => "\x1b[0m",
+                };
+                let ack = if alert.acknowledged_at.is_some() {
+                    "✓"
+                } else {
+                    " "
+                };
+
+                println!(
+                    "{:<6} {:<20} {}{:<5}\x1b[0m {:<4} {}",
+                    alert.id,
+                    alert
+                        .timestamp
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M:%S"),
+                    color,
+                    alert.severity.label(),
+                    ack,
+                    alert.message
+                );
+            }
+            println!("{}", "─".repeat(90));
+        }
+
+        AlertsCommands::Ack { id, all } => {
+            let count = acknowledge(&mut history, if all { None } else { id })?;
+            store::write_jsonl(ALERTS_FILE, &history)?;
+            println!("✅ Acknowledged {} alert(s).", count);
+        }
+    }
+
+    Ok(())
+}
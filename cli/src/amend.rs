@@ -0,0 +1,191 @@
+//! Adjusting a resting limit order's price or size (`basilisk amend`, and `i`
+//! on the dashboard's Orders tab).
+//!
+//! The exchange has no in-place modify, so an amend is a cancel followed by a
+//! new limit order. Two things keep that from misfiring when a step times out
+//! and the command is run again:
+//!
+//! - the original must still be resting, so a second run can't cancel twice or
+//!   replace an order that has since filled;
+//! - the replacement carries a client order ID derived from the original order
+//!   and the new terms, so a backend that deduplicates client order IDs answers
+//!   a resent replacement (`--retry`) with the order it already placed. Older
+//!   backends drop the ID, and a resend there can place the replacement twice.
+//!
+//! Contracts that fill while the cancel is in flight are not re-ordered: without
+//! `--size` the replacement only covers what was still unfilled at cancel time.
+
+use anyhow::{bail, Result};
+
+use crate::api::client::ApiClient;
+use crate::api::models::{Order, TradeRequest, TradeResponse};
+use crate::audit::{self, AuditKind};
+use crate::journal::{self, Origin};
+
+/// Outcome of a cancel/replace
+pub struct Amendment {
+    /// Final state of the original order
+    pub cancelled: Order,
+    /// None when the original filled completely before the cancel landed
+    pub replacement: Option<TradeResponse>,
+    /// Audit or journal writes that failed; they never stop the replacement
+    pub warnings: Vec<String>,
+}
+
+/// Client order ID for the replacement: same original and terms, same ID
+fn replacement_id(order_id: &str, limit_cents: i32, contracts: i32) -> String {
+    format!(
+        "basilisk_amend_{}_{}c_x{}",
+        order_id, limit_cents, contracts
+    )
+}
+
+/// Cancel `order_id` and re-place it at the new price and/or size
+pub async fn amend(
+    client: &ApiClient,
+    order_id: &str,
+    price: Option<f64>,
+    size: Option<i32>,
+    source: &str,
+) -> Result<Amendment> {
+    if price.is_none() && size.is_none() {
+        bail!("Nothing to amend: pass --price and/or --size");
+    }
+    let limit_cents = match price {
+        Some(price) if (0.01..=0.99).contains(&price) => Some((price * 100.0).round() as i32),
+        Some(price) => bail!(
+            "Limit price must be between $0.01 and $0.99 (got {})",
+            price
+        ),
+        None => None,
+    };
+    if size.is_some_and(|size| size < 1) {
+        bail!("--size must be at least 1");
+    }
+
+    let original = client.get_order(order_id).await?;
+    if !original.is_resting() {
+        bail!(
+            "Order {} is {}, not resting; a previous amend may already have replaced it \
+             (check `basilisk audit --kind cancel`)",
+            order_id,
+            original.status
+        );
+    }
+    let Some(limit_cents) = limit_cents.or(original.limit_price) else {
+        bail!("Order {} has no limit price; pass --price", order_id);
+    };
+    if limit_cents == original.limit_price.unwrap_or_default()
+        && size.is_none_or(|size| size == original.remaining)
+    {
+        bail!("Order {} already rests at those terms", order_id);
+    }
+
+    let cancelled = client.cancel_order(order_id).await;
+    let description = format!("amend: cancel order {}", order_id);
+    let mut warnings = Vec::new();
+    if let Err(e) = audit::record_cancel(source, &description, &cancelled) {
+        warnings.push(format!("Could not write audit log: {}", e));
+    }
+    let cancelled = cancelled?;
+
+    let contracts = size.unwrap_or(cancelled.remaining);
+    if contracts < 1 {
+        return Ok(Amendment {
+            cancelled,
+            replacement: None,
+            warnings,
+        });
+    }
+
+    let request = TradeRequest {
+        ticker: cancelled.ticker.clone(),
+        asset: cancelled.asset.clone(),
+        direction: cancelled.direction.to_uppercase(),
+        strike: cancelled.strike,
+        contracts,
+        order_type: "limit".to_string(),
+        limit_price: Some(limit_cents),
+        signal_id: None,
+        client_order_id: Some(replacement_id(order_id, limit_cents, contracts)),
+        tag: None,
+        note: None,
+    };
+    let description = format!(
+        "amend {}: {} {} x{} limit ${:.2}",
+        order_id,
+        request.ticker,
+        request.direction,
+        contracts,
+        limit_cents as f64 / 100.0
+    );
+    let response = client.execute_trade(request).await;
+    if let Err(e) = audit::record_result(AuditKind::Order, source, &description, &response) {
+        warnings.push(format!("Could not write audit log: {}", e));
+    }
+    let response = response?;
+    if let Err(e) = journal::record(&cancelled.ticker, Origin::Manual, None, &response) {
+        warnings.push(format!("Could not write trade journal: {}", e));
+    }
+
+    Ok(Amendment {
+        cancelled,
+        replacement: Some(response),
+        warnings,
+    })
+}
+
+pub async fn handle_amend_command(
+    api_url: &str,
+    retry_orders: bool,
+    order_id: &str,
+    price: Option<f64>,
+    size: Option<i32>,
+) -> Result<()> {
+    let client = ApiClient::new(api_url.to_string(), 30)?.with_order_retry(retry_orders);
+
+    println!("Amending order {}...", order_id);
+    println!();
+
+    let amendment = amend(&client, order_id, price, size, "cli").await?;
+    for warning in &amendment.warnings {
+        println!("⚠️  {}", warning);
+    }
+    let cancelled = &amendment.cancelled;
+    println!(
+        "🗑  Cancelled: {} {} {} left @ {} ({} filled)",
+        cancelled.ticker,
+        cancelled.direction,
+        cancelled.remaining,
+        cancelled.limit_display(),
+        cancelled.filled()
+    );
+
+    match amendment.replacement {
+        None => println!("✅ Order filled before the cancel landed; nothing to replace."),
+        Some(response) if response.success => {
+            println!("✅ Replacement placed!");
+            if let Some(id) = &response.order_id {
+                println!("   Order ID: {}", id);
+            }
+            if response.filled > 0 {
+                println!(
+                    "   Filled: {} contracts{}",
+                    response.filled,
+                    response
+                        .price
+                        .map(|p| format!(" @ ${:.2}", p))
+                        .unwrap_or_default()
+                );
+            }
+        }
+        Some(response) => {
+            println!("❌ Replacement Failed! The original order is already cancelled.");
+            if let Some(error) = response.error {
+                println!("   Error: {}", error);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,15 +1,50 @@
-use anyhow::{Context, Result};
-use reqwest::Client;
-use std::time::Duration;
+use anyhow::{bail, Context, Result};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use std::time::{Duration, Instant};
 
 use super::models::{
-    BtcPriceResponse, CurrentResponse, HealthResponse, HourlyStats, PnLSummary,
-    Position, SignalTradeRequest, TradeHistory, TradeRequest, TradeResponse, VolatilitySkew,
+    BalanceResponse, BtcPriceResponse, CurrentResponse, HealthResponse, HourlyStats, Order,
+    OrderBook, PnLSummary, Position, SignalTradeRequest, TradeHistory, TradeRequest, TradeResponse,
+    VolatilitySkew,
 };
 
+/// Tries per order submission when the request times out or can't connect
+const ORDER_ATTEMPTS: u32 = 3;
+
+/// Wait before the first resend; doubles each time
+const ORDER_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Idempotency key for an order that didn't come with one
+pub fn new_client_order_id() -> String {
+    format!(
+        "basilisk-{}-{:08x}",
+        chrono::Utc::now().timestamp_millis(),
+        rand::random::<u32>()
+    )
+}
+
+/// A rejection for reusing a client order ID, as opposed to any other refusal
+fn is_duplicate(response: &TradeResponse) -> bool {
+    !response.success
+        && response.error.as_deref().is_some_and(|error| {
+            let error = error.to_lowercase();
+            error.contains("duplicate") || error.contains("client_order_id already")
+        })
+}
+
+/// Failures where the order may not have reached the backend, or the
+/// backend couldn't reach the exchange
+enum Attempt {
+    Done(TradeResponse),
+    Transient(anyhow::Error),
+}
+
 pub struct ApiClient {
     client: Client,
     base_url: String,
+    /// Set by `--retry`; otherwise every order is sent exactly once
+    retry_orders: bool,
 }
 
 impl ApiClient {
@@ -19,7 +54,17 @@ impl ApiClient {
             .build()
             .context("Failed to create HTTP client")?;
 
-        Ok(Self { client, base_url })
+        Ok(Self {
+            client,
+            base_url,
+            retry_orders: false,
+        })
+    }
+
+    /// Turn automatic order resends on or off for this client
+    pub fn with_order_retry(mut self, enabled: bool) -> Self {
+        self.retry_orders = enabled;
+        self
     }
 
     #[allow(dead_code)]
@@ -57,6 +102,28 @@ impl ApiClient {
         Ok(current)
     }
 
+    /// Contracts and volatility for any supported asset (btc, eth, sol, ...)
+    pub async fn get_asset_contracts(&self, asset: &str) -> Result<CurrentResponse> {
+        let url = format!(
+            "{}/api/v1/contracts/{}",
+            self.base_url,
+            asset.to_lowercase()
+        );
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to send {} contracts request", asset))?;
+
+        let current = response
+            .json::<CurrentResponse>()
+            .await
+            .with_context(|| format!("Failed to parse {} contracts response", asset))?;
+
+        Ok(current)
+    }
+
     #[allow(dead_code)]
     pub async fn get_btc_price(&self) -> Result<BtcPriceResponse> {
         let url = format!("{}/api/v1/btc-price", self.base_url);
@@ -76,7 +143,10 @@ impl ApiClient {
     }
 
     pub async fn get_hourly_stats(&self) -> Result<HourlyStats> {
-        let url = format!("{}/api/v1/statistics/hourly-movements?hours=720", self.base_url);
+        let url = format!(
+            "{}/api/v1/statistics/hourly-movements?hours=720",
+            self.base_url
+        );
         let response = self
             .client
             .get(&url)
@@ -113,44 +183,232 @@ impl ApiClient {
     // Trading API Methods
     // ============================================
 
-    /// Execute a trade
-    pub async fn execute_trade(&self, request: TradeRequest) -> Result<TradeResponse> {
-        let url = format!("{}/api/v1/trade", self.base_url);
-        let response = self
-            .client
-            .post(&url)
-            .json(&request)
+    /// Measure round-trip time to the trade endpoint
+    pub async fn ping_trade_endpoint(&self) -> Result<Duration> {
+        let url = format!("{}/api/v1/trade/positions", self.base_url);
+        let started = Instant::now();
+        self.client
+            .get(&url)
             .send()
             .await
-            .context("Failed to send trade request")?;
+            .context("Failed to reach trade endpoint")?
+            .error_for_status()
+            .context("Trade endpoint returned an error")?;
+
+        Ok(started.elapsed())
+    }
+
+    /// Execute a trade. It always goes with a client order ID, which the
+    /// backend uses to answer a resend with the order it already placed.
+    pub async fn execute_trade(&self, mut request: TradeRequest) -> Result<TradeResponse> {
+        let url = format!("{}/api/v1/trade", self.base_url);
+        let client_order_id = request
+            .client_order_id
+            .get_or_insert_with(new_client_order_id)
+            .clone();
+        self.submit_order(&url, &request, &client_order_id)
+            .await
+            .context("Failed to send trade request")
+    }
+
+    /// Execute a trade from a signal (with a client order ID, like `execute_trade`)
+    pub async fn execute_from_signal(
+        &self,
+        signal_id: i32,
+        contracts: i32,
+    ) -> Result<TradeResponse> {
+        let url = format!("{}/api/v1/trade/signal", self.base_url);
+        let client_order_id = new_client_order_id();
+        let request = SignalTradeRequest {
+            signal_id,
+            contracts,
+            client_order_id: Some(client_order_id.clone()),
+        };
+        self.submit_order(&url, &request, &client_order_id)
+            .await
+            .context("Failed to send signal trade request")
+    }
+
+    /// POST an order, resending it with the same client order ID after a
+    /// timeout, connection failure or gateway error (with `--retry`)
+    async fn submit_order<T: Serialize>(
+        &self,
+        url: &str,
+        body: &T,
+        client_order_id: &str,
+    ) -> Result<TradeResponse> {
+        let attempts = if self.retry_orders { ORDER_ATTEMPTS } else { 1 };
+        let mut delay = ORDER_RETRY_DELAY;
+        let mut attempt = 1;
+        loop {
+            match self.post_order(url, body).await? {
+                Attempt::Done(response) if attempt > 1 && is_duplicate(&response) => {
+                    // The exchange refuses a repeated client order ID, so an
+                    // earlier try went through: report that order instead
+                    return self.placed_order(client_order_id).await.with_context(|| {
+                        format!(
+                            "Order {} was placed by an earlier attempt but couldn't be looked up; check `basilisk orders` before sending it again",
+                            client_order_id
+                        )
+                    });
+                }
+                Attempt::Done(response) => return Ok(response),
+                Attempt::Transient(e) if attempt >= attempts => return Err(e),
+                Attempt::Transient(_) => {
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    async fn post_order<T: Serialize>(&self, url: &str, body: &T) -> Result<Attempt> {
+        let response = match self.client.post(url).json(body).send().await {
+            Ok(response) => response,
+            Err(e) if e.is_timeout() || e.is_connect() => return Ok(Attempt::Transient(e.into())),
+            Err(e) => return Err(e.into()),
+        };
+        let status = response.status();
+        if matches!(
+            status,
+            StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+        ) {
+            return Ok(Attempt::Transient(anyhow::anyhow!(
+                "backend returned {}",
+                status
+            )));
+        }
 
         let result = response
             .json::<TradeResponse>()
             .await
             .context("Failed to parse trade response")?;
+        Ok(Attempt::Done(result))
+    }
 
-        Ok(result)
+    /// An order the backend already has under `client_order_id`, as a trade
+    /// response carrying the position its fills went into
+    async fn placed_order(&self, client_order_id: &str) -> Result<TradeResponse> {
+        let order = self
+            .get_orders()
+            .await?
+            .into_iter()
+            .find(|o| o.client_order_id.as_deref() == Some(client_order_id))
+            .context("No order with that client order ID")?;
+        let filled = order.filled();
+        let trade_id = match order.trade_id {
+            Some(id) => Some(id),
+            // Older backends don't link orders to positions: the newest open
+            // position on the same contract and side is the one it filled
+            None if filled > 0 => self
+                .get_positions()
+                .await?
+                .into_iter()
+                .filter(|p| {
+                    p.ticker == order.ticker && p.direction.eq_ignore_ascii_case(&order.direction)
+                })
+                .map(|p| p.trade_id)
+                .max(),
+            None => None,
+        };
+        if filled > 0 && trade_id.is_none() {
+            bail!(
+                "Order {} filled but no open position matches it",
+                order.order_id
+            );
+        }
+        let price = order.limit_price.map(|cents| cents as f64 / 100.0);
+        Ok(TradeResponse {
+            success: true,
+            trade_id,
+            order_id: Some(order.order_id.clone()),
+            client_order_id: Some(client_order_id.to_string()),
+            filled,
+            price,
+            cost: price.map(|price| price * filled as f64),
+            error: None,
+        })
     }
 
-    /// Execute a trade from a signal
-    pub async fn execute_from_signal(&self, signal_id: i32, contracts: i32) -> Result<TradeResponse> {
-        let url = format!("{}/api/v1/trade/signal", self.base_url);
-        let request = SignalTradeRequest { signal_id, contracts };
+    /// Orders placed through the backend, newest first (resting and finished)
+    pub async fn get_orders(&self) -> Result<Vec<Order>> {
+        let url = format!("{}/api/v1/trade/orders", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send orders request")?
+            .error_for_status()
+            .context("Orders request failed")?;
+
+        let orders = response
+            .json::<Vec<Order>>()
+            .await
+            .context("Failed to parse orders response")?;
 
+        Ok(orders)
+    }
+
+    /// Get a limit order by exchange order ID
+    pub async fn get_order(&self, order_id: &str) -> Result<Order> {
+        let url = format!("{}/api/v1/trade/orders/{}", self.base_url, order_id);
         let response = self
             .client
-            .post(&url)
-            .json(&request)
+            .get(&url)
             .send()
             .await
-            .context("Failed to send signal trade request")?;
+            .context("Failed to send order request")?
+            .error_for_status()
+            .context("Order lookup failed")?;
 
-        let result = response
-            .json::<TradeResponse>()
+        let order = response
+            .json::<Order>()
             .await
-            .context("Failed to parse trade response")?;
+            .context("Failed to parse order response")?;
 
-        Ok(result)
+        Ok(order)
+    }
+
+    /// Cancel a resting order; returns its final state (fills before the cancel included)
+    pub async fn cancel_order(&self, order_id: &str) -> Result<Order> {
+        let url = format!("{}/api/v1/trade/orders/{}", self.base_url, order_id);
+        let response = self
+            .client
+            .delete(&url)
+            .send()
+            .await
+            .context("Failed to send cancel request")?
+            .error_for_status()
+            .context("Cancel failed")?;
+
+        let order = response
+            .json::<Order>()
+            .await
+            .context("Failed to parse cancel response")?;
+
+        Ok(order)
+    }
+
+    /// Resting bids on both sides of a contract
+    pub async fn get_orderbook(&self, ticker: &str) -> Result<OrderBook> {
+        let url = format!("{}/api/v1/orderbook/{}", self.base_url, ticker);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send orderbook request")?
+            .error_for_status()
+            .context("Orderbook lookup failed")?;
+
+        let book = response
+            .json::<OrderBook>()
+            .await
+            .context("Failed to parse orderbook response")?;
+
+        Ok(book)
     }
 
     /// Get open positions
@@ -189,6 +447,27 @@ impl ApiClient {
         Ok(result)
     }
 
+    /// Close part of a position: a market sell of `contracts` of it
+    pub async fn close_contracts(&self, trade_id: i32, contracts: i32) -> Result<TradeResponse> {
+        let url = format!(
+            "{}/api/v1/trade/positions/{}?contracts={}",
+            self.base_url, trade_id, contracts
+        );
+        let response = self
+            .client
+            .delete(&url)
+            .send()
+            .await
+            .context("Failed to send partial close request")?;
+
+        let result = response
+            .json::<TradeResponse>()
+            .await
+            .context("Failed to parse close response")?;
+
+        Ok(result)
+    }
+
     /// Get trade history
     pub async fn get_trade_history(&self, limit: i32) -> Result<Vec<TradeHistory>> {
         let url = format!("{}/api/v1/trade/history?limit={}", self.base_url, limit);
@@ -224,4 +503,24 @@ impl ApiClient {
 
         Ok(summary)
     }
+
+    /// Get account balance
+    pub async fn get_balance(&self) -> Result<BalanceResponse> {
+        let url = format!("{}/api/v1/trade/balance", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send balance request")?
+            .error_for_status()
+            .context("Balance request failed")?;
+
+        let balance = response
+            .json::<BalanceResponse>()
+            .await
+            .context("Failed to parse balance response")?;
+
+        Ok(balance)
+    }
 }
@@ -2,4 +2,4 @@ pub mod client;
 pub mod models;
 
 pub use client::ApiClient;
-pub use models::{Contract, VolatilityData, HourlyStats, VolatilitySkew};
+pub use models::{parse_timestamp, Contract, HourlyStats, VolatilityData, VolatilitySkew};
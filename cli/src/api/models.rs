@@ -1,4 +1,6 @@
-use chrono::DateTime;
+use anyhow::{Context, Result};
+use basilisk_quant::volatility;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +53,43 @@ pub struct CurrentResponse {
     pub volatility: VolatilityData,
 }
 
+impl VolatilityData {
+    /// False when the endpoint came back empty: all-zero readings are missing
+    /// data, not a calm market
+    pub fn is_available(&self) -> bool {
+        self.realized_vol > 0.0 || self.implied_vol > 0.0
+    }
+
+    /// Stand-in from a locally estimated realized vol (e.g. streamed ticks) for
+    /// when the endpoint is empty. The regime is classified from RV alone; there
+    /// is no IV, so no premium either.
+    pub fn estimated(realized_vol: f64) -> Self {
+        Self {
+            realized_vol,
+            regime: volatility::regime(realized_vol).to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Best available annualized vol: implied (DVOL) if present, else realized
+    pub fn effective_vol(&self) -> Option<f64> {
+        if self.implied_vol > 0.0 {
+            Some(self.implied_vol)
+        } else if self.realized_vol > 0.0 {
+            Some(self.realized_vol)
+        } else {
+            None
+        }
+    }
+}
+
+impl CurrentResponse {
+    /// BTC spot price as reported alongside the contracts
+    pub fn btc_price(&self) -> Option<f64> {
+        self.contracts.first().and_then(|c| c.current_btc_price)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthResponse {
     pub status: String,
@@ -83,6 +122,12 @@ pub struct VolatilitySkew {
 }
 
 impl Contract {
+    /// Asset the contract settles on (see [`ticker_asset`])
+    pub fn asset(&self) -> Result<String> {
+        ticker_asset(&self.ticker)
+            .with_context(|| format!("Can't tell which asset {} settles on", self.ticker))
+    }
+
     /// Calculate distance from current BTC price to strike price
     pub fn distance_dollars(&self) -> f64 {
         match (self.current_btc_price, self.strike_price) {
@@ -94,13 +139,16 @@ impl Contract {
     /// Calculate distance as percentage
     pub fn distance_percent(&self) -> f64 {
         match (self.current_btc_price, self.strike_price) {
-            (Some(current), Some(strike)) if strike != 0.0 => {
-                (current - strike) / strike * 100.0
-            }
+            (Some(current), Some(strike)) if strike != 0.0 => (current - strike) / strike * 100.0,
             _ => 0.0,
         }
     }
 
+    /// Bid-ask spread in dollars: how far the YES and NO asks sum above $1
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.yes_price? + self.no_price? - 1.0)
+    }
+
     /// Is current price above strike (more likely to expire YES)?
     pub fn is_above_strike(&self) -> bool {
         match (self.current_btc_price, self.strike_price) {
@@ -109,25 +157,34 @@ impl Contract {
         }
     }
 
+    /// Seconds until expiry, from the expiry timestamp when it parses
+    /// (`time_to_expiry_hours` is only as fresh as the last snapshot)
+    pub fn seconds_to_expiry(&self) -> Option<i64> {
+        match self.expiry_time.as_deref().and_then(parse_timestamp) {
+            Some(expiry) => Some((expiry - Utc::now()).num_seconds()),
+            None => self
+                .time_to_expiry_hours
+                .map(|hours| (hours * 3600.0) as i64),
+        }
+    }
+
     /// Is contract expiring soon (< 10 minutes)?
     pub fn is_near_expiry(&self) -> bool {
-        match self.time_to_expiry_hours {
-            Some(hours) => hours * 60.0 < 10.0,
+        match self.seconds_to_expiry() {
+            Some(secs) => secs < 600,
             None => false,
         }
     }
 
-    /// Format time to expiry as human-readable string
+    /// Format time to expiry as human-readable string, to the second inside
+    /// the last hour (live from the expiry timestamp, not the last snapshot)
     pub fn time_left_display(&self) -> String {
-        match self.time_to_expiry_hours {
-            Some(hours) if hours < 0.0 => "EXPIRED".to_string(),
-            Some(hours) if hours < 1.0 => {
-                let minutes = (hours * 60.0) as i64;
-                format!("{}m", minutes)
-            }
-            Some(hours) => {
-                let h = hours as i64;
-                let m = ((hours - h as f64) * 60.0) as i64;
+        match self.seconds_to_expiry() {
+            Some(secs) if secs < 0 => "EXPIRED".to_string(),
+            Some(secs) if secs < 3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+            Some(secs) => {
+                let h = secs / 3600;
+                let m = secs % 3600 / 60;
                 if m > 0 {
                     format!("{}h{}m", h, m)
                 } else {
@@ -195,12 +252,25 @@ pub struct TradeRequest {
     pub limit_price: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signal_id: Option<String>,
+    /// Idempotency key. The backend answers a request it has already seen with
+    /// the order that request placed, instead of placing another (backends
+    /// older than that ignore it, which is why resends are opt-in).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    /// Strategy tag and free-form note; the journal keeps them too, for
+    /// backends that don't store them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignalTradeRequest {
     pub signal_id: i32,
     pub contracts: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -215,6 +285,105 @@ pub struct TradeResponse {
     pub error: Option<String>,
 }
 
+/// A limit order as reported by the backend (resting until filled or cancelled)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    pub order_id: String,
+    pub client_order_id: Option<String>,
+    pub ticker: String,
+    pub asset: String,
+    pub direction: String,
+    pub strike: f64,
+    /// Limit in cents
+    pub limit_price: Option<i32>,
+    /// Contracts originally ordered
+    pub contracts: i32,
+    /// Contracts still unfilled
+    pub remaining: i32,
+    /// "resting", "canceled" or "executed"
+    pub status: String,
+    pub created_at: Option<String>,
+    /// Position the fills went into, when the backend reports it
+    #[serde(default)]
+    pub trade_id: Option<i32>,
+}
+
+impl Order {
+    pub fn is_resting(&self) -> bool {
+        self.status.eq_ignore_ascii_case("resting")
+    }
+
+    pub fn filled(&self) -> i32 {
+        self.contracts - self.remaining
+    }
+
+    pub fn limit_display(&self) -> String {
+        match self.limit_price {
+            Some(cents) => format!("${:.2}", cents as f64 / 100.0),
+            None => "market".to_string(),
+        }
+    }
+}
+
+/// One price level of the order book; `total` is the running depth up to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookLevel {
+    pub price: f64,
+    pub quantity: i32,
+    pub total: i32,
+}
+
+impl OrderBookLevel {
+    pub fn price_cents(&self) -> i32 {
+        (self.price * 100.0).round() as i32
+    }
+}
+
+/// Market depth from `/api/v1/orderbook/{ticker}`. Bids are best (highest) first,
+/// asks cheapest first; a YES ask at p is a NO bid at 100 − p.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBook {
+    pub yes_bids: Vec<OrderBookLevel>,
+    pub yes_asks: Vec<OrderBookLevel>,
+    pub no_bids: Vec<OrderBookLevel>,
+    pub no_asks: Vec<OrderBookLevel>,
+    pub spread: f64,
+    pub mid_price: f64,
+    pub source: String,
+}
+
+impl OrderBook {
+    fn depth_at(levels: &[OrderBookLevel], price: i32) -> i32 {
+        levels
+            .iter()
+            .filter(|level| level.price_cents() == price)
+            .map(|level| level.quantity)
+            .sum()
+    }
+
+    /// Contracts bid for the `no` (or YES) side at exactly `price` cents
+    pub fn bid_at(&self, no: bool, price: i32) -> i32 {
+        Self::depth_at(if no { &self.no_bids } else { &self.yes_bids }, price)
+    }
+
+    /// Contracts offered to a buyer of the `no` (or YES) side at exactly `price` cents
+    pub fn offered_at(&self, no: bool, price: i32) -> i32 {
+        Self::depth_at(if no { &self.no_asks } else { &self.yes_asks }, price)
+    }
+
+    /// Offers to a buyer of the `no` (or YES) side as (price in cents, contracts), cheapest first
+    pub fn offers(&self, no: bool) -> Vec<(i32, i32)> {
+        let asks = if no { &self.no_asks } else { &self.yes_asks };
+        let mut offers: Vec<(i32, i32)> = asks
+            .iter()
+            .filter(|level| level.quantity > 0)
+            .map(|level| (level.price_cents(), level.quantity))
+            .collect();
+        offers.sort_by_key(|(price, _)| *price);
+        offers
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub trade_id: i32,
@@ -232,6 +401,32 @@ pub struct Position {
 }
 
 impl Position {
+    /// Hours remaining until the position's contract expires
+    pub fn hours_to_expiry(&self) -> Option<f64> {
+        let expiry = parse_timestamp(self.expiry_at.as_deref()?)?;
+        Some((expiry - Utc::now()).num_seconds() as f64 / 3600.0)
+    }
+
+    /// Within the last 10 minutes before expiry
+    pub fn is_near_expiry(&self) -> bool {
+        self.hours_to_expiry()
+            .is_some_and(|hours| hours * 60.0 < 10.0)
+    }
+
+    /// Time to expiry as "45m" / "1h5m" ("EXPIRED" once past, "N/A" if unknown)
+    pub fn time_left_display(&self) -> String {
+        match self.hours_to_expiry() {
+            Some(hours) if hours < 0.0 => "EXPIRED".to_string(),
+            Some(hours) if hours < 1.0 => format!("{}m", (hours * 60.0) as i64),
+            Some(hours) => format!(
+                "{}h{}m",
+                hours as i64,
+                ((hours - hours.trunc()) * 60.0) as i64
+            ),
+            None => "N/A".to_string(),
+        }
+    }
+
     pub fn pnl_display(&self) -> String {
         match self.unrealized_pnl {
             Some(pnl) => format!("${:+.2}", pnl),
@@ -262,6 +457,11 @@ pub struct TradeHistory {
     pub status: String,
     pub opened_at: String,
     pub closed_at: Option<String>,
+    /// Filled in from the journal when the backend doesn't return them
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
 }
 
 impl TradeHistory {
@@ -284,3 +484,78 @@ pub struct PnLSummary {
     pub losses: i32,
     pub win_rate: f64,
 }
+
+/// Kalshi account balance (amounts in cents)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceResponse {
+    pub balance: i64,
+}
+
+impl BalanceResponse {
+    pub fn dollars(&self) -> f64 {
+        self.balance as f64 / 100.0
+    }
+}
+
+/// Asset in a Kalshi ticker's series: KXBTCD-... → BTC, KXETH15M-... → ETH.
+/// None when the ticker doesn't follow that pattern.
+pub fn ticker_asset(ticker: &str) -> Option<String> {
+    let series = ticker.split('-').next().unwrap_or_default();
+    let series = series.strip_prefix("KX").unwrap_or(series);
+    let series = series.strip_suffix("15M").unwrap_or(series);
+    let series = series.strip_suffix('D').unwrap_or(series);
+    (series.len() >= 2 && series.chars().all(|c| c.is_ascii_alphabetic()))
+        .then(|| series.to_uppercase())
+}
+
+/// Parse a backend timestamp, accepting both RFC 3339 and naive UTC forms
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticker_asset_reads_the_series() {
+        let asset = |ticker| ticker_asset(ticker).unwrap();
+        assert_eq!(asset("KXBTCD-25OCT1614-T95000"), "BTC");
+        assert_eq!(asset("KXETHD-25OCT1614-T3500"), "ETH");
+        assert_eq!(asset("KXSOL-25OCT1614-B150"), "SOL");
+        assert_eq!(asset("KXETH15M-25OCT161415-15"), "ETH");
+        assert_eq!(asset("KXBTCD"), "BTC");
+    }
+
+    #[test]
+    fn ticker_asset_refuses_unknown_series() {
+        assert_eq!(ticker_asset(""), None);
+        assert_eq!(ticker_asset("T95000"), None);
+        assert_eq!(ticker_asset("KX-25OCT"), None);
+        assert_eq!(ticker_asset("KXBTC5M-25OCT1614"), None);
+    }
+
+    #[test]
+    fn order_book_reads_the_backend_levels() {
+        let book: OrderBook = serde_json::from_str(
+            r#"{
+                "yes_bids": [{"price": 0.44, "quantity": 120, "total": 120}],
+                "yes_asks": [{"price": 0.46, "quantity": 80, "total": 80},
+                             {"price": 0.47, "quantity": 200, "total": 280}],
+                "no_bids": [{"price": 0.54, "quantity": 80, "total": 80}],
+                "no_asks": [{"price": 0.56, "quantity": 120, "total": 120}],
+                "spread": 0.02, "mid_price": 0.45, "source": "kalshi_ws"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(book.bid_at(false, 44), 120);
+        assert_eq!(book.offered_at(false, 47), 200);
+        assert_eq!(book.offered_at(true, 56), 120);
+        assert_eq!(book.offers(false), vec![(46, 80), (47, 200)]);
+    }
+}
@@ -1,130 +1,593 @@
-use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+};
 use eventsource_client as es;
 use futures::StreamExt;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Sparkline},
+    widgets::{Block, Borders, Paragraph, Tabs},
     Frame,
 };
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
-use crate::api::{ApiClient, Contract, VolatilityData, HourlyStats, VolatilitySkew};
-use crate::events::AppEvent;
-use crate::ui::{SignalsView, HourlyStatsView, VolSkewView};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ConnectionState {
-    Connected,
-    Disconnected,
-    Connecting,
-}
+use crate::alerts::AlertEngine;
+use crate::amend;
+use crate::api::models::{Order, PnLSummary, Position, TradeHistory, TradeRequest};
+use crate::api::{self, ApiClient, Contract, HourlyStats, VolatilityData, VolatilitySkew};
+use crate::archive::{self, ArchiveWriter};
+use crate::audit::{self, AuditKind};
+use crate::bankroll::{self, Balance};
+use crate::chart_history::ChartHistory;
+use crate::checklist::Checklist;
+use crate::compare::{self, Comparison};
+use crate::config::{Config, HooksConfig, LayoutMode, PollTarget, ThemeName};
+use crate::connection::{ConnectionMonitor, ConnectionState};
+use crate::consistency::{ConsistencyChecker, Source};
+use crate::diary::HourSnapshot;
+use crate::distribution;
+use crate::event_log::{EventLog, LogKind};
+use crate::events::{Action, AppEvent, Key, Keymap};
+use crate::exposure::{ExposureBook, ExposureSummary};
+use crate::feed_latency::FeedLatency;
+use crate::governor::{self, Governor, GovernorStatus};
+use crate::hedge::{self, HedgeLeg};
+use crate::hooks::Hooks;
+use crate::journal::{self, Origin};
+use crate::liquidity::{self, Impact, VolumeTracker};
+use crate::model_override::ModelOverride;
+use crate::momentum::{EdgeHistory, PriceTrend};
+use crate::mute::{MuteList, MuteTarget};
+use crate::order_guard::{LatencyMonitor, OrderGuard};
+use crate::pins::PinList;
+use crate::planner;
+use crate::portfolio;
+use crate::reentry::ReentryGuard;
+use crate::replay::ReplayBuffer;
+use crate::rollover::{self, Settlement};
+use crate::rules::{PriceRule, RuleBook, RuleStatus};
+use crate::score::Scorer;
+use crate::scripting::ScriptHooks;
+use crate::soak::Soak;
+use crate::spread::SpreadBook;
+use crate::store;
+use crate::stream_stats::{self, StreamStats};
+use crate::stress::{self, StressReport};
+use crate::tick_cache::{self, TickWriter};
+use crate::tilt::{Streak, TiltGuard};
+use crate::ui::amend_dialog;
+use crate::ui::btc_chart;
+use crate::ui::close_dialog;
+use crate::ui::diagnostics::Diagnostics;
+use crate::ui::orders;
+use crate::ui::pnl;
+use crate::ui::positions;
+use crate::ui::search::{self, SearchTarget};
+use crate::ui::settlement_watch::{self, SettlementPrices};
+use crate::ui::signals::{QuickFilter, SortKey};
+use crate::ui::vol_chart::VolPoint;
+use crate::ui::{
+    AmendDialog, ArchiveBrowser, BtcChart, CompareView, DetailView, DiagnosticsView, Dialog,
+    DialogResponse, DistributionView, HedgeDialog, HelpScroll, HistoryView, HourlyStatsView,
+    LogPane, OrdersView, OverrideDialog, PlanDialog, PnlView, PortfolioView, PositionsView,
+    RulesView, SearchOverlay, SettlementWatch, SignalsView, StressView, Theme, TradeDialog,
+    VolChart, VolSkewView,
+};
+use crate::vol_pattern::VolRecorder;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ViewMode {
     Signals,
     HourlyStats,
     VolSkew,
+    Portfolio,
+    Positions,
+    Pnl,
+    History,
+    Rules,
+    Distribution,
+    Orders,
+}
+
+impl ViewMode {
+    /// Tab bar order; number keys 1-9 then 0 follow it
+    const ALL: [ViewMode; 10] = [
+        ViewMode::Signals,
+        ViewMode::HourlyStats,
+        ViewMode::VolSkew,
+        ViewMode::Portfolio,
+        ViewMode::Positions,
+        ViewMode::Pnl,
+        ViewMode::History,
+        ViewMode::Rules,
+        ViewMode::Distribution,
+        ViewMode::Orders,
+    ];
+
+    fn tab_title(self) -> &'static str {
+        match self {
+            ViewMode::Signals => "Signals",
+            ViewMode::HourlyStats => "Hourly Stats",
+            ViewMode::VolSkew => "Vol Skew",
+            ViewMode::Portfolio => "Portfolio",
+            ViewMode::Positions => "Positions",
+            ViewMode::Pnl => "P&L",
+            ViewMode::History => "History",
+            ViewMode::Rules => "Rules",
+            ViewMode::Distribution => "Distribution",
+            ViewMode::Orders => "Orders",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|mode| *mode == self).unwrap_or(0)
+    }
+
+    /// Next tab (or previous with `back`), wrapping around
+    fn cycle(self, back: bool) -> Self {
+        let len = Self::ALL.len();
+        let step = if back { len - 1 } else { 1 };
+        Self::ALL[(self.index() + step) % len]
+    }
+}
+
+/// What confirming the open dialog does
+enum Pending {
+    /// Send the trade ticket's order
+    Trade,
+    ClosePosition(i32),
+    CancelOrder(String),
+    /// Cancel/replace: order ID, new price in dollars, new size
+    AmendOrder(String, Option<f64>, Option<i32>),
+    Quit,
 }
 
+/// A confirmation dialog and the action waiting on it
+struct Confirmation {
+    dialog: Dialog,
+    pending: Pending,
+}
+
+/// Headings 1-3 jump to in the help overlay
+const HELP_SECTIONS: [&str; 3] = [
+    "COLUMN EXPLANATIONS",
+    "KEYBOARD SHORTCUTS",
+    "VOLATILITY BANNER METRICS",
+];
+
+/// Trades loaded into the History tab (paged on screen)
+const HISTORY_LIMIT: i32 = 500;
+
+/// The settlement watch panel opens this long before an expiry
+const SETTLEMENT_WATCH_SECS: i64 = 600;
+
+/// Asset behind the signals table; other enabled assets only feed the volatility banner
+const PRIMARY_ASSET: &str = "BTC";
+
+/// Columns the signals table needs before it falls back to abbreviated cells
+const FULL_SIGNALS_WIDTH: u16 = 124;
+
+/// How often price rules are reloaded and checked against the BTC stream
+const RULES_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a toast stays in the footer
+const TOAST_DURATION: Duration = Duration::from_secs(8);
+
+/// How often the trade endpoint round-trip time is sampled
+const LATENCY_PROBE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How often the status bar's account balance is refetched
+const BALANCE_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
 pub struct App {
     api_client: ApiClient,
     api_url: String,
+    config: Config,
     view_mode: ViewMode,
-    extreme_mode: bool,  // Toggle for extreme volatility opportunities
+    extreme_mode: bool, // Toggle for extreme volatility opportunities
     signals_view: SignalsView,
     hourly_stats_view: HourlyStatsView,
     vol_skew_view: VolSkewView,
+    distribution_view: DistributionView,
+    stress_view: StressView,
+    portfolio_view: PortfolioView,
+    positions_view: PositionsView,
+    orders_view: OrdersView,
+    pnl_view: PnlView,
+    history_view: HistoryView,
+    rules_view: RulesView,
+    diagnostics_view: DiagnosticsView,
+    compare_view: CompareView,
+    detail_view: DetailView,
+    btc_chart: BtcChart,
+    vol_chart: VolChart,
+    settlement_watch: SettlementWatch,
     contracts: Vec<Contract>,
     current_btc_price: f64,
-    connection_state: ConnectionState,
+    btc_trend: PriceTrend,
+    connection: ConnectionMonitor,
+    consistency: ConsistencyChecker,
     last_update: Option<Instant>,
-    refresh_interval_secs: u64,
+    poll_failures: u32, // Consecutive failed signal polls, for backoff
     should_quit: bool,
     error_message: Option<String>,
+    toast: Option<(String, Instant)>, // Transient notice and when it was raised
     show_help: bool,
-    help_scroll: u16,
-    volatility_data: VolatilityData,
+    help_scroll: HelpScroll,
+    tabs_area: Rect, // Where the tab bar was last drawn, for mouse clicks
+    keymap: Keymap,
+    show_stress: bool,
+    stress_report: Option<StressReport>,
+    show_diagnostics: bool,
+    show_detail: bool,         // Full-screen detail of the highlighted signal
+    show_chart: bool,          // BTC and vol charts above the signals table ('g')
+    show_vol_banner: bool,     // Volatility regime banner ('b')
+    slim_bars: bool,           // One-line status bar and footer ('B')
+    layout: LayoutMode,        // --layout / [layout] mode, or 'z'; Auto follows the terminal size
+    theme: Theme,              // --theme / [layout] theme, or 'u'
+    screen: Rect,              // Terminal size at the last render
+    side_area: Rect,           // Positions pane beside the signals in the wide layout
+    edge_history: EdgeHistory, // Model − implied per contract, for the detail view
+    trade_dialog: Option<TradeDialog>,
+    hedge_dialog: Option<HedgeDialog>,
+    plan_dialog: Option<PlanDialog>, // Allocation planner basket ('P')
+    confirmation: Option<Confirmation>, // Confirm dialog on top of everything else
+    resting_orders: Vec<String>,     // Orders this session's tickets left resting, checked on quit
+    override_dialog: Option<OverrideDialog>,
+    amend_dialog: Option<AmendDialog>,
+    model_override: ModelOverride, // Settings behind the My% column ('o')
+    search: Option<SearchOverlay>,
+    archive_browser: Option<ArchiveBrowser>,
+    event_log: EventLog, // Scrollback of stream, error and trade events
+    hooks: Hooks,        // Shell commands run on fills, rules, regime changes and outages
+    log_pane: LogPane,
+    show_log: bool, // Log pane under the main view ('l'); takes the motion keys
+    compare_marks: Vec<i32>, // Contract IDs marked with 'c' (at most two)
+    comparison: Option<Comparison>, // Side-by-side view of the two marked contracts
+    auto_status: Option<GovernorStatus>,
+    rule_governor: Governor, // Throttles orders fired by price rules
+    alerts: AlertEngine,
+    mutes: MuteList,
+    pins: PinList,
+    script: Option<ScriptHooks>,
+    trade_latency: LatencyMonitor,
+    feed_latency: FeedLatency,      // Age of SSE events on arrival
+    stream_stats: Arc<StreamStats>, // Event rate and reconnects, counted by the primary SSE task
+    volatility: BTreeMap<String, VolatilityData>, // Latest reading per enabled asset
+    vol_asset: String,              // Asset shown in the volatility banner
+    vol_recorder: VolRecorder,
     hourly_stats: HourlyStats,
     vol_skew: VolatilitySkew,
-    // Sparkline data (last 50 data points for visualization)
-    btc_price_history: Vec<u64>,        // BTC price history for sparkline
-    realized_vol_history: Vec<u64>,     // RV history for sparkline
-    implied_vol_history: Vec<u64>,      // IV history for sparkline
+    replay: ReplayBuffer, // Recent stream events, so newly opened tabs can backfill
+    ticks: Option<TickWriter>, // Persists streamed BTC ticks (None if [tick_cache] is off)
+    archive: ArchiveWriter, // Snapshots contracts for the archive browser
+    exposure: Option<ExposureBook>, // Open notional per expiry, for the portfolio tab
+    concentration: Option<ExposureSummary>, // The same positions by expiry and side
+    positions: Option<Vec<Position>>, // Positions tab
+    spreads: SpreadBook,  // Which positions are spread legs
+    orders: Option<Vec<Order>>, // Orders tab, resting only
+    pnl: Vec<Option<PnLSummary>>, // P&L tab, one per `pnl::PERIODS`
+    trade_history: Option<Vec<TradeHistory>>, // History tab
+    tilt: TiltGuard,      // Session win/loss streak
+    balance: Option<Balance>, // Cash, exposure and pending settlement for the status bar
+    impact: HashMap<i32, Impact>, // Default-size market impact per contract ID
+    sampling_liquidity: bool, // A liquidity sample is still fetching books
+    volume: VolumeTracker, // Recent traded volume per market, for the Liq column
+    // Chart data (last 50 data points, persisted across restarts)
+    chart_history: ChartHistory,
+    front_expiry_secs: Option<i64>, // Countdown to the soonest expiry, updated every Tick
+    expiry_passed: bool,            // Set by the Tick that sees the front contract expire
+    pending_diary: Option<Vec<Settlement>>, // Last rollover's settlements, for the diary
+    rules: RuleBook,                // Price-triggered orders, reloaded every check
+    btc_range: Option<(f64, f64)>,  // BTC low/high since the rules were last checked
+    soak: Option<Soak>,             // Headless soak test (--soak); no keyboard, quits when done
 }
 
 impl App {
-    pub fn new(api_url: String, refresh_interval_secs: u64) -> Result<Self> {
-        let api_client = ApiClient::new(api_url.clone(), 10)?;
+    pub fn new(
+        api_url: String,
+        refresh_interval_secs: Option<u64>,
+        read_only: bool,
+        retry_orders: bool,
+        layout: Option<LayoutMode>,
+        theme: Option<ThemeName>,
+        no_banner: bool,
+    ) -> Result<Self> {
+        let api_client = ApiClient::new(api_url.clone(), 10)?.with_order_retry(retry_orders);
+        let mut config = Config::load()?;
+        if let Some(secs) = refresh_interval_secs {
+            config.polling.interval_secs = secs;
+        }
+        config.read_only |= read_only;
+        let script = ScriptHooks::load(&config.scripting)?;
+        let volatility = config
+            .assets
+            .symbols()
+            .into_iter()
+            .map(|asset| (asset, VolatilityData::default()))
+            .collect();
+        let mut signals_view = SignalsView::new();
+        if config.score.sort_signals {
+            signals_view.set_sort(SortKey::Score);
+        }
+        signals_view.set_extreme_thresholds(config.extreme.clone());
+        signals_view.set_liquidity_thresholds(config.liquidity.clone());
+        signals_view.set_table_config(config.table.clone());
+        let extreme_mode = config.extreme.start_enabled;
+        let mut replay = ReplayBuffer::new();
+        if let Ok(ticks) = tick_cache::load(Utc::now() - chrono::Duration::hours(1)) {
+            replay.seed(&ticks);
+        }
+        let ticks = TickWriter::spawn(&config.tick_cache);
+        let archive = ArchiveWriter::new(&config.archive);
+        let volume = VolumeTracker::new(&config.liquidity);
+        let hooks = Hooks::new(&config.hooks);
+        let rule_governor = Governor::new(config.governor.clone());
+        let keymap = Keymap::new(&config.keymap)?;
+        let show_vol_banner = config.layout.show_vol_banner && !no_banner;
+        let slim_bars = config.layout.slim_bars;
+        let layout = layout.unwrap_or(config.layout.mode);
+        let theme = Theme::new(theme.unwrap_or(config.layout.theme));
 
         Ok(Self {
             api_client,
             api_url,
+            config,
             view_mode: ViewMode::Signals,
-            extreme_mode: false,
-            signals_view: SignalsView::new(),
+            extreme_mode,
+            signals_view,
             hourly_stats_view: HourlyStatsView::new(),
             vol_skew_view: VolSkewView::new(),
+            distribution_view: DistributionView::new(),
+            stress_view: StressView::new(),
+            portfolio_view: PortfolioView::new(),
+            positions_view: PositionsView::new(),
+            orders_view: OrdersView::new(),
+            pnl_view: PnlView::new(),
+            history_view: HistoryView::new(),
+            rules_view: RulesView::new(),
+            diagnostics_view: DiagnosticsView::new(),
+            compare_view: CompareView::new(),
+            detail_view: DetailView::new(),
+            btc_chart: BtcChart::new(),
+            vol_chart: VolChart::new(),
+            settlement_watch: SettlementWatch::new(),
             contracts: Vec::new(),
             current_btc_price: 0.0,
-            connection_state: ConnectionState::Connecting,
+            btc_trend: PriceTrend::new(),
+            connection: ConnectionMonitor::new(),
+            consistency: ConsistencyChecker::new(),
             last_update: None,
-            refresh_interval_secs,
+            poll_failures: 0,
             should_quit: false,
             error_message: None,
+            toast: None,
             show_help: false,
-            help_scroll: 0,
-            volatility_data: VolatilityData::default(),
+            tabs_area: Rect::default(),
+            keymap,
+            help_scroll: HelpScroll::new(),
+            show_stress: false,
+            stress_report: None,
+            show_diagnostics: false,
+            show_detail: false,
+            show_chart: true,
+            show_vol_banner,
+            slim_bars,
+            layout,
+            theme,
+            screen: Rect::default(),
+            side_area: Rect::default(),
+            edge_history: EdgeHistory::new(),
+            trade_dialog: None,
+            hedge_dialog: None,
+            plan_dialog: None,
+            confirmation: None,
+            resting_orders: Vec::new(),
+            override_dialog: None,
+            amend_dialog: None,
+            model_override: ModelOverride::load(),
+            search: None,
+            archive_browser: None,
+            event_log: EventLog::new(),
+            hooks,
+            log_pane: LogPane::new(),
+            show_log: false,
+            compare_marks: Vec::new(),
+            comparison: None,
+            auto_status: None,
+            rule_governor,
+            alerts: AlertEngine::load(),
+            mutes: MuteList::load(),
+            pins: PinList::load(),
+            script,
+            trade_latency: LatencyMonitor::new(),
+            feed_latency: FeedLatency::new(),
+            stream_stats: Arc::new(StreamStats::new()),
+            volatility,
+            vol_asset: PRIMARY_ASSET.to_string(),
+            vol_recorder: VolRecorder::new(),
             hourly_stats: HourlyStats::default(),
             vol_skew: VolatilitySkew::default(),
-            btc_price_history: Vec::new(),
-            realized_vol_history: Vec::new(),
-            implied_vol_history: Vec::new(),
+            replay,
+            ticks,
+            archive,
+            exposure: None,
+            concentration: None,
+            positions: None,
+            spreads: SpreadBook::load(),
+            orders: None,
+            pnl: Vec::new(),
+            trade_history: None,
+            tilt: TiltGuard::default(),
+            balance: None,
+            impact: HashMap::new(),
+            sampling_liquidity: false,
+            volume,
+            chart_history: ChartHistory::load(),
+            front_expiry_secs: None,
+            expiry_passed: false,
+            pending_diary: None,
+            rules: RuleBook::load(),
+            btc_range: None,
+            soak: None,
         })
     }
 
-    pub async fn run(&mut self, terminal: &mut ratatui::Terminal<impl ratatui::backend::Backend>) -> Result<()> {
-        // Initial data fetch (fallback if SSE fails)
+    /// Run headless as a soak test: `run` ignores the keyboard and returns
+    /// once the soak's duration is up. Hooks are switched off, so hours of
+    /// simulated regime changes and outages don't run the user's commands,
+    /// and so is the tick cache (its writer thread stops with the sender).
+    pub fn start_soak(&mut self, soak: Soak) {
+        self.soak = Some(soak);
+        self.hooks = Hooks::new(&HooksConfig::default());
+        self.ticks = None;
+    }
+
+    /// Soak test summary; errors if a leak or backlog threshold was exceeded
+    pub fn finish_soak(&self) -> Result<()> {
+        match &self.soak {
+            Some(soak) => soak.finish(),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn run(
+        &mut self,
+        terminal: &mut ratatui::Terminal<impl ratatui::backend::Backend>,
+    ) -> Result<()> {
+        // Initial data fetch (fallback if SSE fails), plus positions if the layout shows them
+        self.screen = terminal.size()?;
         self.fetch_data().await;
+        self.refresh_view().await;
+        self.fetch_asset_volatility().await;
+        self.refresh_tilt().await;
 
         // Create event channel for SSE messages
         let (event_tx, mut event_rx) = mpsc::unbounded_channel::<AppEvent>();
 
-        // Spawn SSE background task
-        Self::spawn_sse_task(self.api_url.clone(), event_tx);
+        // Spawn an SSE background task per enabled asset
+        for asset in self.volatility.keys() {
+            Self::spawn_sse_task(
+                self.api_url.clone(),
+                asset.clone(),
+                event_tx.clone(),
+                self.ticks.clone(),
+                self.stream_stats.clone(),
+            );
+        }
+        Self::spawn_tick_task(event_tx.clone());
+        // Sample trade endpoint latency so order warnings reflect current conditions
+        Self::spawn_latency_probe(self.api_url.clone(), event_tx.clone());
 
-        // Track polling fallback (every 30 seconds in case SSE fails)
+        // Track polling fallback (cadence depends on connection state)
         let mut last_fallback_update = Instant::now();
-        let fallback_interval = Duration::from_secs(30);
+
+        let mut last_status_load = Instant::now();
+        self.refresh_balance().await;
+        let mut last_balance_refresh = Instant::now();
+        let mut last_liquidity_sample: Option<Instant> = None;
+        let mut last_rules_check = Instant::now();
 
         loop {
+            self.event_log.error_shown(self.error_message.as_deref());
+            self.hooks
+                .stream(self.connection.state() == ConnectionState::Connected);
+            for failure in self.hooks.take_failures() {
+                self.event_log.error(&failure);
+            }
+
             // Draw UI
             terminal.draw(|frame| self.render(frame))?;
 
             // Process all pending SSE events (non-blocking)
+            if let Some(soak) = &mut self.soak {
+                soak.observe_backlog(event_rx.len());
+            }
             while let Ok(event) = event_rx.try_recv() {
                 self.handle_sse_event(event);
             }
 
+            // The front contract just expired: fetch the next hour's straight away
+            if std::mem::take(&mut self.expiry_passed) {
+                self.fetch_data().await;
+            }
+            if let Some(settled) = self.pending_diary.take() {
+                self.write_diary(settled).await;
+            }
+            if last_rules_check.elapsed() >= RULES_CHECK_INTERVAL {
+                self.check_rules().await;
+                last_rules_check = Instant::now();
+            }
+
             // Handle keyboard events with short timeout
             let timeout = Duration::from_millis(50);
-            if event::poll(timeout)? {
-                if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press {
-                        self.handle_key(key.code).await;
+            if self.soak.is_some() {
+                // Headless: no keyboard to read, just keep the loop's pace
+                tokio::time::sleep(timeout).await;
+            } else if event::poll(timeout)? {
+                match event::read()? {
+                    // Search opens from anywhere, dialogs included
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
+                        if self.keymap.action(Key::from_event(key)) == Some(Action::Search) {
+                            self.open_search().await;
+                        } else {
+                            self.handle_key(key).await;
+                        }
                     }
+                    Event::Mouse(mouse) => self.handle_mouse(mouse).await,
+                    _ => {}
+                }
+            }
+            // A lone `g` that never got its second key
+            if let Some(action) = self.keymap.expire() {
+                self.dispatch(action).await;
+            }
+
+            // Fallback polling while SSE is down (faster while reconnecting);
+            // while it's live, an occasional poll cross-checks the stream
+            if let Some(poll_interval) = self.poll_interval() {
+                if last_fallback_update.elapsed() >= poll_interval {
+                    self.poll().await;
+                    last_fallback_update = Instant::now();
+                }
+            }
+
+            if last_status_load.elapsed() >= LATENCY_PROBE_INTERVAL {
+                // `monitor --notify`, `auto` and `brackets` append alerts from other processes
+                if let Err(e) = self.alerts.reload() {
+                    self.error_message = Some(format!("Alerts: {}", e));
                 }
+                if self.show_diagnostics {
+                    self.load_auto_status();
+                }
+                last_status_load = Instant::now();
+            }
+
+            if last_balance_refresh.elapsed() >= BALANCE_REFRESH_INTERVAL {
+                self.refresh_balance().await;
+                last_balance_refresh = Instant::now();
             }
 
-            // Fallback polling: Only if SSE hasn't updated in 30 seconds
-            if self.connection_state == ConnectionState::Disconnected
-                && last_fallback_update.elapsed() >= fallback_interval
+            let sample_every = Duration::from_secs(self.config.liquidity.sample_secs);
+            if self.config.liquidity.sample_secs > 0
+                && !self.sampling_liquidity
+                && last_liquidity_sample.is_none_or(|at| at.elapsed() >= sample_every)
+                && !self.contracts.is_empty()
             {
-                self.fetch_data().await;
-                last_fallback_update = Instant::now();
+                self.sample_liquidity(event_tx.clone());
+                last_liquidity_sample = Some(Instant::now());
+            }
+
+            if let Some(soak) = &mut self.soak {
+                if soak.tick(self.stream_stats.reconnects()) {
+                    self.should_quit = true;
+                }
             }
 
             if self.should_quit {
@@ -132,152 +595,2167 @@ impl App {
             }
         }
 
+        // Keep the charts for the next launch
+        self.chart_history
+            .save()
+            .context("Failed to save chart history")?;
+
         Ok(())
     }
 
-    async fn handle_key(&mut self, key: KeyCode) {
-        match key {
-            KeyCode::Char('q') | KeyCode::Char('Q') => {
-                self.should_quit = true;
+    async fn handle_key(&mut self, event: KeyEvent) {
+        let key = event.code;
+        // A confirmation sits above any dialog that opened it
+        if self.confirmation.is_some() {
+            self.handle_confirmation_key(key).await;
+            return;
+        }
+        // The trade dialog captures all keys while open
+        if self.trade_dialog.is_some() {
+            self.handle_trade_dialog_key(key).await;
+            return;
+        }
+        if self.hedge_dialog.is_some() {
+            self.handle_hedge_dialog_key(key).await;
+            return;
+        }
+        if self.plan_dialog.is_some() {
+            self.handle_plan_dialog_key(key).await;
+            return;
+        }
+        if self.search.is_some() {
+            self.handle_search_key(key).await;
+            return;
+        }
+        if self.archive_browser.is_some() {
+            self.handle_archive_key(key);
+            return;
+        }
+        if self.override_dialog.is_some() {
+            self.handle_override_key(key);
+            return;
+        }
+        if self.amend_dialog.is_some() {
+            self.handle_amend_key(key);
+            return;
+        }
+        if self.view_mode == ViewMode::History
+            && (self.history_view.is_editing_filter() || self.history_view.is_detail_open())
+        {
+            self.handle_history_key(key);
+            return;
+        }
+
+        for action in self.keymap.press(Key::from_event(event)) {
+            self.dispatch(action).await;
+        }
+    }
+
+    async fn dispatch(&mut self, action: Action) {
+        let view = self.view_mode;
+        match action {
+            Action::Quit => {
+                self.request_quit().await;
             }
-            KeyCode::Char('r') | KeyCode::Char('R') => {
+            Action::Refresh => {
                 self.fetch_data().await;
+                self.refresh_view().await;
             }
-            KeyCode::Char('h') | KeyCode::Char('H') | KeyCode::Char('?') => {
+            Action::Help => {
                 self.show_help = !self.show_help;
-                self.help_scroll = 0; // Reset scroll when toggling help
+                self.help_scroll.scroll_to_top(); // Reset scroll when toggling help
             }
-            KeyCode::Esc => {
+            Action::Back => {
                 self.show_help = false;
-                self.help_scroll = 0;
+                self.help_scroll.scroll_to_top();
+                self.show_stress = false;
+                self.show_diagnostics = false;
+                self.show_detail = false;
+                self.show_log = false;
+                if self.comparison.take().is_some() {
+                    self.compare_marks.clear();
+                }
             }
-            // View switching
-            KeyCode::Char('1') => {
-                self.view_mode = ViewMode::Signals;
+            Action::Search => {
+                self.open_search().await;
+            }
+            Action::ToggleLog => {
+                self.show_log = !self.show_log;
+                self.log_pane.follow();
             }
-            KeyCode::Char('2') => {
-                self.view_mode = ViewMode::HourlyStats;
-                // Fetch hourly stats if not already loaded
-                if self.hourly_stats.total_samples == 0 {
-                    self.fetch_hourly_stats().await;
+            Action::Archive => match archive::entries() {
+                Ok(entries) => self.archive_browser = Some(ArchiveBrowser::new(entries)),
+                Err(e) => self.error_message = Some(format!("Failed to open the archive: {}", e)),
+            },
+            // Acknowledge pending alerts
+            Action::AcknowledgeAlerts => {
+                if let Err(e) = self.alerts.acknowledge_all() {
+                    self.error_message = Some(format!("Failed to acknowledge alerts: {}", e));
                 }
             }
-            KeyCode::Char('3') => {
-                self.view_mode = ViewMode::VolSkew;
-                // Fetch vol skew if not already loaded
-                if self.vol_skew.skew_interpretation.is_empty() {
-                    self.fetch_vol_skew().await;
+            // Stress-test overlay
+            Action::StressTest => {
+                self.show_stress = !self.show_stress;
+                if self.show_stress {
+                    self.fetch_stress_report().await;
+                }
+            }
+            // Trade the highlighted signal
+            Action::Trade if view == ViewMode::Signals && !self.config.read_only => {
+                self.open_trade_dialog().await;
+            }
+            // Mute the highlighted strike or its signal type
+            Action::MuteStrike if view == ViewMode::Signals => {
+                self.toggle_mute(false);
+            }
+            Action::MuteSignalType if view == ViewMode::Signals => {
+                self.toggle_mute(true);
+            }
+            // Pin the highlighted strike to the top of the table
+            Action::Pin if view == ViewMode::Signals => {
+                self.toggle_pin();
+            }
+            // Cycle the sort column or flip its direction
+            Action::CycleSort if view == ViewMode::Signals => {
+                self.signals_view.cycle_sort();
+            }
+            Action::ReverseSort if view == ViewMode::Signals => {
+                self.signals_view.reverse_sort();
+            }
+            // Show or hide the BTC chart above the signals table
+            Action::ToggleChart if view == ViewMode::Signals => {
+                self.show_chart = !self.show_chart;
+            }
+            // Model override settings for the My% column
+            Action::ModelOverride if view == ViewMode::Signals => {
+                self.override_dialog = Some(OverrideDialog::new(&self.model_override));
+            }
+            // Drill into the highlighted signal
+            Action::Open if view == ViewMode::Signals => {
+                self.show_detail = !self.show_detail && self.signals_view.selected_id().is_some();
+            }
+            Action::Open if view == ViewMode::History => {
+                self.open_history_detail();
+            }
+            // Mark the highlighted signal for side-by-side compare
+            Action::Compare if view == ViewMode::Signals => {
+                self.toggle_compare_mark();
+            }
+            // Spread the hourly budget across the front expiry's signals
+            Action::Plan if view == ViewMode::Signals && !self.config.read_only => {
+                self.open_plan_dialog().await;
+            }
+            // Plan a hedge back to delta-neutral for the front expiry
+            Action::Hedge if view == ViewMode::Portfolio && !self.config.read_only => {
+                self.open_hedge_dialog().await;
+            }
+            // Cancel the highlighted price rule
+            Action::ClosePosition if view == ViewMode::Rules => {
+                self.cancel_selected_rule();
+            }
+            // Close the highlighted position
+            Action::ClosePosition if view == ViewMode::Positions && !self.config.read_only => {
+                self.open_close_dialog();
+            }
+            // Cancel the highlighted resting order
+            Action::ClosePosition if view == ViewMode::Orders && !self.config.read_only => {
+                self.open_cancel_dialog();
+            }
+            // Change the highlighted order's price or size
+            Action::Amend if view == ViewMode::Orders && !self.config.read_only => {
+                self.open_amend_dialog();
+            }
+            // Diagnostics overlay
+            Action::Diagnostics => {
+                self.show_diagnostics = !self.show_diagnostics;
+                if self.show_diagnostics {
+                    self.load_auto_status();
                 }
             }
+            Action::Filter if view == ViewMode::History => {
+                self.history_view.edit_filter();
+            }
+            // 1-3 jump between the help's sections while it's open
+            Action::Number(n @ 1..=3) if self.show_help => {
+                self.help_scroll.jump_to(n as usize - 1);
+            }
+            Action::QuickFilter(n) if view == ViewMode::Signals => {
+                let filter = match n {
+                    1 => QuickFilter::BuyYes,
+                    2 => QuickFilter::BuyNo,
+                    3 => QuickFilter::PositiveEv,
+                    _ => QuickFilter::All,
+                };
+                self.signals_view.set_quick_filter(filter);
+                self.toast = Some((format!("🔎 Showing {}", filter.label()), Instant::now()));
+            }
+            // View switching
+            Action::Number(n @ 1..=9) => {
+                self.switch_view(ViewMode::ALL[n as usize - 1]).await;
+            }
+            Action::Number(0) => {
+                self.switch_view(ViewMode::Orders).await;
+            }
+            Action::NextView => {
+                self.switch_view(self.view_mode.cycle(false)).await;
+            }
+            Action::PreviousView => {
+                self.switch_view(self.view_mode.cycle(true)).await;
+            }
+            // Cycle the volatility banner through enabled assets
+            Action::CycleVolAsset if self.volatility.len() > 1 => {
+                let assets: Vec<&String> = self.volatility.keys().collect();
+                let next = assets
+                    .iter()
+                    .position(|asset| **asset == self.vol_asset)
+                    .map_or(0, |i| (i + 1) % assets.len());
+                self.vol_asset = assets[next].clone();
+            }
             // Extreme mode toggle
-            KeyCode::Char('e') | KeyCode::Char('E') => {
+            Action::ExtremeMode => {
                 self.extreme_mode = !self.extreme_mode;
             }
-            KeyCode::Up => {
-                if self.show_help {
-                    self.help_scroll = self.help_scroll.saturating_sub(1);
+            // EV column net of taker fees
+            Action::NetEv => {
+                let label = if self.signals_view.toggle_net_ev() {
+                    "net of taker fees"
                 } else {
-                    // TODO: Implement table navigation
+                    "as the backend reports it"
+                };
+                self.toast = Some((format!("💸 EV {}", label), Instant::now()));
+            }
+            // Layout: hide the vol banner, slim the bars, or go compact
+            Action::ToggleBanner => {
+                self.show_vol_banner = !self.show_vol_banner;
+            }
+            Action::SlimBars => {
+                self.slim_bars = !self.slim_bars;
+            }
+            Action::CompactLayout => {
+                let compact = self.screen_layout() != LayoutMode::Compact;
+                self.layout = match (compact, self.auto_layout()) {
+                    (true, _) => LayoutMode::Compact,
+                    (false, LayoutMode::Compact) => LayoutMode::Standard,
+                    (false, _) => LayoutMode::Auto,
+                };
+                let label = if compact { "on" } else { "off" };
+                self.toast = Some((format!("📐 Compact layout {}", label), Instant::now()));
+                self.refresh_view().await;
+            }
+            Action::CycleTheme => {
+                self.theme = self.theme.next();
+                self.toast = Some((
+                    format!("🎨 Theme: {}", self.theme.name.label()),
+                    Instant::now(),
+                ));
+            }
+            Action::Up
+            | Action::Down
+            | Action::PageUp
+            | Action::PageDown
+            | Action::Top
+            | Action::Bottom => {
+                self.move_selection(action);
+            }
+            _ => {}
+        }
+    }
+
+    /// Motions scroll the help or the log pane while open, otherwise the open tab's table
+    fn move_selection(&mut self, action: Action) {
+        if self.show_help {
+            let help = &mut self.help_scroll;
+            match action {
+                Action::Up => help.scroll_up(1),
+                Action::Down => help.scroll_down(1),
+                Action::PageUp => help.scroll_up(help.page()),
+                Action::PageDown => help.scroll_down(help.page()),
+                Action::Top => help.scroll_to_top(),
+                _ => help.scroll_to_bottom(),
+            }
+            return;
+        }
+        if self.show_log {
+            let pane = &mut self.log_pane;
+            match action {
+                Action::Up => pane.scroll_up(1),
+                Action::Down => pane.scroll_down(1),
+                Action::PageUp => pane.scroll_up(pane.page()),
+                Action::PageDown => pane.scroll_down(pane.page()),
+                Action::Top => pane.scroll_to_oldest(),
+                _ => pane.follow(),
+            }
+            return;
+        }
+        match self.view_mode {
+            ViewMode::Signals => {
+                let view = &mut self.signals_view;
+                match action {
+                    Action::Up => view.select_previous(),
+                    Action::Down => view.select_next(),
+                    Action::PageUp => view.page_up(),
+                    Action::PageDown => view.page_down(),
+                    Action::Top => view.select_first(),
+                    _ => view.select_last(),
+                }
+            }
+            ViewMode::Positions => {
+                let view = &mut self.positions_view;
+                match action {
+                    Action::Up => view.select_previous(),
+                    Action::Down => view.select_next(),
+                    Action::PageUp | Action::Top => view.select_first(),
+                    _ => view.select_last(),
+                }
+            }
+            ViewMode::Orders => {
+                let view = &mut self.orders_view;
+                match action {
+                    Action::Up => view.select_previous(),
+                    Action::Down => view.select_next(),
+                    Action::PageUp | Action::Top => view.select_first(),
+                    _ => view.select_last(),
+                }
+            }
+            ViewMode::Rules => {
+                let view = &mut self.rules_view;
+                match action {
+                    Action::Up => view.select_previous(),
+                    Action::Down => view.select_next(),
+                    Action::PageUp | Action::Top => view.select_first(),
+                    _ => view.select_last(),
+                }
+            }
+            ViewMode::History => {
+                let view = &mut self.history_view;
+                match action {
+                    Action::Up => view.select_previous(),
+                    Action::Down => view.select_next(),
+                    Action::PageUp => view.page_up(),
+                    Action::PageDown => view.page_down(),
+                    Action::Top => view.select_first(),
+                    _ => view.select_last(),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Wheel scrolls the help or the open tab's table; a click selects a row or a tab.
+    /// Dialogs and overlays ignore the mouse.
+    async fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if self.show_help {
+            match mouse.kind {
+                MouseEventKind::ScrollUp => self.help_scroll.scroll_up(3),
+                MouseEventKind::ScrollDown => self.help_scroll.scroll_down(3),
+                _ => {}
+            }
+            return;
+        }
+        let overlay = self.trade_dialog.is_some()
+            || self.hedge_dialog.is_some()
+            || self.plan_dialog.is_some()
+            || self.confirmation.is_some()
+            || self.search.is_some()
+            || self.archive_browser.is_some()
+            || self.override_dialog.is_some()
+            || self.amend_dialog.is_some()
+            || self.show_stress
+            || self.show_diagnostics
+            || self.show_detail
+            || self.comparison.is_some()
+            || self.history_view.is_editing_filter()
+            || self.history_view.is_detail_open();
+        if overlay {
+            return;
+        }
+
+        match mouse.kind {
+            MouseEventKind::ScrollUp if self.in_side_pane(mouse.column, mouse.row) => {
+                self.positions_view.select_previous()
+            }
+            MouseEventKind::ScrollDown if self.in_side_pane(mouse.column, mouse.row) => {
+                self.positions_view.select_next()
+            }
+            MouseEventKind::Down(MouseButton::Left)
+                if self.in_side_pane(mouse.column, mouse.row) =>
+            {
+                self.positions_view.click(mouse.row)
+            }
+            MouseEventKind::ScrollUp => match self.view_mode {
+                ViewMode::Signals => self.signals_view.select_previous(),
+                ViewMode::Positions => self.positions_view.select_previous(),
+                ViewMode::Orders => self.orders_view.select_previous(),
+                ViewMode::History => self.history_view.select_previous(),
+                ViewMode::Rules => self.rules_view.select_previous(),
+                _ => {}
+            },
+            MouseEventKind::ScrollDown => match self.view_mode {
+                ViewMode::Signals => self.signals_view.select_next(),
+                ViewMode::Positions => self.positions_view.select_next(),
+                ViewMode::Orders => self.orders_view.select_next(),
+                ViewMode::History => self.history_view.select_next(),
+                ViewMode::Rules => self.rules_view.select_next(),
+                _ => {}
+            },
+            MouseEventKind::Down(MouseButton::Left) if mouse.row == self.tabs_area.y => {
+                if let Some(view) = self.tab_at(mouse.column) {
+                    self.switch_view(view).await;
+                }
+            }
+            MouseEventKind::Down(MouseButton::Left) => match self.view_mode {
+                ViewMode::Signals => self.signals_view.click(mouse.row),
+                ViewMode::Positions => self.positions_view.click(mouse.row),
+                ViewMode::Orders => self.orders_view.click(mouse.row),
+                ViewMode::History => self.history_view.click(mouse.row),
+                ViewMode::Rules => self.rules_view.click(mouse.row),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Over the positions pane of the wide layout
+    fn in_side_pane(&self, column: u16, row: u16) -> bool {
+        let area = self.side_area;
+        column >= area.x
+            && column < area.x + area.width
+            && row >= area.y
+            && row < area.y + area.height
+    }
+
+    /// Tab label under `column`, matching the padding and dividers `Tabs` draws
+    fn tab_at(&self, column: u16) -> Option<ViewMode> {
+        let mut x = self.tabs_area.x;
+        for (i, mode) in ViewMode::ALL.iter().enumerate() {
+            let width = Self::tab_label(i, *mode).chars().count() as u16 + 2;
+            if column >= x && column < x + width {
+                return Some(*mode);
+            }
+            x += width + 1;
+        }
+        None
+    }
+
+    fn tab_label(index: usize, mode: ViewMode) -> String {
+        // The tenth tab is on 0
+        format!("{} {}", (index + 1) % 10, mode.tab_title())
+    }
+
+    async fn open_trade_dialog(&mut self) {
+        let Some(contract) = self
+            .signals_view
+            .selected_id()
+            .and_then(|id| self.contracts.iter().find(|c| c.id == id))
+            .cloned()
+        else {
+            self.error_message = Some("Select a signal with ↑/↓ first".to_string());
+            return;
+        };
+
+        // Bankroll is only needed for Kelly/percent presets; they show n/a without it
+        let bankroll = bankroll::bankroll(&self.api_client).await.ok();
+        let mut guard = OrderGuard::new(&self.config.orders).check(
+            self.trade_latency.current_ms(),
+            contract.time_to_expiry_hours,
+        );
+        // Re-entry after a stop-loss is flagged but never blocked from the dashboard
+        if let Ok(reentry) = ReentryGuard::fetch(&self.api_client, &self.config.reentry).await {
+            if let Some(reason) = reentry.check(&contract.ticker) {
+                guard.warnings.push(reason);
+            }
+        }
+        // A losing streak warns; a tilt cooling-off period blocks like the order guard
+        self.refresh_tilt().await;
+        if let Some(warning) = self.tilt.warning() {
+            guard.warnings.push(warning);
+        }
+        if let Some(reason) = self.tilt.cooling_off() {
+            guard.warnings.push(reason);
+            guard.blocked = true;
+        }
+        if guard.blocked {
+            let message = format!(
+                "order on {} blocked: {}",
+                contract.ticker,
+                guard.warnings.join("; ")
+            );
+            if let Err(e) = audit::record(
+                AuditKind::Block,
+                "dashboard",
+                message,
+                serde_json::Value::Null,
+            ) {
+                self.error_message = Some(format!("Audit log: {}", e));
+            }
+        }
+
+        // Depth is a nice-to-have for the ladder; without it the levels show prices only
+        let book = self.api_client.get_orderbook(&contract.ticker).await.ok();
+
+        self.trade_dialog = Some(TradeDialog::new(
+            contract,
+            &self.config.sizing.presets,
+            bankroll,
+            guard,
+            book,
+        ));
+    }
+
+    fn toggle_compare_mark(&mut self) {
+        let Some(id) = self.signals_view.selected_id() else {
+            self.error_message = Some("Select a signal with ↑/↓ first".to_string());
+            return;
+        };
+
+        if let Some(index) = self.compare_marks.iter().position(|marked| *marked == id) {
+            self.compare_marks.remove(index);
+            self.comparison = None;
+            return;
+        }
+        // Drop stale marks on contracts that have rolled off
+        self.compare_marks
+            .retain(|marked| self.contracts.iter().any(|c| c.id == *marked));
+        if self.compare_marks.len() == 2 {
+            self.compare_marks.remove(0);
+        }
+        self.compare_marks.push(id);
+
+        if self.compare_marks.len() < 2 {
+            self.toast = Some((
+                "📌 Marked for compare (c on a second signal to open)".to_string(),
+                Instant::now(),
+            ));
+            return;
+        }
+        self.refresh_comparison();
+    }
+
+    /// Rebuild the compare view from the latest quotes for the marked pair
+    fn refresh_comparison(&mut self) {
+        let find = |id: i32| self.contracts.iter().find(|c| c.id == id);
+        let (Some(a), Some(b)) = (
+            self.compare_marks.first().copied().and_then(find),
+            self.compare_marks.get(1).copied().and_then(find),
+        ) else {
+            self.comparison = None;
+            return;
+        };
+
+        let spot = (self.current_btc_price > 0.0).then_some(self.current_btc_price);
+        self.comparison = Some(compare::compare(
+            a,
+            b,
+            spot,
+            self.volatility_or_estimate().effective_vol(),
+        ));
+    }
+
+    fn toggle_mute(&mut self, by_signal_type: bool) {
+        let Some(contract) = self
+            .signals_view
+            .selected_id()
+            .and_then(|id| self.contracts.iter().find(|c| c.id == id))
+        else {
+            self.error_message = Some("Select a signal with ↑/↓ first".to_string());
+            return;
+        };
+
+        let target = if by_signal_type {
+            MuteTarget::SignalType(contract.signal_type.clone())
+        } else {
+            MuteTarget::Ticker(contract.ticker.clone())
+        };
+        let label = target.label();
+        match self.mutes.cycle(target) {
+            Ok(Some(until)) => {
+                let minutes = (until - Utc::now()).num_minutes() + 1;
+                self.toast = Some((
+                    format!("🔇 Muted {} for {}m (m again to extend)", label, minutes),
+                    Instant::now(),
+                ));
+            }
+            Ok(None) => {
+                self.toast = Some((format!("🔔 Unmuted {}", label), Instant::now()));
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to save mutes: {}", e));
+            }
+        }
+    }
+
+    fn toggle_pin(&mut self) {
+        let Some(contract) = self
+            .signals_view
+            .selected_id()
+            .and_then(|id| self.contracts.iter().find(|c| c.id == id))
+        else {
+            self.error_message = Some("Select a signal with ↑/↓ first".to_string());
+            return;
+        };
+
+        match self.pins.toggle(contract) {
+            Ok(true) => {
+                self.toast = Some((
+                    format!("📌 Pinned {} (p again to unpin)", contract.ticker),
+                    Instant::now(),
+                ));
+            }
+            Ok(false) => {
+                self.toast = Some((format!("Unpinned {}", contract.ticker), Instant::now()));
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to save pins: {}", e));
+            }
+        }
+    }
+
+    async fn handle_trade_dialog_key(&mut self, key: KeyCode) {
+        let Some(dialog) = self.trade_dialog.as_mut() else {
+            return;
+        };
+
+        if dialog.is_done() {
+            self.trade_dialog = None;
+            return;
+        }
+
+        // While the checklist is up, keys answer it; a complete one lets Enter through
+        if let Some(checklist) = dialog.checklist.as_mut() {
+            match key {
+                KeyCode::Enter if checklist.blocker().is_none() => {}
+                KeyCode::Esc => {
+                    dialog.checklist = None;
+                    return;
+                }
+                KeyCode::Up => {
+                    checklist.select_previous();
+                    return;
+                }
+                KeyCode::Down | KeyCode::Tab => {
+                    checklist.select_next();
+                    return;
                 }
+                KeyCode::Backspace => {
+                    checklist.pop_char();
+                    return;
+                }
+                KeyCode::Char(c) if checklist.editing_reason() => {
+                    checklist.push_char(c);
+                    return;
+                }
+                KeyCode::Char(c @ ('y' | 'Y' | 'n' | 'N')) => {
+                    checklist.answer(c.eq_ignore_ascii_case(&'y'));
+                    return;
+                }
+                _ => return,
+            }
+        }
+
+        match key {
+            KeyCode::Esc => {
+                self.trade_dialog = None;
             }
-            KeyCode::Down => {
-                if self.show_help {
-                    self.help_scroll = self.help_scroll.saturating_add(1);
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                dialog.select_preset(index);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => dialog.adjust(1),
+            KeyCode::Char('-') => dialog.adjust(-1),
+            KeyCode::Up => dialog.move_price(1),
+            KeyCode::Down => dialog.move_price(-1),
+            KeyCode::Enter if dialog.can_submit() => {
+                if dialog.checklist.is_none()
+                    && Checklist::applies(&self.config.checklist, dialog.size)
+                {
+                    dialog.start_checklist(&self.config.checklist);
+                    return;
+                }
+                if self.config.confirm.trades {
+                    self.confirmation = Some(Confirmation {
+                        dialog: dialog.confirmation(self.config.confirm.type_size_at, &self.theme),
+                        pending: Pending::Trade,
+                    });
                 } else {
-                    // TODO: Implement table navigation
+                    self.submit_trade().await;
                 }
             }
             _ => {}
         }
     }
 
-    #[allow(dead_code)]
-    async fn fetch_btc_price(&mut self) {
-        // Lightweight BTC price update (doesn't change connection state)
-        match self.api_client.get_btc_price().await {
-            Ok(response) => {
-                self.current_btc_price = response.price;
-                // Update BTC price in all contracts for real-time distance calculations
-                for contract in &mut self.contracts {
-                    contract.current_btc_price = Some(response.price);
+    /// Send the trade ticket's order and show the outcome on the ticket
+    async fn submit_trade(&mut self) {
+        let Some(dialog) = self.trade_dialog.as_mut() else {
+            return;
+        };
+        let checklist = dialog.checklist.take().map(|checklist| checklist.record());
+
+        // Re-check the expiry cap against live positions right before sending
+        if self.config.exposure.max_per_expiry.is_some() {
+            let verdict = match self.api_client.get_positions().await {
+                Ok(positions) => ExposureBook::from_positions(&positions).check_signal(
+                    &self.config.exposure,
+                    &dialog.contract,
+                    dialog.size,
+                ),
+                Err(e) => Err(format!("Could not check exposure: {}", e)),
+            };
+            if let Err(reason) = verdict {
+                let message = format!("order on {} blocked: {}", dialog.contract.ticker, reason);
+                if let Err(e) = audit::record(
+                    AuditKind::Block,
+                    "dashboard",
+                    message,
+                    serde_json::Value::Null,
+                ) {
+                    self.error_message = Some(format!("Audit log: {}", e));
                 }
+                dialog.set_result(Err(reason));
+                return;
             }
-            Err(_) => {
-                // Silently fail - don't change connection state for BTC price failures
-                // Full data fetch will update connection state
+        }
+
+        // Off the quote the ticket becomes a limit order at the ladder price
+        let mut description = format!(
+            "signal #{} {} x{}",
+            dialog.contract.id, dialog.contract.ticker, dialog.size
+        );
+        let response = match dialog.limit_price() {
+            Some(limit_cents) => {
+                description.push_str(&format!(" limit ${:.2}", limit_cents as f64 / 100.0));
+                match dialog.contract.asset() {
+                    Ok(asset) => {
+                        let request = TradeRequest {
+                            ticker: dialog.contract.ticker.clone(),
+                            asset,
+                            direction: dialog
+                                .contract
+                                .signal_type
+                                .trim_start_matches("BUY ")
+                                .to_string(),
+                            strike: dialog.contract.strike_price.unwrap_or_default(),
+                            contracts: dialog.size,
+                            order_type: "limit".to_string(),
+                            limit_price: Some(limit_cents),
+                            signal_id: Some(dialog.contract.id.to_string()),
+                            client_order_id: None,
+                            tag: None,
+                            note: None,
+                        };
+                        self.api_client.execute_trade(request).await
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                self.api_client
+                    .execute_from_signal(dialog.contract.id, dialog.size)
+                    .await
             }
+        };
+        self.event_log.push(
+            LogKind::Trade,
+            audit::describe_result(&description, &response),
+        );
+        if let Err(e) = audit::record_result(AuditKind::Order, "dashboard", &description, &response)
+        {
+            self.error_message = Some(format!("Audit log: {}", e));
+        }
+        if let Ok(response) = &response {
+            self.hooks.trade(&description, response);
         }
+
+        let result = match response {
+            Ok(response) if response.success => {
+                let origin = Origin::Signal {
+                    signal_id: dialog.contract.id,
+                };
+                if let Err(e) = journal::record_checked(
+                    &dialog.contract.ticker,
+                    origin,
+                    None,
+                    &response,
+                    checklist,
+                ) {
+                    self.error_message = Some(format!("Trade journal: {}", e));
+                }
+                match response
+                    .order_id
+                    .as_deref()
+                    .filter(|_| response.filled < dialog.size)
+                {
+                    Some(order_id) => {
+                        self.resting_orders.push(order_id.to_string());
+                        Ok(format!(
+                            "Order {} resting ({} of {} filled)",
+                            order_id, response.filled, dialog.size
+                        ))
+                    }
+                    None => Ok(format!(
+                        "Trade #{} filled {} @ {}",
+                        response.trade_id.unwrap_or(0),
+                        response.filled,
+                        response
+                            .price
+                            .map(|p| format!("${:.2}", p))
+                            .unwrap_or_else(|| "--".to_string())
+                    )),
+                }
+            }
+            Ok(response) => Err(response
+                .error
+                .unwrap_or_else(|| "Order rejected".to_string())),
+            Err(e) => Err(e.to_string()),
+        };
+        dialog.set_result(result);
     }
 
-    async fn fetch_data(&mut self) {
-        self.connection_state = ConnectionState::Connecting;
-        self.error_message = None;
+    async fn open_hedge_dialog(&mut self) {
+        let Some(vol) = self.volatility_or_estimate().effective_vol() else {
+            self.error_message =
+                Some("Hedge needs a volatility reading; waiting for data".to_string());
+            return;
+        };
+        if self.current_btc_price <= 0.0 {
+            self.error_message = Some("Hedge needs a BTC price; waiting for data".to_string());
+            return;
+        }
 
-        match self.api_client.get_current_signals().await {
-            Ok(response) => {
+        let positions = match self.api_client.get_positions().await {
+            Ok(positions) => positions,
+            Err(e) => {
+                self.error_message = Some(format!("Failed to fetch positions: {}", e));
+                return;
+            }
+        };
+        match hedge::plan(
+            &positions,
+            &self.contracts,
+            self.current_btc_price,
+            vol,
+            &self.config.exposure,
+        ) {
+            Some(plan) => self.hedge_dialog = Some(HedgeDialog::new(plan)),
+            None => {
+                self.toast = Some((
+                    "No open positions before expiry to hedge".to_string(),
+                    Instant::now(),
+                ))
+            }
+        }
+    }
+
+    async fn handle_hedge_dialog_key(&mut self, key: KeyCode) {
+        let Some(dialog) = self.hedge_dialog.as_mut() else {
+            return;
+        };
+
+        if dialog.is_done() {
+            self.hedge_dialog = None;
+            self.fetch_exposure().await;
+            return;
+        }
+
+        match key {
+            KeyCode::Esc => {
+                self.hedge_dialog = None;
+            }
+            KeyCode::Enter if dialog.can_submit() => {
+                // Legs go out in order; stop at the first failure so the book is never
+                // left further from neutral than the plan intended
+                for leg in dialog.plan.legs.clone() {
+                    let result = match &leg {
+                        HedgeLeg::Close { position, .. } => {
+                            let response = self.api_client.close_position(position.trade_id).await;
+                            let description =
+                                format!("hedge: close position #{}", position.trade_id);
+                            self.event_log.push(
+                                LogKind::Trade,
+                                audit::describe_result(&description, &response),
+                            );
+                            if let Err(e) = audit::record_result(
+                                AuditKind::Close,
+                                "dashboard",
+                                &description,
+                                &response,
+                            ) {
+                                self.error_message = Some(format!("Audit log: {}", e));
+                            }
+                            if let Ok(response) = &response {
+                                self.hooks.trade(&description, response);
+                            }
+                            response
+                        }
+                        HedgeLeg::Open {
+                            contract,
+                            direction,
+                            contracts,
+                            ..
+                        } => {
+                            let response = match contract.asset() {
+                                Ok(asset) => {
+                                    let request = TradeRequest {
+                                        ticker: contract.ticker.clone(),
+                                        asset,
+                                        direction: direction.to_string(),
+                                        strike: contract.strike_price.unwrap_or_default(),
+                                        contracts: *contracts,
+                                        order_type: "market".to_string(),
+                                        limit_price: None,
+                                        signal_id: None,
+                                        client_order_id: None,
+                                        tag: None,
+                                        note: None,
+                                    };
+                                    self.api_client.execute_trade(request).await
+                                }
+                                Err(e) => Err(e),
+                            };
+                            let description =
+                                format!("hedge: {} {} x{}", contract.ticker, direction, contracts);
+                            self.event_log.push(
+                                LogKind::Trade,
+                                audit::describe_result(&description, &response),
+                            );
+                            if let Err(e) = audit::record_result(
+                                AuditKind::Order,
+                                "dashboard",
+                                &description,
+                                &response,
+                            ) {
+                                self.error_message = Some(format!("Audit log: {}", e));
+                            }
+                            if let Ok(response) = &response {
+                                self.hooks.trade(&description, response);
+                            }
+                            if let Ok(response) = &response {
+                                if let Err(e) = journal::record(
+                                    &contract.ticker,
+                                    Origin::Manual,
+                                    None,
+                                    response,
+                                ) {
+                                    self.error_message = Some(format!("Trade journal: {}", e));
+                                }
+                            }
+                            response
+                        }
+                    };
+
+                    let result = match result {
+                        Ok(response) if response.success => Ok(format!(
+                            "{}: filled {} @ {}",
+                            leg.describe(),
+                            response.filled,
+                            response
+                                .price
+                                .map(|p| format!("${:.2}", p))
+                                .unwrap_or_else(|| "--".to_string())
+                        )),
+                        Ok(response) => Err(format!(
+                            "{}: {}",
+                            leg.describe(),
+                            response.error.unwrap_or_else(|| "rejected".to_string())
+                        )),
+                        Err(e) => Err(format!("{}: {}", leg.describe(), e)),
+                    };
+                    let failed = result.is_err();
+                    dialog.push_result(result);
+                    if failed {
+                        break;
+                    }
+                }
+                dialog.finish();
+            }
+            _ => {}
+        }
+    }
+
+    async fn open_plan_dialog(&mut self) {
+        let positions = match self.api_client.get_positions().await {
+            Ok(positions) => positions,
+            Err(e) => {
+                self.error_message = Some(format!("Failed to fetch positions: {}", e));
+                return;
+            }
+        };
+        let book = ExposureBook::from_positions(&positions);
+        match planner::plan(
+            &self.contracts,
+            &book,
+            &self.config.planner,
+            &self.config.exposure,
+            |c| self.mutes.is_muted(c),
+        ) {
+            Some(plan) => self.plan_dialog = Some(PlanDialog::new(plan)),
+            None => {
+                let message = format!(
+                    "No BUY signals with EV ≥ {:.1}% to allocate",
+                    self.config.planner.min_ev * 100.0
+                );
+                self.toast = Some((message, Instant::now()));
+            }
+        }
+    }
+
+    async fn handle_plan_dialog_key(&mut self, key: KeyCode) {
+        let Some(dialog) = self.plan_dialog.as_mut() else {
+            return;
+        };
+
+        if dialog.is_done() {
+            self.plan_dialog = None;
+            self.fetch_exposure().await;
+            return;
+        }
+
+        match key {
+            KeyCode::Esc => {
+                self.plan_dialog = None;
+            }
+            KeyCode::Enter if dialog.can_submit() => {
+                // Each leg is its own bet, so one rejection doesn't stop the rest
+                for leg in dialog.plan.legs.clone() {
+                    let response = self
+                        .api_client
+                        .execute_from_signal(leg.contract.id, leg.contracts)
+                        .await;
+                    let description = format!(
+                        "plan: signal #{} {} x{}",
+                        leg.contract.id, leg.contract.ticker, leg.contracts
+                    );
+                    self.event_log.push(
+                        LogKind::Trade,
+                        audit::describe_result(&description, &response),
+                    );
+                    if let Err(e) =
+                        audit::record_result(AuditKind::Order, "dashboard", &description, &response)
+                    {
+                        self.error_message = Some(format!("Audit log: {}", e));
+                    }
+                    if let Ok(response) = &response {
+                        self.hooks.trade(&description, response);
+                    }
+                    let result = match response {
+                        Ok(response) if response.success => {
+                            let origin = Origin::Signal {
+                                signal_id: leg.contract.id,
+                            };
+                            if let Err(e) = journal::record(
+                                &leg.contract.ticker,
+                                origin,
+                                Some("plan"),
+                                &response,
+                            ) {
+                                self.error_message = Some(format!("Trade journal: {}", e));
+                            }
+                            Ok(format!(
+                                "{}: filled {} @ {}",
+                                leg.describe(),
+                                response.filled,
+                                response
+                                    .price
+                                    .map(|p| format!("${:.2}", p))
+                                    .unwrap_or_else(|| "--".to_string())
+                            ))
+                        }
+                        Ok(response) => Err(format!(
+                            "{}: {}",
+                            leg.describe(),
+                            response.error.unwrap_or_else(|| "rejected".to_string())
+                        )),
+                        Err(e) => Err(format!("{}: {}", leg.describe(), e)),
+                    };
+                    dialog.push_result(result);
+                }
+                dialog.finish();
+            }
+            _ => {}
+        }
+    }
+
+    /// Reload the rules (the CLI adds and cancels them), expire overdue ones and
+    /// fire any whose trigger BTC crossed since the last check
+    async fn check_rules(&mut self) {
+        let mut book = RuleBook::load();
+        let mut changed = book.expire(Utc::now());
+        if let Some((low, high)) = self.btc_range.take().filter(|_| !self.config.read_only) {
+            for rule in book.due(low, high) {
+                let status = self.fire_rule(&rule).await;
+                self.hooks.rule_fired(&rule, &status);
+                let message = match &status {
+                    RuleStatus::Fired { .. } => {
+                        format!("⚡ Rule #{} fired: {}", rule.id, rule.describe())
+                    }
+                    status => format!("⚠ Rule #{} {}", rule.id, status.label()),
+                };
+                self.toast = Some((message, Instant::now()));
+                book.set_status(rule.id, status);
+                changed = true;
+            }
+        }
+        if changed {
+            if let Err(e) = book.save() {
+                self.error_message = Some(format!("Failed to save rules: {}", e));
+            }
+        }
+        self.rules = book;
+    }
+
+    /// Place a triggered rule's order on the soonest listed contract at its strike
+    async fn fire_rule(&mut self, rule: &PriceRule) -> RuleStatus {
+        let Some(contract) = rule.contract(&self.contracts) else {
+            return RuleStatus::Failed {
+                error: format!("no listed contract at ${:.0}", rule.strike),
+            };
+        };
+        // A rule that fires over and over is the case the governor is for
+        if let Err(throttle) = self.rule_governor.acquire() {
+            return RuleStatus::Failed {
+                error: format!("held back: {}", throttle),
+            };
+        }
+        let ticker = contract.ticker.clone();
+        let order = match rule.order(contract) {
+            Ok(order) => order,
+            Err(e) => {
+                return RuleStatus::Failed {
+                    error: e.to_string(),
+                }
+            }
+        };
+        let response = self.api_client.execute_trade(order).await;
+        self.rule_governor
+            .complete(matches!(&response, Ok(response) if response.success));
+        let description = format!("rule #{}: {} {}", rule.id, ticker, rule.describe());
+        self.event_log.push(
+            LogKind::Trade,
+            audit::describe_result(&description, &response),
+        );
+        if let Err(e) = audit::record_result(AuditKind::Order, "dashboard", &description, &response)
+        {
+            self.error_message = Some(format!("Audit log: {}", e));
+        }
+        if let Ok(response) = &response {
+            self.hooks.trade(&description, response);
+        }
+        match response {
+            Ok(response) if response.success => {
+                let origin = Origin::Rule { id: rule.id };
+                if let Err(e) = journal::record(&ticker, origin, None, &response) {
+                    self.error_message = Some(format!("Trade journal: {}", e));
+                }
+                RuleStatus::Fired {
+                    trade_id: response.trade_id,
+                    filled: response.filled,
+                    price: response.price,
+                }
+            }
+            Ok(response) => RuleStatus::Failed {
+                error: response.error.unwrap_or_else(|| "rejected".to_string()),
+            },
+            Err(e) => RuleStatus::Failed {
+                error: e.to_string(),
+            },
+        }
+    }
+
+    fn cancel_selected_rule(&mut self) {
+        let Some(id) = self.rules_view.selected_id() else {
+            return;
+        };
+        let mut book = RuleBook::load();
+        let result = book.cancel(id).and_then(|_| book.save());
+        match result {
+            Ok(()) => {
+                self.toast = Some((format!("Rule #{} cancelled", id), Instant::now()));
+                self.rules = book;
+            }
+            Err(e) => self.error_message = Some(e.to_string()),
+        }
+    }
+
+    /// Index signals, positions and history (fetching the account tabs if not loaded yet)
+    async fn open_search(&mut self) {
+        if self.positions.is_none() {
+            self.fetch_positions().await;
+        }
+        if self.trade_history.is_none() {
+            self.fetch_trade_history().await;
+        }
+        let tags: HashMap<i32, String> = journal::load()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|entry| Some((entry.trade_id?, entry.tag?)))
+            .collect();
+        let hits = search::index(
+            &self.contracts,
+            self.positions.as_deref().unwrap_or_default(),
+            self.trade_history.as_deref().unwrap_or_default(),
+            &tags,
+        );
+        self.search = Some(SearchOverlay::new(hits));
+    }
+
+    fn handle_archive_key(&mut self, key: KeyCode) {
+        let Some(browser) = self.archive_browser.as_mut() else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => self.archive_browser = None,
+            KeyCode::Up => browser.select_previous(),
+            KeyCode::Down | KeyCode::Tab => browser.select_next(),
+            KeyCode::Backspace => browser.pop_char(),
+            KeyCode::Char(c) => browser.push_char(c),
+            _ => {}
+        }
+    }
+
+    async fn handle_search_key(&mut self, key: KeyCode) {
+        let Some(overlay) = self.search.as_mut() else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => {
+                self.search = None;
+            }
+            KeyCode::Up => overlay.select_previous(),
+            KeyCode::Down | KeyCode::Tab => overlay.select_next(),
+            KeyCode::Backspace => overlay.pop_char(),
+            KeyCode::Char(c) => overlay.push_char(c),
+            KeyCode::Enter => {
+                let Some(target) = overlay.selected_target() else {
+                    return;
+                };
+                self.search = None;
+                match target {
+                    SearchTarget::Signal(id) => {
+                        self.switch_view(ViewMode::Signals).await;
+                        self.signals_view.select_id(id);
+                    }
+                    SearchTarget::Position(id) => {
+                        self.switch_view(ViewMode::Positions).await;
+                        self.positions_view.select_id(id);
+                    }
+                    SearchTarget::History(id) => {
+                        self.switch_view(ViewMode::History).await;
+                        self.history_view.select_id(id);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Keys while the History tab's filter is being typed or a trade is open
+    fn handle_history_key(&mut self, key: KeyCode) {
+        if self.history_view.is_editing_filter() {
+            match key {
+                KeyCode::Esc => self.history_view.finish_filter(true),
+                KeyCode::Enter => self.history_view.finish_filter(false),
+                KeyCode::Backspace => self.history_view.pop_filter_char(),
+                KeyCode::Char(c) => self.history_view.push_filter_char(c),
+                _ => {}
+            }
+            return;
+        }
+
+        match key {
+            KeyCode::Esc | KeyCode::Enter => self.history_view.close_detail(),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.history_view.select_previous();
+                self.open_history_detail();
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.history_view.select_next();
+                self.open_history_detail();
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the highlighted trade with its journal entry, if Basilisk placed it
+    fn open_history_detail(&mut self) {
+        if self.history_view.selected_id().is_none() {
+            self.history_view.select_next();
+        }
+        let Some(id) = self.history_view.selected_id() else {
+            return;
+        };
+        let entry = journal::load()
+            .unwrap_or_default()
+            .into_iter()
+            .rfind(|entry| entry.trade_id == Some(id));
+        self.history_view.open_detail(entry);
+    }
+
+    fn handle_override_key(&mut self, key: KeyCode) {
+        let Some(dialog) = self.override_dialog.as_mut() else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => {
+                self.override_dialog = None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => dialog.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => dialog.select_next(),
+            KeyCode::Left | KeyCode::Char('-') => dialog.adjust(-1),
+            KeyCode::Right | KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char(' ') => {
+                dialog.adjust(1)
+            }
+            KeyCode::Enter => {
+                self.model_override = dialog.draft.clone();
+                self.override_dialog = None;
+                match self.model_override.save() {
+                    Ok(()) if self.model_override.enabled => {
+                        self.toast = Some(("🧮 My% column updated".to_string(), Instant::now()));
+                    }
+                    Ok(()) => {
+                        self.toast = Some(("🧮 My% column off".to_string(), Instant::now()));
+                    }
+                    Err(e) => {
+                        self.error_message = Some(format!("Failed to save model override: {}", e));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Vol the override engine runs on with `settings`
+    fn override_vol(&self, settings: &ModelOverride) -> Option<f64> {
+        settings.vol(self.primary_volatility(), self.replay.realized_vol())
+    }
+
+    /// YES probability per contract under the override, while it's on
+    fn my_model(&self) -> Option<HashMap<i32, f64>> {
+        if !self.model_override.enabled {
+            return None;
+        }
+        let vol = self.override_vol(&self.model_override);
+        Some(
+            self.contracts
+                .iter()
+                .filter_map(|c| Some((c.id, self.model_override.probability(c, vol?)?)))
+                .collect(),
+        )
+    }
+
+    fn open_close_dialog(&mut self) {
+        let Some(position) = self
+            .positions_view
+            .selected_id()
+            .and_then(|id| self.positions.as_deref()?.iter().find(|p| p.trade_id == id))
+        else {
+            return;
+        };
+        let mark = positions::mark(position, &self.contracts);
+        self.confirmation = Some(Confirmation {
+            dialog: close_dialog::confirm_close(position, &mark, &self.theme),
+            pending: Pending::ClosePosition(position.trade_id),
+        });
+    }
+
+    async fn handle_confirmation_key(&mut self, key: KeyCode) {
+        let Some(confirmation) = self.confirmation.as_mut() else {
+            return;
+        };
+
+        match confirmation.dialog.handle_key(key) {
+            DialogResponse::Pending => {}
+            DialogResponse::Cancelled => self.confirmation = None,
+            DialogResponse::Dismissed => match self.confirmation.take().map(|c| c.pending) {
+                Some(Pending::ClosePosition(_)) => self.fetch_positions().await,
+                Some(Pending::CancelOrder(_) | Pending::AmendOrder(..)) => {
+                    self.fetch_orders().await
+                }
+                _ => {}
+            },
+            DialogResponse::Confirmed => match confirmation.pending {
+                Pending::Trade => {
+                    self.confirmation = None;
+                    self.submit_trade().await;
+                }
+                Pending::ClosePosition(trade_id) => {
+                    let result = self.close_position(trade_id).await;
+                    if let Some(confirmation) = self.confirmation.as_mut() {
+                        confirmation.dialog.set_result(result);
+                    }
+                }
+                Pending::CancelOrder(ref order_id) => {
+                    let order_id = order_id.clone();
+                    let result = self.cancel_order(&order_id).await;
+                    if let Some(confirmation) = self.confirmation.as_mut() {
+                        confirmation.dialog.set_result(result);
+                    }
+                }
+                Pending::AmendOrder(ref order_id, price, size) => {
+                    let order_id = order_id.clone();
+                    let result = self.amend_order(&order_id, price, size).await;
+                    if let Some(confirmation) = self.confirmation.as_mut() {
+                        confirmation.dialog.set_result(result);
+                    }
+                }
+                Pending::Quit => self.should_quit = true,
+            },
+        }
+    }
+
+    /// Send a market close and describe the outcome
+    async fn close_position(&mut self, trade_id: i32) -> Result<String, String> {
+        let response = self.api_client.close_position(trade_id).await;
+        let description = format!("close position #{}", trade_id);
+        self.event_log.push(
+            LogKind::Trade,
+            audit::describe_result(&description, &response),
+        );
+        if let Err(e) = audit::record_result(AuditKind::Close, "dashboard", &description, &response)
+        {
+            self.error_message = Some(format!("Audit log: {}", e));
+        }
+        if let Ok(response) = &response {
+            self.hooks.trade(&description, response);
+        }
+        match response {
+            Ok(response) if response.success => Ok(format!(
+                "Closed {} @ {}",
+                response.filled,
+                response
+                    .price
+                    .map(|p| format!("${:.2}", p))
+                    .unwrap_or_else(|| "--".to_string())
+            )),
+            Ok(response) => Err(response
+                .error
+                .unwrap_or_else(|| "Close rejected".to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn open_cancel_dialog(&mut self) {
+        let Some(order) = self
+            .orders_view
+            .selected_id()
+            .and_then(|id| self.orders.as_deref()?.iter().find(|o| o.order_id == id))
+        else {
+            return;
+        };
+        self.confirmation = Some(Confirmation {
+            dialog: orders::confirm_cancel(order, &self.theme),
+            pending: Pending::CancelOrder(order.order_id.clone()),
+        });
+    }
+
+    fn open_amend_dialog(&mut self) {
+        let Some(order) = self
+            .orders_view
+            .selected_id()
+            .and_then(|id| self.orders.as_deref()?.iter().find(|o| o.order_id == id))
+        else {
+            return;
+        };
+        match AmendDialog::new(order) {
+            Some(dialog) => self.amend_dialog = Some(dialog),
+            None => {
+                self.error_message = Some(format!(
+                    "Order {} has no limit price to amend",
+                    order.order_id
+                ))
+            }
+        }
+    }
+
+    fn handle_amend_key(&mut self, key: KeyCode) {
+        let Some(dialog) = self.amend_dialog.as_mut() else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => {
+                self.amend_dialog = None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => dialog.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => dialog.select_next(),
+            KeyCode::Left | KeyCode::Char('-') => dialog.adjust(-1),
+            KeyCode::Right | KeyCode::Char('+') | KeyCode::Char('=') => dialog.adjust(1),
+            KeyCode::PageDown => dialog.adjust(-10),
+            KeyCode::PageUp => dialog.adjust(10),
+            KeyCode::Enter if dialog.changed() => {
+                let (price, size) = dialog.terms();
+                self.confirmation = Some(Confirmation {
+                    dialog: amend_dialog::confirm_amend(dialog, &self.theme),
+                    pending: Pending::AmendOrder(dialog.order.order_id.clone(), price, size),
+                });
+                self.amend_dialog = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Cancel/replace a resting order and describe the outcome
+    async fn amend_order(
+        &mut self,
+        order_id: &str,
+        price: Option<f64>,
+        size: Option<i32>,
+    ) -> Result<String, String> {
+        let result = amend::amend(&self.api_client, order_id, price, size, "dashboard").await;
+        self.resting_orders.retain(|id| id != order_id);
+        let amendment = match result {
+            Ok(amendment) => amendment,
+            Err(e) => {
+                self.event_log
+                    .push(LogKind::Trade, format!("amend order {}: {}", order_id, e));
+                return Err(e.to_string());
+            }
+        };
+        if let Some(warning) = amendment.warnings.first() {
+            self.error_message = Some(warning.clone());
+        }
+        match amendment.replacement {
+            None => {
+                self.event_log.push(
+                    LogKind::Trade,
+                    format!("amend order {}: filled before the cancel", order_id),
+                );
+                Ok("Filled before the cancel landed; nothing to replace".to_string())
+            }
+            Some(response) if response.success => {
+                let new_id = response.order_id.clone().unwrap_or_default();
+                self.event_log.push(
+                    LogKind::Trade,
+                    format!("amend order {}: replaced by {}", order_id, new_id),
+                );
+                self.hooks
+                    .trade(&format!("amend order {}", order_id), &response);
+                if !new_id.is_empty() {
+                    self.resting_orders.push(new_id.clone());
+                }
+                Ok(format!("Replaced by {}", new_id))
+            }
+            Some(response) => {
+                let error = response
+                    .error
+                    .unwrap_or_else(|| "Replacement rejected".to_string());
+                self.event_log.push(
+                    LogKind::Trade,
+                    format!("amend order {}: replacement failed: {}", order_id, error),
+                );
+                Err(format!("Original cancelled, replacement failed: {}", error))
+            }
+        }
+    }
+
+    /// Cancel a resting order and describe the outcome
+    async fn cancel_order(&mut self, order_id: &str) -> Result<String, String> {
+        let result = self.api_client.cancel_order(order_id).await;
+        let description = format!("cancel order {}", order_id);
+        self.event_log.push(
+            LogKind::Trade,
+            audit::describe_cancel(&description, &result),
+        );
+        if let Err(e) = audit::record_cancel("dashboard", &description, &result) {
+            self.error_message = Some(format!("Audit log: {}", e));
+        }
+        self.resting_orders.retain(|id| id != order_id);
+        match result {
+            Ok(order) => Ok(format!(
+                "Cancelled; {} of {} had filled",
+                order.filled(),
+                order.contracts
+            )),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Quit, or ask first while orders this session placed are still resting
+    async fn request_quit(&mut self) {
+        let theme = self.theme;
+        if !self.config.confirm.quit_with_open_orders || self.resting_orders.is_empty() {
+            self.should_quit = true;
+            return;
+        }
+
+        // An order whose status can't be fetched counts as resting
+        let mut resting = Vec::new();
+        for order_id in std::mem::take(&mut self.resting_orders) {
+            match self.api_client.get_order(&order_id).await {
+                Ok(order) if !order.is_resting() => {}
+                Ok(order) => resting.push((
+                    order_id,
+                    format!(
+                        "{} {} x{} ({} filled)",
+                        order.ticker,
+                        order.direction,
+                        order.contracts,
+                        order.filled()
+                    ),
+                )),
+                Err(e) => resting.push((order_id, format!("status unknown: {}", e))),
+            }
+        }
+        if resting.is_empty() {
+            self.should_quit = true;
+            return;
+        }
+
+        let mut body = vec![Line::from(format!(
+            "  {} order{} placed this session still resting:",
+            resting.len(),
+            if resting.len() == 1 { "" } else { "s" }
+        ))];
+        for (order_id, summary) in &resting {
+            body.push(Line::from(Span::styled(
+                format!("    {}  {}", order_id, summary),
+                Style::default().fg(theme.highlight),
+            )));
+        }
+        body.push(Line::from(Span::styled(
+            "  They stay on the exchange after Basilisk exits.",
+            Style::default().fg(theme.muted),
+        )));
+        self.resting_orders = resting.into_iter().map(|(order_id, _)| order_id).collect();
+        self.confirmation = Some(Confirmation {
+            dialog: Dialog::confirm("QUIT WITH OPEN ORDERS?", body, "quit anyway")
+                .destructive()
+                .width(80),
+            pending: Pending::Quit,
+        });
+    }
+
+    #[allow(dead_code)]
+    async fn fetch_btc_price(&mut self) {
+        // Lightweight BTC price update (doesn't change connection state)
+        match self.api_client.get_btc_price().await {
+            Ok(response) => {
+                self.current_btc_price = response.price;
+                // Update BTC price in all contracts for real-time distance calculations
+                for contract in &mut self.contracts {
+                    contract.current_btc_price = Some(response.price);
+                }
+            }
+            Err(_) => {
+                // Silently fail - don't change connection state for BTC price failures
+                // Full data fetch will update connection state
+            }
+        }
+    }
+
+    /// Front expiry and its contracts, once it is inside the settlement watch window
+    fn settlement_watch_contracts(&self) -> Option<(DateTime<Utc>, Vec<&Contract>)> {
+        let now = Utc::now();
+        let expiry_of = |c: &Contract| c.expiry_time.as_deref().and_then(api::parse_timestamp);
+        let expiry = self
+            .contracts
+            .iter()
+            .filter_map(expiry_of)
+            .filter(|expiry| *expiry > now)
+            .min()?;
+        if (expiry - now).num_seconds() > SETTLEMENT_WATCH_SECS {
+            return None;
+        }
+        let contracts = self
+            .contracts
+            .iter()
+            .filter(|c| c.is_active && expiry_of(c) == Some(expiry))
+            .collect();
+        Some((expiry, contracts))
+    }
+
+    /// Seconds until the soonest listed contract expires
+    fn seconds_to_front_expiry(&self) -> Option<i64> {
+        self.contracts
+            .iter()
+            .filter_map(|c| c.seconds_to_expiry())
+            .filter(|secs| *secs > 0)
+            .min()
+    }
+
+    /// Delay before the next HTTP poll (None while SSE is live and cross-checks are off).
+    /// Inside the final minutes of an expiry polls speed up to `fast_interval_secs`,
+    /// unless the backend is offline.
+    fn poll_interval(&self) -> Option<Duration> {
+        let polling = &self.config.polling;
+        let state = self.connection.state();
+        let near_expiry = polling.is_near_expiry(self.seconds_to_front_expiry())
+            && !matches!(state, ConnectionState::Offline { .. })
+            && self.config.market_hours.downtime().is_none();
+        let interval = match state.fallback_interval(polling.interval()) {
+            Some(interval) => interval,
+            None if polling.while_connected || near_expiry => polling.check_interval(),
+            None => return None,
+        };
+        let interval = if near_expiry {
+            interval.min(polling.fast_interval())
+        } else {
+            interval
+        };
+        Some(polling.backoff(interval, self.poll_failures))
+    }
+
+    /// Fetch whatever `[polling] fetch` lists
+    async fn poll(&mut self) {
+        for target in self.config.polling.fetch.clone() {
+            match target {
+                PollTarget::Signals => self.fetch_data().await,
+                PollTarget::HourlyStats => self.fetch_hourly_stats().await,
+                PollTarget::VolSkew => self.fetch_vol_skew().await,
+            }
+        }
+        self.fetch_asset_volatility().await;
+        self.refresh_tilt().await;
+        // Keep the open tab current while it's on screen
+        self.refresh_view().await;
+    }
+
+    async fn switch_view(&mut self, mode: ViewMode) {
+        self.view_mode = mode;
+        self.show_detail = false;
+        match mode {
+            // Fetched once; `[polling] fetch` can keep them fresh
+            ViewMode::HourlyStats if self.hourly_stats.total_samples == 0 => {
+                self.fetch_hourly_stats().await
+            }
+            ViewMode::VolSkew if self.vol_skew.skew_interpretation.is_empty() => {
+                self.fetch_vol_skew().await
+            }
+            _ => self.refresh_view().await,
+        }
+    }
+
+    /// Refetch the data behind account tabs while they're on screen
+    async fn refresh_view(&mut self) {
+        match self.view_mode {
+            ViewMode::Portfolio => self.fetch_exposure().await,
+            ViewMode::Positions => self.fetch_positions().await,
+            ViewMode::Orders => self.fetch_orders().await,
+            ViewMode::Pnl => self.fetch_pnl().await,
+            ViewMode::History => self.fetch_trade_history().await,
+            ViewMode::Rules => self.rules = RuleBook::load(),
+            ViewMode::Signals if self.screen_layout() == LayoutMode::Wide => {
+                self.fetch_positions().await
+            }
+            ViewMode::Signals
+            | ViewMode::HourlyStats
+            | ViewMode::VolSkew
+            | ViewMode::Distribution => {}
+        }
+    }
+
+    /// Volatility for enabled assets besides the primary one (their streams carry it too)
+    async fn fetch_asset_volatility(&mut self) {
+        let assets: Vec<String> = self
+            .volatility
+            .keys()
+            .filter(|a| *a != PRIMARY_ASSET)
+            .cloned()
+            .collect();
+        for asset in assets {
+            if let Ok(response) = self.api_client.get_asset_contracts(&asset).await {
+                self.volatility.insert(asset, response.volatility);
+            }
+        }
+    }
+
+    /// Latest volatility for the primary asset (pricing, alerts and charts use it)
+    fn primary_volatility(&self) -> &VolatilityData {
+        &self.volatility[PRIMARY_ASSET]
+    }
+
+    /// Primary volatility, or an estimate from the streamed ticks while the
+    /// endpoint is empty (the default when neither is there yet)
+    fn volatility_or_estimate(&self) -> VolatilityData {
+        let volatility = self.primary_volatility();
+        match self.replay.realized_vol() {
+            Some(rv) if !volatility.is_available() => VolatilityData::estimated(rv),
+            _ => volatility.clone(),
+        }
+    }
+
+    async fn fetch_data(&mut self) {
+        self.error_message = None;
+
+        match self.api_client.get_current_signals().await {
+            Ok(response) => {
+                self.connection.http_succeeded();
+                self.poll_failures = 0;
+                if !self.check_consistency(Source::Poll, Utc::now(), &response.contracts) {
+                    return;
+                }
+                self.handle_rollover(&response.contracts);
                 self.contracts = response.contracts;
-                self.volatility_data = response.volatility;
+                self.signals_view.record_quotes(&self.contracts);
+                self.edge_history.record(&self.contracts);
+                self.archive_contracts();
+                self.volatility
+                    .insert(PRIMARY_ASSET.to_string(), response.volatility);
+                self.record_vol_sample();
+
+                if let Some(first_contract) = self.contracts.first() {
+                    if let Some(price) = first_contract.current_btc_price {
+                        self.current_btc_price = price;
+                        self.btc_trend.record(price);
+                        // Update BTC price history for sparkline
+                        self.chart_history.record_price(price);
+                    }
+                }
+
+                // Update volatility history for the IV vs RV chart; an empty
+                // reading would plot as a drop to zero
+                if self.primary_volatility().is_available() {
+                    self.chart_history.record_vol(
+                        self.primary_volatility().realized_vol,
+                        self.primary_volatility().implied_vol,
+                    );
+                }
+
+                self.last_update = Some(Instant::now());
+                self.evaluate_alerts();
+                if self.comparison.is_some() {
+                    self.refresh_comparison();
+                }
+            }
+            Err(e) => {
+                self.connection.http_failed();
+                self.poll_failures = self.poll_failures.saturating_add(1);
+                self.connection_failed(format!("Failed to fetch data: {}", e), &e.to_string());
+            }
+        }
+    }
+
+    /// Compare a snapshot with the other data source; false if it's older and should be dropped
+    fn check_consistency(
+        &mut self,
+        source: Source,
+        as_of: DateTime<Utc>,
+        contracts: &[Contract],
+    ) -> bool {
+        match self.consistency.observe(source, as_of, contracts) {
+            Ok(fresher) => fresher,
+            Err(e) => {
+                self.error_message = Some(format!("Failed to log data discrepancy: {}", e));
+                true
+            }
+        }
+    }
+
+    /// Settle and re-anchor when an hour's contracts drop out of the new snapshot
+    fn handle_rollover(&mut self, contracts: &[Contract]) {
+        let Some(rollover) = rollover::detect(&self.contracts, contracts) else {
+            return;
+        };
+
+        // Settle on the final minute's average when the ticks cover it
+        let settle_price = rollover
+            .expired
+            .iter()
+            .find_map(|c| c.expiry_time.as_deref().and_then(api::parse_timestamp))
+            .and_then(|expiry| self.replay.settlement_average(expiry))
+            .unwrap_or(self.current_btc_price);
+        if let Err(e) = self.archive.record_final(&rollover.expired) {
+            self.error_message = Some(format!("Failed to archive contracts: {}", e));
+        }
+        match rollover.record_settlements(settle_price, self.btc_trend.change_pct()) {
+            // Written to the diary once trade history has caught up (see run loop)
+            Ok(settled) if !settled.is_empty() => self.pending_diary = Some(settled),
+            Ok(_) => {}
+            Err(e) => {
+                self.error_message = Some(format!("Failed to record settlements: {}", e));
+            }
+        }
+
+        // Follow the selected strike into the new expiry
+        let selected = self.signals_view.selected_id();
+        if let Some(old) = rollover.expired.iter().find(|c| Some(c.id) == selected) {
+            if let Some(equivalent) = rollover.equivalent(old, contracts) {
+                self.signals_view.select_id(equivalent.id);
+            }
+        }
+
+        self.toast = Some((format!("⏰ {}", rollover.summary()), Instant::now()));
+    }
+
+    /// Snapshot the contract list into the archive when one is due
+    fn archive_contracts(&mut self) {
+        if let Err(e) = self.archive.record(&self.contracts) {
+            self.error_message = Some(format!("Failed to archive contracts: {}", e));
+        }
+    }
+
+    /// Append the settled hour to today's diary, with my trades on those contracts
+    async fn write_diary(&mut self, settled: Vec<Settlement>) {
+        // Without history the hour is still worth recording; my trades are just missing
+        let history = self
+            .api_client
+            .get_trade_history(HISTORY_LIMIT)
+            .await
+            .unwrap_or_default();
+        let Some(snapshot) = HourSnapshot::new(
+            settled,
+            &history,
+            self.primary_volatility(),
+            self.replay.realized_vol(),
+        ) else {
+            return;
+        };
+        if let Err(e) = snapshot.append() {
+            self.error_message = Some(format!("Failed to write diary: {}", e));
+        }
+    }
+
+    /// Run alert rules against the latest snapshot
+    fn evaluate_alerts(&mut self) {
+        let volatility = self.volatility_or_estimate();
+        self.hooks.volatility(&volatility);
+        if let Err(e) = self.alerts.evaluate(
+            &self.contracts,
+            &volatility,
+            self.script.as_ref(),
+            &self.mutes,
+        ) {
+            self.error_message = Some(format!("Failed to record alert: {}", e));
+        }
+    }
+
+    /// Keep the intraday vol history behind `basilisk vol-pattern`
+    fn record_vol_sample(&mut self) {
+        if let Err(e) = self.vol_recorder.record(&self.volatility[PRIMARY_ASSET]) {
+            self.error_message = Some(format!("Failed to record vol history: {}", e));
+        }
+    }
+
+    /// Surface a failed poll or stream drop, unless it falls in known downtime
+    fn connection_failed(&mut self, message: String, reason: &str) {
+        self.event_log.error(&message);
+        if self.config.market_hours.downtime().is_some() {
+            return;
+        }
+        self.error_message = Some(message);
+        self.record_connection_lost(reason);
+    }
+
+    fn record_connection_lost(&mut self, reason: &str) {
+        if let Err(e) = self.alerts.connection_lost(reason) {
+            self.error_message = Some(format!("Failed to record alert: {}", e));
+        }
+    }
+
+    async fn fetch_hourly_stats(&mut self) {
+        match self.api_client.get_hourly_stats().await {
+            Ok(stats) => {
+                self.hourly_stats = stats;
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to fetch hourly stats: {}", e));
+            }
+        }
+    }
+
+    async fn fetch_vol_skew(&mut self) {
+        match self.api_client.get_volatility_skew().await {
+            Ok(skew) => {
+                self.vol_skew = skew;
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to fetch volatility skew: {}", e));
+            }
+        }
+    }
+
+    async fn fetch_exposure(&mut self) {
+        match self.api_client.get_positions().await {
+            Ok(positions) => {
+                self.exposure = Some(ExposureBook::from_positions(&positions));
+                self.concentration = Some(ExposureSummary::from_positions(&positions, |p| {
+                    positions::mark(p, &self.contracts).price
+                }));
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to fetch positions: {}", e));
+            }
+        }
+    }
+
+    async fn fetch_positions(&mut self) {
+        match self.api_client.get_positions().await {
+            Ok(positions) => {
+                self.positions = Some(positions);
+                self.spreads = SpreadBook::load();
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to fetch positions: {}", e));
+            }
+        }
+    }
+
+    async fn fetch_orders(&mut self) {
+        match self.api_client.get_orders().await {
+            Ok(orders) => {
+                self.orders = Some(orders.into_iter().filter(|o| o.is_resting()).collect());
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to fetch orders: {}", e));
+            }
+        }
+    }
+
+    async fn fetch_pnl(&mut self) {
+        let mut summaries = Vec::new();
+        for period in pnl::PERIODS {
+            match self.api_client.get_pnl_summary(period).await {
+                Ok(summary) => summaries.push(Some(summary)),
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to fetch P&L: {}", e));
+                    summaries.push(None);
+                }
+            }
+        }
+        self.pnl = summaries;
+    }
+
+    /// Estimate the default size's market impact on every active signal. The books
+    /// are fetched off the UI loop and come back as `AppEvent::LiquiditySample`.
+    fn sample_liquidity(&mut self, tx: mpsc::UnboundedSender<AppEvent>) {
+        let signals: Vec<(i32, String, bool)> = self
+            .contracts
+            .iter()
+            .filter(|c| c.is_active && c.signal_type != "HOLD")
+            .map(|c| (c.id, c.ticker.clone(), c.signal_type.contains("NO")))
+            .collect();
+        let size = self.config.liquidity.default_size;
+        let api_url = self.api_url.clone();
+        self.sampling_liquidity = true;
 
-                if let Some(first_contract) = self.contracts.first() {
-                    if let Some(price) = first_contract.current_btc_price {
-                        self.current_btc_price = price;
-                        // Update BTC price history for sparkline (keep last 50 points)
-                        Self::update_sparkline_history(&mut self.btc_price_history, price as u64);
+        tokio::spawn(async move {
+            let mut impact = HashMap::new();
+            let mut failures = Vec::new();
+            match ApiClient::new(api_url, 10) {
+                Ok(client) => {
+                    for (id, ticker, no) in signals {
+                        match client.get_orderbook(&ticker).await {
+                            Ok(book) => {
+                                if let Some(estimate) = Impact::estimate(&book, no, size) {
+                                    impact.insert(id, estimate);
+                                }
+                            }
+                            Err(e) => failures.push(format!("{}: {:#}", ticker, e)),
+                        }
                     }
                 }
+                Err(e) => failures.push(format!("{:#}", e)),
+            }
+            let _ = tx.send(AppEvent::LiquiditySample { impact, failures });
+        });
+    }
 
-                // Update volatility history for sparklines
-                Self::update_sparkline_history(&mut self.realized_vol_history, (self.volatility_data.realized_vol * 100.0) as u64);
-                Self::update_sparkline_history(&mut self.implied_vol_history, (self.volatility_data.implied_vol * 100.0) as u64);
+    /// Account balance for the status bar (the last one stays up if this fails)
+    async fn refresh_balance(&mut self) {
+        if let Ok(balance) = Balance::fetch(&self.api_client).await {
+            self.balance = Some(balance);
+        }
+    }
 
-                self.connection_state = ConnectionState::Connected;
-                self.last_update = Some(Instant::now());
-            }
-            Err(e) => {
-                self.connection_state = ConnectionState::Disconnected;
-                self.error_message = Some(format!("Failed to fetch data: {}", e));
-            }
+    /// Session win/loss streak for the status bar and trade dialog
+    async fn refresh_tilt(&mut self) {
+        if let Ok(tilt) = TiltGuard::fetch(&self.api_client, &self.config.tilt).await {
+            self.tilt = tilt;
         }
     }
 
-    async fn fetch_hourly_stats(&mut self) {
-        match self.api_client.get_hourly_stats().await {
-            Ok(stats) => {
-                self.hourly_stats = stats;
+    async fn fetch_trade_history(&mut self) {
+        match self.api_client.get_trade_history(HISTORY_LIMIT).await {
+            Ok(history) => {
+                if self.config.tilt.warn_after > 0 {
+                    self.tilt = TiltGuard::from_history(&history, &self.config.tilt);
+                }
+                self.trade_history = Some(history);
             }
             Err(e) => {
-                self.error_message = Some(format!("Failed to fetch hourly stats: {}", e));
+                self.error_message = Some(format!("Failed to fetch trade history: {}", e));
             }
         }
     }
 
-    async fn fetch_vol_skew(&mut self) {
-        match self.api_client.get_volatility_skew().await {
-            Ok(skew) => {
-                self.vol_skew = skew;
+    async fn fetch_stress_report(&mut self) {
+        self.stress_report = None;
+
+        match self.api_client.get_positions().await {
+            Ok(positions) => {
+                let spot = (self.current_btc_price > 0.0).then_some(self.current_btc_price);
+                let records = portfolio::build_records(
+                    &positions,
+                    spot,
+                    self.volatility_or_estimate().effective_vol(),
+                );
+                self.stress_report = Some(stress::run(
+                    &records,
+                    &stress::DEFAULT_MOVES,
+                    &stress::DEFAULT_VOL_SHOCKS,
+                ));
             }
             Err(e) => {
-                self.error_message = Some(format!("Failed to fetch volatility skew: {}", e));
+                self.show_stress = false;
+                self.error_message = Some(format!("Failed to fetch positions: {}", e));
             }
         }
     }
 
-    /// Update sparkline history, keeping last 50 data points
-    fn update_sparkline_history(history: &mut Vec<u64>, new_value: u64) {
-        history.push(new_value);
-        if history.len() > 50 {
-            history.remove(0);
+    /// Read the auto-trader's governor counters (written by `basilisk auto`)
+    fn load_auto_status(&mut self) {
+        self.auto_status = store::read_json(governor::STATUS_FILE).ok().flatten();
+    }
+
+    /// Layout in effect: the override if there is one, otherwise by terminal size
+    fn screen_layout(&self) -> LayoutMode {
+        match self.layout {
+            LayoutMode::Auto => self.auto_layout(),
+            mode => mode,
+        }
+    }
+
+    /// Compact on small terminals, wide (positions beside signals) on wide ones
+    fn auto_layout(&self) -> LayoutMode {
+        let layout = &self.config.layout;
+        if self.screen.width < layout.auto_compact_width
+            || self.screen.height < layout.auto_compact_height
+        {
+            LayoutMode::Compact
+        } else if self.screen.width >= layout.wide_min_width {
+            LayoutMode::Wide
+        } else {
+            LayoutMode::Standard
         }
     }
 
     fn render(&mut self, frame: &mut Frame) {
+        self.screen = frame.size();
+        // Compact mode gives the signals table every spare row
+        let layout = self.screen_layout();
+        let compact = layout == LayoutMode::Compact;
+        let bar_height = if compact || self.slim_bars { 1 } else { 3 };
+        let banner_height = if self.show_vol_banner && !compact {
+            3
+        } else {
+            0
+        };
+        let log_height = if self.show_log {
+            LogPane::height(frame.size().height)
+        } else {
+            0
+        };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Status bar
-                Constraint::Length(3), // Volatility regime banner
-                Constraint::Min(0),    // Main content
-                Constraint::Length(3), // Footer
+                Constraint::Length(bar_height),    // Status bar
+                Constraint::Length(banner_height), // Volatility regime banner
+                Constraint::Length(1),             // Tab bar
+                Constraint::Min(0),                // Main content
+                Constraint::Length(log_height),    // Log pane
+                Constraint::Length(bar_height),    // Footer
             ])
             .split(frame.size());
 
@@ -285,45 +2763,428 @@ impl App {
         self.render_status_bar(frame, chunks[0]);
 
         // Render volatility regime banner
-        self.render_vol_regime(frame, chunks[1]);
+        if banner_height > 0 {
+            self.render_vol_regime(frame, chunks[1]);
+        }
+
+        // Render tab bar
+        self.tabs_area = chunks[2];
+        self.render_tabs(frame, chunks[2]);
 
         // Render main content based on view mode
+        self.side_area = Rect::default();
         match self.view_mode {
             ViewMode::Signals => {
-                self.signals_view.render(frame, chunks[2], &self.contracts, self.extreme_mode, self.current_btc_price);
+                // Wide terminals keep open positions in view beside the signals
+                let main = if layout == LayoutMode::Wide {
+                    let split = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([Constraint::Percentage(58), Constraint::Percentage(42)])
+                        .split(chunks[3]);
+                    self.side_area = split[1];
+                    self.positions_view.render(
+                        frame,
+                        split[1],
+                        self.positions.as_deref(),
+                        &self.contracts,
+                        &self.spreads,
+                        &self.theme,
+                    );
+                    split[0]
+                } else {
+                    chunks[3]
+                };
+                let muted = self
+                    .contracts
+                    .iter()
+                    .filter(|c| self.mutes.is_muted(c))
+                    .map(|c| c.id)
+                    .collect();
+                self.signals_view.set_muted(muted);
+                let pinned = self
+                    .contracts
+                    .iter()
+                    .filter(|c| self.pins.is_pinned(c))
+                    .map(|c| c.id)
+                    .collect();
+                self.signals_view.set_pinned(pinned);
+                self.signals_view.set_extreme_mode(self.extreme_mode);
+                self.signals_view.set_marked(self.compare_marks.clone());
+                let scorer = Scorer::new(
+                    &self.config.score,
+                    &self.volatility[PRIMARY_ASSET].regime,
+                    self.script.as_ref(),
+                );
+                let scores = self
+                    .contracts
+                    .iter()
+                    .map(|c| (c.id, scorer.score(c)))
+                    .collect();
+                self.signals_view.set_scores(scores);
+                let illiquid = self
+                    .impact
+                    .iter()
+                    .filter(|(_, i)| i.is_illiquid(&self.config.liquidity))
+                    .map(|(id, _)| *id)
+                    .collect();
+                self.signals_view.set_illiquid(illiquid);
+                let liquidity = self
+                    .contracts
+                    .iter()
+                    .filter_map(|c| {
+                        Some((c.id, self.volume.score(&c.ticker, &self.config.liquidity)?))
+                    })
+                    .collect();
+                self.signals_view.set_liquidity_scores(liquidity);
+                self.signals_view.set_my_model(self.my_model());
+                // A narrow wide-layout pane also needs the abbreviated columns
+                self.signals_view
+                    .set_compact(compact || main.width < FULL_SIGNALS_WIDTH);
+                // Final minutes of the front expiry: settlement watch above everything else
+                let content = match self.settlement_watch_contracts() {
+                    Some((expiry, contracts)) => {
+                        let split = Layout::default()
+                            .direction(Direction::Vertical)
+                            .constraints([
+                                Constraint::Length(settlement_watch::height(contracts.len())),
+                                Constraint::Min(0),
+                            ])
+                            .split(main);
+                        let prices = SettlementPrices {
+                            spot: self.current_btc_price,
+                            average: self.replay.settlement_average(expiry),
+                        };
+                        self.settlement_watch.render(
+                            frame,
+                            split[0],
+                            expiry,
+                            prices,
+                            &contracts,
+                            &self.theme,
+                        );
+                        split[1]
+                    }
+                    None => main,
+                };
+                let table_area = if self.show_chart && !compact && content.height >= 24 {
+                    let split = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Length(12), Constraint::Min(0)])
+                        .split(content);
+                    let charts = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                        .split(split[0]);
+                    let points = self.btc_chart_points();
+                    self.btc_chart.render(
+                        frame,
+                        charts[0],
+                        &points,
+                        &self.contracts,
+                        self.signals_view.selected_id(),
+                        &self.theme,
+                    );
+                    self.vol_chart.render(
+                        frame,
+                        charts[1],
+                        &self.vol_chart_points(),
+                        self.primary_volatility().is_available(),
+                        &self.theme,
+                    );
+                    split[1]
+                } else {
+                    content
+                };
+                self.signals_view.render(
+                    frame,
+                    table_area,
+                    &self.contracts,
+                    self.current_btc_price,
+                    self.script.as_ref(),
+                    &self.theme,
+                );
             }
             ViewMode::HourlyStats => {
-                self.hourly_stats_view.render(frame, chunks[2], &self.hourly_stats);
+                self.hourly_stats_view
+                    .set_hour_path(self.replay.btc_this_hour());
+                self.hourly_stats_view
+                    .render(frame, chunks[3], &self.hourly_stats, &self.theme);
             }
             ViewMode::VolSkew => {
-                self.vol_skew_view.render(frame, chunks[2], &self.vol_skew);
+                self.vol_skew_view.set_iv_trend(self.replay.implied_vol());
+                self.vol_skew_view
+                    .render(frame, chunks[3], &self.vol_skew, &self.theme);
+            }
+            ViewMode::Portfolio => {
+                self.portfolio_view.render(
+                    frame,
+                    chunks[3],
+                    self.exposure.as_ref(),
+                    self.concentration.as_ref(),
+                    self.config.exposure.max_per_expiry,
+                    &self.theme,
+                );
+            }
+            ViewMode::Positions => {
+                self.positions_view.render(
+                    frame,
+                    chunks[3],
+                    self.positions.as_deref(),
+                    &self.contracts,
+                    &self.spreads,
+                    &self.theme,
+                );
+            }
+            ViewMode::Orders => {
+                self.orders_view.render(
+                    frame,
+                    chunks[3],
+                    self.orders.as_deref(),
+                    &self.contracts,
+                    &self.theme,
+                );
+            }
+            ViewMode::Pnl => {
+                self.pnl_view
+                    .render(frame, chunks[3], &self.pnl, &self.theme);
+            }
+            ViewMode::History => {
+                self.history_view.render(
+                    frame,
+                    chunks[3],
+                    self.trade_history.as_deref(),
+                    &self.theme,
+                );
             }
+            ViewMode::Rules => {
+                self.rules_view.render(
+                    frame,
+                    chunks[3],
+                    &self.rules.recent(),
+                    self.current_btc_price,
+                    &self.theme,
+                );
+            }
+            ViewMode::Distribution => {
+                let distribution = distribution::front_expiry(&self.contracts);
+                self.distribution_view.render(
+                    frame,
+                    chunks[3],
+                    distribution.as_ref(),
+                    self.current_btc_price,
+                    &self.theme,
+                );
+            }
+        }
+
+        if self.show_log {
+            self.log_pane
+                .render(frame, chunks[4], &self.event_log, &self.theme);
         }
 
         // Render footer
-        self.render_footer(frame, chunks[3]);
+        self.render_footer(frame, chunks[5]);
+
+        // Render signal detail over the whole screen if open
+        if self.show_detail && self.view_mode == ViewMode::Signals {
+            let selected = self
+                .signals_view
+                .selected_id()
+                .and_then(|id| self.contracts.iter().find(|c| c.id == id));
+            if let Some(contract) = selected {
+                let edges = self.edge_history.edges(&contract.ticker);
+                self.detail_view.render(
+                    frame,
+                    contract,
+                    &edges,
+                    &self.replay.btc_this_hour(),
+                    self.impact.get(&contract.id),
+                    &self.theme,
+                );
+            }
+        }
+
+        // Render stress-test overlay if active
+        if self.show_stress {
+            self.stress_view
+                .render(frame, self.stress_report.as_ref(), &self.theme);
+        }
+
+        // Render trade dialog if open
+        if let Some(dialog) = &self.trade_dialog {
+            dialog.render(frame, &self.theme);
+        }
+
+        // Render hedge plan if open
+        if let Some(dialog) = &self.hedge_dialog {
+            dialog.render(frame, &self.theme);
+        }
+
+        // Render allocation plan if open
+        if let Some(dialog) = &self.plan_dialog {
+            dialog.render(frame, &self.theme);
+        }
+
+        // Render model override settings if open
+        if let Some(dialog) = &self.override_dialog {
+            dialog.render(frame, self.override_vol(&dialog.draft), &self.theme);
+        }
+
+        // Render the order amend editor if open
+        if let Some(dialog) = &self.amend_dialog {
+            dialog.render(frame, &self.theme);
+        }
+
+        // Render global search if open
+        if let Some(overlay) = &self.search {
+            overlay.render(frame, &self.theme);
+        }
+
+        // Render the contract archive browser if open
+        if let Some(browser) = &self.archive_browser {
+            browser.render(frame, &self.theme);
+        }
+
+        // Render compare view if two signals are marked
+        if let Some(comparison) = &self.comparison {
+            self.compare_view.render(frame, comparison, &self.theme);
+        }
+
+        // Render diagnostics overlay if active
+        if self.show_diagnostics {
+            let diagnostics = Diagnostics {
+                connection: self.connection.state(),
+                trade_latency_ms: self.trade_latency.current_ms(),
+                feed_latency: &self.feed_latency,
+                divergence: self.consistency.divergence(),
+                auto_status: self.auto_status.as_ref(),
+                rule_status: self.rule_governor.status(),
+            };
+            self.diagnostics_view
+                .render(frame, &diagnostics, &self.theme);
+        }
 
         // Render help overlay if active
         if self.show_help {
             self.render_help(frame);
         }
+
+        // Confirmations go over whatever asked for them
+        if let Some(confirmation) = &self.confirmation {
+            confirmation.dialog.render(frame, &self.theme);
+        }
     }
 
-    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
-        // Split status bar: left for info, right for BTC price sparkline
-        let chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(70),  // Status info
-                Constraint::Percentage(30),  // BTC sparkline
-            ])
-            .split(area);
+    fn render_tabs(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme;
+        let titles: Vec<Line> = ViewMode::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, mode)| Line::from(Self::tab_label(i, *mode)))
+            .collect();
+
+        let tabs = Tabs::new(titles)
+            .select(self.view_mode.index())
+            .style(Style::default().fg(theme.muted))
+            .highlight_style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            )
+            .divider("│");
+
+        frame.render_widget(tabs, area);
+    }
+
+    /// (seconds before now, price) for the BTC chart: streamed prices, or
+    /// polled ones while the stream has nothing yet
+    fn btc_chart_points(&self) -> Vec<(f64, f64)> {
+        let now = Utc::now();
+        let since = now - chrono::Duration::minutes(btc_chart::WINDOW_MINUTES);
+        let mut prices = self.replay.btc_since(since);
+        if prices.len() < 2 {
+            prices = self.chart_history.btc_price_since(since);
+        }
+        prices
+            .into_iter()
+            .map(|(at, price)| ((at - now).num_milliseconds() as f64 / 1000.0, price))
+            .collect()
+    }
+
+    /// RV/IV readings for the vol chart, in vol points
+    fn vol_chart_points(&self) -> Vec<VolPoint> {
+        let now = Utc::now();
+        self.chart_history
+            .vol()
+            .into_iter()
+            .map(|(at, realized, implied)| VolPoint {
+                secs: (at - now).num_milliseconds() as f64 / 1000.0,
+                realized: realized * 100.0,
+                implied: implied * 100.0,
+            })
+            .collect()
+    }
+
+    /// " 2s ago · 42/min · ↻1", yellow once the stream goes quiet and red when it looks stalled
+    fn stream_activity(&self) -> Span<'static> {
+        let theme = self.theme;
+        let Some(since) = self.stream_stats.since_last_event() else {
+            return Span::styled(" · waiting for data", Style::default().fg(theme.highlight));
+        };
+        let color = if since >= stream_stats::STALLED_AFTER {
+            theme.negative
+        } else if since >= stream_stats::QUIET_AFTER {
+            theme.highlight
+        } else {
+            theme.muted
+        };
+        Span::styled(
+            format!(
+                " {}s ago · {}/min · ↻{}",
+                since.as_secs(),
+                self.stream_stats.events_per_minute(),
+                self.stream_stats.reconnects()
+            ),
+            Style::default().fg(color),
+        )
+    }
 
-        // Left side: Connection status and info
-        let connection_indicator = match self.connection_state {
-            ConnectionState::Connected => Span::styled("● Live", Style::default().fg(Color::Green)),
-            ConnectionState::Disconnected => Span::styled("● Offline", Style::default().fg(Color::Red)),
-            ConnectionState::Connecting => Span::styled("● Connecting...", Style::default().fg(Color::Yellow)),
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme;
+        // Connection status and info
+        let downtime = self.config.market_hours.downtime();
+        let connection_indicator = match self.connection.state() {
+            // Known downtime explains any outage; say when the market is back instead
+            state if state != ConnectionState::Connected && downtime.is_some() => {
+                let summary = downtime.map(|d| d.summary(Utc::now())).unwrap_or_default();
+                Span::styled(
+                    format!("◐ {}", summary),
+                    Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+                )
+            }
+            ConnectionState::Connected => {
+                Span::styled("● Live", Style::default().fg(theme.positive))
+            }
+            ConnectionState::DegradedPolling => Span::styled(
+                "● Polling (stream down)",
+                Style::default().fg(theme.highlight),
+            ),
+            ConnectionState::Reconnecting { attempt: 0 } => {
+                Span::styled("● Connecting...", Style::default().fg(theme.highlight))
+            }
+            ConnectionState::Reconnecting { attempt } => Span::styled(
+                format!("● Reconnecting (#{})", attempt),
+                Style::default().fg(theme.caution),
+            ),
+            ConnectionState::Offline { since } => {
+                let mins = since.elapsed().as_secs() / 60;
+                Span::styled(
+                    format!("● Offline {}m", mins),
+                    Style::default()
+                        .fg(theme.negative)
+                        .add_modifier(Modifier::BOLD),
+                )
+            }
         };
 
         let btc_price = if self.current_btc_price > 0.0 {
@@ -339,45 +3200,210 @@ impl App {
             "Update: --".to_string()
         };
 
-        let next_refresh = if let Some(last) = self.last_update {
-            let elapsed = last.elapsed().as_secs();
-            let remaining = self.refresh_interval_secs.saturating_sub(elapsed);
-            format!("Next: {}s", remaining)
-        } else {
-            "Next: --".to_string()
+        let next_refresh = match (self.last_update, self.poll_interval()) {
+            (Some(last), Some(interval)) => {
+                let remaining = interval.as_secs().saturating_sub(last.elapsed().as_secs());
+                if interval < self.config.polling.interval()
+                    && self
+                        .config
+                        .polling
+                        .is_near_expiry(self.seconds_to_front_expiry())
+                {
+                    Span::styled(
+                        format!("Next: {}s ⚡", remaining),
+                        Style::default().fg(theme.highlight),
+                    )
+                } else {
+                    Span::raw(format!("Next: {}s", remaining))
+                }
+            }
+            (Some(_), None) => Span::raw("Next: live"),
+            (None, _) => Span::raw("Next: --"),
+        };
+
+        let latency = match self.trade_latency.current_ms() {
+            Some(ms) if OrderGuard::new(&self.config.orders).is_latency_high(ms) => Span::styled(
+                format!("RTT: {}ms", ms),
+                Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Some(ms) => Span::styled(
+                format!("RTT: {}ms", ms),
+                Style::default().fg(theme.positive),
+            ),
+            None => Span::styled("RTT: --", Style::default().fg(theme.muted)),
         };
 
-        let line = Line::from(vec![
-            connection_indicator,
+        let mut spans = vec![connection_indicator];
+        // Whether data is actually flowing, not just whether the stream is open
+        if self.connection.state() == ConnectionState::Connected {
+            spans.push(self.stream_activity());
+        }
+        spans.extend([
             Span::raw("  │  "),
-            Span::styled(btc_price, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                btc_price,
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
             Span::raw("  │  "),
             Span::raw(update_time),
             Span::raw("  │  "),
-            Span::raw(next_refresh),
+            next_refresh,
+            Span::raw("  │  "),
+            latency,
         ]);
 
-        let paragraph = Paragraph::new(line)
-            .block(Block::default().borders(Borders::ALL).title(" BASILISK "));
+        if let Some(balance) = &self.balance {
+            let mut text = format!("Cash: ${:.2}", balance.cash);
+            if balance.exposure > 0.0 {
+                text.push_str(&format!(" · At risk ${:.2}", balance.exposure));
+            }
+            if balance.pending_settlement > 0.0 {
+                text.push_str(&format!(" · Settling ${:.2}", balance.pending_settlement));
+            }
+            spans.push(Span::raw("  │  "));
+            spans.push(Span::styled(text, Style::default().fg(theme.text)));
+        }
+
+        // Countdown to the front expiry, ticking every second
+        if let Some(secs) = self.front_expiry_secs {
+            let color = match secs {
+                s if s < 120 => theme.negative,
+                s if s < 600 => theme.highlight,
+                _ => theme.text,
+            };
+            spans.push(Span::raw("  │  "));
+            spans.push(Span::styled(
+                format!(
+                    "⏱ Expiry in {}:{:02}:{:02}",
+                    secs / 3600,
+                    secs % 3600 / 60,
+                    secs % 60
+                ),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        // Streamed data arriving late; the diagnostics panel has the detail
+        if self.connection.state() == ConnectionState::Connected && self.feed_latency.is_stale() {
+            if let Some(ms) = self.feed_latency.average_ms() {
+                spans.push(Span::raw("  │  "));
+                spans.push(Span::styled(
+                    format!("⚠ Feed {:.1}s behind", ms as f64 / 1000.0),
+                    Style::default()
+                        .fg(theme.negative)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+        }
+
+        if self.config.read_only {
+            spans.push(Span::raw("  │  "));
+            spans.push(Span::styled(
+                "👁 Read-only",
+                Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+            ));
+        }
 
-        frame.render_widget(paragraph, chunks[0]);
+        if self.extreme_mode {
+            spans.push(Span::raw("  │  "));
+            spans.push(Span::styled(
+                format!(
+                    "🎲 Extreme <{:.0}% / >{}%",
+                    self.config.extreme.max_implied * 100.0,
+                    self.config.extreme.min_move_pct
+                ),
+                Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
 
-        // Right side: BTC price sparkline
-        if !self.btc_price_history.is_empty() {
-            let sparkline = Sparkline::default()
-                .block(Block::default().borders(Borders::ALL).title(" BTC Trend "))
-                .data(&self.btc_price_history)
-                .style(Style::default().fg(Color::Cyan));
+        // Session streak; a losing one turns red once it trips the tilt guard
+        if let Some(label) = self.tilt.streak().label() {
+            let style = match self.tilt.streak() {
+                Streak::Losses(_) if self.tilt.cooling_off().is_some() => Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK),
+                Streak::Losses(_) if self.tilt.warning().is_some() => Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD),
+                Streak::Losses(_) => Style::default().fg(theme.highlight),
+                _ => Style::default().fg(theme.positive),
+            };
+            spans.push(Span::raw("  │  "));
+            spans.push(Span::styled(format!("Streak: {}", label), style));
+        }
 
-            frame.render_widget(sparkline, chunks[1]);
+        if let Some(divergence) = self.consistency.divergence() {
+            spans.push(Span::raw("  │  "));
+            spans.push(Span::styled(
+                format!(
+                    "⚠ Feeds differ ({} {}s stale)",
+                    divergence.stale_source.label(),
+                    divergence.lag_secs
+                ),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ));
         }
+
+        let line = Line::from(spans);
+
+        // Slim bars drop the border (and the title with it)
+        let block = if area.height < 3 {
+            Block::default()
+        } else {
+            Block::default().borders(Borders::ALL).title(" BASILISK ")
+        };
+        let paragraph = Paragraph::new(line).block(block);
+
+        frame.render_widget(paragraph, area);
     }
 
     fn render_footer(&self, frame: &mut Frame, area: Rect) {
-        let footer_text = if let Some(ref error) = self.error_message {
+        let theme = self.theme;
+        let footer_text = if let Some(alert) = self.alerts.pending_critical() {
+            // Critical alerts blink until acknowledged
+            let blink = Style::default()
+                .fg(theme.negative)
+                .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK);
+            let mut spans = vec![
+                Span::styled("⚠ CRITICAL: ", blink),
+                Span::styled(alert.message.clone(), Style::default().fg(theme.negative)),
+            ];
+            let pending = self.alerts.pending_critical_count();
+            if pending > 1 {
+                spans.push(Span::raw(format!(" (+{} more)", pending - 1)));
+            }
+            spans.push(Span::raw("  │  "));
+            spans.push(Span::styled("[a] ", Style::default().fg(theme.highlight)));
+            spans.push(Span::raw("Acknowledge"));
+            Line::from(spans)
+        } else if let Some((message, _)) = self
+            .toast
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < TOAST_DURATION)
+        {
+            Line::from(Span::styled(
+                message.clone(),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else if let Some(ref error) = self.error_message {
             Line::from(vec![
-                Span::styled("ERROR: ", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                Span::styled(error, Style::default().fg(Color::Red)),
+                Span::styled(
+                    "ERROR: ",
+                    Style::default()
+                        .fg(theme.negative)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(error, Style::default().fg(theme.negative)),
             ])
         } else {
             // Show current view
@@ -388,148 +3414,287 @@ impl App {
                     } else {
                         "SIGNALS"
                     }
-                },
+                }
                 ViewMode::HourlyStats => "HOURLY STATS",
                 ViewMode::VolSkew => "VOL SKEW",
+                ViewMode::Portfolio => "PORTFOLIO",
+                ViewMode::Positions => "POSITIONS",
+                ViewMode::Pnl => "P&L",
+                ViewMode::History => "HISTORY",
+                ViewMode::Rules => "RULES",
+                ViewMode::Distribution => "DISTRIBUTION",
+                ViewMode::Orders => "ORDERS",
             };
 
             let view_color = match self.view_mode {
                 ViewMode::Signals => {
                     if self.extreme_mode {
-                        Color::Red
+                        theme.negative
                     } else {
-                        Color::Green
+                        theme.positive
                     }
-                },
-                ViewMode::HourlyStats => Color::Cyan,
-                ViewMode::VolSkew => Color::Magenta,
+                }
+                ViewMode::HourlyStats => theme.accent,
+                ViewMode::VolSkew => theme.special,
+                ViewMode::Portfolio => theme.secondary,
+                ViewMode::Positions => theme.secondary,
+                ViewMode::Pnl => theme.positive,
+                ViewMode::History => theme.accent,
+                ViewMode::Rules => theme.special,
+                ViewMode::Distribution => theme.accent,
+                ViewMode::Orders => theme.secondary,
             };
 
             let mut spans = vec![
-                Span::styled("View: ", Style::default().fg(Color::Gray)),
-                Span::styled(view_name, Style::default().fg(view_color).add_modifier(Modifier::BOLD)),
+                Span::styled("View: ", Style::default().fg(theme.muted)),
+                Span::styled(
+                    view_name,
+                    Style::default().fg(view_color).add_modifier(Modifier::BOLD),
+                ),
                 Span::raw("  │  "),
-                Span::styled("[1] ", Style::default().fg(Color::Yellow)),
-                Span::raw("Signals  "),
-                Span::styled("[2] ", Style::default().fg(Color::Yellow)),
-                Span::raw("Hourly Stats  "),
-                Span::styled("[3] ", Style::default().fg(Color::Yellow)),
-                Span::raw("Vol Skew  "),
+                Span::styled("[0-9/Tab] ", Style::default().fg(theme.highlight)),
+                Span::raw("Views  "),
             ];
 
             // Show [e] shortcut after numbered views
+            // Order keys are hidden in read-only mode
+            let trading = !self.config.read_only;
+            if self.view_mode == ViewMode::Portfolio && trading {
+                spans.push(Span::styled("[n] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Hedge to neutral  "));
+            }
+            if self.view_mode == ViewMode::Positions && trading {
+                spans.push(Span::styled("[x] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Close  "));
+            }
+            if self.view_mode == ViewMode::Orders && trading {
+                spans.push(Span::styled("[x] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Cancel order  "));
+                spans.push(Span::styled("[i] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Amend  "));
+            }
+            if self.view_mode == ViewMode::Rules {
+                spans.push(Span::styled("[x] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Cancel rule  "));
+            }
+            if self.view_mode == ViewMode::History {
+                spans.push(Span::styled("[/] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Filter  "));
+                spans.push(Span::styled(
+                    "[Enter] ",
+                    Style::default().fg(theme.highlight),
+                ));
+                spans.push(Span::raw("Details  "));
+            }
             if self.view_mode == ViewMode::Signals {
-                spans.push(Span::styled("[e] ", Style::default().fg(Color::Yellow)));
+                spans.push(Span::styled("[e] ", Style::default().fg(theme.highlight)));
                 spans.push(Span::raw("Extreme  "));
+                spans.push(Span::styled("[f] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Net EV  "));
+                if trading {
+                    spans.push(Span::styled("[t] ", Style::default().fg(theme.highlight)));
+                    spans.push(Span::raw("Trade  "));
+                    spans.push(Span::styled("[P] ", Style::default().fg(theme.highlight)));
+                    spans.push(Span::raw("Plan  "));
+                }
+                spans.push(Span::styled(
+                    "[Enter] ",
+                    Style::default().fg(theme.highlight),
+                ));
+                spans.push(Span::raw("Detail  "));
+                spans.push(Span::styled("[g] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Chart  "));
+                spans.push(Span::styled("[p] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Pin  "));
+                spans.push(Span::styled("[m/M] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Mute  "));
+                spans.push(Span::styled("[c] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Compare  "));
+                spans.push(Span::styled("[s/S] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Sort  "));
+                spans.push(Span::styled("[o] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("My model  "));
+                spans.push(Span::styled(
+                    "[1/2/3/0] ",
+                    Style::default().fg(theme.highlight),
+                ));
+                spans.push(Span::raw("YES/NO/+EV/All  "));
+            }
+
+            if self.volatility.len() > 1 {
+                spans.push(Span::styled("[v] ", Style::default().fg(theme.highlight)));
+                spans.push(Span::raw("Vol asset  "));
             }
 
             spans.extend(vec![
                 Span::raw("│  "),
-                Span::styled("[r] ", Style::default().fg(Color::Yellow)),
+                Span::styled("[^F] ", Style::default().fg(theme.highlight)),
+                Span::raw("Search  "),
+                Span::styled("[y] ", Style::default().fg(theme.highlight)),
+                Span::raw("Archive  "),
+                Span::styled("[l] ", Style::default().fg(theme.highlight)),
+                Span::raw("Log  "),
+                Span::styled("[r] ", Style::default().fg(theme.highlight)),
                 Span::raw("Refresh  "),
-                Span::styled("[h/?] ", Style::default().fg(Color::Yellow)),
+                Span::styled("[w] ", Style::default().fg(theme.highlight)),
+                Span::raw("Stress  "),
+                Span::styled("[z] ", Style::default().fg(theme.highlight)),
+                Span::raw("Compact  "),
+                Span::styled("[h/?] ", Style::default().fg(theme.highlight)),
                 Span::raw("Help  "),
-                Span::styled("[q] ", Style::default().fg(Color::Yellow)),
+                Span::styled("[q] ", Style::default().fg(theme.highlight)),
                 Span::raw("Quit"),
             ]);
 
             Line::from(spans)
         };
 
-        let paragraph = Paragraph::new(footer_text)
-            .block(Block::default().borders(Borders::ALL));
+        let block = if area.height < 3 {
+            Block::default()
+        } else {
+            Block::default().borders(Borders::ALL)
+        };
+        let paragraph = Paragraph::new(footer_text).block(block);
 
         frame.render_widget(paragraph, area);
     }
 
     fn render_vol_regime(&self, frame: &mut Frame, area: Rect) {
-        // Split volatility banner: left for info, middle for RV sparkline, right for IV sparkline
-        let chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(50),  // Volatility info
-                Constraint::Percentage(25),  // RV sparkline
-                Constraint::Percentage(25),  // IV sparkline
-            ])
-            .split(area);
-
-        // Left side: Volatility regime and stats
-        let rv = self.volatility_data.realized_vol;
-        let iv = self.volatility_data.implied_vol;
+        let theme = self.theme;
+        // Volatility regime and stats for the selected asset (the RV/IV trend is
+        // charted above the signals table)
+        let volatility = &self.volatility[&self.vol_asset];
+        if !volatility.is_available() {
+            self.render_vol_unavailable(frame, area);
+            return;
+        }
+        let rv = volatility.realized_vol;
+        let iv = volatility.implied_vol;
         let vol_multiplier = if iv > 0.0 { rv / iv } else { 1.0 };
         let is_extreme_vol = vol_multiplier >= 1.2 || rv >= 0.60;
 
-        let (_regime_color, regime_text, regime_style) = if self.volatility_data.regime.is_empty() {
-            (Color::Gray, "UNKNOWN", Style::default().fg(Color::Gray))
+        let (_regime_color, regime_text, regime_style) = if volatility.regime.is_empty() {
+            (theme.muted, "UNKNOWN", Style::default().fg(theme.muted))
         } else {
-            match self.volatility_data.regime.as_str() {
-                "CALM" => (Color::Green, "CALM", Style::default().fg(Color::Green)),
-                "NORMAL" => (Color::Yellow, "NORMAL", Style::default().fg(Color::Yellow)),
+            match volatility.regime.as_str() {
+                "CALM" => (theme.positive, "CALM", Style::default().fg(theme.positive)),
+                "NORMAL" => (
+                    theme.highlight,
+                    "NORMAL",
+                    Style::default().fg(theme.highlight),
+                ),
                 "ELEVATED" => {
                     let style = if is_extreme_vol {
                         Style::default()
-                            .fg(Color::LightRed)
+                            .fg(theme.caution)
                             .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK)
                     } else {
-                        Style::default().fg(Color::LightRed)
+                        Style::default().fg(theme.caution)
                     };
-                    (Color::LightRed, "ELEVATED 🔥", style)
-                },
+                    (theme.caution, "ELEVATED 🔥", style)
+                }
                 "CRISIS" => {
                     let style = Style::default()
-                        .fg(Color::Red)
+                        .fg(theme.negative)
                         .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK);
-                    (Color::Red, "CRISIS 🔥🔥", style)
-                },
-                _ => (Color::White, self.volatility_data.regime.as_str(), Style::default().fg(Color::White)),
+                    (theme.negative, "CRISIS 🔥🔥", style)
+                }
+                _ => (
+                    theme.text,
+                    volatility.regime.as_str(),
+                    Style::default().fg(theme.text),
+                ),
             }
         };
 
-        let rv_pct = format!("{:.0}%", self.volatility_data.realized_vol * 100.0);
-        let iv_pct = format!("{:.0}%", self.volatility_data.implied_vol * 100.0);
-        let premium_pct = format!("{:.1}%", self.volatility_data.vol_premium_pct * 100.0);
+        let rv_pct = format!("{:.0}%", volatility.realized_vol * 100.0);
+        let iv_pct = format!("{:.0}%", volatility.implied_vol * 100.0);
+        let premium_pct = format!("{:.1}%", volatility.vol_premium_pct * 100.0);
+        // RV from the last hour of streamed ticks, next to the backend's
+        let local_rv = match self.replay.realized_vol() {
+            Some(rv) if self.vol_asset == PRIMARY_ASSET => {
+                format!(" (1h ticks {:.0}%)", rv * 100.0)
+            }
+            _ => String::new(),
+        };
 
-        let text = vec![
-            Line::from(vec![
-                Span::raw("Regime: "),
-                Span::styled(regime_text, regime_style),
-                Span::raw(" │ "),
-                Span::raw(format!("RV: {}", rv_pct)),
-                Span::raw(" │ "),
-                Span::raw(format!("IV: {}", iv_pct)),
-                Span::raw(" │ "),
-                Span::raw(format!("Premium: {}", premium_pct)),
-            ]),
-        ];
+        let text = vec![Line::from(vec![
+            Span::raw("Regime: "),
+            Span::styled(regime_text, regime_style),
+            Span::raw(" │ "),
+            Span::raw(format!("RV: {}", rv_pct)),
+            Span::raw(local_rv),
+            Span::raw(" │ "),
+            Span::raw(format!("IV: {}", iv_pct)),
+            Span::raw(" │ "),
+            Span::raw(format!("Premium: {}", premium_pct)),
+        ])];
 
-        let paragraph = Paragraph::new(text)
-            .block(Block::default().borders(Borders::ALL).title(" VOLATILITY "))
-            .alignment(ratatui::layout::Alignment::Center);
+        self.render_vol_block(frame, area, text);
+    }
 
-        frame.render_widget(paragraph, chunks[0]);
+    /// The endpoint came back empty: say so rather than showing zeros, with the
+    /// regime estimated from streamed ticks when there are enough of them
+    fn render_vol_unavailable(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme;
+        let gray = Style::default().fg(theme.muted);
+        let mut spans = vec![Span::styled(
+            "Volatility unavailable",
+            Style::default()
+                .fg(theme.faint)
+                .add_modifier(Modifier::BOLD),
+        )];
+        match self.replay.realized_vol() {
+            Some(rv) if self.vol_asset == PRIMARY_ASSET => {
+                let estimate = VolatilityData::estimated(rv);
+                spans.push(Span::styled(
+                    format!(" │ Regime (est.): {}", estimate.regime),
+                    gray,
+                ));
+                spans.push(Span::styled(
+                    format!(" │ RV: {:.0}% (1h ticks)", rv * 100.0),
+                    gray,
+                ));
+            }
+            _ => spans.push(Span::styled(" │ waiting for data", gray)),
+        }
 
-        // Middle: RV sparkline
-        if !self.realized_vol_history.is_empty() {
-            let rv_sparkline = Sparkline::default()
-                .block(Block::default().borders(Borders::ALL).title(" RV Trend "))
-                .data(&self.realized_vol_history)
-                .style(Style::default().fg(Color::LightRed));
+        self.render_vol_block(frame, area, vec![Line::from(spans)]);
+    }
 
-            frame.render_widget(rv_sparkline, chunks[1]);
+    fn render_vol_block(&self, frame: &mut Frame, area: Rect, text: Vec<Line>) {
+        let theme = self.theme;
+        // With several assets enabled the title doubles as tabs ([v] cycles)
+        let mut title = vec![Span::raw(" VOLATILITY ")];
+        if self.volatility.len() > 1 {
+            for asset in self.volatility.keys() {
+                title.push(if *asset == self.vol_asset {
+                    Span::styled(
+                        format!("[{}]", asset),
+                        Style::default()
+                            .fg(theme.accent)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Span::styled(format!(" {} ", asset), Style::default().fg(theme.muted))
+                });
+            }
+            title.push(Span::raw(" "));
         }
 
-        // Right: IV sparkline
-        if !self.implied_vol_history.is_empty() {
-            let iv_sparkline = Sparkline::default()
-                .block(Block::default().borders(Borders::ALL).title(" IV Trend "))
-                .data(&self.implied_vol_history)
-                .style(Style::default().fg(Color::LightBlue));
+        let paragraph = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(Line::from(title)),
+            )
+            .alignment(ratatui::layout::Alignment::Center);
 
-            frame.render_widget(iv_sparkline, chunks[2]);
-        }
+        frame.render_widget(paragraph, area);
     }
 
-    fn render_help(&self, frame: &mut Frame) {
+    fn render_help(&mut self, frame: &mut Frame) {
+        let theme = self.theme;
         use ratatui::widgets::{Clear, Wrap};
 
         // Create centered popup area (80% width, 90% height)
@@ -552,213 +3717,293 @@ impl App {
         // Help content
         let help_text = vec![
             Line::from(vec![
-                Span::styled("HELP & METRICS GUIDE", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled("HELP & METRICS GUIDE", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("═".repeat(popup_width as usize - 4), Style::default().fg(Color::DarkGray)),
+                Span::styled("═".repeat(popup_width as usize - 4), Style::default().fg(theme.faint)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("COLUMN EXPLANATIONS", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled("COLUMN EXPLANATIONS", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Imp% ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled("Imp% ", Style::default().fg(theme.positive).add_modifier(Modifier::BOLD)),
                 Span::raw("(Implied Probability)"),
             ]),
             Line::from("  Market's implied probability of the contract winning"),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("↑ Higher", Style::default().fg(Color::Green)),
+                Span::styled("↑ Higher", Style::default().fg(theme.positive)),
                 Span::raw(" = Market thinks it's more likely to happen"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("↓ Lower", Style::default().fg(Color::Red)),
+                Span::styled("↓ Lower", Style::default().fg(theme.negative)),
                 Span::raw("  = Market thinks it's less likely to happen"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Mod% ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled("Mod% ", Style::default().fg(theme.positive).add_modifier(Modifier::BOLD)),
                 Span::raw("(Model Probability)"),
             ]),
             Line::from("  Our model's calculated probability of the contract winning"),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("↑ Higher", Style::default().fg(Color::Green)),
+                Span::styled("↑ Higher", Style::default().fg(theme.positive)),
                 Span::raw(" = Our model thinks it's more likely"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("↓ Lower", Style::default().fg(Color::Red)),
+                Span::styled("↓ Lower", Style::default().fg(theme.negative)),
                 Span::raw("  = Our model thinks it's less likely"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("EV ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled("EV ", Style::default().fg(theme.positive).add_modifier(Modifier::BOLD)),
                 Span::raw("(Expected Value)"),
             ]),
             Line::from("  The edge we have over the market"),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("↑ Higher Positive EV", Style::default().fg(Color::Green)),
+                Span::styled("↑ Higher Positive EV", Style::default().fg(theme.positive)),
                 Span::raw(" = Better trading opportunity"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("↓ Lower/Negative EV", Style::default().fg(Color::Red)),
+                Span::styled("↓ Lower/Negative EV", Style::default().fg(theme.negative)),
                 Span::raw(" = Worse trading opportunity"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("KEY: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled("KEY: ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
                 Span::raw("When Mod% > Imp%, you have positive EV (market underpricing)"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Action", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled("Action", Style::default().fg(theme.positive).add_modifier(Modifier::BOLD)),
             ]),
             Line::from("  Trading recommendation based on EV"),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("BUY YES", Style::default().fg(Color::Green)),
+                Span::styled("BUY YES", Style::default().fg(theme.positive)),
                 Span::raw(" = Market underpriced YES - buy the YES side"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("BUY NO", Style::default().fg(Color::Red)),
+                Span::styled("BUY NO", Style::default().fg(theme.negative)),
                 Span::raw("  = Market overpriced YES - buy the NO side"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("HOLD", Style::default().fg(Color::Yellow)),
+                Span::styled("HOLD", Style::default().fg(theme.highlight)),
                 Span::raw("    = No edge or insufficient edge to trade"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("─".repeat(popup_width as usize - 4), Style::default().fg(Color::DarkGray)),
+                Span::styled("─".repeat(popup_width as usize - 4), Style::default().fg(theme.faint)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("KEYBOARD SHORTCUTS", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled("KEYBOARD SHORTCUTS", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("  [h/?] ", Style::default().fg(Color::Cyan)),
+                Span::styled(if self.config.keymap.vim { "  [?]   " } else { "  [h/?] " }, Style::default().fg(theme.accent)),
                 Span::raw("Toggle this help screen"),
             ]),
             Line::from(vec![
-                Span::styled("  [r]   ", Style::default().fg(Color::Cyan)),
+                Span::styled("  [gg/G] ", Style::default().fg(theme.accent)),
+                Span::raw("First / last row (^U/^D or PgUp/PgDn to page, j/k to step)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [r]   ", Style::default().fg(theme.accent)),
                 Span::raw("Refresh data manually"),
             ]),
             Line::from(vec![
-                Span::styled("  [q]   ", Style::default().fg(Color::Cyan)),
+                Span::styled("  [a]   ", Style::default().fg(theme.accent)),
+                Span::raw("Acknowledge alerts (critical alerts blink until acknowledged)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [w]   ", Style::default().fg(theme.accent)),
+                Span::raw("Stress-test open positions (spot × vol shocks)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [t]   ", Style::default().fg(theme.accent)),
+                Span::raw(if self.config.read_only {
+                    "Trading is off in read-only mode (t, P, x and n do nothing)"
+                } else {
+                    "Trade highlighted signal (1-9 size presets, +/- adjust, ↑/↓ price, Enter submit)"
+                }),
+            ]),
+            Line::from(vec![
+                Span::styled("  [P]   ", Style::default().fg(theme.accent)),
+                Span::raw("Allocation plan: spread the hourly budget over the front expiry's signals"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [p]   ", Style::default().fg(theme.accent)),
+                Span::raw("Pin/unpin the highlighted strike: kept at the top with 📌, saved across restarts"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [Enter] ", Style::default().fg(theme.accent)),
+                Span::raw("Detail view of the highlighted signal (Enter/ESC to go back)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [!/@/#/)] ", Style::default().fg(theme.accent)),
+                Span::raw("Signals tab (Shift+1/2/3/0): BUY YES only, BUY NO only, positive EV only, all"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [o]   ", Style::default().fg(theme.accent)),
+                Span::raw("My model: vol source, IV bump and drift for a My% column next to Mod%"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [f]   ", Style::default().fg(theme.accent)),
+                Span::raw("EV column net of Kalshi's taker fee, or as the backend reports it"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [b/B/z] ", Style::default().fg(theme.accent)),
+                Span::raw("Hide the vol banner / one-line status bar and footer / compact layout"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [u]   ", Style::default().fg(theme.accent)),
+                Span::raw("Color theme: dark, light or high-contrast (also --theme and [layout] theme)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [d]   ", Style::default().fg(theme.accent)),
+                Span::raw("Diagnostics (connection health, auto-trader governor)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [^F]  ", Style::default().fg(theme.accent)),
+                Span::raw("Search signals, positions and history; Enter jumps to the match"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [y]   ", Style::default().fg(theme.accent)),
+                Span::raw("Archive: search past hours' contracts by date, hour, strike or outcome"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [l]   ", Style::default().fg(theme.accent)),
+                Span::raw("Log pane: stream events, errors, trades and reconnects (↑/↓ scroll while open; L in vim mode)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [x]   ", Style::default().fg(theme.accent)),
+                Span::raw("Positions tab: close the highlighted position; Orders tab: cancel the order; Rules tab: cancel the rule"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [i]   ", Style::default().fg(theme.accent)),
+                Span::raw("Orders tab: amend the highlighted order's limit or size (cancel & replace)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [/]   ", Style::default().fg(theme.accent)),
+                Span::raw("History tab: filter by ticker, asset, side or status (PgUp/PgDn page, Enter opens a trade)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  [q]   ", Style::default().fg(theme.accent)),
                 Span::raw("Quit application"),
             ]),
             Line::from(vec![
-                Span::styled("  [ESC] ", Style::default().fg(Color::Cyan)),
+                Span::styled("  [ESC] ", Style::default().fg(theme.accent)),
                 Span::raw("Close help screen"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("─".repeat(popup_width as usize - 4), Style::default().fg(Color::DarkGray)),
+                Span::styled("─".repeat(popup_width as usize - 4), Style::default().fg(theme.faint)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("VOLATILITY BANNER METRICS", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled("VOLATILITY BANNER METRICS", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Regime ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled("Regime ", Style::default().fg(theme.positive).add_modifier(Modifier::BOLD)),
                 Span::raw("(Market Volatility Classification)"),
             ]),
             Line::from("  Current volatility level based on realized movement"),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("CALM", Style::default().fg(Color::Green)),
+                Span::styled("CALM", Style::default().fg(theme.positive)),
                 Span::raw(" (<30% RV) = Very quiet market"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("NORMAL", Style::default().fg(Color::Yellow)),
+                Span::styled("NORMAL", Style::default().fg(theme.highlight)),
                 Span::raw(" (30-50% RV) = Typical Bitcoin volatility"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("ELEVATED", Style::default().fg(Color::LightRed)),
+                Span::styled("ELEVATED", Style::default().fg(theme.caution)),
                 Span::raw(" (50-75% RV) = Higher than normal movement"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("CRISIS", Style::default().fg(Color::Red)),
+                Span::styled("CRISIS", Style::default().fg(theme.negative)),
                 Span::raw(" (>75% RV) = Extreme volatility"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("RV ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled("RV ", Style::default().fg(theme.positive).add_modifier(Modifier::BOLD)),
                 Span::raw("(Realized Volatility)"),
             ]),
             Line::from("  How much BTC has ACTUALLY moved in the past 24 hours (annualized)"),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("Example: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Example: ", Style::default().fg(theme.accent)),
                 Span::raw("RV: 70% = BTC swinging ±70% annualized rate"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("This is REALITY", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled("This is REALITY", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
                 Span::raw(" - what's happening right now"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("IV ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled("IV ", Style::default().fg(theme.positive).add_modifier(Modifier::BOLD)),
                 Span::raw("(Implied Volatility from Deribit DVOL)"),
             ]),
             Line::from("  What the OPTIONS MARKET expects volatility to be (next 30 days)"),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("Example: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Example: ", Style::default().fg(theme.accent)),
                 Span::raw("IV: 49% = Traders pricing 49% annualized volatility"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("This is EXPECTATION", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled("This is EXPECTATION", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
                 Span::raw(" - what market thinks will happen"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Premium ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled("Premium ", Style::default().fg(theme.positive).add_modifier(Modifier::BOLD)),
                 Span::raw("(Volatility Risk Premium)"),
             ]),
             Line::from("  Difference between expected (IV) vs actual (RV) volatility"),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("Formula: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Formula: ", Style::default().fg(theme.accent)),
                 Span::raw("(IV - RV) / RV"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("Positive ", Style::default().fg(Color::Green)),
+                Span::styled("Positive ", Style::default().fg(theme.positive)),
                 Span::raw("(IV > RV) = Vol is EXPENSIVE → Sell volatility"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("Negative ", Style::default().fg(Color::Red)),
+                Span::styled("Negative ", Style::default().fg(theme.negative)),
                 Span::raw("(IV < RV) = Vol is CHEAP → Buy volatility"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("Example: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Example: ", Style::default().fg(theme.accent)),
                 Span::raw("Premium: -30% = Market underpricing risk by 30%!"),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("─".repeat(popup_width as usize - 4), Style::default().fg(Color::DarkGray)),
+                Span::styled("─".repeat(popup_width as usize - 4), Style::default().fg(theme.faint)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("HOW IT WORKS", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled("HOW IT WORKS", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from("  Basilisk analyzes Bitcoin hourly contracts on Kalshi to find mispriced"),
@@ -769,11 +4014,11 @@ impl App {
             Line::from("  we've found an edge. Higher mispricing = better trading opportunity."),
             Line::from(""),
             Line::from(vec![
-                Span::styled("─".repeat(popup_width as usize - 4), Style::default().fg(Color::DarkGray)),
+                Span::styled("─".repeat(popup_width as usize - 4), Style::default().fg(theme.faint)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("SETTLEMENT RULE", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled("SETTLEMENT RULE", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from("  • Settlement begins 1 minute before expiration"),
@@ -781,37 +4026,100 @@ impl App {
             Line::from("  • Contract resolves YES if final price is above strike, NO if below"),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Press [ESC] or [h] to close", Style::default().fg(Color::DarkGray)),
+                Span::styled("Press [ESC] or [h] to close", Style::default().fg(theme.faint)),
             ]),
         ];
 
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(" HELP (↑↓ PgUp/PgDn scroll │ 1 Columns  2 Shortcuts  3 Volatility) ")
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            );
+        // One column in from the border so the text doesn't run under the scrollbar
+        let inner = block.inner(popup_area);
+        let text_area = Rect {
+            width: inner.width.saturating_sub(1),
+            ..inner
+        };
+        self.help_scroll
+            .layout(&help_text, text_area, &HELP_SECTIONS);
+
         let paragraph = Paragraph::new(help_text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Cyan))
-                    .title(" HELP (Use ↑↓ to scroll) ")
-                    .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
-            )
             .wrap(Wrap { trim: false })
-            .scroll((self.help_scroll, 0));
+            .scroll((self.help_scroll.offset(), 0));
+
+        frame.render_widget(block, popup_area);
+        frame.render_widget(paragraph, text_area);
+        self.help_scroll.render_scrollbar(frame, popup_area);
+    }
+
+    /// Send a Tick every second so countdowns move between data refreshes
+    fn spawn_tick_task(tx: mpsc::UnboundedSender<AppEvent>) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(1));
+            loop {
+                interval.tick().await;
+                if tx.send(AppEvent::Tick).is_err() {
+                    break;
+                }
+            }
+        });
+    }
 
-        frame.render_widget(paragraph, popup_area);
+    /// Ping the trade endpoint every `LATENCY_PROBE_INTERVAL` off the UI loop.
+    /// Failures are left out of the average, so nothing is sent for them.
+    fn spawn_latency_probe(api_url: String, tx: mpsc::UnboundedSender<AppEvent>) {
+        tokio::spawn(async move {
+            let Ok(client) = ApiClient::new(api_url, 10) else {
+                return;
+            };
+            let mut interval = tokio::time::interval(LATENCY_PROBE_INTERVAL);
+            loop {
+                interval.tick().await;
+                if let Ok(rtt) = client.ping_trade_endpoint().await {
+                    if tx.send(AppEvent::TradeLatency(rtt)).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
     }
 
-    /// Spawn SSE background task that streams trading data
-    fn spawn_sse_task(api_url: String, tx: mpsc::UnboundedSender<AppEvent>) {
+    /// Spawn SSE background task that streams trading data for one asset.
+    /// Only the primary asset's stream drives the connection state.
+    fn spawn_sse_task(
+        api_url: String,
+        asset: String,
+        tx: mpsc::UnboundedSender<AppEvent>,
+        ticks: Option<TickWriter>,
+        stats: Arc<StreamStats>,
+    ) {
+        let primary = asset == PRIMARY_ASSET;
         tokio::spawn(async move {
             loop {
-                if let Err(e) = Self::run_sse_client(&api_url, &tx).await {
-                    eprintln!("SSE error: {}, reconnecting in 5s...", e);
-                    tx.send(AppEvent::SseError(e.to_string())).ok();
+                if let Err(e) =
+                    Self::run_sse_client(&api_url, &asset, &tx, ticks.as_ref(), &stats).await
+                {
+                    if primary {
+                        eprintln!("SSE error: {}, reconnecting in 5s...", e);
+                        tx.send(AppEvent::SseError(e.to_string())).ok();
+                    }
                     tokio::time::sleep(Duration::from_secs(5)).await;
                 } else {
                     // Connection closed gracefully
-                    tx.send(AppEvent::SseDisconnected).ok();
+                    if primary {
+                        tx.send(AppEvent::SseDisconnected).ok();
+                    }
                     tokio::time::sleep(Duration::from_secs(2)).await;
                 }
+                if primary {
+                    stats.reconnecting();
+                    tx.send(AppEvent::SseReconnecting).ok();
+                }
             }
         });
     }
@@ -819,64 +4127,103 @@ impl App {
     /// Run the SSE client connection
     async fn run_sse_client(
         api_url: &str,
+        asset: &str,
         tx: &mpsc::UnboundedSender<AppEvent>,
+        ticks: Option<&TickWriter>,
+        stats: &StreamStats,
     ) -> Result<()> {
         use es::Client;
 
-        let stream_url = format!("{}/api/v1/stream/trading", api_url);
+        let stream_url = format!("{}/api/v1/stream/{}", api_url, asset.to_lowercase());
+        let primary = asset == PRIMARY_ASSET;
+        let price_event = format!("{}_price", asset.to_lowercase());
 
         let client = es::ClientBuilder::for_url(&stream_url)?
             .header("Accept", "text/event-stream")?
             .build();
 
-        tx.send(AppEvent::SseConnected).ok();
-
         let mut stream = Box::pin(client.stream());
 
         while let Some(event) = stream.next().await {
             match event {
                 Ok(es::SSE::Connected(_)) => {
-                    // Connection established
+                    // Only report connected once the server actually answers
+                    if primary {
+                        tx.send(AppEvent::SseConnected).ok();
+                    }
                 }
                 Ok(es::SSE::Event(event)) => {
+                    if primary {
+                        stats.event();
+                    }
                     match event.event_type.as_str() {
                         "connected" => {
                             // Initial connection confirmation
                         }
-                        "btc_price" => {
-                            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&event.data) {
+                        name if name == price_event && primary => {
+                            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&event.data)
+                            {
                                 if let (Some(price), Some(timestamp)) = (
                                     data.get("price").and_then(|v| v.as_f64()),
                                     data.get("timestamp").and_then(|v| v.as_str()),
                                 ) {
+                                    if let Some(ticks) = ticks {
+                                        ticks.record(
+                                            price,
+                                            api::parse_timestamp(timestamp)
+                                                .unwrap_or_else(Utc::now),
+                                        );
+                                    }
                                     tx.send(AppEvent::BtcPriceUpdate {
                                         price,
-                                        _timestamp: timestamp.to_string(),
-                                    }).ok();
+                                        timestamp: timestamp.to_string(),
+                                    })
+                                    .ok();
                                 }
                             }
                         }
                         "contracts_update" => {
-                            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&event.data) {
+                            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&event.data)
+                            {
                                 if let (Some(contracts_json), Some(timestamp)) = (
                                     data.get("contracts"),
                                     data.get("timestamp").and_then(|v| v.as_str()),
                                 ) {
-                                    if let Ok(contracts) = serde_json::from_value::<Vec<Contract>>(contracts_json.clone()) {
+                                    if let Ok(contracts) = serde_json::from_value::<Vec<Contract>>(
+                                        contracts_json.clone(),
+                                    ) {
                                         // Extract volatility data if present
-                                        let volatility = data.get("volatility")
-                                            .and_then(|v| serde_json::from_value::<VolatilityData>(v.clone()).ok())
+                                        let volatility = data
+                                            .get("volatility")
+                                            .and_then(|v| {
+                                                serde_json::from_value::<VolatilityData>(v.clone())
+                                                    .ok()
+                                            })
                                             .unwrap_or_default();
 
                                         tx.send(AppEvent::ContractsUpdate {
+                                            asset: asset.to_string(),
                                             contracts,
                                             volatility,
-                                            _timestamp: timestamp.to_string(),
-                                        }).ok();
+                                            timestamp: timestamp.to_string(),
+                                        })
+                                        .ok();
                                     }
                                 }
                             }
                         }
+                        "ticker_update" if primary => {
+                            if let Some((ticker, volume, open_interest)) =
+                                liquidity::parse_ticker_update(&event.data)
+                            {
+                                tx.send(AppEvent::TickerUpdate {
+                                    ticker,
+                                    volume,
+                                    open_interest,
+                                })
+                                .ok();
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -894,45 +4241,124 @@ impl App {
 
     /// Handle SSE events
     fn handle_sse_event(&mut self, event: AppEvent) {
+        self.replay.record(&event);
         match event {
             AppEvent::SseConnected => {
-                self.connection_state = ConnectionState::Connected;
+                self.event_log.push(LogKind::Connection, "stream connected");
+                self.connection.sse_connected();
+                self.feed_latency.reset();
                 self.error_message = None;
+                self.alerts.connection_restored();
             }
             AppEvent::SseDisconnected => {
-                self.connection_state = ConnectionState::Disconnected;
-                self.error_message = Some("SSE disconnected, reconnecting...".to_string());
+                self.event_log
+                    .push(LogKind::Connection, "stream disconnected");
+                self.connection.sse_lost();
+                self.connection_failed(
+                    "SSE disconnected, reconnecting...".to_string(),
+                    "SSE disconnected",
+                );
             }
-            AppEvent::BtcPriceUpdate { price, .. } => {
+            AppEvent::BtcPriceUpdate { price, timestamp } => {
+                self.feed_latency.record(&timestamp);
                 self.current_btc_price = price;
+                self.btc_trend.record(price);
+                self.btc_range = Some(match self.btc_range {
+                    Some((low, high)) => (low.min(price), high.max(price)),
+                    None => (price, price),
+                });
                 // Update price in all contracts for real-time distance calculations
                 for contract in &mut self.contracts {
                     contract.current_btc_price = Some(price);
                 }
             }
             AppEvent::ContractsUpdate {
+                asset,
                 contracts,
                 volatility,
-                ..
+                timestamp,
             } => {
+                self.feed_latency.record(&timestamp);
+                self.event_log.push(
+                    LogKind::Stream,
+                    format!("{} contracts update: {} contracts", asset, contracts.len()),
+                );
+                if asset != PRIMARY_ASSET {
+                    // Other assets only feed their volatility banner tab
+                    self.volatility.insert(asset, volatility);
+                    return;
+                }
+                let as_of = api::parse_timestamp(&timestamp).unwrap_or_else(Utc::now);
+                if !self.check_consistency(Source::Stream, as_of, &contracts) {
+                    // A poll already delivered newer data than this event
+                    return;
+                }
+                self.handle_rollover(&contracts);
                 self.contracts = contracts;
-                self.volatility_data = volatility;
+                self.signals_view.record_quotes(&self.contracts);
+                self.edge_history.record(&self.contracts);
+                self.archive_contracts();
+                self.volatility
+                    .insert(PRIMARY_ASSET.to_string(), volatility);
+                self.record_vol_sample();
 
                 if let Some(first) = self.contracts.first() {
                     if let Some(price) = first.current_btc_price {
                         self.current_btc_price = price;
+                        self.btc_trend.record(price);
                     }
                 }
                 self.last_update = Some(Instant::now());
+                self.evaluate_alerts();
+                if self.comparison.is_some() {
+                    self.refresh_comparison();
+                }
+            }
+            AppEvent::TickerUpdate {
+                ticker,
+                volume,
+                open_interest,
+            } => {
+                self.volume.record(&ticker, volume, open_interest);
+            }
+            AppEvent::SseReconnecting => {
+                let attempt = format!(
+                    "reconnecting (#{} this session)",
+                    self.stream_stats.reconnects()
+                );
+                self.event_log.push(LogKind::Connection, attempt);
+                self.connection.sse_reconnecting();
             }
             AppEvent::SseError(err) => {
-                self.connection_state = ConnectionState::Disconnected;
-                self.error_message = Some(format!("SSE Error: {}", err));
+                self.connection.sse_lost();
+                self.connection_failed(format!("SSE Error: {}", err), &err);
             }
             AppEvent::Keyboard(_key) => {
                 // Handle in main loop
             }
-            AppEvent::Tick | AppEvent::Quit => {
+            AppEvent::Tick => {
+                let previous = self.front_expiry_secs;
+                self.front_expiry_secs = self.seconds_to_front_expiry();
+                // The countdown jumping up (or vanishing) means the front contract expired
+                if let Some(previous) = previous {
+                    if self.front_expiry_secs.is_none_or(|secs| secs > previous) {
+                        self.expiry_passed = true;
+                    }
+                }
+            }
+            AppEvent::TradeLatency(rtt) => self.trade_latency.record(rtt),
+            AppEvent::LiquiditySample { impact, failures } => {
+                self.sampling_liquidity = false;
+                self.impact = impact;
+                if let Some(first) = failures.first() {
+                    self.error_message = Some(format!(
+                        "Liquidity sample: {} order book(s) failed ({})",
+                        failures.len(),
+                        first
+                    ));
+                }
+            }
+            AppEvent::Quit => {
                 // Handle in main loop
             }
         }
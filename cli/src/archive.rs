@@ -0,0 +1,500 @@
+//! Historical contract archive (`basilisk archive`, `y` on the dashboard).
+//!
+//! The dashboard appends a snapshot of every listed contract to
+//! `archive.jsonl` each `[archive] snapshot_secs` (0 = every update), plus a
+//! last one for each contract as its expiry drops off the list. Joined with
+//! the outcomes already kept in `settlements.jsonl` that gives one entry per
+//! contract: how its signal, quotes and EV moved through the hour, and how it
+//! settled. Snapshots older than `max_age_days` are pruned on startup.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Timelike, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::api::{parse_timestamp, Contract};
+use crate::config::ArchiveConfig;
+use crate::rollover::{self, Settlement};
+use crate::store;
+
+const ARCHIVE_FILE: &str = "archive.jsonl";
+
+/// A contract as the dashboard saw it at one moment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractSnapshot {
+    pub recorded_at: DateTime<Utc>,
+    pub ticker: String,
+    pub expiry_time: Option<String>,
+    pub strike: Option<f64>,
+    pub btc_price: Option<f64>,
+    pub signal_type: String,
+    pub expected_value: f64,
+    pub confidence_score: f64,
+    pub yes_price: Option<f64>,
+    pub no_price: Option<f64>,
+    pub implied_probability: Option<f64>,
+    pub model_probability: Option<f64>,
+}
+
+impl ContractSnapshot {
+    fn new(contract: &Contract, recorded_at: DateTime<Utc>) -> Self {
+        Self {
+            recorded_at,
+            ticker: contract.ticker.clone(),
+            expiry_time: contract.expiry_time.clone(),
+            strike: contract.strike_price,
+            btc_price: contract.current_btc_price,
+            signal_type: contract.signal_type.clone(),
+            expected_value: contract.expected_value,
+            confidence_score: contract.confidence_score,
+            yes_price: contract.yes_price,
+            no_price: contract.no_price,
+            implied_probability: contract.implied_probability,
+            model_probability: contract.model_probability,
+        }
+    }
+}
+
+/// Appends the dashboard's contract updates at the configured pace
+pub struct ArchiveWriter {
+    enabled: bool,
+    every: std::time::Duration,
+    last_written: Option<Instant>,
+}
+
+impl ArchiveWriter {
+    /// Prunes old snapshots once, then starts recording
+    pub fn new(config: &ArchiveConfig) -> Self {
+        if config.enabled {
+            // Best-effort: an unprunable archive only costs disk
+            prune(Duration::days(config.max_age_days as i64)).ok();
+        }
+        Self {
+            enabled: config.enabled,
+            every: std::time::Duration::from_secs(config.snapshot_secs),
+            last_written: None,
+        }
+    }
+
+    /// Archive a contracts update if a snapshot is due
+    pub fn record(&mut self, contracts: &[Contract]) -> Result<()> {
+        if !self.enabled
+            || self
+                .last_written
+                .is_some_and(|at| at.elapsed() < self.every)
+        {
+            return Ok(());
+        }
+        self.last_written = Some(Instant::now());
+        write(contracts)
+    }
+
+    /// Archive an expiry's contracts as they were last listed
+    pub fn record_final(&self, contracts: &[Contract]) -> Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+        write(contracts)
+    }
+}
+
+fn write(contracts: &[Contract]) -> Result<()> {
+    let now = Utc::now();
+    let snapshots: Vec<ContractSnapshot> = contracts
+        .iter()
+        .map(|c| ContractSnapshot::new(c, now))
+        .collect();
+    if snapshots.is_empty() {
+        return Ok(());
+    }
+    store::append_jsonl_all(ARCHIVE_FILE, &snapshots)
+}
+
+fn prune(max_age: Duration) -> Result<()> {
+    let cutoff = Utc::now() - max_age;
+    let snapshots: Vec<ContractSnapshot> = store::read_jsonl(ARCHIVE_FILE)?;
+    let kept: Vec<&ContractSnapshot> = snapshots
+        .iter()
+        .filter(|s| s.recorded_at >= cutoff)
+        .collect();
+    if kept.len() < snapshots.len() {
+        store::write_jsonl(ARCHIVE_FILE, &kept)?;
+    }
+    Ok(())
+}
+
+/// One archived contract: its snapshots through the hour and how it settled
+#[derive(Debug, Clone)]
+pub struct ArchiveEntry {
+    pub ticker: String,
+    pub expiry: Option<DateTime<Utc>>,
+    pub strike: Option<f64>,
+    /// Oldest first
+    pub snapshots: Vec<ContractSnapshot>,
+    pub settlement: Option<Settlement>,
+}
+
+impl ArchiveEntry {
+    fn new(ticker: &str, expiry_time: Option<&str>, strike: Option<f64>) -> Self {
+        Self {
+            ticker: ticker.to_string(),
+            expiry: expiry_time.and_then(parse_timestamp),
+            strike,
+            snapshots: Vec::new(),
+            settlement: None,
+        }
+    }
+
+    /// Signal when first seen, e.g. "BUY YES"
+    pub fn first_signal(&self) -> &str {
+        self.snapshots
+            .first()
+            .map(|s| s.signal_type.as_str())
+            .or(self.settlement.as_ref().map(|s| s.signal_type.as_str()))
+            .unwrap_or("--")
+    }
+
+    /// Signal at expiry (the one the settlement scored)
+    pub fn last_signal(&self) -> &str {
+        self.settlement
+            .as_ref()
+            .map(|s| s.signal_type.as_str())
+            .or(self.snapshots.last().map(|s| s.signal_type.as_str()))
+            .unwrap_or("--")
+    }
+
+    /// "YES" or "NO" once settled
+    pub fn outcome(&self) -> Option<&str> {
+        self.settlement.as_ref().map(|s| s.outcome.as_str())
+    }
+
+    /// Whether the final signal's side won (None for HOLD or unsettled)
+    pub fn signal_correct(&self) -> Option<bool> {
+        self.settlement.as_ref().and_then(|s| s.signal_correct)
+    }
+
+    /// Last archived EV
+    pub fn last_ev(&self) -> Option<f64> {
+        self.snapshots.last().map(|s| s.expected_value)
+    }
+
+    /// "2026-10-15 14:00"
+    pub fn expiry_label(&self) -> String {
+        self.expiry
+            .map(|at| at.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "--".to_string())
+    }
+
+    pub fn strike_label(&self) -> String {
+        self.strike
+            .map(|s| format!("${:.0}", s))
+            .unwrap_or_else(|| "--".to_string())
+    }
+
+    /// "YES @ $98512", or "unsettled"
+    pub fn result_label(&self) -> String {
+        match &self.settlement {
+            Some(s) => format!("{} @ ${:.0}", s.outcome, s.settle_price),
+            None => "unsettled".to_string(),
+        }
+    }
+
+    /// "hit", "miss", or "--" for HOLD and unsettled
+    pub fn verdict_label(&self) -> &'static str {
+        match self.signal_correct() {
+            Some(true) => "hit",
+            Some(false) => "miss",
+            None => "--",
+        }
+    }
+}
+
+/// Every archived contract, newest expiry first and by strike within an hour.
+/// Settlements recorded before the archive existed show up without snapshots.
+pub fn entries() -> Result<Vec<ArchiveEntry>> {
+    let snapshots: Vec<ContractSnapshot> =
+        store::read_jsonl(ARCHIVE_FILE).context("Failed to read the contract archive")?;
+    let settlements = rollover::settlements().context("Failed to read settlements")?;
+
+    let mut by_ticker: HashMap<String, ArchiveEntry> = HashMap::new();
+    for snapshot in snapshots {
+        by_ticker
+            .entry(snapshot.ticker.clone())
+            .or_insert_with(|| {
+                ArchiveEntry::new(
+                    &snapshot.ticker,
+                    snapshot.expiry_time.as_deref(),
+                    snapshot.strike,
+                )
+            })
+            .snapshots
+            .push(snapshot);
+    }
+    for settlement in settlements {
+        let entry = by_ticker
+            .entry(settlement.ticker.clone())
+            .or_insert_with(|| {
+                ArchiveEntry::new(
+                    &settlement.ticker,
+                    settlement.expiry_time.as_deref(),
+                    settlement.strike,
+                )
+            });
+        entry.settlement = Some(settlement);
+    }
+
+    let mut entries: Vec<ArchiveEntry> = by_ticker.into_values().collect();
+    for entry in &mut entries {
+        entry.snapshots.sort_by_key(|s| s.recorded_at);
+    }
+    entries.sort_by(|a, b| {
+        b.expiry
+            .cmp(&a.expiry)
+            .then(a.strike.unwrap_or(0.0).total_cmp(&b.strike.unwrap_or(0.0)))
+    });
+    Ok(entries)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Outcome {
+    /// Settled YES (BTC finished above the strike)
+    Yes,
+    /// Settled NO
+    No,
+    /// The final signal's side won
+    Hit,
+    /// The final signal's side lost
+    Miss,
+    /// No settlement recorded
+    Unsettled,
+}
+
+impl Outcome {
+    fn matches(self, entry: &ArchiveEntry) -> bool {
+        match self {
+            Outcome::Yes => entry.outcome() == Some("YES"),
+            Outcome::No => entry.outcome() == Some("NO"),
+            Outcome::Hit => entry.signal_correct() == Some(true),
+            Outcome::Miss => entry.signal_correct() == Some(false),
+            Outcome::Unsettled => entry.settlement.is_none(),
+        }
+    }
+}
+
+/// Search over the archive; every field that is set must match
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveQuery {
+    /// Expiry date (UTC)
+    pub date: Option<NaiveDate>,
+    /// Expiry hour (UTC, 0-23)
+    pub hour: Option<u32>,
+    pub strike: Option<f64>,
+    pub outcome: Option<Outcome>,
+    /// Lower-cased words matched against the ticker and signals
+    pub text: Vec<String>,
+}
+
+impl ArchiveQuery {
+    /// Parse the browser's search line: `2026-10-15` is a date, `14h` an
+    /// hour, a number a strike, `yes`/`no`/`hit`/`miss`/`unsettled` an
+    /// outcome; anything else matches the ticker or signal.
+    pub fn parse(text: &str) -> Self {
+        let mut query = Self::default();
+        for term in text.split_whitespace() {
+            let term = term.to_lowercase();
+            if let Ok(date) = NaiveDate::parse_from_str(&term, "%Y-%m-%d") {
+                query.date = Some(date);
+            } else if let Some(hour) = term
+                .strip_suffix('h')
+                .and_then(|h| h.parse::<u32>().ok())
+                .filter(|h| *h < 24)
+            {
+                query.hour = Some(hour);
+            } else if let Ok(strike) = term.trim_start_matches('$').parse::<f64>() {
+                query.strike = Some(strike);
+            } else if let Ok(outcome) = Outcome::from_str(&term, true) {
+                query.outcome = Some(outcome);
+            } else {
+                query.text.push(term);
+            }
+        }
+        query
+    }
+
+    pub fn matches(&self, entry: &ArchiveEntry) -> bool {
+        if let Some(date) = self.date {
+            if entry.expiry.map(|at| at.date_naive()) != Some(date) {
+                return false;
+            }
+        }
+        if let Some(hour) = self.hour {
+            if entry.expiry.map(|at| at.hour()) != Some(hour) {
+                return false;
+            }
+        }
+        if let Some(strike) = self.strike {
+            if !entry.strike.is_some_and(|s| (s - strike).abs() < 0.5) {
+                return false;
+            }
+        }
+        if let Some(outcome) = self.outcome {
+            if !outcome.matches(entry) {
+                return false;
+            }
+        }
+        let haystack = format!(
+            "{} {} {}",
+            entry.ticker,
+            entry.first_signal(),
+            entry.last_signal()
+        )
+        .to_lowercase();
+        self.text
+            .iter()
+            .all(|term| haystack.contains(term.as_str()))
+    }
+}
+
+/// Hit rate of the matched entries' final signals: (hits, scored)
+pub fn hit_rate(entries: &[&ArchiveEntry]) -> (usize, usize) {
+    let scored: Vec<bool> = entries.iter().filter_map(|e| e.signal_correct()).collect();
+    (scored.iter().filter(|hit| **hit).count(), scored.len())
+}
+
+pub fn handle_archive_command(
+    query: ArchiveQuery,
+    ticker: Option<String>,
+    limit: usize,
+) -> Result<()> {
+    if query.hour.is_some_and(|h| h > 23) {
+        bail!("--hour is a UTC hour (0-23)");
+    }
+    let entries = entries()?;
+
+    if let Some(ticker) = ticker {
+        let Some(entry) = entries
+            .iter()
+            .find(|e| e.ticker.eq_ignore_ascii_case(&ticker))
+        else {
+            bail!("{} is not in the archive", ticker);
+        };
+        print_timeline(entry);
+        return Ok(());
+    }
+
+    let matched: Vec<&ArchiveEntry> = entries.iter().filter(|e| query.matches(e)).collect();
+    if matched.is_empty() {
+        println!("📭 No archived contracts match.");
+        if entries.is_empty() {
+            println!("   Contracts are archived while the dashboard is running.");
+        }
+        return Ok(());
+    }
+
+    println!(
+        "🗄  Contract Archive ({} match{}, expiries in UTC)",
+        matched.len(),
+        if matched.len() == 1 { "" } else { "es" }
+    );
+    println!("{}", "─".repeat(110));
+    println!(
+        "{:<17} {:<26} {:<8} {:<20} {:>8} {:<16} {:<6} {:>5}",
+        "Expiry", "Ticker", "Strike", "Signal", "Last EV", "Settled", "Call", "Snaps"
+    );
+    println!("{}", "─".repeat(110));
+    for entry in matched.iter().take(limit) {
+        let signal = if entry.first_signal() == entry.last_signal() {
+            entry.last_signal().to_string()
+        } else {
+            format!("{} → {}", entry.first_signal(), entry.last_signal())
+        };
+        let color = match entry.signal_correct() {
+            Some(true) => "\x1b[32m",
+            Some(false) => "\x1b[31m",
+            None => "\x1b[90m",
+        };
+        println!(
+            "{:<17} {:<26} {:<8} {:<20} {:>8} {:<16} {}{:<6}\x1b[0m {:>5}",
+            entry.expiry_label(),
+            entry.ticker,
+            entry.strike_label(),
+            signal,
+            entry
+                .last_ev()
+                .map(|ev| format!("{:+.1}%", ev * 100.0))
+                .unwrap_or_else(|| "--".to_string()),
+            entry.result_label(),
+            color,
+            entry.verdict_label(),
+            entry.snapshots.len()
+        );
+    }
+    println!("{}", "─".repeat(110));
+    if matched.len() > limit {
+        println!("… {} more (raise --limit)", matched.len() - limit);
+    }
+    let (hits, scored) = hit_rate(&matched);
+    if scored > 0 {
+        println!(
+            "Signals: {}/{} hit ({:.0}%)",
+            hits,
+            scored,
+            hits as f64 / scored as f64 * 100.0
+        );
+    }
+    Ok(())
+}
+
+fn print_timeline(entry: &ArchiveEntry) {
+    println!(
+        "🗄  {}  strike {}  expiry {} UTC",
+        entry.ticker,
+        entry.strike_label(),
+        entry.expiry_label()
+    );
+    println!("{}", "─".repeat(80));
+    println!(
+        "{:<10} {:>9} {:<9} {:>8} {:>6} {:>6} {:>7} {:>7}",
+        "Time", "BTC", "Signal", "EV", "Yes", "No", "Model", "Implied"
+    );
+    println!("{}", "─".repeat(80));
+    let price = |p: Option<f64>| {
+        p.map(|p| format!("${:.2}", p))
+            .unwrap_or_else(|| "--".to_string())
+    };
+    let percent = |p: Option<f64>| {
+        p.map(|p| format!("{:.0}%", p * 100.0))
+            .unwrap_or_else(|| "--".to_string())
+    };
+    for s in &entry.snapshots {
+        println!(
+            "{:<10} {:>9} {:<9} {:>8} {:>6} {:>6} {:>7} {:>7}",
+            s.recorded_at.format("%H:%M:%S"),
+            s.btc_price
+                .map(|p| format!("${:.0}", p))
+                .unwrap_or_else(|| "--".to_string()),
+            s.signal_type,
+            format!("{:+.1}%", s.expected_value * 100.0),
+            price(s.yes_price),
+            price(s.no_price),
+            percent(s.model_probability),
+            percent(s.implied_probability)
+        );
+    }
+    if entry.snapshots.is_empty() {
+        println!("(no snapshots: settled before the archive was recording)");
+    }
+    println!("{}", "─".repeat(80));
+    match &entry.settlement {
+        Some(s) => println!(
+            "Settled {} (BTC ${:.0}); final signal {}: {}",
+            s.outcome,
+            s.settle_price,
+            s.signal_type,
+            entry.verdict_label()
+        ),
+        None => println!("No settlement recorded."),
+    }
+}
@@ -0,0 +1,135 @@
+//! P&L attribution: joins backend trade history with the local journal and
+//! splits results by trade origin or strategy tag.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::api::client::ApiClient;
+use crate::api::models::TradeHistory;
+use crate::journal::{self, JournalEntry};
+
+/// Trades fetched from history for the report
+const HISTORY_LIMIT: i32 = 1000;
+
+/// Trades placed outside Basilisk (or before the journal existed)
+const UNTRACKED: &str = "untracked";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    Origin,
+    Tag,
+}
+
+#[derive(Debug, Default)]
+struct Bucket {
+    trades: usize,
+    contracts: i64,
+    notional: f64,
+    wins: usize,
+    losses: usize,
+    pnl: f64,
+    fees: f64,
+}
+
+impl Bucket {
+    fn add(&mut self, trade: &TradeHistory) {
+        self.trades += 1;
+        self.contracts += trade.contracts as i64;
+        self.notional += trade.entry_price * trade.contracts as f64;
+        self.fees += trade.fees.unwrap_or(0.0);
+        if let Some(pnl) = trade.pnl {
+            self.pnl += pnl;
+            if pnl > 0.0 {
+                self.wins += 1;
+            } else if pnl < 0.0 {
+                self.losses += 1;
+            }
+        }
+    }
+
+    fn win_rate(&self) -> Option<f64> {
+        let decided = self.wins + self.losses;
+        (decided > 0).then(|| self.wins as f64 / decided as f64)
+    }
+}
+
+fn group_key(entry: Option<&JournalEntry>, by: GroupBy) -> String {
+    match (entry, by) {
+        (Some(entry), GroupBy::Origin) => entry.origin.label(),
+        (Some(entry), GroupBy::Tag) => entry.tag.clone().unwrap_or_else(|| "(none)".to_string()),
+        (None, _) => UNTRACKED.to_string(),
+    }
+}
+
+pub async fn handle_attribution_command(api_url: &str, by: GroupBy) -> Result<()> {
+    let client = ApiClient::new(api_url.to_string(), 30)?;
+    let history = client.get_trade_history(HISTORY_LIMIT).await?;
+    let journal = journal::load()?;
+
+    let by_trade_id: HashMap<i32, &JournalEntry> = journal
+        .iter()
+        .filter_map(|entry| entry.trade_id.map(|id| (id, entry)))
+        .collect();
+
+    let mut buckets: BTreeMap<String, Bucket> = BTreeMap::new();
+    for trade in &history {
+        let key = group_key(by_trade_id.get(&trade.id).copied(), by);
+        buckets.entry(key).or_default().add(trade);
+    }
+
+    if buckets.is_empty() {
+        println!("📭 No trade history.");
+        return Ok(());
+    }
+
+    let heading = match by {
+        GroupBy::Origin => "Origin",
+        GroupBy::Tag => "Tag",
+    };
+
+    println!(
+        "🧭 P&L Attribution by {} ({} trades)",
+        heading.to_lowercase(),
+        history.len()
+    );
+    println!("{}", "─".repeat(86));
+    println!(
+        "{:<20} {:>7} {:>9} {:>11} {:>9} {:>12} {:>10}",
+        heading, "Trades", "Contracts", "Volume", "Win Rate", "P&L", "Fees"
+    );
+    println!("{}", "─".repeat(86));
+
+    for (key, bucket) in &buckets {
+        let pnl_color = if bucket.pnl > 0.0 {
+            "\x1b[32m"
+        } else if bucket.pnl < 0.0 {
+            "\x1b[31m"
+        } else {
+            "\x1b[0m"
+        };
+        let win_rate = bucket
+            .win_rate()
+            .map(|rate| format!("{:.1}%", rate * 100.0))
+            .unwrap_or_else(|| "--".to_string());
+
+        println!(
+            "{:<20} {:>7} {:>9} {:>11} {:>9} {}{:>12}\x1b[0m {:>10}",
+            key,
+            bucket.trades,
+            bucket.contracts,
+            format!("${:.2}", bucket.notional),
+            win_rate,
+            pnl_color,
+            format!("${:+.2}", bucket.pnl),
+            format!("${:.2}", bucket.fees)
+        );
+    }
+    println!("{}", "─".repeat(86));
+
+    if buckets.contains_key(UNTRACKED) {
+        println!("Untracked trades were placed outside Basilisk or before the journal existed.");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,230 @@
+//! Append-only audit log of everything Basilisk does to the account
+//! (`audit.jsonl`), plus the `basilisk audit` viewer.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::models::{Order, TradeResponse};
+use crate::config::Config;
+use crate::store;
+
+const AUDIT_FILE: &str = "audit.jsonl";
+
+/// Last config seen, so edits to config.toml show up in the log
+const CONFIG_FINGERPRINT_FILE: &str = "audit_config.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditKind {
+    /// Order submitted (and its outcome)
+    Order,
+    /// Position close request
+    Close,
+    /// Resting order cancelled
+    Cancel,
+    /// Auto-trader decision
+    Auto,
+    /// Order refused by a risk guard
+    Block,
+    /// config.toml changed
+    Config,
+}
+
+impl AuditKind {
+    fn label(&self) -> &'static str {
+        match self {
+            AuditKind::Order => "ORDER",
+            AuditKind::Close => "CLOSE",
+            AuditKind::Cancel => "CANCEL",
+            AuditKind::Auto => "AUTO",
+            AuditKind::Block => "BLOCK",
+            AuditKind::Config => "CONFIG",
+        }
+    }
+
+    fn color(&self) -> &'static str {
+        match self {
+            AuditKind::Order | AuditKind::Close | AuditKind::Cancel => "\x1b[36m",
+            AuditKind::Auto => "\x1b[35m",
+            AuditKind::Block => "\x1b[31m",
+            AuditKind::Config => "\x1b[33m",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub timestamp: DateTime<Utc>,
+    pub kind: AuditKind,
+    /// Where the action came from: "cli", "dashboard" or "auto"
+    pub source: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub details: Value,
+}
+
+pub fn record(
+    kind: AuditKind,
+    source: &str,
+    message: impl Into<String>,
+    details: Value,
+) -> Result<()> {
+    let event = AuditEvent {
+        timestamp: Utc::now(),
+        kind,
+        source: source.to_string(),
+        message: message.into(),
+        details,
+    };
+    store::append_jsonl(AUDIT_FILE, &event)
+}
+
+/// Record an order or close request together with the backend's answer
+pub fn record_result(
+    kind: AuditKind,
+    source: &str,
+    description: &str,
+    result: &Result<TradeResponse>,
+) -> Result<()> {
+    let details = match result {
+        Ok(response) => serde_json::to_value(response)?,
+        Err(_) => Value::Null,
+    };
+    record(kind, source, describe_result(description, result), details)
+}
+
+/// "signal #12 KXBTCD-... x3 → trade #7 filled 3 @ $0.45"
+pub fn describe_result(description: &str, result: &Result<TradeResponse>) -> String {
+    let response = match result {
+        Ok(response) => response,
+        Err(e) => return format!("{} → request failed: {}", description, e),
+    };
+
+    let outcome = if response.success {
+        format!(
+            "trade #{} filled {}{}",
+            response.trade_id.unwrap_or(0),
+            response.filled,
+            response
+                .price
+                .map(|p| format!(" @ ${:.2}", p))
+                .unwrap_or_default()
+        )
+    } else {
+        format!(
+            "rejected: {}",
+            response.error.as_deref().unwrap_or("unknown error")
+        )
+    };
+    format!("{} → {}", description, outcome)
+}
+
+/// Log an order cancel with the order's final state
+pub fn record_cancel(source: &str, description: &str, result: &Result<Order>) -> Result<()> {
+    let details = match result {
+        Ok(order) => serde_json::to_value(order)?,
+        Err(_) => Value::Null,
+    };
+    record(
+        AuditKind::Cancel,
+        source,
+        describe_cancel(description, result),
+        details,
+    )
+}
+
+/// "cancel order abc123 (KXBTCD-... YES 2 left @ $0.38)"
+pub fn describe_cancel(description: &str, result: &Result<Order>) -> String {
+    match result {
+        Ok(order) => format!(
+            "{} ({} {} {} left @ {})",
+            description,
+            order.ticker,
+            order.direction,
+            order.remaining,
+            order.limit_display()
+        ),
+        Err(e) => format!("{} → request failed: {}", description, e),
+    }
+}
+
+/// Log the config when it differs from the last one seen
+pub fn record_config(config: &Config) -> Result<()> {
+    let current = toml::to_string(config)?;
+    let previous: Option<String> = store::read_json(CONFIG_FINGERPRINT_FILE)?;
+    if previous.as_deref() == Some(current.as_str()) {
+        return Ok(());
+    }
+
+    let message = if previous.is_some() {
+        "config.toml changed"
+    } else {
+        "config.toml first loaded"
+    };
+    record(
+        AuditKind::Config,
+        "config",
+        message,
+        serde_json::to_value(config)?,
+    )?;
+    store::write_json(CONFIG_FINGERPRINT_FILE, &current)
+}
+
+/// Parse `--since` as a local date (YYYY-MM-DD)
+pub fn parse_since(value: &str) -> Result<DateTime<Utc>, String> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("expected YYYY-MM-DD, got '{}'", value))?;
+    Local
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        .earliest()
+        .map(|at| at.with_timezone(&Utc))
+        .ok_or_else(|| format!("invalid local date '{}'", value))
+}
+
+pub fn handle_audit_command(
+    limit: usize,
+    kind: Option<AuditKind>,
+    since: Option<DateTime<Utc>>,
+    details: bool,
+) -> Result<()> {
+    let events: Vec<AuditEvent> =
+        store::read_jsonl(AUDIT_FILE).context("Failed to read audit log")?;
+
+    let matching: Vec<&AuditEvent> = events
+        .iter()
+        .filter(|e| kind.is_none_or(|k| e.kind == k))
+        .filter(|e| since.is_none_or(|at| e.timestamp >= at))
+        .collect();
+
+    if matching.is_empty() {
+        println!("📭 No audit events.");
+        return Ok(());
+    }
+
+    let shown = &matching[matching.len().saturating_sub(limit)..];
+    println!("🧾 Audit Log (last {} of {}):", shown.len(), matching.len());
+    println!("{}", "─".repeat(90));
+
+    for event in shown {
+        println!(
+            "{}  {}{:<7}\x1b[0m {:<10} {}",
+            event
+                .timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S"),
+            event.kind.color(),
+            event.kind.label(),
+            event.source,
+            event.message
+        );
+        if details && !event.details.is_null() {
+            println!("    \x1b[90m{}\x1b[0m", event.details);
+        }
+    }
+    println!("{}", "─".repeat(90));
+
+    Ok(())
+}
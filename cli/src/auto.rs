@@ -0,0 +1,561 @@
+//! Headless auto-trader (`basilisk auto`).
+//!
+//! Each cycle fetches current signals, picks contracts that pass the entry
+//! rule (`[auto]` thresholds plus the script's `filter()`, if any) and aren't
+//! already held, ranks them by composite score (see `score.rs`) and submits
+//! the best through the order [`Governor`]. Every decision is printed as a
+//! single log line.
+//!
+//! With `[liquidity] auto_min_score` set, strikes whose volume/open-interest
+//! score (from the stream's ticker updates) is below it are skipped.
+//!
+//! `[auto.pause]` suspends entries while the vol regime or premium is outside
+//! the conditions the model's edge was measured in.
+//!
+//! With `--paper` the same pipeline runs against live data, but orders go to
+//! the local [`PaperBook`] instead of the exchange.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Local, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::alerts::AlertEngine;
+use crate::api::client::ApiClient;
+use crate::api::models::{Contract, TradeResponse};
+use crate::api::VolatilityData;
+use crate::audit::{self, AuditKind};
+use crate::bankroll;
+use crate::config::Config;
+use crate::exposure::ExposureBook;
+use crate::governor::{self, Governor, Throttle};
+use crate::journal::{self, Origin};
+use crate::liquidity::{self, VolumeTracker};
+use crate::paper::PaperBook;
+use crate::reentry::ReentryGuard;
+use crate::rollover;
+use crate::score::Scorer;
+use crate::scripting::ScriptHooks;
+use crate::sizing;
+use crate::store;
+use crate::tilt::TiltGuard;
+
+/// Decision log for paper runs (live decisions go to the audit log)
+const PAPER_DECISIONS_FILE: &str = "paper_decisions.jsonl";
+
+#[derive(Serialize)]
+struct Decision<'a> {
+    timestamp: DateTime<Utc>,
+    event: &'a str,
+    message: &'a str,
+}
+
+/// Prints decision lines and keeps a copy of each
+struct Decisions {
+    paper: bool,
+}
+
+impl Decisions {
+    fn log(&self, event: &str, message: impl Display) {
+        let message = message.to_string();
+        println!(
+            "{} {:<8} {}",
+            Local::now().format("%H:%M:%S"),
+            event,
+            message
+        );
+
+        let saved = if self.paper {
+            let decision = Decision {
+                timestamp: Utc::now(),
+                event,
+                message: &message,
+            };
+            store::append_jsonl(PAPER_DECISIONS_FILE, &decision)
+        } else {
+            let entry = format!("{} {}", event, message);
+            audit::record(AuditKind::Auto, "auto", entry, Value::Null)
+        };
+        if let Err(e) = saved {
+            println!(
+                "{} {:<8} could not write decision log: {}",
+                Local::now().format("%H:%M:%S"),
+                "WARN",
+                e
+            );
+        }
+    }
+}
+
+pub async fn handle_auto_command(
+    api_url: &str,
+    retry_orders: bool,
+    once: bool,
+    reset_kill_switch: bool,
+    resume: bool,
+    paper: bool,
+) -> Result<()> {
+    let config = Config::load()?;
+    let client = ApiClient::new(api_url.to_string(), 30)?.with_order_retry(retry_orders);
+    let script = ScriptHooks::load(&config.scripting)?;
+    let decisions = Decisions { paper };
+
+    // Paper runs keep their own governor counters and kill switch
+    let status_file = if paper {
+        governor::PAPER_STATUS_FILE
+    } else {
+        governor::STATUS_FILE
+    };
+    let mut governor = Governor::load(config.governor.clone(), status_file);
+    let mut book = if paper {
+        Some(PaperBook::load()?)
+    } else {
+        None
+    };
+
+    if reset_kill_switch {
+        governor.reset_kill_switch();
+        governor.save()?;
+        decisions.log("RESET", "kill switch cleared");
+    }
+    if let Some(reason) = &governor.status().killed {
+        bail!(
+            "Auto-trader kill switch is engaged ({}); fix the cause and rerun with --reset-kill-switch",
+            reason
+        );
+    }
+    // Ticker updates only arrive on the stream, so follow it while trading
+    let volume = (config.liquidity.auto_min_score > 0.0).then(|| {
+        let tracker = Arc::new(Mutex::new(VolumeTracker::new(&config.liquidity)));
+        liquidity::spawn_volume_listener(api_url, "BTC", tracker.clone());
+        tracker
+    });
+
+    if resume {
+        if let Some(reason) = governor.status().paused.clone() {
+            governor.resume();
+            governor.save()?;
+            decisions.log("RESUME", format!("manually (was: {})", reason));
+        }
+    }
+
+    decisions.log(
+        "START",
+        format!(
+            "{}min EV {:.1}%, min confidence {:.2}, max {} positions, every {}s{}{}",
+            if paper { "PAPER, " } else { "" },
+            config.auto.min_ev * 100.0,
+            config.auto.min_confidence,
+            config.auto.max_open_positions,
+            config.auto.interval_secs,
+            if volume.is_some() {
+                format!(", min liquidity {:.0}", config.liquidity.auto_min_score)
+            } else {
+                String::new()
+            },
+            if script.is_some() {
+                ", script hooks on"
+            } else {
+                ""
+            }
+        ),
+    );
+    if let Some(reason) = &governor.status().paused {
+        decisions.log(
+            "PAUSED",
+            format!(
+                "{}; {}",
+                reason,
+                if config.auto.pause.auto_resume {
+                    "resumes once conditions clear"
+                } else {
+                    "rerun with --resume once conditions clear"
+                }
+            ),
+        );
+    }
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(config.auto.interval_secs.max(1)));
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = tokio::signal::ctrl_c() => {
+                decisions.log("STOP", "interrupted");
+                break;
+            }
+        }
+
+        let cycle = run_cycle(
+            &client,
+            &config,
+            script.as_ref(),
+            &mut governor,
+            book.as_mut(),
+            volume.as_deref(),
+            &decisions,
+        );
+        if let Err(e) = cycle.await {
+            decisions.log("ERROR", e);
+        }
+        governor.save()?;
+        if let Some(book) = &book {
+            book.save()?;
+        }
+
+        if let Some(reason) = &governor.status().killed {
+            decisions.log("KILL", reason);
+            bail!("Auto-trader stopped by kill switch ({})", reason);
+        }
+        if once {
+            break;
+        }
+    }
+
+    governor.save()
+}
+
+async fn run_cycle(
+    client: &ApiClient,
+    config: &Config,
+    script: Option<&ScriptHooks>,
+    governor: &mut Governor,
+    mut book: Option<&mut PaperBook>,
+    volume: Option<&Mutex<VolumeTracker>>,
+    decisions: &Decisions,
+) -> Result<()> {
+    let signals = client.get_current_signals().await?;
+
+    let (held, open_count, mut exposure): (HashSet<String>, usize, ExposureBook) =
+        match book.as_deref_mut() {
+            Some(book) => {
+                settle_paper(book, &signals.contracts, decisions)?;
+                let held = book
+                    .held_tickers()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect();
+                (
+                    held,
+                    book.positions.len(),
+                    ExposureBook::from_paper(&book.positions),
+                )
+            }
+            None => {
+                let positions = client.get_positions().await?;
+                let held = positions.iter().map(|p| p.ticker.clone()).collect();
+                (
+                    held,
+                    positions.len(),
+                    ExposureBook::from_positions(&positions),
+                )
+            }
+        };
+
+    if !check_pause(config, &signals.volatility, governor, decisions) {
+        return Ok(());
+    }
+
+    // Losing streaks are read from exchange history, which paper trades never reach
+    if book.is_none() {
+        if let Some(reason) = TiltGuard::fetch(client, &config.tilt).await?.cooling_off() {
+            decisions.log("SKIP", format!("all entries: {}", reason));
+            return Ok(());
+        }
+    }
+
+    let open_slots = config.auto.max_open_positions.saturating_sub(open_count);
+
+    let mut candidates: Vec<&Contract> = signals
+        .contracts
+        .iter()
+        .filter(|c| {
+            c.is_active
+                && c.signal_type != "HOLD"
+                && c.expected_value >= config.auto.min_ev
+                && c.confidence_score >= config.auto.min_confidence
+                && !held.contains(&c.ticker)
+        })
+        .filter(|c| match script.map(|s| s.filter(c)) {
+            Some(Ok(Some(passes))) => passes,
+            Some(Err(e)) => {
+                // A broken script must never turn into orders
+                decisions.log("SKIP", format!("{} script error: {}", c.ticker, e));
+                false
+            }
+            _ => true,
+        })
+        .collect();
+
+    // Stop-outs only happen on the exchange; paper positions just settle
+    if !candidates.is_empty() && book.is_none() {
+        let reentry = ReentryGuard::fetch(client, &config.reentry).await?;
+        candidates.retain(|c| match reentry.check(&c.ticker) {
+            Some(reason) => {
+                decisions.log("SKIP", reason);
+                false
+            }
+            None => true,
+        });
+    }
+
+    // A big EV on a strike nobody trades can't be captured
+    if let Some(volume) = volume {
+        let volume = volume
+            .lock()
+            .map_err(|_| anyhow!("volume tracker lock poisoned"))?;
+        let min_score = config.liquidity.auto_min_score;
+        candidates.retain(|c| match volume.score(&c.ticker, &config.liquidity) {
+            Some(score) if score >= min_score => true,
+            Some(score) => {
+                decisions.log(
+                    "SKIP",
+                    format!(
+                        "{} liquidity score {:.0} < {:.0}",
+                        c.ticker, score, min_score
+                    ),
+                );
+                false
+            }
+            None => {
+                decisions.log("SKIP", format!("{} no volume data yet", c.ticker));
+                false
+            }
+        });
+    }
+
+    // Highest score first, EV breaking ties
+    let scorer = Scorer::new(&config.score, &signals.volatility.regime, script);
+    let mut ranked: Vec<(&Contract, f64)> = candidates
+        .into_iter()
+        .map(|c| (c, scorer.score(c)))
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.1.total_cmp(&a.1)
+            .then(b.0.expected_value.total_cmp(&a.0.expected_value))
+    });
+    ranked.truncate(open_slots);
+
+    if ranked.is_empty() {
+        return Ok(());
+    }
+
+    // Bankroll is only needed when the script sizes orders
+    let bankroll = match script {
+        Some(hooks) if hooks.has_size() => Some(bankroll::bankroll(client).await?),
+        _ => None,
+    };
+
+    // Size each order and reserve a governor slot for it
+    let mut orders = Vec::new();
+    for (contract, score) in ranked {
+        let size = match (script, bankroll) {
+            (Some(hooks), Some(bankroll)) => match hooks.size(contract, bankroll) {
+                Ok(Some(size)) => size,
+                Ok(None) => config.auto.size,
+                Err(e) => {
+                    decisions.log("SKIP", format!("{} script error: {}", contract.ticker, e));
+                    continue;
+                }
+            },
+            _ => config.auto.size,
+        };
+        if size < 1 {
+            decisions.log(
+                "SKIP",
+                format!("{} sized at {} contracts", contract.ticker, size),
+            );
+            continue;
+        }
+
+        if let Err(reason) = exposure.check_signal(&config.exposure, contract, size) {
+            decisions.log("SKIP", format!("{} {}", contract.ticker, reason));
+            continue;
+        }
+
+        match governor.acquire() {
+            Ok(()) => {
+                // Count the order against its expiry before sizing the next one
+                exposure.add(
+                    contract.expiry_time.as_deref(),
+                    sizing::entry_price(contract).unwrap_or(0.0) * size as f64,
+                );
+                orders.push((contract, size, score));
+            }
+            Err(throttle) => {
+                decisions.log(
+                    "THROTTLE",
+                    format!("{} held back: {}", contract.ticker, throttle),
+                );
+                if matches!(throttle, Throttle::Killed(_)) {
+                    break;
+                }
+            }
+        }
+    }
+
+    // Journal fills under the script when it picked the contracts, else the threshold rule
+    let origin = match script {
+        Some(hooks) if hooks.has_filter() => Origin::Script,
+        _ => Origin::Auto {
+            rule: "threshold".to_string(),
+        },
+    };
+
+    // Submit together; the governor already capped how many are in flight
+    let results: Vec<Result<TradeResponse>> = match book.as_deref_mut() {
+        Some(book) => orders
+            .iter()
+            .map(|(contract, size, _)| book.execute(contract, *size))
+            .collect(),
+        None => {
+            futures::future::join_all(
+                orders
+                    .iter()
+                    .map(|(contract, size, _)| client.execute_from_signal(contract.id, *size)),
+            )
+            .await
+        }
+    };
+
+    for ((contract, size, score), result) in orders.iter().zip(results) {
+        let summary = format!(
+            "{} {} x{} (EV {}, score {:.0})",
+            contract.ticker,
+            contract.signal_type,
+            size,
+            contract.ev_display(),
+            score
+        );
+        match result {
+            Ok(response) if response.success => {
+                governor.complete(true);
+                if book.is_none() {
+                    if let Err(e) =
+                        journal::record(&contract.ticker, origin.clone(), None, &response)
+                    {
+                        decisions.log("WARN", format!("could not write trade journal: {}", e));
+                    }
+                }
+                decisions.log(
+                    "FILL",
+                    format!(
+                        "{} → trade #{}, filled {}",
+                        summary,
+                        response.trade_id.unwrap_or(0),
+                        response.filled
+                    ),
+                );
+            }
+            Ok(response) => {
+                governor.complete(false);
+                decisions.log(
+                    "REJECT",
+                    format!(
+                        "{}: {}",
+                        summary,
+                        response
+                            .error
+                            .unwrap_or_else(|| "unknown error".to_string())
+                    ),
+                );
+            }
+            Err(e) => {
+                governor.complete(false);
+                decisions.log("REJECT", format!("{}: {}", summary, e));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Pause or resume on `[auto.pause]` conditions; false while entries are suspended
+fn check_pause(
+    config: &Config,
+    volatility: &VolatilityData,
+    governor: &mut Governor,
+    decisions: &Decisions,
+) -> bool {
+    let pause = &config.auto.pause;
+    match (pause.reason(volatility), governor.status().paused.clone()) {
+        (Some(reason), None) => {
+            decisions.log("PAUSE", &reason);
+            // Paper runs trade nothing real, so they don't page anyone
+            if pause.alert && !decisions.paper {
+                if let Err(e) = AlertEngine::load().auto_paused(&reason) {
+                    decisions.log("WARN", format!("could not raise alert: {}", e));
+                }
+            }
+            governor.pause(reason);
+            false
+        }
+        (None, Some(reason)) if pause.auto_resume => {
+            decisions.log("RESUME", format!("conditions cleared (was: {})", reason));
+            governor.resume();
+            true
+        }
+        (None, None) => true,
+        // Still paused, or waiting for --resume
+        _ => false,
+    }
+}
+
+/// Settle expired paper positions and report the book
+fn settle_paper(book: &mut PaperBook, contracts: &[Contract], decisions: &Decisions) -> Result<()> {
+    // Record how the hour that just rolled off settled (unless the dashboard has)
+    let previous = std::mem::replace(&mut book.last_contracts, contracts.to_vec());
+    if let Some(rollover) = rollover::detect(&previous, contracts) {
+        let spot = contracts
+            .iter()
+            .find_map(|c| c.current_btc_price)
+            .unwrap_or_default();
+        rollover.record_settlements(spot, None)?;
+    }
+
+    if book.awaiting_results() {
+        let results = rollover::settlements()?;
+        for settled in book.settle_expired(&results)? {
+            let position = &settled.position;
+            decisions.log(
+                "SETTLE",
+                format!(
+                    "{} {} x{} @ ${:.2} → {} at ${:.0}, P&L ${:+.2}",
+                    position.ticker,
+                    position.side,
+                    position.contracts,
+                    position.entry_price,
+                    if settled.won { "won" } else { "lost" },
+                    settled.settle_price,
+                    settled.pnl
+                ),
+            );
+        }
+        for position in book.void_unresolved()? {
+            decisions.log(
+                "VOID",
+                format!(
+                    "{} {} x{} @ ${:.2}: no settlement recorded for its hour, entry refunded",
+                    position.ticker, position.side, position.contracts, position.entry_price
+                ),
+            );
+        }
+    }
+
+    decisions.log(
+        "PAPER",
+        format!(
+            "{} open, realized ${:+.2} ({}W/{}L), unrealized ${:+.2}",
+            book.positions.len(),
+            book.realized_pnl,
+            book.wins,
+            book.losses,
+            book.unrealized_pnl(contracts)
+        ),
+    );
+    Ok(())
+}
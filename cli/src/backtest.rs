@@ -0,0 +1,188 @@
+use anyhow::{bail, Result};
+use basilisk_quant::monte_carlo;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use std::path::Path;
+
+use crate::portfolio::{self, PositionRecord};
+
+/// Settings for a Monte Carlo run over imported positions
+#[derive(Debug, Clone)]
+pub struct BacktestConfig {
+    pub paths: usize,
+    pub spot_override: Option<f64>,
+    pub vol_override: Option<f64>,
+    pub seed: Option<u64>,
+}
+
+/// Aggregate outcome of all simulated paths
+#[derive(Debug, Clone)]
+pub struct BacktestSummary {
+    pub positions: usize,
+    pub skipped: usize,
+    pub paths: usize,
+    pub mean_pnl: f64,
+    pub median_pnl: f64,
+    pub p5_pnl: f64,
+    pub p95_pnl: f64,
+    pub worst_pnl: f64,
+    pub best_pnl: f64,
+    pub prob_loss: f64,
+    /// (trade_id, ticker, simulated win rate) per position
+    pub win_rates: Vec<(i32, String, f64)>,
+}
+
+/// A position with all inputs needed to simulate its settlement
+struct SimPosition<'a> {
+    record: &'a PositionRecord,
+    spot: f64,
+    vol: f64,
+    hours: f64,
+}
+
+/// Simulate settlement of the imported exposure across random BTC paths.
+///
+/// All positions share one shock per path (same underlying), scaled by each
+/// position's own horizon, so correlated strikes win and lose together.
+pub fn simulate(records: &[PositionRecord], config: &BacktestConfig) -> Result<BacktestSummary> {
+    let sims: Vec<SimPosition> = records
+        .iter()
+        .filter_map(|record| {
+            let spot = config.spot_override.or(record.spot_price)?;
+            let vol = config.vol_override.or(record.volatility)?;
+            let hours = record.hours_to_expiry?.max(0.0);
+            Some(SimPosition {
+                record,
+                spot,
+                vol,
+                hours,
+            })
+        })
+        .collect();
+
+    if sims.is_empty() {
+        bail!("No positions with spot, volatility, and expiry data to simulate (try --spot and --vol)");
+    }
+
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut path_pnls = Vec::with_capacity(config.paths);
+    let mut wins = vec![0usize; sims.len()];
+
+    for _ in 0..config.paths {
+        let z: f64 = rng.sample(StandardNormal);
+        let mut pnl = 0.0;
+
+        for (i, sim) in sims.iter().enumerate() {
+            let terminal = monte_carlo::terminal_price(sim.spot, sim.vol, sim.hours, z);
+            let yes_wins = terminal > sim.record.strike;
+            let won = if sim.record.direction.eq_ignore_ascii_case("NO") {
+                !yes_wins
+            } else {
+                yes_wins
+            };
+
+            let payout = if won { 1.0 } else { 0.0 };
+            pnl += (payout - sim.record.entry_price) * sim.record.contracts as f64;
+            if won {
+                wins[i] += 1;
+            }
+        }
+
+        path_pnls.push(pnl);
+    }
+
+    path_pnls.sort_by(|a, b| a.total_cmp(b));
+    let n = path_pnls.len();
+    let percentile = |p: f64| monte_carlo::percentile(&path_pnls, p);
+
+    Ok(BacktestSummary {
+        positions: sims.len(),
+        skipped: records.len() - sims.len(),
+        paths: n,
+        mean_pnl: path_pnls.iter().sum::<f64>() / n as f64,
+        median_pnl: percentile(0.5),
+        p5_pnl: percentile(0.05),
+        p95_pnl: percentile(0.95),
+        worst_pnl: path_pnls[0],
+        best_pnl: path_pnls[n - 1],
+        prob_loss: path_pnls.iter().filter(|pnl| **pnl < 0.0).count() as f64 / n as f64,
+        win_rates: sims
+            .iter()
+            .zip(wins)
+            .map(|(sim, w)| {
+                (
+                    sim.record.trade_id,
+                    sim.record.ticker.clone(),
+                    w as f64 / n as f64,
+                )
+            })
+            .collect(),
+    })
+}
+
+/// Import an exported positions file and print the simulated P&L distribution
+pub fn handle_backtest_command(file: &Path, config: BacktestConfig) -> Result<()> {
+    if config.paths == 0 {
+        bail!("--paths must be at least 1");
+    }
+
+    let records = portfolio::read_records(file)?;
+    if records.is_empty() {
+        println!("📂 No positions in {}.", file.display());
+        return Ok(());
+    }
+
+    let summary = simulate(&records, &config)?;
+
+    let color = |pnl: f64| if pnl >= 0.0 { "\x1b[32m" } else { "\x1b[31m" };
+
+    println!(
+        "🎲 Backtest - {} positions, {} simulated paths",
+        summary.positions, summary.paths
+    );
+    if summary.skipped > 0 {
+        println!(
+            "   ({} positions skipped: missing spot/vol/expiry)",
+            summary.skipped
+        );
+    }
+    println!("{}", "─".repeat(50));
+    println!(
+        "   Expected P&L:   {}${:+.2}\x1b[0m",
+        color(summary.mean_pnl),
+        summary.mean_pnl
+    );
+    println!(
+        "   Median P&L:     {}${:+.2}\x1b[0m",
+        color(summary.median_pnl),
+        summary.median_pnl
+    );
+    println!(
+        "   5th pct (VaR):  {}${:+.2}\x1b[0m",
+        color(summary.p5_pnl),
+        summary.p5_pnl
+    );
+    println!(
+        "   95th pct:       {}${:+.2}\x1b[0m",
+        color(summary.p95_pnl),
+        summary.p95_pnl
+    );
+    println!(
+        "   Worst / Best:   ${:+.2} / ${:+.2}",
+        summary.worst_pnl, summary.best_pnl
+    );
+    println!("   P(loss):        {:.1}%", summary.prob_loss * 100.0);
+    println!("{}", "─".repeat(50));
+    println!("{:<8} {:<32} {:<10}", "ID", "Ticker", "Win Rate");
+    for (trade_id, ticker, win_rate) in &summary.win_rates {
+        println!("{:<8} {:<32} {:.1}%", trade_id, ticker, win_rate * 100.0);
+    }
+    println!("{}", "─".repeat(50));
+
+    Ok(())
+}
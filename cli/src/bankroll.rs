@@ -0,0 +1,284 @@
+//! Account capital for sizing and performance.
+//!
+//! The bankroll is account equity (cash plus open positions at their current
+//! price), so capital tied up in positions still counts. Deposits and
+//! withdrawals recorded with `basilisk funding` are kept in `funding.jsonl`
+//! and separate new money from trading P&L. `basilisk balance` (and the
+//! dashboard's status bar) break the account down into cash that can be
+//! traded, cost tied up in open positions and payouts waiting on settlement.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, Utc};
+use clap::Subcommand;
+use serde::{Deserialize, Serialize};
+
+use crate::api::client::ApiClient;
+use crate::api::models::Position;
+use crate::store;
+
+const FUNDING_FILE: &str = "funding.jsonl";
+
+/// A deposit (positive) or withdrawal (negative), in dollars
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundingEntry {
+    pub timestamp: DateTime<Utc>,
+    pub amount: f64,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+pub fn funding_entries() -> Result<Vec<FundingEntry>> {
+    store::read_jsonl(FUNDING_FILE)
+}
+
+/// Deposits minus withdrawals (None until something is recorded)
+pub fn net_deposits() -> Result<Option<f64>> {
+    let entries = funding_entries()?;
+    Ok((!entries.is_empty()).then(|| entries.iter().map(|e| e.amount).sum()))
+}
+
+#[derive(Debug, Clone)]
+pub struct Capital {
+    pub cash: f64,
+    pub positions_value: f64,
+    pub net_deposits: Option<f64>,
+}
+
+impl Capital {
+    pub async fn fetch(client: &ApiClient) -> Result<Self> {
+        let cash = client.get_balance().await?.dollars();
+        let positions = client.get_positions().await?;
+        let positions_value = positions
+            .iter()
+            .map(|p| p.current_price.unwrap_or(p.entry_price) * p.contracts as f64)
+            .fold(0.0, |total, value| total + value);
+
+        Ok(Self {
+            cash,
+            positions_value,
+            net_deposits: net_deposits()?,
+        })
+    }
+
+    pub fn equity(&self) -> f64 {
+        self.cash + self.positions_value
+    }
+
+    /// Trading P&L: equity not explained by funding
+    pub fn pnl(&self) -> Option<f64> {
+        self.net_deposits.map(|deposits| self.equity() - deposits)
+    }
+
+    pub fn return_pct(&self) -> Option<f64> {
+        let deposits = self.net_deposits.filter(|d| *d > 0.0)?;
+        Some(self.pnl()? / deposits * 100.0)
+    }
+}
+
+/// Where the account's money is, for sizing decisions
+#[derive(Debug, Clone, Default)]
+pub struct Balance {
+    /// Cash available for new orders
+    pub cash: f64,
+    /// What the open positions cost
+    pub exposure: f64,
+    /// Open positions at their current price
+    pub market_value: f64,
+    pub open_positions: usize,
+    /// Expired positions not yet paid out, at their last price
+    pub pending_settlement: f64,
+    pub pending_positions: usize,
+}
+
+impl Balance {
+    pub async fn fetch(client: &ApiClient) -> Result<Self> {
+        let cash = client.get_balance().await?.dollars();
+        let positions = client.get_positions().await?;
+        Ok(Self::from_positions(cash, &positions))
+    }
+
+    pub fn from_positions(cash: f64, positions: &[Position]) -> Self {
+        let mut balance = Self {
+            cash,
+            ..Self::default()
+        };
+        for position in positions {
+            let contracts = position.contracts as f64;
+            let value = position.current_price.unwrap_or(position.entry_price) * contracts;
+            // A position still listed after its contract expired is awaiting settlement
+            if position.hours_to_expiry().is_some_and(|hours| hours <= 0.0) {
+                balance.pending_settlement += value;
+                balance.pending_positions += 1;
+            } else {
+                balance.exposure += position.entry_price * contracts;
+                balance.market_value += value;
+                balance.open_positions += 1;
+            }
+        }
+        balance
+    }
+
+    /// Cash, positions at market and pending payouts together
+    pub fn total(&self) -> f64 {
+        self.cash + self.market_value + self.pending_settlement
+    }
+}
+
+/// Bankroll used by Kelly and percentage sizing
+pub async fn bankroll(client: &ApiClient) -> Result<f64> {
+    Ok(Capital::fetch(client).await?.equity())
+}
+
+#[derive(Subcommand, Debug)]
+pub enum FundingCommands {
+    /// Record money added to the account
+    #[command(name = "deposit")]
+    Deposit {
+        /// Amount in dollars
+        amount: f64,
+        #[arg(long)]
+        note: Option<String>,
+    },
+
+    /// Record money taken out of the account
+    #[command(name = "withdraw")]
+    Withdraw {
+        /// Amount in dollars
+        amount: f64,
+        #[arg(long)]
+        note: Option<String>,
+    },
+
+    /// Show the funding ledger and return on capital
+    #[command(name = "show")]
+    Show,
+}
+
+pub async fn handle_funding_command(cmd: FundingCommands, api_url: &str) -> Result<()> {
+    let (amount, note) = match cmd {
+        FundingCommands::Deposit { amount, note } => (amount, note),
+        FundingCommands::Withdraw { amount, note } => (-amount, note),
+        FundingCommands::Show => return show_funding(api_url).await,
+    };
+    if amount == 0.0 || !amount.is_finite() {
+        bail!("Amount must be a non-zero number of dollars");
+    }
+
+    let entry = FundingEntry {
+        timestamp: Utc::now(),
+        amount,
+        note,
+    };
+    store::append_jsonl(FUNDING_FILE, &entry)?;
+
+    let verb = if amount > 0.0 {
+        "Deposit"
+    } else {
+        "Withdrawal"
+    };
+    println!("✅ {} of ${:.2} recorded.", verb, amount.abs());
+    if let Some(net) = net_deposits()? {
+        println!("   Net deposits: ${:.2}", net);
+    }
+    Ok(())
+}
+
+pub async fn handle_balance_command(api_url: &str) -> Result<()> {
+    let client = ApiClient::new(api_url.to_string(), 30)?;
+    let balance = Balance::fetch(&client).await?;
+
+    println!("💰 Account Balance:");
+    println!("{}", "─".repeat(60));
+    println!("   Buying power:        \x1b[1m${:.2}\x1b[0m", balance.cash);
+    println!(
+        "   Exposure:            ${:.2} in {} open position{}",
+        balance.exposure,
+        balance.open_positions,
+        if balance.open_positions == 1 { "" } else { "s" }
+    );
+    if balance.open_positions > 0 {
+        let unrealized = balance.market_value - balance.exposure;
+        let color = if unrealized >= 0.0 {
+            "\x1b[32m"
+        } else {
+            "\x1b[31m"
+        };
+        println!(
+            "   Market value:        ${:.2} ({}${:+.2}\x1b[0m)",
+            balance.market_value, color, unrealized
+        );
+    }
+    println!(
+        "   Pending settlement:  ${:.2}{}",
+        balance.pending_settlement,
+        if balance.pending_positions > 0 {
+            format!(" in {} expired position(s)", balance.pending_positions)
+        } else {
+            String::new()
+        }
+    );
+    println!("{}", "─".repeat(60));
+    println!("   Total:               ${:.2}", balance.total());
+    if balance.total() > 0.0 {
+        println!(
+            "   Deployed:            {:.1}% of the account",
+            (balance.market_value + balance.pending_settlement) / balance.total() * 100.0
+        );
+    }
+
+    Ok(())
+}
+
+async fn show_funding(api_url: &str) -> Result<()> {
+    let entries = funding_entries()?;
+
+    println!("🏦 Funding Ledger:");
+    println!("{}", "─".repeat(60));
+    if entries.is_empty() {
+        println!("   No deposits or withdrawals recorded.");
+        println!("   Add one with `basilisk funding deposit <amount>`.");
+    }
+    for entry in &entries {
+        let color = if entry.amount >= 0.0 {
+            "\x1b[32m"
+        } else {
+            "\x1b[31m"
+        };
+        println!(
+            "   {}  {}{:>+12.2}\x1b[0m  {}",
+            entry
+                .timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M"),
+            color,
+            entry.amount,
+            entry.note.as_deref().unwrap_or("")
+        );
+    }
+    println!("{}", "─".repeat(60));
+
+    let client = ApiClient::new(api_url.to_string(), 30)?;
+    let capital = match Capital::fetch(&client).await {
+        Ok(capital) => capital,
+        Err(e) => {
+            println!("⚠️  Could not fetch account balance: {}", e);
+            return Ok(());
+        }
+    };
+
+    println!("   Cash:           ${:.2}", capital.cash);
+    println!("   Positions:      ${:.2}", capital.positions_value);
+    println!("   Equity:         ${:.2}", capital.equity());
+    if let (Some(deposits), Some(pnl)) = (capital.net_deposits, capital.pnl()) {
+        let color = if pnl >= 0.0 { "\x1b[32m" } else { "\x1b[31m" };
+        println!("   Net deposits:   ${:.2}", deposits);
+        print!("   Trading P&L:    {}${:+.2}\x1b[0m", color, pnl);
+        match capital.return_pct() {
+            Some(pct) => println!(" ({:+.1}% on capital)", pct),
+            None => println!(),
+        }
+    }
+    println!("{}", "─".repeat(60));
+
+    Ok(())
+}
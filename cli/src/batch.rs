@@ -0,0 +1,259 @@
+//! Batch orders for `basilisk trade-batch`.
+//!
+//! A batch is a JSON array of objects or a CSV file with a header row. Each
+//! entry names either a signal (`signal_id`, optional `size`) or a contract
+//! (`ticker`, `direction`, optional `size`, `strike`, `asset`), and may add a
+//! `limit` in cents, a `tag` and a `note`. Entries run one at a time through
+//! `trade` or `manual`, so each one passes the same guards and lands in the
+//! audit log and journal like a single order would.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::api::client::ApiClient;
+use crate::api::models::{ticker_asset, Contract};
+use crate::trading::{handle_trading_command, OutputFormat, TradingCommands};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BatchEntry {
+    #[serde(default)]
+    pub signal_id: Option<i32>,
+    #[serde(default)]
+    pub ticker: Option<String>,
+    #[serde(default)]
+    pub direction: Option<String>,
+    /// Contracts; a signal entry falls back to the script's size(), else 1
+    #[serde(default)]
+    pub size: Option<i32>,
+    /// Limit price in cents
+    #[serde(default)]
+    pub limit: Option<i32>,
+    /// Defaults to the strike of the ticker in the current signals
+    #[serde(default)]
+    pub strike: Option<f64>,
+    #[serde(default)]
+    pub asset: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl BatchEntry {
+    pub fn describe(&self) -> String {
+        let mut text = match (self.signal_id, &self.ticker) {
+            (Some(signal_id), _) => format!("signal #{}", signal_id),
+            (None, Some(ticker)) => format!(
+                "{} {}",
+                ticker,
+                self.direction.as_deref().unwrap_or("?").to_uppercase()
+            ),
+            (None, None) => "?".to_string(),
+        };
+        if let Some(size) = self.size {
+            text.push_str(&format!(" x{}", size));
+        }
+        if let Some(cents) = self.limit {
+            text.push_str(&format!(" limit {}¢", cents));
+        }
+        text
+    }
+
+    /// The `trade` or `manual` command this entry stands for
+    fn command(&self, contracts: &[Contract]) -> Result<TradingCommands> {
+        if let Some(size) = self.size.filter(|size| *size < 1) {
+            bail!("size must be at least 1 (got {})", size);
+        }
+        if let Some(cents) = self.limit.filter(|cents| !(1..=99).contains(cents)) {
+            bail!("limit must be 1-99 cents (got {})", cents);
+        }
+
+        match (self.signal_id, &self.ticker, &self.direction) {
+            (Some(_), Some(_), _) => bail!("give either signal_id or ticker, not both"),
+            (Some(signal_id), None, _) => Ok(TradingCommands::Trade {
+                signal_id,
+                size: self.size,
+                limit: self.limit,
+                take_profit: None,
+                stop_loss: None,
+                tag: self.tag.clone(),
+                note: self.note.clone(),
+            }),
+            (None, Some(ticker), Some(direction)) => {
+                let direction = direction.to_uppercase();
+                if direction != "YES" && direction != "NO" {
+                    bail!("direction must be YES or NO (got {})", direction);
+                }
+                let strike = self.strike.or_else(|| {
+                    contracts
+                        .iter()
+                        .find(|c| &c.ticker == ticker)
+                        .and_then(|c| c.strike_price)
+                });
+                let Some(strike) = strike else {
+                    bail!("{} isn't in the current signals; give its strike", ticker);
+                };
+                Ok(TradingCommands::Manual {
+                    asset: match &self.asset {
+                        Some(asset) => asset.clone(),
+                        None => ticker_asset(ticker).with_context(|| {
+                            format!("Can't tell the asset of {}; give its `asset`", ticker)
+                        })?,
+                    },
+                    direction,
+                    strike,
+                    ticker: ticker.clone(),
+                    size: self.size.unwrap_or(1),
+                    limit: self.limit,
+                    take_profit: None,
+                    stop_loss: None,
+                    tag: self.tag.clone(),
+                    note: self.note.clone(),
+                })
+            }
+            (None, Some(_), None) => bail!("a ticker entry needs a direction"),
+            (None, None, _) => bail!("needs a signal_id, or a ticker and direction"),
+        }
+    }
+}
+
+/// Entries from a JSON array or a CSV file with a header row
+pub fn parse(text: &str) -> Result<Vec<BatchEntry>> {
+    if text.trim_start().starts_with('[') {
+        return serde_json::from_str(text).context("Failed to parse JSON batch");
+    }
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes())
+        .deserialize()
+        .collect::<Result<Vec<BatchEntry>, _>>()
+        .context("Failed to parse CSV batch")
+}
+
+/// Read a batch from a file, or from stdin when the path is missing or `-`
+pub fn load(path: Option<&Path>) -> Result<Vec<BatchEntry>> {
+    let text = match path.filter(|p| p.as_os_str() != "-") {
+        Some(path) => fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?,
+        None => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .context("Failed to read batch from stdin")?;
+            text
+        }
+    };
+    parse(&text)
+}
+
+enum Outcome {
+    Done,
+    Failed(String),
+    Skipped,
+}
+
+/// Run every entry in order, stopping once `max_failures` have failed
+pub async fn run(
+    entries: Vec<BatchEntry>,
+    api_url: &str,
+    dry_run: bool,
+    retry_orders: bool,
+    max_failures: Option<usize>,
+) -> Result<()> {
+    if entries.is_empty() {
+        bail!("The batch has no entries");
+    }
+
+    // Resolve every entry before sending anything, so a typo doesn't leave
+    // half a batch placed
+    let contracts = if entries
+        .iter()
+        .any(|e| e.ticker.is_some() && e.strike.is_none())
+    {
+        let client = ApiClient::new(api_url.to_string(), 30)?;
+        client
+            .get_current_signals()
+            .await
+            .map(|current| current.contracts)
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let commands = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            entry
+                .command(&contracts)
+                .with_context(|| format!("Batch entry {} ({})", i + 1, entry.describe()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let total = commands.len();
+    let mut outcomes: Vec<Outcome> = Vec::with_capacity(total);
+    let mut failures = 0;
+    for (i, (entry, command)) in entries.iter().zip(commands).enumerate() {
+        if max_failures.is_some_and(|max| failures >= max) {
+            outcomes.push(Outcome::Skipped);
+            continue;
+        }
+        println!("━━ [{}/{}] {} ━━", i + 1, total, entry.describe());
+        match Box::pin(handle_trading_command(
+            command,
+            api_url,
+            dry_run,
+            retry_orders,
+            OutputFormat::Table,
+        ))
+        .await
+        {
+            Ok(()) => outcomes.push(Outcome::Done),
+            Err(e) => {
+                println!("❌ {}", e);
+                failures += 1;
+                outcomes.push(Outcome::Failed(e.to_string()));
+                if let Some(max) = max_failures.filter(|max| failures >= *max && i + 1 < total) {
+                    println!();
+                    println!(
+                        "🛑 {} failed; stopping the batch (--max-failures {})",
+                        failures, max
+                    );
+                }
+            }
+        }
+        println!();
+    }
+
+    let done_label = if dry_run { "Previewed" } else { "Placed" };
+    println!("📦 Batch Summary");
+    println!("{}", "─".repeat(70));
+    for (i, (entry, outcome)) in entries.iter().zip(&outcomes).enumerate() {
+        let result = match outcome {
+            Outcome::Done => format!("\x1b[32m✅ {}\x1b[0m", done_label.to_lowercase()),
+            Outcome::Failed(error) => format!("\x1b[31m❌ {}\x1b[0m", error),
+            Outcome::Skipped => "\x1b[2m⏭  not attempted\x1b[0m".to_string(),
+        };
+        println!("   {:<4} {:<36} {}", i + 1, entry.describe(), result);
+    }
+    println!("{}", "─".repeat(70));
+    let skipped = outcomes
+        .iter()
+        .filter(|o| matches!(o, Outcome::Skipped))
+        .count();
+    println!(
+        "   {}: {}   Failed: {}   Not attempted: {}",
+        done_label,
+        total - failures - skipped,
+        failures,
+        skipped
+    );
+
+    if failures > 0 {
+        bail!("{} of {} batch orders failed", failures, total);
+    }
+    Ok(())
+}
@@ -0,0 +1,657 @@
+//! Take-profit and stop-loss exits attached to a trade (`--take-profit` /
+//! `--stop-loss` on `trade` and `manual`).
+//!
+//! When the entry fills, the bracket is saved in `brackets.json` against the
+//! new position's trade ID. `basilisk brackets watch` polls the quote for the
+//! position's side and closes the position at market once it reaches either
+//! leg; the other leg is dropped with it. A close that errors or is rejected
+//! is tried again on the next pass, up to `MAX_EXIT_ATTEMPTS` times, before
+//! the bracket is marked failed. A bracket whose contract expires
+//! untouched is marked expired and the position settles as usual. Brackets
+//! only fire while `brackets watch` is running.
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, Utc};
+use clap::Subcommand;
+use serde::{Deserialize, Serialize};
+
+use crate::api::client::ApiClient;
+use crate::api::models::{Contract, TradeResponse};
+use crate::api::parse_timestamp;
+use crate::audit::{self, AuditKind};
+use crate::store;
+
+const BRACKETS_FILE: &str = "brackets.json";
+
+/// Closes tried on a due bracket before it's given up as failed
+const MAX_EXIT_ATTEMPTS: u32 = 5;
+
+/// Which side of the bracket fired
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Leg {
+    TakeProfit,
+    StopLoss,
+}
+
+impl Leg {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Leg::TakeProfit => "take-profit",
+            Leg::StopLoss => "stop-loss",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum BracketStatus {
+    Active,
+    Triggered {
+        leg: Leg,
+        /// Quote that crossed the leg
+        quote: f64,
+        exit_price: Option<f64>,
+        pnl: Option<f64>,
+    },
+    Failed {
+        leg: Leg,
+        error: String,
+    },
+    Expired,
+    Cancelled,
+}
+
+impl BracketStatus {
+    pub fn label(&self) -> String {
+        match self {
+            BracketStatus::Active => "active".to_string(),
+            BracketStatus::Triggered {
+                leg,
+                exit_price,
+                pnl,
+                ..
+            } => format!(
+                "{} @ {}{}",
+                leg.label(),
+                exit_price
+                    .map(|p| format!("${:.2}", p))
+                    .unwrap_or_else(|| "--".to_string()),
+                pnl.map(|pnl| format!(" (${:+.2})", pnl))
+                    .unwrap_or_default()
+            ),
+            BracketStatus::Failed { leg, error } => format!("{} failed: {}", leg.label(), error),
+            BracketStatus::Expired => "expired".to_string(),
+            BracketStatus::Cancelled => "cancelled".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bracket {
+    /// Position (trade) ID the exit closes
+    pub trade_id: i32,
+    pub ticker: String,
+    /// "YES" or "NO"
+    pub direction: String,
+    pub contracts: i32,
+    pub entry_price: Option<f64>,
+    /// Close once the side's quote reaches this many cents
+    pub take_profit: Option<i32>,
+    /// Close once the side's quote falls to this many cents
+    pub stop_loss: Option<i32>,
+    /// Contract expiry; the bracket lapses after it
+    pub expiry: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub status: BracketStatus,
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Closes tried since the bracket came due
+    #[serde(default)]
+    pub attempts: u32,
+    /// Why the last close didn't go through
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+impl Bracket {
+    pub fn is_active(&self) -> bool {
+        self.status == BracketStatus::Active
+    }
+
+    /// "#1187 KXBTCD-... YES x3: take-profit 70¢, stop-loss 30¢"
+    pub fn describe(&self) -> String {
+        let legs: Vec<String> = [
+            self.take_profit.map(|c| format!("take-profit {}¢", c)),
+            self.stop_loss.map(|c| format!("stop-loss {}¢", c)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        format!(
+            "#{} {} {} x{}: {}",
+            self.trade_id,
+            self.ticker,
+            self.direction,
+            self.contracts,
+            legs.join(", ")
+        )
+    }
+
+    /// Latest quote for the position's side, in dollars
+    pub fn quote(&self, contracts: &[Contract]) -> Option<f64> {
+        let contract = contracts.iter().find(|c| c.ticker == self.ticker)?;
+        if self.direction.eq_ignore_ascii_case("NO") {
+            contract.no_price
+        } else {
+            contract.yes_price
+        }
+    }
+
+    /// The leg `quote` reaches, if any (take-profit wins a tie)
+    pub fn check(&self, quote: f64) -> Option<Leg> {
+        let cents = quote * 100.0;
+        if self.take_profit.is_some_and(|tp| cents >= tp as f64 - 1e-9) {
+            Some(Leg::TakeProfit)
+        } else if self.stop_loss.is_some_and(|sl| cents <= sl as f64 + 1e-9) {
+            Some(Leg::StopLoss)
+        } else {
+            None
+        }
+    }
+}
+
+/// Every bracket ever placed, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BracketBook {
+    brackets: Vec<Bracket>,
+}
+
+impl BracketBook {
+    /// Load saved brackets (a missing file means none). A file that can't be
+    /// read is an error rather than an empty book, so it isn't overwritten.
+    pub fn load() -> Result<Self> {
+        let book = store::read_json(BRACKETS_FILE).context("Failed to load brackets")?;
+        Ok(book.unwrap_or_default())
+    }
+
+    pub fn save(&self) -> Result<()> {
+        store::write_json(BRACKETS_FILE, self)
+    }
+
+    pub fn brackets(&self) -> &[Bracket] {
+        &self.brackets
+    }
+
+    /// Attach a bracket to the position an order just opened
+    pub fn add(
+        &mut self,
+        contract: Option<&Contract>,
+        ticker: &str,
+        direction: &str,
+        response: &TradeResponse,
+        take_profit: Option<i32>,
+        stop_loss: Option<i32>,
+    ) -> Result<Bracket> {
+        let Some(trade_id) = response.trade_id.filter(|_| response.filled > 0) else {
+            bail!("nothing filled yet, so there's no position to bracket");
+        };
+        let bracket = Bracket {
+            trade_id,
+            ticker: ticker.to_string(),
+            direction: direction.to_uppercase(),
+            contracts: response.filled,
+            entry_price: response.price,
+            take_profit,
+            stop_loss,
+            expiry: contract
+                .and_then(|c| c.expiry_time.as_deref())
+                .and_then(parse_timestamp),
+            created_at: Utc::now(),
+            status: BracketStatus::Active,
+            updated_at: None,
+            attempts: 0,
+            last_error: None,
+        };
+        self.brackets.push(bracket.clone());
+        Ok(bracket)
+    }
+
+    pub fn cancel(&mut self, trade_id: i32) -> Result<()> {
+        let Some(bracket) = self
+            .brackets
+            .iter_mut()
+            .find(|b| b.trade_id == trade_id && b.is_active())
+        else {
+            bail!("No active bracket on position #{}", trade_id);
+        };
+        bracket.status = BracketStatus::Cancelled;
+        bracket.updated_at = Some(Utc::now());
+        Ok(())
+    }
+
+    pub fn set_status(&mut self, trade_id: i32, status: BracketStatus) {
+        if let Some(bracket) = self
+            .brackets
+            .iter_mut()
+            .find(|b| b.trade_id == trade_id && b.is_active())
+        {
+            bracket.status = status;
+            bracket.updated_at = Some(Utc::now());
+        }
+    }
+
+    /// Note a close that didn't go through; the bracket stays active until
+    /// `MAX_EXIT_ATTEMPTS` have failed. Whether it's now given up.
+    pub fn record_failure(&mut self, trade_id: i32, leg: Leg, error: String) -> bool {
+        let Some(bracket) = self
+            .brackets
+            .iter_mut()
+            .find(|b| b.trade_id == trade_id && b.is_active())
+        else {
+            return false;
+        };
+        bracket.attempts += 1;
+        bracket.updated_at = Some(Utc::now());
+        if bracket.attempts >= MAX_EXIT_ATTEMPTS {
+            bracket.status = BracketStatus::Failed { leg, error };
+            bracket.last_error = None;
+            true
+        } else {
+            bracket.last_error = Some(error);
+            false
+        }
+    }
+
+    /// Expire active brackets whose contract has expired; the ones that changed
+    pub fn expire(&mut self, now: DateTime<Utc>) -> Vec<Bracket> {
+        let mut expired = Vec::new();
+        for bracket in &mut self.brackets {
+            if bracket.is_active() && bracket.expiry.is_some_and(|expiry| now >= expiry) {
+                bracket.status = BracketStatus::Expired;
+                bracket.updated_at = Some(now);
+                expired.push(bracket.clone());
+            }
+        }
+        expired
+    }
+
+    /// Active brackets with the leg their quote in `contracts` reached
+    pub fn due(&self, contracts: &[Contract]) -> Vec<(Bracket, Leg, f64)> {
+        self.brackets
+            .iter()
+            .filter(|b| b.is_active())
+            .filter_map(|b| {
+                let quote = b.quote(contracts)?;
+                Some((b.clone(), b.check(quote)?, quote))
+            })
+            .collect()
+    }
+}
+
+/// Check that the legs make sense against each other
+pub fn validate(take_profit: Option<i32>, stop_loss: Option<i32>) -> Result<()> {
+    if let (Some(tp), Some(sl)) = (take_profit, stop_loss) {
+        if tp <= sl {
+            bail!(
+                "--take-profit ({}¢) must be above --stop-loss ({}¢)",
+                tp,
+                sl
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Close a bracketed position at market and record it like `basilisk close`.
+/// The triggered status, or why the close didn't go through.
+async fn exit(
+    client: &ApiClient,
+    bracket: &Bracket,
+    leg: Leg,
+    quote: f64,
+) -> std::result::Result<BracketStatus, String> {
+    let description = format!(
+        "{} close position #{} ({} {} at {:.0}¢)",
+        leg.label(),
+        bracket.trade_id,
+        bracket.ticker,
+        bracket.direction,
+        quote * 100.0
+    );
+    let result = client.close_position(bracket.trade_id).await;
+    if let Err(e) = audit::record_result(AuditKind::Close, "brackets", &description, &result) {
+        log("WARN", format!("could not write audit log: {}", e));
+    }
+    match result {
+        Ok(response) if response.success => Ok(BracketStatus::Triggered {
+            leg,
+            quote,
+            exit_price: response.price,
+            pnl: response.cost,
+        }),
+        Ok(response) => Err(response.error.unwrap_or_else(|| "rejected".to_string())),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn log(event: &str, message: impl std::fmt::Display) {
+    println!(
+        "{} {:<8} {}",
+        Local::now().format("%H:%M:%S"),
+        event,
+        message
+    );
+}
+
+/// One pass: expire lapsed brackets and close positions whose quote reached
+/// a leg. The book is read again before saving, so brackets that trades added
+/// while the quotes and closes were in flight are kept.
+async fn check(client: &ApiClient) -> Result<()> {
+    let now = Utc::now();
+    let mut book = BracketBook::load()?;
+    let expired = book.expire(now);
+    for bracket in &expired {
+        log("EXPIRED", bracket.describe());
+    }
+
+    let mut exits = Vec::new();
+    if book.brackets().iter().any(Bracket::is_active) {
+        let contracts = client.get_current_signals().await?.contracts;
+        for (bracket, leg, quote) in book.due(&contracts) {
+            let result = exit(client, &bracket, leg, quote).await;
+            exits.push((bracket, leg, result));
+        }
+    }
+    if expired.is_empty() && exits.is_empty() {
+        return Ok(());
+    }
+
+    let mut book = BracketBook::load()?;
+    book.expire(now);
+    for (bracket, leg, result) in exits {
+        match result {
+            Ok(status) => {
+                let event = match leg {
+                    Leg::TakeProfit => "PROFIT",
+                    Leg::StopLoss => "STOP",
+                };
+                log(
+                    event,
+                    format!("{} → {}", bracket.describe(), status.label()),
+                );
+                book.set_status(bracket.trade_id, status);
+            }
+            Err(error) => {
+                let message = format!(
+                    "{} → {} close failed: {}",
+                    bracket.describe(),
+                    leg.label(),
+                    error
+                );
+                if book.record_failure(bracket.trade_id, leg, error) {
+                    log("FAILED", format!("{} (giving up)", message));
+                } else {
+                    log("RETRY", message);
+                }
+            }
+        }
+    }
+    book.save()
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BracketsCommands {
+    /// Show brackets (active ones, or every bracket with --all)
+    #[command(name = "list")]
+    List {
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Drop the bracket on a position (the position stays open)
+    #[command(name = "cancel")]
+    Cancel {
+        /// Position/trade ID
+        position_id: i32,
+    },
+
+    /// Watch quotes and close bracketed positions when a leg is reached
+    #[command(name = "watch")]
+    Watch {
+        /// Seconds between quote checks
+        #[arg(long, default_value = "5")]
+        interval: u64,
+    },
+}
+
+impl BracketsCommands {
+    /// Whether the command can close positions (refused in read-only mode)
+    pub fn places_orders(&self) -> bool {
+        matches!(self, BracketsCommands::Watch { .. })
+    }
+}
+
+pub async fn handle_brackets_command(
+    cmd: BracketsCommands,
+    api_url: &str,
+    retry_orders: bool,
+) -> Result<()> {
+    match cmd {
+        BracketsCommands::List { all } => {
+            let mut book = BracketBook::load()?;
+            if !book.expire(Utc::now()).is_empty() {
+                book.save()?;
+            }
+            let brackets: Vec<&Bracket> = book
+                .brackets()
+                .iter()
+                .rev()
+                .filter(|bracket| all || bracket.is_active())
+                .collect();
+            if brackets.is_empty() {
+                println!("🎯 No {}brackets.", if all { "" } else { "active " });
+                return Ok(());
+            }
+
+            println!("🎯 Brackets ({}):", brackets.len());
+            println!("{}", "─".repeat(100));
+            println!("{:<60} Status", "Bracket");
+            println!("{}", "─".repeat(100));
+            for bracket in brackets {
+                let color = match bracket.status {
+                    BracketStatus::Active => "\x1b[36m",
+                    BracketStatus::Triggered {
+                        leg: Leg::TakeProfit,
+                        ..
+                    } => "\x1b[32m",
+                    BracketStatus::Triggered { .. } | BracketStatus::Failed { .. } => "\x1b[31m",
+                    BracketStatus::Expired | BracketStatus::Cancelled => "\x1b[90m",
+                };
+                let status = match &bracket.last_error {
+                    Some(error) if bracket.is_active() => format!(
+                        "active, {} close(s) failed, last: {}",
+                        bracket.attempts, error
+                    ),
+                    _ => bracket.status.label(),
+                };
+                println!("{:<60} {}{}\x1b[0m", bracket.describe(), color, status);
+            }
+            println!("{}", "─".repeat(100));
+        }
+
+        BracketsCommands::Cancel { position_id } => {
+            let mut book = BracketBook::load()?;
+            book.cancel(position_id)?;
+            book.save()?;
+            println!("✅ Bracket on position #{} cancelled.", position_id);
+        }
+
+        BracketsCommands::Watch { interval } => {
+            let client = ApiClient::new(api_url.to_string(), 30)?.with_order_retry(retry_orders);
+            let active = BracketBook::load()?
+                .brackets()
+                .iter()
+                .filter(|b| b.is_active())
+                .count();
+            log(
+                "START",
+                format!(
+                    "{} active bracket(s), checking every {}s",
+                    active,
+                    interval.max(1)
+                ),
+            );
+
+            let mut ticker = tokio::time::interval(Duration::from_secs(interval.max(1)));
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    _ = tokio::signal::ctrl_c() => {
+                        log("STOP", "interrupted");
+                        break;
+                    }
+                }
+                if let Err(e) = check(&client).await {
+                    log("ERROR", e);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bracket(take_profit: Option<i32>, stop_loss: Option<i32>) -> Bracket {
+        Bracket {
+            trade_id: 7,
+            ticker: "KXBTCD-T95000".to_string(),
+            direction: "YES".to_string(),
+            contracts: 3,
+            entry_price: Some(0.5),
+            take_profit,
+            stop_loss,
+            expiry: None,
+            created_at: Utc::now(),
+            status: BracketStatus::Active,
+            updated_at: None,
+            attempts: 0,
+            last_error: None,
+        }
+    }
+
+    fn contract(yes_price: f64, no_price: f64) -> Contract {
+        Contract {
+            id: 1,
+            ticker: "KXBTCD-T95000".to_string(),
+            signal_type: "HOLD".to_string(),
+            expected_value: 0.0,
+            edge_percentage: 0.0,
+            recommended_price: 0.0,
+            confidence_score: 0.0,
+            time_to_expiry_hours: None,
+            is_active: true,
+            strike_price: Some(95000.0),
+            expiry_time: None,
+            current_btc_price: None,
+            yes_price: Some(yes_price),
+            no_price: Some(no_price),
+            implied_probability: None,
+            model_probability: None,
+        }
+    }
+
+    #[test]
+    fn check_fires_at_each_leg() {
+        let b = bracket(Some(70), Some(30));
+        assert_eq!(b.check(0.70), Some(Leg::TakeProfit));
+        assert_eq!(b.check(0.85), Some(Leg::TakeProfit));
+        assert_eq!(b.check(0.30), Some(Leg::StopLoss));
+        assert_eq!(b.check(0.05), Some(Leg::StopLoss));
+        assert_eq!(b.check(0.50), None);
+    }
+
+    #[test]
+    fn check_with_one_leg() {
+        assert_eq!(bracket(Some(70), None).check(0.10), None);
+        assert_eq!(bracket(None, Some(30)).check(0.99), None);
+        assert_eq!(bracket(None, None).check(0.50), None);
+    }
+
+    #[test]
+    fn check_prefers_take_profit_on_a_tie() {
+        assert_eq!(
+            bracket(Some(50), Some(50)).check(0.50),
+            Some(Leg::TakeProfit)
+        );
+    }
+
+    #[test]
+    fn validate_needs_take_profit_above_stop_loss() {
+        assert!(validate(Some(70), Some(30)).is_ok());
+        assert!(validate(Some(70), None).is_ok());
+        assert!(validate(None, Some(30)).is_ok());
+        assert!(validate(Some(30), Some(30)).is_err());
+        assert!(validate(Some(30), Some(70)).is_err());
+    }
+
+    #[test]
+    fn due_reads_the_positions_side() {
+        let mut book = BracketBook::default();
+        book.brackets.push(bracket(Some(70), Some(30)));
+        let mut no = bracket(Some(70), Some(30));
+        no.trade_id = 8;
+        no.direction = "NO".to_string();
+        book.brackets.push(no);
+
+        let due = book.due(&[contract(0.75, 0.25)]);
+        let legs: Vec<(i32, Leg)> = due.iter().map(|(b, leg, _)| (b.trade_id, *leg)).collect();
+        assert_eq!(legs, vec![(7, Leg::TakeProfit), (8, Leg::StopLoss)]);
+    }
+
+    #[test]
+    fn record_failure_gives_up_after_max_attempts() {
+        let mut book = BracketBook::default();
+        book.brackets.push(bracket(Some(70), None));
+
+        for _ in 1..MAX_EXIT_ATTEMPTS {
+            assert!(!book.record_failure(7, Leg::TakeProfit, "timeout".to_string()));
+            assert!(book.brackets[0].is_active());
+        }
+        assert_eq!(book.brackets[0].last_error.as_deref(), Some("timeout"));
+        assert!(book.record_failure(7, Leg::TakeProfit, "timeout".to_string()));
+        assert_eq!(
+            book.brackets[0].status,
+            BracketStatus::Failed {
+                leg: Leg::TakeProfit,
+                error: "timeout".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn expire_only_touches_active_brackets_past_expiry() {
+        let now = Utc::now();
+        let mut book = BracketBook::default();
+        let mut past = bracket(Some(70), None);
+        past.expiry = Some(now - chrono::Duration::minutes(1));
+        let mut future = bracket(Some(70), None);
+        future.trade_id = 8;
+        future.expiry = Some(now + chrono::Duration::minutes(1));
+        let mut cancelled = past.clone();
+        cancelled.trade_id = 9;
+        cancelled.status = BracketStatus::Cancelled;
+        book.brackets = vec![past, future, cancelled];
+
+        let expired = book.expire(now);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].trade_id, 7);
+        assert_eq!(book.brackets[1].status, BracketStatus::Active);
+        assert_eq!(book.brackets[2].status, BracketStatus::Cancelled);
+    }
+}
@@ -0,0 +1,108 @@
+//! BTC price, RV and IV histories behind the dashboard charts.
+//!
+//! Saved to `chart_history.json` on exit and reloaded on startup, so relaunching
+//! mid-session picks the charts up where they left off. Samples older than
+//! `MAX_AGE_MINUTES` are dropped on load rather than drawn as if they were recent.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::store;
+
+const CHART_HISTORY_FILE: &str = "chart_history.json";
+
+/// Points kept per chart
+const CAPACITY: usize = 50;
+
+/// Oldest sample worth restoring
+const MAX_AGE_MINUTES: i64 = 240;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Sample {
+    timestamp: DateTime<Utc>,
+    value: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Series {
+    samples: VecDeque<Sample>,
+}
+
+impl Series {
+    fn push(&mut self, value: f64) {
+        self.samples.push_back(Sample {
+            timestamp: Utc::now(),
+            value,
+        });
+        while self.samples.len() > CAPACITY {
+            self.samples.pop_front();
+        }
+    }
+
+    fn prune(&mut self, cutoff: DateTime<Utc>) {
+        self.samples.retain(|sample| sample.timestamp >= cutoff);
+        while self.samples.len() > CAPACITY {
+            self.samples.pop_front();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChartHistory {
+    btc_price: Series,
+    realized_vol: Series,
+    implied_vol: Series,
+}
+
+impl ChartHistory {
+    /// Restore saved histories, dropping stale samples (missing or unreadable file = empty)
+    pub fn load() -> Self {
+        let mut history: Self = store::read_json(CHART_HISTORY_FILE)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
+        let cutoff = Utc::now() - Duration::minutes(MAX_AGE_MINUTES);
+        history.btc_price.prune(cutoff);
+        history.realized_vol.prune(cutoff);
+        history.implied_vol.prune(cutoff);
+        history
+    }
+
+    pub fn save(&self) -> Result<()> {
+        store::write_json(CHART_HISTORY_FILE, self)
+    }
+
+    pub fn record_price(&mut self, price: f64) {
+        self.btc_price.push(price);
+    }
+
+    pub fn record_vol(&mut self, realized_vol: f64, implied_vol: f64) {
+        self.realized_vol.push(realized_vol);
+        self.implied_vol.push(implied_vol);
+    }
+
+    /// Polled BTC prices since `since`, oldest first (the BTC chart's
+    /// fallback while the stream is down)
+    pub fn btc_price_since(&self, since: DateTime<Utc>) -> Vec<(DateTime<Utc>, f64)> {
+        self.btc_price
+            .samples
+            .iter()
+            .filter(|sample| sample.timestamp >= since)
+            .map(|sample| (sample.timestamp, sample.value))
+            .collect()
+    }
+
+    /// (time, RV, IV) readings, oldest first. Both are recorded together, so
+    /// their samples line up one to one.
+    pub fn vol(&self) -> Vec<(DateTime<Utc>, f64, f64)> {
+        self.realized_vol
+            .samples
+            .iter()
+            .zip(&self.implied_vol.samples)
+            .map(|(rv, iv)| (rv.timestamp, rv.value, iv.value))
+            .collect()
+    }
+}
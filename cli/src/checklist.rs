@@ -0,0 +1,180 @@
+//! Optional pre-trade checklist (`[checklist]`).
+//!
+//! When enabled, dashboard orders of at least `min_contracts` stop at a short
+//! list of questions before they are sent. The built-in items come with the
+//! figure that answers them (the quote spread, the order's share of bankroll,
+//! time to settlement); `reason` is a free-text line. Anything else in `items`
+//! is asked as written. The answers are journaled with the trade.
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::Contract;
+use crate::config::ChecklistConfig;
+use crate::sizing;
+
+/// One question and the answer given
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Answer {
+    pub question: String,
+    pub yes: bool,
+}
+
+/// What is journaled alongside the trade
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistRecord {
+    pub answers: Vec<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// A yes/no question, with the figure that helps answer it
+pub struct Question {
+    pub text: String,
+    pub hint: Option<String>,
+    pub answer: Option<bool>,
+}
+
+pub struct Checklist {
+    pub questions: Vec<Question>,
+    /// Present when `reason` is one of the items; always listed last
+    pub reason: Option<String>,
+    /// Index into the questions, or `questions.len()` for the reason line
+    pub selected: usize,
+    require_yes: bool,
+}
+
+impl Checklist {
+    /// Whether an order of `size` contracts has to go through the checklist
+    pub fn applies(config: &ChecklistConfig, size: i32) -> bool {
+        config.enabled && !config.items.is_empty() && size >= config.min_contracts
+    }
+
+    pub fn new(
+        config: &ChecklistConfig,
+        contract: &Contract,
+        size: i32,
+        bankroll: Option<f64>,
+    ) -> Self {
+        let mut questions = Vec::new();
+        let mut reason = None;
+        for item in &config.items {
+            let (text, hint) = match item.to_lowercase().as_str() {
+                "spread" => (
+                    "Spread acceptable?",
+                    contract
+                        .spread()
+                        .map(|spread| format!("spread {:.0}¢", spread * 100.0)),
+                ),
+                "risk" => (
+                    "Within risk limits?",
+                    sizing::entry_price(contract).map(|price| {
+                        let cost = price * size as f64;
+                        match bankroll.filter(|b| *b > 0.0) {
+                            Some(bankroll) => {
+                                format!(
+                                    "cost ${:.2} = {:.1}% of bankroll",
+                                    cost,
+                                    cost / bankroll * 100.0
+                                )
+                            }
+                            None => format!("cost ${:.2}", cost),
+                        }
+                    }),
+                ),
+                "settlement" => (
+                    "Not near settlement?",
+                    Some(format!("{} left", contract.time_left_display())),
+                ),
+                "reason" => {
+                    reason = Some(String::new());
+                    continue;
+                }
+                _ => (item.as_str(), None),
+            };
+            questions.push(Question {
+                text: text.to_string(),
+                hint,
+                answer: None,
+            });
+        }
+
+        Self {
+            questions,
+            reason,
+            selected: 0,
+            require_yes: config.require_yes,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.questions.len() + usize::from(self.reason.is_some())
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(self.len().saturating_sub(1));
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The reason line is highlighted, so keystrokes are text
+    pub fn editing_reason(&self) -> bool {
+        self.reason.is_some() && self.selected == self.questions.len()
+    }
+
+    /// Answer the highlighted question and move to the next item
+    pub fn answer(&mut self, yes: bool) {
+        if let Some(question) = self.questions.get_mut(self.selected) {
+            question.answer = Some(yes);
+            self.select_next();
+        }
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        if self.editing_reason() {
+            if let Some(reason) = self.reason.as_mut() {
+                reason.push(c);
+            }
+        }
+    }
+
+    pub fn pop_char(&mut self) {
+        if self.editing_reason() {
+            if let Some(reason) = self.reason.as_mut() {
+                reason.pop();
+            }
+        }
+    }
+
+    /// Why the order can't go out yet, if anything is missing
+    pub fn blocker(&self) -> Option<&'static str> {
+        if self.questions.iter().any(|q| q.answer.is_none()) {
+            Some("Answer every question (y/n)")
+        } else if self.reason.as_ref().is_some_and(|r| r.trim().is_empty()) {
+            Some("Enter a reason for the journal")
+        } else if self.require_yes && self.questions.iter().any(|q| q.answer == Some(false)) {
+            Some("Every question must be answered yes")
+        } else {
+            None
+        }
+    }
+
+    pub fn record(&self) -> ChecklistRecord {
+        ChecklistRecord {
+            answers: self
+                .questions
+                .iter()
+                .map(|q| Answer {
+                    question: q.text.clone(),
+                    yes: q.answer.unwrap_or(false),
+                })
+                .collect(),
+            reason: self
+                .reason
+                .as_ref()
+                .map(|r| r.trim().to_string())
+                .filter(|r| !r.is_empty()),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Side-by-side comparison of two contracts for the dashboard's compare view.
+//!
+//! Each contract gets its quote, probabilities and payoff per contract, plus a
+//! Monte Carlo distribution of BTC at its expiry. Both sides are simulated
+//! from the same random draws and binned over one shared price range, so
+//! adjacent strikes or a near vs next-hour expiry line up directly.
+
+use basilisk_quant::monte_carlo::{self, percentile};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::api::Contract;
+use crate::sizing;
+
+/// Simulated paths per contract
+const PATHS: usize = 4000;
+
+/// Fixed seed: both sides see the same shocks, and the view is stable across redraws
+const SEED: u64 = 0xB451_115C;
+
+/// Histogram resolution
+pub const BINS: usize = 32;
+
+/// Everything shown for one side of the comparison
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub contract: Contract,
+    /// "YES" or "NO": the side the signal recommends (YES for HOLD)
+    pub side: &'static str,
+    pub price: Option<f64>,
+    /// Probability the side wins, as priced by the market and by the model
+    pub implied: Option<f64>,
+    pub model: Option<f64>,
+    /// Share of simulated paths where the side wins
+    pub mc_win_rate: Option<f64>,
+    /// BTC at expiry: 5th, 50th and 95th percentile
+    pub terminal: Option<[f64; 3]>,
+    /// Path counts per bin over the shared range
+    pub histogram: Vec<u64>,
+    /// Bin containing the strike, if it falls inside the range
+    pub strike_bin: Option<usize>,
+}
+
+impl Profile {
+    /// Profit per contract if the side wins (payout is $1)
+    pub fn win_payoff(&self) -> Option<f64> {
+        self.price.map(|p| 1.0 - p)
+    }
+
+    /// Reward-to-risk per contract
+    pub fn reward_risk(&self) -> Option<f64> {
+        let price = self.price.filter(|p| *p > 0.0)?;
+        Some((1.0 - price) / price)
+    }
+}
+
+pub struct Comparison {
+    pub left: Profile,
+    pub right: Profile,
+    /// BTC price range covered by the histograms
+    pub range: (f64, f64),
+}
+
+/// Terminal BTC prices under driftless GBM, sorted
+fn simulate(spot: f64, vol: f64, hours: f64) -> Vec<f64> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    monte_carlo::simulate_terminal(&mut rng, spot, vol, hours, PATHS)
+}
+
+fn bin_of(price: f64, (low, high): (f64, f64)) -> Option<usize> {
+    if price < low || price > high || high <= low {
+        return None;
+    }
+    Some((((price - low) / (high - low)) * BINS as f64).min(BINS as f64 - 1.0) as usize)
+}
+
+fn side_of(contract: &Contract) -> &'static str {
+    if contract.signal_type.contains("NO") {
+        "NO"
+    } else {
+        "YES"
+    }
+}
+
+pub fn compare(a: &Contract, b: &Contract, spot: Option<f64>, vol: Option<f64>) -> Comparison {
+    let paths = |contract: &Contract| {
+        let hours = contract.seconds_to_expiry()? as f64 / 3600.0;
+        Some(simulate(spot?, vol?, hours.max(0.0)))
+    };
+    let (left_paths, right_paths) = (paths(a), paths(b));
+
+    // Shared range: the union of both sides' 1st-99th percentiles, widened to show the strikes
+    let mut low = f64::INFINITY;
+    let mut high = f64::NEG_INFINITY;
+    for sorted in left_paths.iter().chain(right_paths.iter()) {
+        low = low.min(percentile(sorted, 0.01));
+        high = high.max(percentile(sorted, 0.99));
+    }
+    for strike in [a.strike_price, b.strike_price].into_iter().flatten() {
+        if low.is_finite() {
+            low = low.min(strike);
+            high = high.max(strike);
+        }
+    }
+    let range = if low.is_finite() && high > low {
+        (low, high)
+    } else {
+        (0.0, 0.0)
+    };
+
+    let profile = |contract: &Contract, sorted: Option<Vec<f64>>| {
+        let side = side_of(contract);
+        let (price, implied, model) = if side == "NO" {
+            (
+                contract.no_price,
+                contract.implied_probability.map(|p| 1.0 - p),
+                contract.model_probability.map(|p| 1.0 - p),
+            )
+        } else {
+            (
+                contract.yes_price,
+                contract.implied_probability,
+                contract.model_probability,
+            )
+        };
+
+        let mut histogram = vec![0u64; BINS];
+        let mut mc_win_rate = None;
+        let mut terminal = None;
+        if let Some(sorted) = &sorted {
+            for price in sorted {
+                if let Some(bin) = bin_of(*price, range) {
+                    histogram[bin] += 1;
+                }
+            }
+            terminal = Some([
+                percentile(sorted, 0.05),
+                percentile(sorted, 0.5),
+                percentile(sorted, 0.95),
+            ]);
+            mc_win_rate = contract.strike_price.map(|strike| {
+                let above = sorted.iter().filter(|p| **p > strike).count() as f64;
+                let yes = above / sorted.len() as f64;
+                if side == "NO" {
+                    1.0 - yes
+                } else {
+                    yes
+                }
+            });
+        }
+
+        Profile {
+            contract: contract.clone(),
+            side,
+            price: price.or_else(|| sizing::entry_price(contract)),
+            implied,
+            model,
+            mc_win_rate,
+            terminal,
+            histogram,
+            strike_bin: contract.strike_price.and_then(|s| bin_of(s, range)),
+        }
+    };
+
+    Comparison {
+        left: profile(a, left_paths),
+        right: profile(b, right_paths),
+        range,
+    }
+}
@@ -0,0 +1,766 @@
+//! User configuration, read from `config.toml` in the data directory.
+//!
+//! Every section and field has a default, so a missing file or a partial one
+//! behaves exactly like the built-in settings.
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::api::VolatilityData;
+use crate::audit;
+use crate::market_hours::{self, Closure, Downtime, MaintenanceWindow};
+use crate::sizing::{self, SizePreset};
+use crate::store;
+
+const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Spectator mode: every order-placing path is disabled (also `--read-only`)
+    pub read_only: bool,
+    pub orders: OrderGuardConfig,
+    pub scripting: ScriptingConfig,
+    pub auto: AutoConfig,
+    pub governor: GovernorConfig,
+    pub sizing: SizingConfig,
+    pub planner: PlannerConfig,
+    pub polling: PollingConfig,
+    pub exposure: ExposureConfig,
+    pub reentry: ReentryConfig,
+    pub tilt: TiltConfig,
+    pub liquidity: LiquidityConfig,
+    pub market_hours: MarketHoursConfig,
+    pub assets: AssetsConfig,
+    pub score: ScoreConfig,
+    pub checklist: ChecklistConfig,
+    pub confirm: ConfirmConfig,
+    pub extreme: ExtremeConfig,
+    pub table: TableConfig,
+    pub keymap: KeymapConfig,
+    pub layout: LayoutConfig,
+    pub tick_cache: TickCacheConfig,
+    pub archive: ArchiveConfig,
+    pub hooks: HooksConfig,
+}
+
+/// What to do when an order trips a latency/expiry guard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GuardMode {
+    #[default]
+    Warn,
+    Block,
+}
+
+/// Pre-submission checks for order placement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrderGuardConfig {
+    /// Round-trip time to the trade endpoint above which orders are flagged
+    pub max_latency_ms: u64,
+    /// Orders on contracts closer than this to expiry are flagged
+    pub min_seconds_to_expiry: u64,
+    pub mode: GuardMode,
+}
+
+impl Default for OrderGuardConfig {
+    fn default() -> Self {
+        Self {
+            max_latency_ms: 1500,
+            min_seconds_to_expiry: 90,
+            mode: GuardMode::Warn,
+        }
+    }
+}
+
+/// User strategy script (see `scripting.rs`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScriptingConfig {
+    /// Rhai script path; relative paths are resolved against the data directory
+    pub script: Option<PathBuf>,
+}
+
+/// Entry rule for `basilisk auto`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoConfig {
+    /// Seconds between decision cycles
+    pub interval_secs: u64,
+    /// Minimum expected value to enter (0.05 = 5%)
+    pub min_ev: f64,
+    /// Minimum model confidence to enter
+    pub min_confidence: f64,
+    /// Contracts per order when the script has no size()
+    pub size: i32,
+    /// Stop entering once this many positions are open
+    pub max_open_positions: usize,
+    pub pause: AutoPauseConfig,
+}
+
+impl Default for AutoConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 30,
+            min_ev: 0.05,
+            min_confidence: 0.0,
+            size: 1,
+            max_open_positions: 3,
+            pause: AutoPauseConfig::default(),
+        }
+    }
+}
+
+/// Conditions that suspend `basilisk auto` (`[auto.pause]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoPauseConfig {
+    /// Vol regimes the model's edge isn't trusted in
+    pub regimes: Vec<String>,
+    /// Pause while the vol premium ((IV - RV) / RV) is above this (0.30 = 30%)
+    pub max_vol_premium: Option<f64>,
+    /// ...or below this (e.g. -0.20, RV running well ahead of IV)
+    pub min_vol_premium: Option<f64>,
+    /// Raise a critical dashboard alert when the auto-trader pauses
+    pub alert: bool,
+    /// Resume by itself once conditions clear; otherwise wait for `--resume`
+    pub auto_resume: bool,
+}
+
+impl Default for AutoPauseConfig {
+    fn default() -> Self {
+        Self {
+            regimes: vec!["ELEVATED".to_string(), "CRISIS".to_string()],
+            max_vol_premium: None,
+            min_vol_premium: None,
+            alert: true,
+            auto_resume: true,
+        }
+    }
+}
+
+impl AutoPauseConfig {
+    /// Why these vol conditions should suspend trading, if they should
+    pub fn reason(&self, volatility: &VolatilityData) -> Option<String> {
+        if self
+            .regimes
+            .iter()
+            .any(|regime| regime.eq_ignore_ascii_case(&volatility.regime))
+        {
+            return Some(format!("vol regime {}", volatility.regime));
+        }
+        let premium = volatility.vol_premium_pct;
+        if let Some(max) = self.max_vol_premium.filter(|max| premium > *max) {
+            return Some(format!(
+                "vol premium {:+.1}% above {:+.1}%",
+                premium * 100.0,
+                max * 100.0
+            ));
+        }
+        if let Some(min) = self.min_vol_premium.filter(|min| premium < *min) {
+            return Some(format!(
+                "vol premium {:+.1}% below {:+.1}%",
+                premium * 100.0,
+                min * 100.0
+            ));
+        }
+        None
+    }
+}
+
+/// Limits enforced on every automated order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GovernorConfig {
+    pub max_orders_per_minute: usize,
+    pub max_in_flight: usize,
+    /// Rejections in a row that trip the kill switch
+    pub max_consecutive_rejections: u32,
+}
+
+impl Default for GovernorConfig {
+    fn default() -> Self {
+        Self {
+            max_orders_per_minute: 6,
+            max_in_flight: 2,
+            max_consecutive_rejections: 3,
+        }
+    }
+}
+
+/// Per-expiry risk cap (see `exposure.rs`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExposureConfig {
+    /// Most notional (premium paid) allowed to settle at any one expiry; unset = no cap
+    pub max_per_expiry: Option<f64>,
+}
+
+/// Cooldown after a stop-loss exit (see `reentry.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReentryConfig {
+    /// Minutes before a stopped-out ticker can be re-entered; 0 disables the guard
+    pub cooldown_minutes: u64,
+}
+
+impl Default for ReentryConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_minutes: 15,
+        }
+    }
+}
+
+/// Losing-streak warnings and cooling-off (see `tilt.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TiltConfig {
+    /// Losses in a row before entries are flagged; 0 disables the guard
+    pub warn_after: u32,
+    /// Losses in a row that start a cooling-off period; 0 only warns
+    pub cooloff_after: u32,
+    /// How long entries stay blocked after the last loss
+    pub cooloff_minutes: u64,
+    /// Trades closed this far back count toward the session's streak
+    pub session_hours: u64,
+}
+
+impl Default for TiltConfig {
+    fn default() -> Self {
+        Self {
+            warn_after: 2,
+            cooloff_after: 0,
+            cooloff_minutes: 30,
+            session_hours: 8,
+        }
+    }
+}
+
+/// Orderbook sampling for market impact (see `liquidity.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LiquidityConfig {
+    /// Order size the impact is estimated for
+    pub default_size: i32,
+    /// Price move, as a percentage of the best offer, above which a strike is flagged
+    pub max_impact_pct: f64,
+    /// Seconds between samples; 0 turns sampling off
+    pub sample_secs: u64,
+    /// Recent volume for the liquidity score is what traded over this window
+    pub volume_window_minutes: u64,
+    /// Contracts traded in the window that earn the volume part full marks
+    pub full_volume: i64,
+    /// Open interest that earns the open-interest part full marks
+    pub full_open_interest: i64,
+    /// The auto-trader skips strikes scoring below this (0 = off)
+    pub auto_min_score: f64,
+}
+
+impl Default for LiquidityConfig {
+    fn default() -> Self {
+        Self {
+            default_size: 10,
+            max_impact_pct: 5.0,
+            sample_secs: 60,
+            volume_window_minutes: 15,
+            full_volume: 500,
+            full_open_interest: 2000,
+            auto_min_score: 0.0,
+        }
+    }
+}
+
+/// Composite contract score (see `score.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreConfig {
+    pub weights: ScoreWeights,
+    /// Expected value that earns the EV part full marks (0.10 = 10%)
+    pub full_ev: f64,
+    /// YES + NO overround at which the liquidity part reaches zero
+    pub max_spread: f64,
+    /// Minutes left at which the time part earns full marks
+    pub full_time_minutes: f64,
+    /// Order the dashboard's signals table by score instead of backend order
+    pub sort_signals: bool,
+}
+
+impl Default for ScoreConfig {
+    fn default() -> Self {
+        Self {
+            weights: ScoreWeights::default(),
+            full_ev: 0.10,
+            max_spread: 0.10,
+            full_time_minutes: 30.0,
+            sort_signals: false,
+        }
+    }
+}
+
+/// Relative weight of each score part; they needn't sum to 1
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreWeights {
+    pub ev: f64,
+    pub confidence: f64,
+    pub liquidity: f64,
+    pub time: f64,
+    pub regime: f64,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            ev: 0.35,
+            confidence: 0.25,
+            liquidity: 0.15,
+            time: 0.15,
+            regime: 0.10,
+        }
+    }
+}
+
+/// Pre-trade checklist in the dashboard trade dialog (see `checklist.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChecklistConfig {
+    pub enabled: bool,
+    /// Only orders of at least this many contracts get the checklist
+    pub min_contracts: i32,
+    /// Built-in checks ("spread", "risk", "settlement", "reason") or questions of your own
+    pub items: Vec<String>,
+    /// Refuse to submit while any question is answered "no"
+    pub require_yes: bool,
+}
+
+impl Default for ChecklistConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_contracts: 10,
+            items: ["spread", "risk", "settlement", "reason"]
+                .iter()
+                .map(|item| item.to_string())
+                .collect(),
+            require_yes: false,
+        }
+    }
+}
+
+/// Signals tab's extreme mode (`e`): long-shot contracts that need a big move
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExtremeConfig {
+    /// Only contracts the market prices below this probability (0.25 = 25%)
+    pub max_implied: f64,
+    /// ...whose strike is more than this far from spot, in percent
+    pub min_move_pct: f64,
+    /// Open the dashboard with extreme mode already on
+    pub start_enabled: bool,
+}
+
+impl Default for ExtremeConfig {
+    fn default() -> Self {
+        Self {
+            max_implied: 0.25,
+            min_move_pct: 3.0,
+            start_enabled: false,
+        }
+    }
+}
+
+/// Optional columns of the dashboard's signals table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableConfig {
+    /// Show YES/NO ask prices and their spread after Dist
+    pub quote_columns: bool,
+    /// Spread (YES + NO asks over $1) at or under which quotes show green
+    pub tight_spread: f64,
+    /// ...and at or over which they show red (yellow in between)
+    pub wide_spread: f64,
+    /// Start with the EV column net of taker fees (`f` toggles)
+    pub net_ev: bool,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            quote_columns: false,
+            tight_spread: 0.02,
+            wide_spread: 0.05,
+            net_ev: false,
+        }
+    }
+}
+
+/// Dashboard key bindings on top of the defaults (see `events.rs`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeymapConfig {
+    /// `h`/`l` switch views instead of `h` opening help (`?` still does)
+    pub vim: bool,
+    /// Extra bindings, key to action name: `"ctrl-r" = "refresh"`, `"gt" = "next_view"`
+    pub bindings: BTreeMap<String, String>,
+}
+
+/// How the dashboard arranges its panes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum LayoutMode {
+    /// Pick from the terminal size
+    #[default]
+    Auto,
+    /// Signals table only, abbreviated columns, one-line bars
+    Compact,
+    /// One tab at a time
+    Standard,
+    /// Signals and positions side by side
+    Wide,
+}
+
+/// Dashboard color palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    /// For dark terminal backgrounds
+    #[default]
+    Dark,
+    /// For light terminal backgrounds
+    Light,
+    /// Bright colors only
+    HighContrast,
+}
+
+impl ThemeName {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::HighContrast => "high-contrast",
+        }
+    }
+}
+
+/// Dashboard panes at startup; `b`, `B` and `z` toggle them while running
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutConfig {
+    /// Volatility regime banner under the status bar (also `--no-banner`)
+    pub show_vol_banner: bool,
+    /// One-line status bar and footer instead of bordered boxes
+    pub slim_bars: bool,
+    /// Fixed layout instead of one picked by terminal size (also `--layout`)
+    pub mode: LayoutMode,
+    /// Below this many columns the compact layout kicks in on its own
+    pub auto_compact_width: u16,
+    /// ...or below this many rows
+    pub auto_compact_height: u16,
+    /// From this many columns the Signals tab shows positions alongside
+    pub wide_min_width: u16,
+    /// Color palette (also `--theme`; `u` cycles it while running)
+    pub theme: ThemeName,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            show_vol_banner: true,
+            slim_bars: false,
+            mode: LayoutMode::Auto,
+            auto_compact_width: 120,
+            auto_compact_height: 30,
+            wide_min_width: 160,
+            theme: ThemeName::Dark,
+        }
+    }
+}
+
+/// On-disk cache of stream ticks (see `tick_cache.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TickCacheConfig {
+    pub enabled: bool,
+    /// Ticks older than this are pruned
+    pub max_age_hours: u64,
+    /// Size cap for `ticks.jsonl`; the oldest ticks go first
+    pub max_mb: u64,
+}
+
+impl Default for TickCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_age_hours: 24,
+            max_mb: 20,
+        }
+    }
+}
+
+/// Confirmation prompts on the dashboard (closing a position always asks)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfirmConfig {
+    /// Ask once more before the trade ticket sends an order
+    pub trades: bool,
+    /// Orders of at least this many contracts need the size typed in (0 = never)
+    pub type_size_at: i32,
+    /// Ask before quitting while orders placed this session are still resting
+    pub quit_with_open_orders: bool,
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            trades: true,
+            type_size_at: 25,
+            quit_with_open_orders: true,
+        }
+    }
+}
+
+/// Contract snapshots kept for the archive browser (see `archive.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArchiveConfig {
+    pub enabled: bool,
+    /// Seconds between snapshots of the contract list; 0 archives every update
+    pub snapshot_secs: u64,
+    /// Snapshots older than this are pruned on startup
+    pub max_age_days: u64,
+}
+
+impl Default for ArchiveConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            snapshot_secs: 300,
+            max_age_days: 30,
+        }
+    }
+}
+
+/// Shell commands run on dashboard events (see `hooks.rs`); unset = nothing runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// An order or close from the dashboard filled at least one contract
+    pub trade_filled: Option<String>,
+    /// A price rule's trigger was hit and its order sent
+    pub stop_triggered: Option<String>,
+    /// The volatility regime changed
+    pub regime_change: Option<String>,
+    /// The live stream has been down for `stream_down_secs`
+    pub stream_down: Option<String>,
+    pub stream_down_secs: u64,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            trade_filled: None,
+            stop_triggered: None,
+            regime_change: None,
+            stream_down: None,
+            stream_down_secs: 60,
+        }
+    }
+}
+
+/// Assets followed by the dashboard. The signals table is always BTC; every
+/// enabled asset gets its own volatility banner tab.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssetsConfig {
+    pub enabled: Vec<String>,
+}
+
+impl AssetsConfig {
+    /// Enabled assets, upper-cased and deduplicated, BTC first
+    pub fn symbols(&self) -> Vec<String> {
+        let mut symbols = vec!["BTC".to_string()];
+        for asset in &self.enabled {
+            let asset = asset.to_uppercase();
+            if !symbols.contains(&asset) {
+                symbols.push(asset);
+            }
+        }
+        symbols
+    }
+}
+
+impl Default for AssetsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: vec!["BTC".to_string()],
+        }
+    }
+}
+
+/// Known downtime (see `market_hours.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarketHoursConfig {
+    /// Weekly windows; defaults to Kalshi's Thursday maintenance, `[]` disables
+    pub maintenance: Vec<MaintenanceWindow>,
+    /// One-off closures with explicit start and end times
+    pub closures: Vec<Closure>,
+}
+
+impl MarketHoursConfig {
+    /// Downtime in effect right now
+    pub fn downtime(&self) -> Option<Downtime> {
+        market_hours::downtime_at(&self.maintenance, &self.closures, chrono::Utc::now())
+    }
+}
+
+impl Default for MarketHoursConfig {
+    fn default() -> Self {
+        Self {
+            maintenance: market_hours::default_windows(),
+            closures: Vec::new(),
+        }
+    }
+}
+
+/// Quick-size options for the dashboard trade dialog, bound to keys 1-9 in order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SizingConfig {
+    pub presets: Vec<SizePreset>,
+}
+
+impl Default for SizingConfig {
+    fn default() -> Self {
+        Self {
+            presets: sizing::default_presets(),
+        }
+    }
+}
+
+/// Allocation planner ('p' on the Signals tab, see `planner.rs`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlannerConfig {
+    /// Dollars to spread across one expiry hour, including what's already open there
+    pub hourly_budget: f64,
+    /// Most dollars any one contract gets
+    pub max_per_contract: f64,
+    /// Signals below this EV are left out
+    pub min_ev: f64,
+    /// At most this many legs, best EV × confidence first
+    pub max_legs: usize,
+}
+
+impl Default for PlannerConfig {
+    fn default() -> Self {
+        Self {
+            hourly_budget: 100.0,
+            max_per_contract: 40.0,
+            min_ev: 0.02,
+            max_legs: 5,
+        }
+    }
+}
+
+/// Dashboard data that can be refreshed over HTTP
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PollTarget {
+    /// Current signals and volatility (also drives the connection indicator)
+    Signals,
+    HourlyStats,
+    VolSkew,
+}
+
+/// HTTP polling that backs up (and cross-checks) the SSE stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PollingConfig {
+    /// Base cadence while the stream is down (`--refresh` overrides it)
+    pub interval_secs: u64,
+    /// Keep polling while the stream is healthy, to cross-check it
+    pub while_connected: bool,
+    /// Cadence of those cross-check polls
+    pub check_interval_secs: u64,
+    /// What each poll fetches
+    pub fetch: Vec<PollTarget>,
+    /// Upper bound for the interval while the backend keeps erroring
+    pub max_backoff_secs: u64,
+    /// Poll this often once the front expiry is inside `fast_window_secs`
+    pub fast_interval_secs: u64,
+    /// How close to expiry the fast cadence starts (0 disables it)
+    pub fast_window_secs: u64,
+}
+
+impl PollingConfig {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs.max(1))
+    }
+
+    pub fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.check_interval_secs.max(1))
+    }
+
+    pub fn fast_interval(&self) -> Duration {
+        Duration::from_secs(self.fast_interval_secs.max(1))
+    }
+
+    /// Whether `seconds_left` on the front expiry falls inside the fast window
+    pub fn is_near_expiry(&self, seconds_left: Option<i64>) -> bool {
+        seconds_left.is_some_and(|secs| secs > 0 && secs <= self.fast_window_secs as i64)
+    }
+
+    /// Double `interval` per consecutive failure, capped at `max_backoff_secs`
+    pub fn backoff(&self, interval: Duration, failures: u32) -> Duration {
+        let cap = Duration::from_secs(self.max_backoff_secs).max(interval);
+        interval
+            .saturating_mul(2u32.saturating_pow(failures.min(16)))
+            .min(cap)
+    }
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 30,
+            while_connected: true,
+            check_interval_secs: 60,
+            fetch: vec![PollTarget::Signals],
+            max_backoff_secs: 300,
+            fast_interval_secs: 1,
+            fast_window_secs: 300,
+        }
+    }
+}
+
+impl Config {
+    /// Load the config file, falling back to defaults when it doesn't exist
+    pub fn load() -> Result<Self> {
+        let path = store::path(CONFIG_FILE)?;
+        let config: Self = match std::fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("Invalid config file {}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        // Best effort: an unwritable audit log shouldn't stop Basilisk from starting
+        let _ = audit::record_config(&config);
+        Ok(config)
+    }
+
+    /// Refuse to place, close or amend orders in read-only mode
+    pub fn ensure_can_trade(&self) -> Result<()> {
+        if self.read_only {
+            anyhow::bail!("Read-only mode: orders are disabled (read_only = true in config.toml or --read-only)");
+        }
+        Ok(())
+    }
+}
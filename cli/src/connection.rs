@@ -0,0 +1,215 @@
+use std::time::{Duration, Instant};
+
+/// Failed SSE reconnects (with HTTP also failing) before we call it offline
+const OFFLINE_AFTER_ATTEMPTS: u32 = 3;
+
+/// User-visible data connection phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// SSE stream is up
+    Connected,
+    /// SSE is down but HTTP polling still returns data
+    DegradedPolling,
+    /// SSE is down and we're retrying (attempt 0 = initial connect)
+    Reconnecting { attempt: u32 },
+    /// Neither SSE nor HTTP has worked for a while
+    Offline { since: Instant },
+}
+
+impl ConnectionState {
+    /// How often to poll over HTTP in this state (None = SSE is enough)
+    pub fn fallback_interval(&self, base: Duration) -> Option<Duration> {
+        match self {
+            ConnectionState::Connected => None,
+            // Polling is the only data source, keep it at the normal cadence
+            ConnectionState::DegradedPolling => Some(base),
+            // Probe quickly so we notice the backend coming back
+            ConnectionState::Reconnecting { .. } => Some(base.min(Duration::from_secs(5))),
+            // Back off to avoid hammering a dead backend
+            ConnectionState::Offline { .. } => Some(base * 2),
+        }
+    }
+}
+
+/// Combines SSE and HTTP health into a single connection state
+#[derive(Debug)]
+pub struct ConnectionMonitor {
+    sse_connected: bool,
+    sse_attempt: u32,
+    http_healthy: Option<bool>,
+    offline_since: Option<Instant>,
+}
+
+impl ConnectionMonitor {
+    pub fn new() -> Self {
+        Self {
+            sse_connected: false,
+            sse_attempt: 0,
+            http_healthy: None,
+            offline_since: None,
+        }
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        if self.sse_connected {
+            ConnectionState::Connected
+        } else if self.http_healthy == Some(true) {
+            ConnectionState::DegradedPolling
+        } else if let Some(since) = self.offline_since {
+            ConnectionState::Offline { since }
+        } else {
+            ConnectionState::Reconnecting {
+                attempt: self.sse_attempt,
+            }
+        }
+    }
+
+    pub fn sse_connected(&mut self) {
+        self.sse_connected = true;
+        self.sse_attempt = 0;
+        self.offline_since = None;
+    }
+
+    pub fn sse_lost(&mut self) {
+        self.sse_connected = false;
+        self.update_offline();
+    }
+
+    pub fn sse_reconnecting(&mut self) {
+        self.sse_attempt += 1;
+        self.update_offline();
+    }
+
+    pub fn http_succeeded(&mut self) {
+        self.http_healthy = Some(true);
+        self.offline_since = None;
+    }
+
+    pub fn http_failed(&mut self) {
+        self.http_healthy = Some(false);
+        self.update_offline();
+    }
+
+    fn update_offline(&mut self) {
+        let all_down = !self.sse_connected
+            && self.http_healthy != Some(true)
+            && self.sse_attempt >= OFFLINE_AFTER_ATTEMPTS;
+
+        if all_down {
+            self.offline_since.get_or_insert_with(Instant::now);
+        } else {
+            self.offline_since = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offline_monitor() -> ConnectionMonitor {
+        let mut monitor = ConnectionMonitor::new();
+        monitor.http_failed();
+        for _ in 0..OFFLINE_AFTER_ATTEMPTS {
+            monitor.sse_reconnecting();
+        }
+        monitor
+    }
+
+    #[test]
+    fn starts_reconnecting() {
+        assert_eq!(
+            ConnectionMonitor::new().state(),
+            ConnectionState::Reconnecting { attempt: 0 }
+        );
+    }
+
+    #[test]
+    fn sse_wins_over_http() {
+        let mut monitor = ConnectionMonitor::new();
+        monitor.http_failed();
+        monitor.sse_connected();
+        assert_eq!(monitor.state(), ConnectionState::Connected);
+    }
+
+    #[test]
+    fn polling_keeps_data_flowing_without_sse() {
+        let mut monitor = ConnectionMonitor::new();
+        monitor.sse_connected();
+        monitor.sse_lost();
+        monitor.sse_reconnecting();
+        assert_eq!(
+            monitor.state(),
+            ConnectionState::Reconnecting { attempt: 1 }
+        );
+
+        monitor.http_succeeded();
+        for _ in 0..OFFLINE_AFTER_ATTEMPTS {
+            monitor.sse_reconnecting();
+        }
+        assert_eq!(monitor.state(), ConnectionState::DegradedPolling);
+    }
+
+    #[test]
+    fn offline_once_both_fail_long_enough() {
+        let mut monitor = ConnectionMonitor::new();
+        monitor.http_failed();
+        for attempt in 1..OFFLINE_AFTER_ATTEMPTS {
+            monitor.sse_reconnecting();
+            assert_eq!(monitor.state(), ConnectionState::Reconnecting { attempt });
+        }
+        monitor.sse_reconnecting();
+        let ConnectionState::Offline { since } = monitor.state() else {
+            panic!("expected offline, got {:?}", monitor.state());
+        };
+
+        // Further failures don't restart the outage clock
+        monitor.sse_reconnecting();
+        monitor.http_failed();
+        assert_eq!(monitor.state(), ConnectionState::Offline { since });
+    }
+
+    #[test]
+    fn recovers_from_offline() {
+        let mut monitor = offline_monitor();
+        monitor.http_succeeded();
+        assert_eq!(monitor.state(), ConnectionState::DegradedPolling);
+
+        let mut monitor = offline_monitor();
+        monitor.sse_connected();
+        assert_eq!(monitor.state(), ConnectionState::Connected);
+        // A later drop counts attempts from zero again
+        monitor.sse_lost();
+        monitor.http_failed();
+        monitor.sse_reconnecting();
+        assert_eq!(
+            monitor.state(),
+            ConnectionState::Reconnecting { attempt: 1 }
+        );
+    }
+
+    #[test]
+    fn fallback_polling_follows_the_state() {
+        let base = Duration::from_secs(30);
+        assert_eq!(ConnectionState::Connected.fallback_interval(base), None);
+        assert_eq!(
+            ConnectionState::DegradedPolling.fallback_interval(base),
+            Some(base)
+        );
+        assert_eq!(
+            ConnectionState::Reconnecting { attempt: 2 }.fallback_interval(base),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            ConnectionState::Reconnecting { attempt: 2 }.fallback_interval(Duration::from_secs(2)),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            ConnectionState::Offline {
+                since: Instant::now()
+            }
+            .fallback_interval(base),
+            Some(Duration::from_secs(60))
+        );
+    }
+}
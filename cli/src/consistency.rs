@@ -0,0 +1,172 @@
+//! Cross-checks the SSE stream against HTTP polls of the same contracts.
+//!
+//! Each source's latest snapshot is kept; when a new one arrives it is
+//! compared with the other source's, material differences are logged to
+//! `discrepancies.jsonl`, and only the fresher snapshot is applied.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::api::Contract;
+use crate::store;
+
+const DISCREPANCIES_FILE: &str = "discrepancies.jsonl";
+
+/// Implied probability gap that counts as a material difference (5 points)
+const PROBABILITY_TOLERANCE: f64 = 0.05;
+
+/// Relative BTC price gap that counts as a material difference (0.25%)
+const PRICE_TOLERANCE: f64 = 0.0025;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Stream,
+    Poll,
+}
+
+impl Source {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Source::Stream => "stream",
+            Source::Poll => "poll",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    as_of: DateTime<Utc>,
+    btc_price: Option<f64>,
+    implied: HashMap<String, Option<f64>>, // ticker -> implied probability
+}
+
+impl Snapshot {
+    fn from_contracts(as_of: DateTime<Utc>, contracts: &[Contract]) -> Self {
+        Self {
+            as_of,
+            btc_price: contracts.first().and_then(|c| c.current_btc_price),
+            implied: contracts
+                .iter()
+                .map(|c| (c.ticker.clone(), c.implied_probability))
+                .collect(),
+        }
+    }
+}
+
+/// A material difference between the stream and poll snapshots
+#[derive(Debug, Clone, Serialize)]
+pub struct Discrepancy {
+    pub detected_at: DateTime<Utc>,
+    /// Source with the older snapshot (the one most likely to be wrong)
+    pub stale_source: Source,
+    pub lag_secs: i64,
+    pub stream_as_of: DateTime<Utc>,
+    pub poll_as_of: DateTime<Utc>,
+    pub issues: Vec<String>,
+}
+
+/// Keeps the latest snapshot from each source and compares them
+#[derive(Debug, Default)]
+pub struct ConsistencyChecker {
+    stream: Option<Snapshot>,
+    poll: Option<Snapshot>,
+    divergence: Option<Discrepancy>,
+}
+
+impl ConsistencyChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a snapshot and compare it with the other source.
+    ///
+    /// Returns whether the snapshot is at least as fresh as the other source's,
+    /// i.e. whether the caller should display it.
+    pub fn observe(
+        &mut self,
+        source: Source,
+        as_of: DateTime<Utc>,
+        contracts: &[Contract],
+    ) -> Result<bool> {
+        let snapshot = Snapshot::from_contracts(as_of, contracts);
+        let other = match source {
+            Source::Stream => self.poll.as_ref(),
+            Source::Poll => self.stream.as_ref(),
+        };
+        let fresher = other.is_none_or(|other| as_of >= other.as_of);
+
+        if let Some(other) = other {
+            let (stream, poll) = match source {
+                Source::Stream => (&snapshot, other),
+                Source::Poll => (other, &snapshot),
+            };
+            let issues = compare(stream, poll);
+
+            if issues.is_empty() {
+                self.divergence = None;
+            } else {
+                let stale_source = if stream.as_of < poll.as_of {
+                    Source::Stream
+                } else {
+                    Source::Poll
+                };
+                let discrepancy = Discrepancy {
+                    detected_at: Utc::now(),
+                    stale_source,
+                    lag_secs: (stream.as_of - poll.as_of).num_seconds().abs(),
+                    stream_as_of: stream.as_of,
+                    poll_as_of: poll.as_of,
+                    issues,
+                };
+                store::append_jsonl(DISCREPANCIES_FILE, &discrepancy)?;
+                self.divergence = Some(discrepancy);
+            }
+        }
+
+        match source {
+            Source::Stream => self.stream = Some(snapshot),
+            Source::Poll => self.poll = Some(snapshot),
+        }
+        Ok(fresher)
+    }
+
+    /// Most recent comparison result, if the sources disagreed
+    pub fn divergence(&self) -> Option<&Discrepancy> {
+        self.divergence.as_ref()
+    }
+}
+
+fn compare(stream: &Snapshot, poll: &Snapshot) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    if let (Some(a), Some(b)) = (stream.btc_price, poll.btc_price) {
+        if b > 0.0 && ((a - b) / b).abs() > PRICE_TOLERANCE {
+            issues.push(format!("BTC price stream ${:.0} vs poll ${:.0}", a, b));
+        }
+    }
+
+    let mut tickers: Vec<&String> = stream.implied.keys().chain(poll.implied.keys()).collect();
+    tickers.sort();
+    tickers.dedup();
+
+    for ticker in tickers {
+        match (stream.implied.get(ticker), poll.implied.get(ticker)) {
+            (Some(_), None) => issues.push(format!("{} only in stream", ticker)),
+            (None, Some(_)) => issues.push(format!("{} only in poll", ticker)),
+            (Some(Some(a)), Some(Some(b))) if (a - b).abs() > PROBABILITY_TOLERANCE => {
+                issues.push(format!(
+                    "{} implied stream {:.0}% vs poll {:.0}%",
+                    ticker,
+                    a * 100.0,
+                    b * 100.0
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    issues
+}
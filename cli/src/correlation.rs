@@ -0,0 +1,142 @@
+//! Signal hit rate against concurrent BTC momentum, from the settlements the
+//! dashboard records at each rollover (`basilisk correlation`).
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+
+use crate::momentum::TREND_WINDOW;
+use crate::rollover::{self, Settlement};
+
+/// BTC moves (percent over the trend window) beyond these count as up/down
+const WEAK_MOVE_PCT: f64 = 0.15;
+const STRONG_MOVE_PCT: f64 = 0.5;
+
+const BANDS: [&str; 5] = ["Strong ↓", "Down", "Flat", "Up", "Strong ↑"];
+
+fn band(momentum_pct: f64) -> usize {
+    if momentum_pct <= -STRONG_MOVE_PCT {
+        0
+    } else if momentum_pct <= -WEAK_MOVE_PCT {
+        1
+    } else if momentum_pct < WEAK_MOVE_PCT {
+        2
+    } else if momentum_pct < STRONG_MOVE_PCT {
+        3
+    } else {
+        4
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Cell {
+    hits: usize,
+    total: usize,
+}
+
+impl Cell {
+    fn add(&mut self, correct: bool) {
+        self.total += 1;
+        if correct {
+            self.hits += 1;
+        }
+    }
+
+    fn hit_rate(&self) -> Option<f64> {
+        (self.total > 0).then(|| self.hits as f64 / self.total as f64)
+    }
+
+    /// Heatmap shading: green above a coin flip, red below
+    fn render(&self) -> String {
+        let Some(rate) = self.hit_rate() else {
+            return format!("{:>12}", "--");
+        };
+        let color = if rate >= 0.6 {
+            "\x1b[42;30m"
+        } else if rate >= 0.5 {
+            "\x1b[32m"
+        } else if rate >= 0.4 {
+            "\x1b[33m"
+        } else {
+            "\x1b[41;97m"
+        };
+        format!(
+            "{}{:>12}\x1b[0m",
+            color,
+            format!("{:.0}% ({}) ", rate * 100.0, self.total)
+        )
+    }
+}
+
+pub fn handle_correlation_command() -> Result<()> {
+    let settlements = rollover::settlements().context("Failed to read settlements")?;
+
+    // Only signals with a side and a momentum reading can be placed in the grid
+    let scored: Vec<(&Settlement, bool, f64)> = settlements
+        .iter()
+        .filter_map(|s| Some((s, s.signal_correct?, s.btc_momentum_pct?)))
+        .collect();
+
+    if scored.is_empty() {
+        println!("📭 No settlements with momentum recorded yet.");
+        println!("   Keep the dashboard running across hourly rollovers to collect them.");
+        return Ok(());
+    }
+
+    let mut rows: BTreeMap<&str, [Cell; 5]> = BTreeMap::new();
+    let mut totals = [Cell::default(); 5];
+    for (settlement, correct, momentum) in &scored {
+        let column = band(*momentum);
+        rows.entry(settlement.signal_type.as_str()).or_default()[column].add(*correct);
+        totals[column].add(*correct);
+    }
+
+    println!(
+        "📈 Signal Hit Rate vs BTC Momentum ({} settled signals, {}m trend before expiry)",
+        scored.len(),
+        TREND_WINDOW.as_secs() / 60
+    );
+    println!("{}", "─".repeat(90));
+    print!("{:<12}", "Signal");
+    for label in BANDS {
+        print!(" {:>12}", label);
+    }
+    println!(" {:>12}", "All");
+    println!(
+        "{:<12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "",
+        format!("≤-{:.2}%", STRONG_MOVE_PCT),
+        format!("≤-{:.2}%", WEAK_MOVE_PCT),
+        format!("±{:.2}%", WEAK_MOVE_PCT),
+        format!("≥+{:.2}%", WEAK_MOVE_PCT),
+        format!("≥+{:.2}%", STRONG_MOVE_PCT)
+    );
+    println!("{}", "─".repeat(90));
+
+    let print_row = |label: &str, cells: &[Cell; 5]| {
+        print!("{:<12}", label);
+        let mut all = Cell::default();
+        for cell in cells {
+            print!(" {}", cell.render());
+            all.hits += cell.hits;
+            all.total += cell.total;
+        }
+        println!(" {}", all.render());
+    };
+
+    for (signal, cells) in &rows {
+        print_row(signal, cells);
+    }
+    println!("{}", "─".repeat(90));
+    print_row("All", &totals);
+    println!("{}", "─".repeat(90));
+
+    let skipped = settlements.len() - scored.len();
+    if skipped > 0 {
+        println!(
+            "{} settlements left out (HOLD signals or recorded before momentum tracking).",
+            skipped
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+//! Automatic trading diary: at each hourly rollover the dashboard appends a
+//! snapshot of the settled hour (contracts, my trades on them, P&L, vol) to
+//! `diary-YYYY-MM-DD.jsonl` and a readable `diary-YYYY-MM-DD.md` in the data
+//! directory, dated by the expiry in UTC.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use crate::api::models::TradeHistory;
+use crate::api::{parse_timestamp, VolatilityData};
+use crate::rollover::Settlement;
+use crate::store;
+
+/// Snapshots recorded for `day` (none if the dashboard didn't run)
+pub fn entries(day: NaiveDate) -> Result<Vec<HourSnapshot>> {
+    store::read_jsonl(&format!("diary-{}.jsonl", day.format("%Y-%m-%d")))
+}
+
+/// One of my trades on a contract that just settled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MyOutcome {
+    pub trade_id: i32,
+    pub ticker: String,
+    pub direction: String,
+    pub contracts: i32,
+    pub entry_price: f64,
+    /// None while the backend hasn't booked the settlement yet
+    pub pnl: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolStats {
+    pub realized_vol: f64,
+    pub implied_vol: f64,
+    pub vol_premium: f64,
+    pub regime: String,
+    /// RV over the last hour of streamed ticks
+    pub tick_rv: Option<f64>,
+}
+
+/// Diary entry for one expiry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourSnapshot {
+    pub recorded_at: DateTime<Utc>,
+    pub expiry_time: Option<String>,
+    pub settle_price: f64,
+    pub settled: Vec<Settlement>,
+    pub my_trades: Vec<MyOutcome>,
+    /// Sum of the booked P&L in `my_trades`
+    pub pnl: f64,
+    pub vol: VolStats,
+}
+
+impl HourSnapshot {
+    pub fn new(
+        settled: Vec<Settlement>,
+        history: &[TradeHistory],
+        volatility: &VolatilityData,
+        tick_rv: Option<f64>,
+    ) -> Option<Self> {
+        let first = settled.first()?;
+        let my_trades: Vec<MyOutcome> = history
+            .iter()
+            .filter(|trade| settled.iter().any(|s| s.ticker == trade.ticker))
+            .map(|trade| MyOutcome {
+                trade_id: trade.id,
+                ticker: trade.ticker.clone(),
+                direction: trade.direction.clone(),
+                contracts: trade.contracts,
+                entry_price: trade.entry_price,
+                pnl: trade.pnl,
+            })
+            .collect();
+
+        Some(Self {
+            recorded_at: Utc::now(),
+            expiry_time: first.expiry_time.clone(),
+            settle_price: first.settle_price,
+            pnl: my_trades.iter().filter_map(|t| t.pnl).sum(),
+            settled,
+            my_trades,
+            vol: VolStats {
+                realized_vol: volatility.realized_vol,
+                implied_vol: volatility.implied_vol,
+                vol_premium: volatility.vol_premium,
+                regime: volatility.regime.clone(),
+                tick_rv,
+            },
+        })
+    }
+
+    /// Date of the expiry (recording time if it doesn't parse)
+    fn day(&self) -> String {
+        self.expiry_time
+            .as_deref()
+            .and_then(parse_timestamp)
+            .unwrap_or(self.recorded_at)
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+
+    fn markdown(&self) -> String {
+        let hour = self
+            .expiry_time
+            .as_deref()
+            .and_then(parse_timestamp)
+            .map(|expiry| expiry.format("%H:%M UTC").to_string())
+            .unwrap_or_else(|| "Unknown expiry".to_string());
+
+        let mut text = format!("\n## {} (settled at ${:.0})\n\n", hour, self.settle_price);
+        let correct = self
+            .settled
+            .iter()
+            .filter(|s| s.signal_correct == Some(true))
+            .count();
+        let called = self
+            .settled
+            .iter()
+            .filter(|s| s.signal_correct.is_some())
+            .count();
+        text.push_str(&format!(
+            "- Contracts settled: {} ({}/{} signals right)\n",
+            self.settled.len(),
+            correct,
+            called
+        ));
+        text.push_str(&format!(
+            "- Vol: RV {:.1}% │ IV {:.1}% │ premium {:+.1}pp │ {}",
+            self.vol.realized_vol * 100.0,
+            self.vol.implied_vol * 100.0,
+            self.vol.vol_premium * 100.0,
+            self.vol.regime
+        ));
+        if let Some(rv) = self.vol.tick_rv {
+            text.push_str(&format!(" │ 1h tick RV {:.1}%", rv * 100.0));
+        }
+        text.push('\n');
+
+        if self.my_trades.is_empty() {
+            text.push_str("- My trades: none\n");
+            return text;
+        }
+        text.push_str(&format!("- My P&L: ${:+.2}\n\n", self.pnl));
+        text.push_str("| Trade | Ticker | Side | Qty | Entry | P&L |\n");
+        text.push_str("|-------|--------|------|-----|-------|-----|\n");
+        for trade in &self.my_trades {
+            text.push_str(&format!(
+                "| #{} | {} | {} | {} | ${:.2} | {} |\n",
+                trade.trade_id,
+                trade.ticker,
+                trade.direction,
+                trade.contracts,
+                trade.entry_price,
+                trade
+                    .pnl
+                    .map(|pnl| format!("${:+.2}", pnl))
+                    .unwrap_or_else(|| "pending".to_string())
+            ));
+        }
+        text
+    }
+
+    /// Append to the day's JSON and markdown reports
+    pub fn append(&self) -> Result<()> {
+        let day = self.day();
+        store::append_jsonl(&format!("diary-{}.jsonl", day), self)?;
+
+        let name = format!("diary-{}.md", day);
+        let path = store::path(&name)?;
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let mut text = String::new();
+        if is_new {
+            text.push_str(&format!("# Trading diary {}\n", day));
+        }
+        text.push_str(&self.markdown());
+        file.write_all(text.as_bytes())
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+}
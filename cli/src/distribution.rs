@@ -0,0 +1,103 @@
+//! Model vs market distribution of BTC at the front expiry (Distribution tab).
+//!
+//! Every listed strike prices the chance BTC settles above it twice: by the
+//! backend's model (`model_probability`) and by the market
+//! (`implied_probability`). Differencing adjacent strikes turns each strip
+//! into a distribution over the ranges between strikes. Ranges the model
+//! finds likelier than the market are where its BUY signals' EV comes from.
+
+use basilisk_quant::distribution::bucket_masses;
+use chrono::{DateTime, Utc};
+
+use crate::api::{parse_timestamp, Contract};
+
+/// BTC settling between two strikes (open-ended below the first and above the last)
+#[derive(Debug, Clone)]
+pub struct Bucket {
+    pub low: Option<f64>,
+    pub high: Option<f64>,
+    /// Probability mass by the model and by the market
+    pub model: f64,
+    pub market: f64,
+}
+
+impl Bucket {
+    pub fn label(&self) -> String {
+        match (self.low, self.high) {
+            (None, Some(high)) => format!("< ${:.0}", high),
+            (Some(low), None) => format!("> ${:.0}", low),
+            (Some(low), Some(high)) => format!("${:.0}–{:.0}", low, high),
+            (None, None) => "any".to_string(),
+        }
+    }
+
+    /// Model minus market: positive where the model sees more mass
+    pub fn edge(&self) -> f64 {
+        self.model - self.market
+    }
+
+    pub fn contains(&self, price: f64) -> bool {
+        self.low.is_none_or(|low| price > low) && self.high.is_none_or(|high| price <= high)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Distribution {
+    pub expiry: DateTime<Utc>,
+    /// Lowest range first
+    pub buckets: Vec<Bucket>,
+}
+
+impl Distribution {
+    /// Share of probability mass the two distributions put in different places
+    /// (total variation distance: 0 = identical, 1 = no overlap)
+    pub fn disagreement(&self) -> f64 {
+        self.buckets.iter().map(|b| b.edge().abs()).sum::<f64>() / 2.0
+    }
+}
+
+/// Distributions for the soonest expiry with strikes priced by both model and
+/// market (None until there is one)
+pub fn front_expiry(contracts: &[Contract]) -> Option<Distribution> {
+    let now = Utc::now();
+    let priced: Vec<(DateTime<Utc>, f64, f64, f64)> = contracts
+        .iter()
+        .filter(|c| c.is_active)
+        .filter_map(|c| {
+            Some((
+                c.expiry_time.as_deref().and_then(parse_timestamp)?,
+                c.strike_price?,
+                c.model_probability?,
+                c.implied_probability?,
+            ))
+        })
+        .filter(|(expiry, ..)| *expiry > now)
+        .collect();
+    let expiry = priced.iter().map(|(expiry, ..)| *expiry).min()?;
+
+    let mut strip: Vec<(f64, f64, f64)> = priced
+        .into_iter()
+        .filter(|(e, ..)| *e == expiry)
+        .map(|(_, strike, model, market)| (strike, model, market))
+        .collect();
+    strip.sort_by(|a, b| a.0.total_cmp(&b.0));
+    strip.dedup_by(|a, b| a.0 == b.0);
+
+    let model: Vec<f64> = strip.iter().map(|(_, model, _)| *model).collect();
+    let market: Vec<f64> = strip.iter().map(|(_, _, market)| *market).collect();
+    let strikes: Vec<f64> = strip.iter().map(|(strike, ..)| *strike).collect();
+
+    let buckets = bucket_masses(&model)
+        .into_iter()
+        .zip(bucket_masses(&market))
+        .enumerate()
+        .map(|(i, (model, market))| Bucket {
+            low: i.checked_sub(1).map(|below| strikes[below]),
+            high: strikes.get(i).copied(),
+            model,
+            market,
+        })
+        .collect();
+
+    Some(Distribution { expiry, buckets })
+}
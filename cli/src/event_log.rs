@@ -0,0 +1,90 @@
+//! In-app scrollback of what the dashboard did (`l` on the dashboard).
+//!
+//! Stream connects, drops and reconnect attempts, contracts updates, fetch and
+//! other errors, and every order or close the dashboard sends are kept with a
+//! timestamp in a ring buffer, so a wrong-looking screen can be traced back
+//! without re-running outside the TUI. BTC price and ticker updates are too
+//! frequent to be useful here; the status bar shows their rate instead.
+//! Nothing is written to disk.
+
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+
+/// Entries kept at most; the oldest drop off first
+const CAPACITY: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogKind {
+    /// Stream connect, disconnect and reconnect attempts
+    Connection,
+    /// Data events from the stream
+    Stream,
+    /// Orders and closes sent from the dashboard
+    Trade,
+    /// Failed fetches and anything else shown in the status bar
+    Error,
+}
+
+impl LogKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogKind::Connection => "CONN",
+            LogKind::Stream => "SSE",
+            LogKind::Trade => "TRADE",
+            LogKind::Error => "ERROR",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub at: DateTime<Local>,
+    pub kind: LogKind,
+    pub message: String,
+}
+
+#[derive(Debug, Default)]
+pub struct EventLog {
+    entries: VecDeque<LogEntry>,
+    /// Last error logged, so a message that stays on screen is logged once
+    last_error: Option<String>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, kind: LogKind, message: impl Into<String>) {
+        if self.entries.len() == CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(LogEntry {
+            at: Local::now(),
+            kind,
+            message: message.into(),
+        });
+    }
+
+    /// Log an error even if it repeats the last one (every failed fetch counts)
+    pub fn error(&mut self, message: &str) {
+        self.last_error = Some(message.to_string());
+        self.push(LogKind::Error, message);
+    }
+
+    /// Log the status bar's error once when it first appears
+    pub fn error_shown(&mut self, error: Option<&str>) {
+        if error == self.last_error.as_deref() {
+            return;
+        }
+        match error {
+            Some(error) => self.error(error),
+            None => self.last_error = None,
+        }
+    }
+
+    /// Oldest first
+    pub fn entries(&self) -> &VecDeque<LogEntry> {
+        &self.entries
+    }
+}
@@ -1,5 +1,10 @@
 use crate::api::{Contract, VolatilityData};
-use crossterm::event::KeyCode;
+use crate::config::KeymapConfig;
+use crate::liquidity::Impact;
+use anyhow::{bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// Unified event type for all app events (keyboard, SSE, timers)
 #[derive(Debug)]
@@ -14,14 +19,25 @@ pub enum AppEvent {
     /// SSE connection lost
     SseDisconnected,
 
+    /// SSE task is about to retry the connection
+    SseReconnecting,
+
     /// BTC price update from SSE
-    BtcPriceUpdate { price: f64, _timestamp: String },
+    BtcPriceUpdate { price: f64, timestamp: String },
 
-    /// Full contracts update from SSE
+    /// Full contracts update from SSE (one stream per enabled asset)
     ContractsUpdate {
+        asset: String,
         contracts: Vec<Contract>,
         volatility: VolatilityData,
-        _timestamp: String,
+        timestamp: String,
+    },
+
+    /// Market volume and open interest from the stream's ticker channel
+    TickerUpdate {
+        ticker: String,
+        volume: i64,
+        open_interest: i64,
     },
 
     /// SSE error occurred
@@ -30,6 +46,362 @@ pub enum AppEvent {
     /// Periodic tick for UI refresh
     Tick,
 
+    /// Round-trip time of a trade endpoint probe (sent only when it answered)
+    TradeLatency(Duration),
+
+    /// Default-size market impact per contract ID from a background liquidity
+    /// sample, with one message per order book that couldn't be fetched
+    LiquiditySample {
+        impact: HashMap<i32, Impact>,
+        failures: Vec<String>,
+    },
+
     /// Request app shutdown
     Quit,
 }
+
+/// What a key does on the dashboard; the main key handler matches on these,
+/// dialogs still read raw keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Refresh,
+    Help,
+    Back,
+    AcknowledgeAlerts,
+    StressTest,
+    Trade,
+    MuteStrike,
+    MuteSignalType,
+    CycleSort,
+    ReverseSort,
+    ToggleChart,
+    ModelOverride,
+    Open,
+    Compare,
+    Hedge,
+    Pin,
+    Plan,
+    ClosePosition,
+    Amend,
+    Diagnostics,
+    Search,
+    Archive,
+    ToggleLog,
+    Filter,
+    NextView,
+    PreviousView,
+    CycleVolAsset,
+    ExtremeMode,
+    NetEv,
+    ToggleBanner,
+    SlimBars,
+    CompactLayout,
+    CycleTheme,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    /// Number keys: switch view
+    Number(u8),
+    /// Shifted number keys: quick filter on the Signals tab (0 clears)
+    QuickFilter(u8),
+}
+
+impl Action {
+    /// Names used in `[keymap.bindings]`
+    const NAMED: [(&'static str, Action); 40] = [
+        ("quit", Action::Quit),
+        ("refresh", Action::Refresh),
+        ("help", Action::Help),
+        ("back", Action::Back),
+        ("acknowledge_alerts", Action::AcknowledgeAlerts),
+        ("stress_test", Action::StressTest),
+        ("trade", Action::Trade),
+        ("mute_strike", Action::MuteStrike),
+        ("mute_signal_type", Action::MuteSignalType),
+        ("cycle_sort", Action::CycleSort),
+        ("reverse_sort", Action::ReverseSort),
+        ("toggle_chart", Action::ToggleChart),
+        ("model_override", Action::ModelOverride),
+        ("open", Action::Open),
+        ("compare", Action::Compare),
+        ("hedge", Action::Hedge),
+        ("pin", Action::Pin),
+        ("plan_allocation", Action::Plan),
+        ("close_position", Action::ClosePosition),
+        ("amend_order", Action::Amend),
+        ("diagnostics", Action::Diagnostics),
+        ("search", Action::Search),
+        ("archive", Action::Archive),
+        ("toggle_log", Action::ToggleLog),
+        ("filter", Action::Filter),
+        ("next_view", Action::NextView),
+        ("previous_view", Action::PreviousView),
+        ("cycle_vol_asset", Action::CycleVolAsset),
+        ("extreme_mode", Action::ExtremeMode),
+        ("net_ev", Action::NetEv),
+        ("toggle_banner", Action::ToggleBanner),
+        ("slim_bars", Action::SlimBars),
+        ("compact_layout", Action::CompactLayout),
+        ("cycle_theme", Action::CycleTheme),
+        ("up", Action::Up),
+        ("down", Action::Down),
+        ("page_up", Action::PageUp),
+        ("page_down", Action::PageDown),
+        ("top", Action::Top),
+        ("bottom", Action::Bottom),
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        if let Some(digit) = name.strip_prefix("number_") {
+            return digit.parse().ok().filter(|d| *d <= 9).map(Action::Number);
+        }
+        if let Some(digit) = name.strip_prefix("quick_filter_") {
+            return digit
+                .parse()
+                .ok()
+                .filter(|d| *d <= 3)
+                .map(Action::QuickFilter);
+        }
+        Self::NAMED
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, action)| *action)
+    }
+}
+
+/// One key press; Ctrl is the only modifier that changes the binding
+/// (Shift is already in the character)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    code: KeyCode,
+    ctrl: bool,
+}
+
+impl Key {
+    const fn plain(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: false,
+        }
+    }
+
+    const fn ctrl(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: true,
+        }
+    }
+
+    const fn code(code: KeyCode) -> Self {
+        Self { code, ctrl: false }
+    }
+
+    pub fn from_event(event: KeyEvent) -> Self {
+        Self {
+            code: event.code,
+            ctrl: event.modifiers.contains(KeyModifiers::CONTROL),
+        }
+    }
+
+    /// "q", "ctrl-d", "enter", "pagedown", ...
+    fn parse(text: &str) -> Option<Self> {
+        let (ctrl, name) = match text.strip_prefix("ctrl-") {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let code = match name.to_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" | "shift-tab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            _ => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return None,
+                }
+            }
+        };
+        Some(Self { code, ctrl })
+    }
+}
+
+/// A bare `g` waits this long for a second `g` before toggling the chart
+const SEQUENCE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Keys to actions, including two-key sequences like `gg`. Defaults follow
+/// the on-screen hints plus vim motions; `[keymap]` in config.toml adds to them.
+pub struct Keymap {
+    bindings: HashMap<Vec<Key>, Action>,
+    pending: Vec<Key>,
+    pending_since: Option<Instant>,
+}
+
+impl Keymap {
+    pub fn new(config: &KeymapConfig) -> Result<Self> {
+        let mut bindings = HashMap::new();
+        let mut bind = |keys: &[Key], action: Action| {
+            bindings.insert(keys.to_vec(), action);
+        };
+
+        let letters = [
+            ('q', Action::Quit),
+            ('r', Action::Refresh),
+            ('a', Action::AcknowledgeAlerts),
+            ('w', Action::StressTest),
+            ('t', Action::Trade),
+            ('o', Action::ModelOverride),
+            ('c', Action::Compare),
+            ('n', Action::Hedge),
+            ('x', Action::ClosePosition),
+            ('i', Action::Amend),
+            ('d', Action::Diagnostics),
+            ('v', Action::CycleVolAsset),
+            ('e', Action::ExtremeMode),
+            ('f', Action::NetEv),
+            ('y', Action::Archive),
+        ];
+        for (c, action) in letters {
+            bind(&[Key::plain(c)], action);
+            bind(&[Key::plain(c.to_ascii_uppercase())], action);
+        }
+        bind(&[Key::plain('p')], Action::Pin);
+        bind(&[Key::plain('P')], Action::Plan);
+        bind(&[Key::plain('m')], Action::MuteStrike);
+        bind(&[Key::plain('M')], Action::MuteSignalType);
+        bind(&[Key::plain('s')], Action::CycleSort);
+        bind(&[Key::plain('S')], Action::ReverseSort);
+        bind(&[Key::plain('b')], Action::ToggleBanner);
+        bind(&[Key::plain('B')], Action::SlimBars);
+        bind(&[Key::plain('z')], Action::CompactLayout);
+        bind(&[Key::plain('Z')], Action::CompactLayout);
+        bind(&[Key::plain('u')], Action::CycleTheme);
+        bind(&[Key::plain('?')], Action::Help);
+        bind(&[Key::plain('/')], Action::Filter);
+        bind(&[Key::ctrl('f')], Action::Search);
+        bind(&[Key::code(KeyCode::Esc)], Action::Back);
+        bind(&[Key::code(KeyCode::Enter)], Action::Open);
+        bind(&[Key::code(KeyCode::Tab)], Action::NextView);
+        bind(&[Key::code(KeyCode::BackTab)], Action::PreviousView);
+        for digit in 0..=9u8 {
+            bind(&[Key::plain((b'0' + digit) as char)], Action::Number(digit));
+        }
+        // Shift+1/2/3/0 on a US layout; other layouts can rebind quick_filter_N
+        for (c, filter) in [('!', 1), ('@', 2), ('#', 3), (')', 0)] {
+            bind(&[Key::plain(c)], Action::QuickFilter(filter));
+        }
+
+        // Motions: arrows and paging keys, plus the vim equivalents
+        bind(&[Key::code(KeyCode::Up)], Action::Up);
+        bind(&[Key::code(KeyCode::Down)], Action::Down);
+        bind(&[Key::plain('k')], Action::Up);
+        bind(&[Key::plain('j')], Action::Down);
+        bind(&[Key::code(KeyCode::PageUp)], Action::PageUp);
+        bind(&[Key::code(KeyCode::PageDown)], Action::PageDown);
+        bind(&[Key::ctrl('u')], Action::PageUp);
+        bind(&[Key::ctrl('d')], Action::PageDown);
+        bind(&[Key::code(KeyCode::Home)], Action::Top);
+        bind(&[Key::code(KeyCode::End)], Action::Bottom);
+        bind(&[Key::plain('g'), Key::plain('g')], Action::Top);
+        bind(&[Key::plain('G')], Action::Bottom);
+        bind(&[Key::plain('g')], Action::ToggleChart);
+
+        if config.vim {
+            bind(&[Key::plain('h')], Action::PreviousView);
+            bind(&[Key::plain('l')], Action::NextView);
+        } else {
+            bind(&[Key::plain('h')], Action::Help);
+            bind(&[Key::plain('H')], Action::Help);
+            bind(&[Key::plain('l')], Action::ToggleLog);
+        }
+        bind(&[Key::plain('L')], Action::ToggleLog);
+
+        for (keys, name) in &config.bindings {
+            let Some(action) = Action::from_name(name) else {
+                bail!(
+                    "Unknown action '{}' for '{}' in [keymap.bindings]",
+                    name,
+                    keys
+                );
+            };
+            let sequence = Self::parse_sequence(keys)?;
+            bind(&sequence, action);
+        }
+
+        Ok(Self {
+            bindings,
+            pending: Vec::new(),
+            pending_since: None,
+        })
+    }
+
+    /// "ctrl-r" is one key, "gt" is a sequence of two
+    fn parse_sequence(text: &str) -> Result<Vec<Key>> {
+        if let Some(key) = Key::parse(text) {
+            return Ok(vec![key]);
+        }
+        let keys: Option<Vec<Key>> = text.chars().map(|c| Key::parse(&c.to_string())).collect();
+        match keys {
+            Some(keys) if keys.len() == 2 => Ok(keys),
+            _ => bail!("Unknown key '{}' in [keymap.bindings]", text),
+        }
+    }
+
+    /// Single-key binding, ignoring any sequence in progress
+    pub fn action(&self, key: Key) -> Option<Action> {
+        self.bindings.get(&vec![key]).copied()
+    }
+
+    /// Feed a key press. A key that starts a longer binding waits for the next one;
+    /// if that doesn't complete it, the shorter binding fires first.
+    pub fn press(&mut self, key: Key) -> Vec<Action> {
+        let mut actions = Vec::new();
+        let mut sequence = std::mem::take(&mut self.pending);
+        sequence.push(key);
+        if sequence.len() > 1 && !self.bindings.contains_key(&sequence) {
+            // Not a sequence after all: resolve the prefix, then this key on its own
+            actions.extend(self.bindings.get(&sequence[..1]).copied());
+            sequence = vec![key];
+        }
+
+        if self.is_prefix(&sequence) {
+            self.pending = sequence;
+            self.pending_since = Some(Instant::now());
+        } else {
+            self.pending_since = None;
+            actions.extend(self.bindings.get(&sequence).copied());
+        }
+        actions
+    }
+
+    /// The shorter binding behind a half-typed sequence, once it has waited long enough
+    pub fn expire(&mut self) -> Option<Action> {
+        let since = self.pending_since?;
+        if since.elapsed() < SEQUENCE_TIMEOUT {
+            return None;
+        }
+        self.pending_since = None;
+        let pending = std::mem::take(&mut self.pending);
+        self.bindings.get(&pending).copied()
+    }
+
+    /// Some longer binding starts with `keys`
+    fn is_prefix(&self, keys: &[Key]) -> bool {
+        self.bindings
+            .keys()
+            .any(|binding| binding.len() > keys.len() && binding.starts_with(keys))
+    }
+}
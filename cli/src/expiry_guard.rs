@@ -0,0 +1,145 @@
+//! Closing positions before settlement (`basilisk guard`).
+//!
+//! Hourly contracts settle on BTC's average over the final minute, so a
+//! position held into the window can flip on a late move the holder never
+//! saw. The guard polls open positions and closes, at market, any whose
+//! contract expires within `--close-before-expiry`, except the positions
+//! passed with `--keep`. A close that fails is retried on the next check
+//! until the contract expires.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::Local;
+
+use crate::api::client::ApiClient;
+use crate::api::models::Position;
+use crate::audit::{self, AuditKind};
+
+fn log(event: &str, message: impl std::fmt::Display) {
+    println!(
+        "{} {:<8} {}",
+        Local::now().format("%H:%M:%S"),
+        event,
+        message
+    );
+}
+
+/// Open positions inside the window and not kept
+fn due<'a>(positions: &'a [Position], window: Duration, keep: &HashSet<i32>) -> Vec<&'a Position> {
+    let window_hours = window.as_secs_f64() / 3600.0;
+    positions
+        .iter()
+        .filter(|p| !keep.contains(&p.trade_id))
+        .filter(|p| {
+            p.hours_to_expiry()
+                .is_some_and(|hours| hours > 0.0 && hours <= window_hours)
+        })
+        .collect()
+}
+
+/// One pass: close every due position
+async fn check(client: &ApiClient, window: Duration, keep: &HashSet<i32>) -> Result<()> {
+    let positions = client.get_positions().await?;
+    for position in due(&positions, window, keep) {
+        let description = format!(
+            "guard: close position #{} ({} {} x{}, {} left)",
+            position.trade_id,
+            position.ticker,
+            position.direction,
+            position.contracts,
+            position.time_left_display()
+        );
+        let result = client.close_position(position.trade_id).await;
+        if let Err(e) = audit::record_result(AuditKind::Close, "guard", &description, &result) {
+            log("WARN", format!("could not write audit log: {}", e));
+        }
+        match result {
+            Ok(response) if response.success => {
+                log(
+                    "CLOSE",
+                    format!(
+                        "#{} {} {} x{} @ {}{}",
+                        position.trade_id,
+                        position.ticker,
+                        position.direction,
+                        response.filled,
+                        response
+                            .price
+                            .map(|p| format!("${:.2}", p))
+                            .unwrap_or_else(|| "--".to_string()),
+                        response
+                            .cost
+                            .map(|pnl| format!(" (P&L ${:+.2})", pnl))
+                            .unwrap_or_default()
+                    ),
+                );
+            }
+            Ok(response) => log(
+                "FAILED",
+                format!(
+                    "#{}: {} (retrying next check)",
+                    position.trade_id,
+                    response.error.as_deref().unwrap_or("close rejected")
+                ),
+            ),
+            Err(e) => log(
+                "FAILED",
+                format!("#{}: {} (retrying next check)", position.trade_id, e),
+            ),
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_guard_command(
+    api_url: &str,
+    retry_orders: bool,
+    close_before_expiry: Duration,
+    keep: Vec<i32>,
+    interval: u64,
+    once: bool,
+) -> Result<()> {
+    let client = ApiClient::new(api_url.to_string(), 30)?.with_order_retry(retry_orders);
+    let keep: HashSet<i32> = keep.into_iter().collect();
+
+    let mut kept: Vec<String> = keep.iter().map(|id| format!("#{}", id)).collect();
+    kept.sort();
+    log(
+        "START",
+        format!(
+            "closing positions {} before expiry, checking every {}s{}",
+            match close_before_expiry.as_secs() {
+                secs if secs % 3600 == 0 => format!("{}h", secs / 3600),
+                secs if secs % 60 == 0 => format!("{}m", secs / 60),
+                secs => format!("{}s", secs),
+            },
+            interval.max(1),
+            if kept.is_empty() {
+                String::new()
+            } else {
+                format!(", keeping {}", kept.join(" "))
+            }
+        ),
+    );
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval.max(1)));
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = tokio::signal::ctrl_c() => {
+                log("STOP", "interrupted");
+                break;
+            }
+        }
+        if let Err(e) = check(&client, close_before_expiry, &keep).await {
+            log("ERROR", e);
+        }
+        if once {
+            break;
+        }
+    }
+
+    Ok(())
+}
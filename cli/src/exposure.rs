@@ -0,0 +1,394 @@
+//! Exposure per expiry hour.
+//!
+//! Every position in an hourly contract settles at the same instant, so risk
+//! is capped per expiry: the notional (premium paid, i.e. the most that hour
+//! can lose) is summed per settlement time and compared with
+//! `[exposure] max_per_expiry`.
+//!
+//! `basilisk exposure` (and the Portfolio tab's Concentration table) break
+//! the same positions down by expiry and side, with contracts, value at the
+//! current price and max loss, to show where the risk is concentrated.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+use crate::api::client::ApiClient;
+use crate::api::models::{Contract, Position};
+use crate::api::parse_timestamp;
+use crate::config::{Config, ExposureConfig};
+use crate::fees;
+use crate::paper::PaperPosition;
+use crate::sizing;
+
+/// Notional committed to one settlement time
+#[derive(Debug, Clone, Default)]
+pub struct ExpiryExposure {
+    pub expiry: Option<DateTime<Utc>>,
+    pub notional: f64,
+    pub positions: usize,
+}
+
+impl ExpiryExposure {
+    pub fn label(&self) -> String {
+        hour_label(self.expiry)
+    }
+
+    /// Fraction of the cap used (None when uncapped)
+    pub fn utilization(&self, cap: Option<f64>) -> Option<f64> {
+        cap.filter(|cap| *cap > 0.0).map(|cap| self.notional / cap)
+    }
+}
+
+/// Open notional grouped by expiry, soonest first
+#[derive(Debug, Clone, Default)]
+pub struct ExposureBook {
+    by_expiry: BTreeMap<Option<DateTime<Utc>>, ExpiryExposure>,
+}
+
+impl ExposureBook {
+    pub fn from_positions(positions: &[Position]) -> Self {
+        let mut book = Self::default();
+        for position in positions {
+            book.add(
+                position.expiry_at.as_deref(),
+                position.entry_price * position.contracts as f64,
+            );
+        }
+        book
+    }
+
+    pub fn from_paper(positions: &[PaperPosition]) -> Self {
+        let mut book = Self::default();
+        for position in positions {
+            book.add(
+                position.expiry_time.as_deref(),
+                position.entry_price * position.contracts as f64,
+            );
+        }
+        book
+    }
+
+    pub fn add(&mut self, expiry: Option<&str>, notional: f64) {
+        let expiry = expiry.and_then(parse_timestamp);
+        let bucket = self.by_expiry.entry(expiry).or_insert(ExpiryExposure {
+            expiry,
+            ..Default::default()
+        });
+        bucket.notional += notional;
+        bucket.positions += 1;
+    }
+
+    pub fn expiries(&self) -> impl Iterator<Item = &ExpiryExposure> {
+        self.by_expiry.values()
+    }
+
+    /// Check an order of `size` contracts on a signal at its entry price
+    pub fn check_signal(
+        &self,
+        config: &ExposureConfig,
+        contract: &Contract,
+        size: i32,
+    ) -> Result<(), String> {
+        let notional = sizing::entry_price(contract).unwrap_or(0.0) * size as f64;
+        self.check(config, contract.expiry_time.as_deref(), notional)
+    }
+
+    pub fn notional_at(&self, expiry: Option<&str>) -> f64 {
+        self.by_expiry
+            .get(&expiry.and_then(parse_timestamp))
+            .map(|bucket| bucket.notional)
+            .unwrap_or(0.0)
+    }
+
+    /// Err with the reason when adding `notional` at `expiry` would exceed
+    /// the cap
+    pub fn check(
+        &self,
+        config: &ExposureConfig,
+        expiry: Option<&str>,
+        notional: f64,
+    ) -> Result<(), String> {
+        let Some(cap) = config.max_per_expiry else {
+            return Ok(());
+        };
+        let current = self.notional_at(expiry);
+
+        if current >= cap {
+            return Err(format!(
+                "{} expiry already at its ${:.2} exposure cap (${:.2} open)",
+                expiry_label(expiry),
+                cap,
+                current
+            ));
+        }
+        if current + notional > cap {
+            return Err(format!(
+                "{} expiry would reach ${:.2} of its ${:.2} exposure cap (${:.2} left)",
+                expiry_label(expiry),
+                current + notional,
+                cap,
+                cap - current
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn hour_label(expiry: Option<DateTime<Utc>>) -> String {
+    expiry
+        .map(|at| at.format("%H:%M UTC").to_string())
+        .unwrap_or_else(|| "No expiry".to_string())
+}
+
+fn expiry_label(expiry: Option<&str>) -> String {
+    ExpiryExposure {
+        expiry: expiry.and_then(parse_timestamp),
+        ..Default::default()
+    }
+    .label()
+}
+
+/// Size and risk of a group of positions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Concentration {
+    pub positions: usize,
+    pub contracts: i32,
+    /// Premium paid, as counted against `[exposure] max_per_expiry`
+    pub cost: f64,
+    /// Worth at the current price: what settling against them would lose from here
+    pub at_risk: f64,
+    /// Cost plus entry fees: what they lose in total if they all settle worthless
+    pub max_loss: f64,
+}
+
+impl Concentration {
+    fn add(&mut self, position: &Position, price: f64) {
+        let contracts = position.contracts as f64;
+        self.positions += 1;
+        self.contracts += position.contracts;
+        let cost = position.entry_price * contracts;
+        self.cost += cost;
+        self.at_risk += price * contracts;
+        self.max_loss += cost + fees::taker_fee(position.contracts, position.entry_price);
+    }
+
+    fn merge(mut self, other: &Concentration) -> Self {
+        self.positions += other.positions;
+        self.contracts += other.contracts;
+        self.cost += other.cost;
+        self.at_risk += other.at_risk;
+        self.max_loss += other.max_loss;
+        self
+    }
+
+    /// Share of `total`'s max loss
+    pub fn share_of(&self, total: &Concentration) -> f64 {
+        if total.max_loss > 0.0 {
+            self.max_loss / total.max_loss
+        } else {
+            0.0
+        }
+    }
+}
+
+/// One expiry's positions, split by side
+#[derive(Debug, Clone, Default)]
+pub struct ExpirySides {
+    pub expiry: Option<DateTime<Utc>>,
+    pub yes: Concentration,
+    pub no: Concentration,
+}
+
+impl ExpirySides {
+    pub fn label(&self) -> String {
+        hour_label(self.expiry)
+    }
+
+    pub fn total(&self) -> Concentration {
+        self.yes.merge(&self.no)
+    }
+
+    /// The sides holding positions, as ("YES" | "NO", figures)
+    pub fn sides(&self) -> impl Iterator<Item = (&'static str, &Concentration)> {
+        [("YES", &self.yes), ("NO", &self.no)]
+            .into_iter()
+            .filter(|(_, side)| side.positions > 0)
+    }
+}
+
+/// Open positions grouped by expiry and side, soonest expiry first
+#[derive(Debug, Clone, Default)]
+pub struct ExposureSummary {
+    by_expiry: BTreeMap<Option<DateTime<Utc>>, ExpirySides>,
+}
+
+impl ExposureSummary {
+    /// Group `positions`, valuing each at `price` (its entry price when None)
+    pub fn from_positions(
+        positions: &[Position],
+        price: impl Fn(&Position) -> Option<f64>,
+    ) -> Self {
+        let mut summary = Self::default();
+        for position in positions {
+            let expiry = position.expiry_at.as_deref().and_then(parse_timestamp);
+            let bucket = summary.by_expiry.entry(expiry).or_insert(ExpirySides {
+                expiry,
+                ..Default::default()
+            });
+            let side = if position.direction.eq_ignore_ascii_case("NO") {
+                &mut bucket.no
+            } else {
+                &mut bucket.yes
+            };
+            side.add(position, price(position).unwrap_or(position.entry_price));
+        }
+        summary
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_expiry.is_empty()
+    }
+
+    pub fn expiries(&self) -> impl Iterator<Item = &ExpirySides> {
+        self.by_expiry.values()
+    }
+
+    /// Every expiry added together, still split by side
+    pub fn by_side(&self) -> ExpirySides {
+        self.expiries()
+            .fold(ExpirySides::default(), |all, bucket| ExpirySides {
+                expiry: None,
+                yes: all.yes.merge(&bucket.yes),
+                no: all.no.merge(&bucket.no),
+            })
+    }
+
+    pub fn total(&self) -> Concentration {
+        self.by_side().total()
+    }
+}
+
+pub async fn handle_exposure_command(api_url: &str) -> Result<()> {
+    let client = ApiClient::new(api_url.to_string(), 30)?;
+    let config = Config::load()?;
+    let positions = client.get_positions().await?;
+    // Value positions at the live quote for their side when the signals have one
+    let contracts = client
+        .get_current_signals()
+        .await
+        .map(|current| current.contracts)
+        .unwrap_or_default();
+    let summary = ExposureSummary::from_positions(&positions, |position| {
+        contracts
+            .iter()
+            .find(|c| c.ticker == position.ticker)
+            .and_then(|c| {
+                if position.direction.eq_ignore_ascii_case("NO") {
+                    c.no_price
+                } else {
+                    c.yes_price
+                }
+            })
+            .or(position.current_price)
+    });
+
+    if summary.is_empty() {
+        println!("📂 No open positions.");
+        return Ok(());
+    }
+
+    let total = summary.total();
+    let cap = config.exposure.max_per_expiry;
+    println!(
+        "📊 Exposure: {} position{}, {} contracts",
+        total.positions,
+        if total.positions == 1 { "" } else { "s" },
+        total.contracts
+    );
+    println!("{}", "─".repeat(78));
+    println!(
+        "{:<12} {:<5} {:>4} {:>10} {:>10} {:>10} {:>7}  {}",
+        "Expiry",
+        "Side",
+        "Pos",
+        "Contracts",
+        "At Risk",
+        "Max Loss",
+        "Share",
+        if cap.is_some() { "Cap" } else { "" }
+    );
+    println!("{}", "─".repeat(78));
+    for bucket in summary.expiries() {
+        let bucket_total = bucket.total();
+        let mut rows: Vec<(&str, &Concentration)> = bucket.sides().collect();
+        if rows.len() > 1 {
+            rows.push(("Both", &bucket_total));
+        }
+        // The expiry's cap use goes on its last row
+        let used = match cap.filter(|cap| *cap > 0.0) {
+            Some(cap) => {
+                let used = bucket_total.cost / cap;
+                let color = if used >= 1.0 {
+                    "\x1b[31m"
+                } else if used >= 0.75 {
+                    "\x1b[33m"
+                } else {
+                    "\x1b[32m"
+                };
+                format!("{}{:.0}%\x1b[0m", color, used * 100.0)
+            }
+            None => String::new(),
+        };
+        let last = rows.len() - 1;
+        for (i, (side, figures)) in rows.into_iter().enumerate() {
+            let label = if i == 0 {
+                bucket.label()
+            } else {
+                String::new()
+            };
+            let used = if i == last { used.as_str() } else { "" };
+            print_concentration_row(&label, side, figures, &total, used);
+        }
+    }
+    println!("{}", "─".repeat(78));
+    let sides = summary.by_side();
+    let mut label = "All".to_string();
+    if sides.sides().count() > 1 {
+        for (side, figures) in sides.sides() {
+            print_concentration_row(&label, side, figures, &total, "");
+            label.clear();
+        }
+    }
+    print_concentration_row(&label, "Total", &total, &total, "");
+    println!("{}", "─".repeat(78));
+    println!("   At Risk: value at the current price. Max Loss: cost plus entry fees.");
+    if let Some(cap) = cap {
+        println!(
+            "   Cap: share of [exposure] max_per_expiry (${:.2}) used by the expiry's cost.",
+            cap
+        );
+    }
+
+    Ok(())
+}
+
+fn print_concentration_row(
+    label: &str,
+    side: &str,
+    figures: &Concentration,
+    total: &Concentration,
+    cap: &str,
+) {
+    println!(
+        "{:<12} {:<5} {:>4} {:>10} {:>10} {:>10} {:>6.0}%  {}",
+        label,
+        side,
+        figures.positions,
+        figures.contracts,
+        format!("${:.2}", figures.at_risk),
+        format!("${:.2}", figures.max_loss),
+        figures.share_of(total) * 100.0,
+        cap
+    );
+}
@@ -0,0 +1,62 @@
+use chrono::Utc;
+use std::collections::VecDeque;
+
+use crate::api::parse_timestamp;
+
+/// SSE events averaged for the pipeline latency
+const LATENCY_WINDOW: usize = 40;
+
+/// Samples needed before a high average counts as "consistently" stale
+const MIN_SAMPLES: usize = 10;
+
+/// Average age past which the feed is flagged stale
+pub const STALE_AFTER_MS: i64 = 3_000;
+
+/// Rolling age of SSE data on arrival: the event's own timestamp against the
+/// local clock, so it covers the backend, the network and our event queue.
+/// Clock skew between the two machines shows up here too.
+#[derive(Debug, Default)]
+pub struct FeedLatency {
+    samples: VecDeque<i64>,
+}
+
+impl FeedLatency {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an event stamped `timestamp` that arrived just now
+    pub fn record(&mut self, timestamp: &str) {
+        let Some(sent_at) = parse_timestamp(timestamp) else {
+            return;
+        };
+        self.samples
+            .push_back((Utc::now() - sent_at).num_milliseconds());
+        if self.samples.len() > LATENCY_WINDOW {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Mean age of recent events in milliseconds
+    pub fn average_ms(&self) -> Option<i64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        Some(self.samples.iter().sum::<i64>() / self.samples.len() as i64)
+    }
+
+    /// Latest event's age in milliseconds
+    pub fn last_ms(&self) -> Option<i64> {
+        self.samples.back().copied()
+    }
+
+    /// Enough recent events averaging over `STALE_AFTER_MS`
+    pub fn is_stale(&self) -> bool {
+        self.samples.len() >= MIN_SAMPLES && self.average_ms().is_some_and(|ms| ms > STALE_AFTER_MS)
+    }
+
+    /// Start over, e.g. after the stream reconnects
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
@@ -0,0 +1,135 @@
+//! Kalshi trading fees.
+//!
+//! Kalshi charges takers `0.07 × contracts × price × (1 − price)`, rounded up
+//! to the next cent, so fees peak at 50¢ and shrink toward either end of the
+//! book. Resting orders that fill as maker pay less or nothing, so these are
+//! upper bounds for limit orders.
+//!
+//! The backend's EV only takes a flat cut of the winnings, so an edge of a
+//! cent or two near 50¢ can vanish once the real fee is paid. `net_ev` prices
+//! the signal again with the taker fee taken off.
+
+use crate::api::Contract;
+use crate::sizing;
+
+/// Fraction of `price × (1 − price)` charged per contract
+const TAKER_RATE: f64 = 0.07;
+
+/// Fee in dollars for taking `contracts` at `price` (dollars per contract)
+pub fn taker_fee(contracts: i32, price: f64) -> f64 {
+    if contracts <= 0 || !(0.0..=1.0).contains(&price) {
+        return 0.0;
+    }
+    let raw = TAKER_RATE * contracts as f64 * price * (1.0 - price);
+    // Round up to the cent, ignoring float noise just above a whole cent
+    (raw * 100.0 - 1e-9).ceil().max(0.0) / 100.0
+}
+
+/// Taker fee per contract at `price` before rounding, i.e. each contract's
+/// share of a large order's fee
+pub fn fee_per_contract(price: f64) -> f64 {
+    if !(0.0..=1.0).contains(&price) {
+        return 0.0;
+    }
+    TAKER_RATE * price * (1.0 - price)
+}
+
+/// EV per contract of the signal's side at its entry price after the taker
+/// fee, in dollars like `Contract::expected_value` (None for a HOLD, or
+/// without a model probability or a price)
+pub fn net_ev(contract: &Contract) -> Option<f64> {
+    if !contract.signal_type.starts_with("BUY ") {
+        return None;
+    }
+    let price = sizing::entry_price(contract)?;
+    let win = sizing::win_probability(contract)?;
+    Some(win - price - fee_per_contract(price))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    fn contract(signal_type: &str, price: f64, model_probability: Option<f64>) -> Contract {
+        Contract {
+            id: 1,
+            ticker: "KXBTCD-T95000".to_string(),
+            signal_type: signal_type.to_string(),
+            expected_value: 0.0,
+            edge_percentage: 0.0,
+            recommended_price: price,
+            confidence_score: 0.0,
+            time_to_expiry_hours: None,
+            is_active: true,
+            strike_price: Some(95000.0),
+            expiry_time: None,
+            current_btc_price: None,
+            yes_price: None,
+            no_price: None,
+            implied_probability: None,
+            model_probability,
+        }
+    }
+
+    #[test]
+    fn taker_fee_rounds_up_to_the_cent() {
+        assert_close(taker_fee(100, 0.50), 1.75);
+        assert_close(taker_fee(1, 0.50), 0.02);
+        assert_close(taker_fee(10, 0.35), 0.16);
+        assert_close(taker_fee(1, 0.99), 0.01);
+    }
+
+    #[test]
+    fn taker_fee_ignores_float_noise_on_whole_cents() {
+        // 0.07 × 20 × 0.25 is 0.35000000000000003 in floating point
+        assert_close(taker_fee(20, 0.50), 0.35);
+    }
+
+    #[test]
+    fn taker_fee_is_symmetric_and_zero_at_the_edges() {
+        assert_close(taker_fee(10, 0.30), taker_fee(10, 0.70));
+        assert_close(taker_fee(10, 0.0), 0.0);
+        assert_close(taker_fee(10, 1.0), 0.0);
+        assert_close(taker_fee(0, 0.50), 0.0);
+        assert_close(taker_fee(10, 1.5), 0.0);
+    }
+
+    #[test]
+    fn fee_per_contract_is_unrounded() {
+        assert_close(fee_per_contract(0.50), 0.0175);
+        assert_close(fee_per_contract(0.10), 0.0063);
+        assert_close(fee_per_contract(-0.1), 0.0);
+    }
+
+    #[test]
+    fn net_ev_takes_the_fee_off_the_signals_side() {
+        let yes = contract("BUY YES", 0.45, Some(0.52));
+        assert_close(
+            net_ev(&yes).unwrap_or_default(),
+            0.52 - 0.45 - 0.07 * 0.45 * 0.55,
+        );
+
+        // A NO signal wins when the model's YES probability doesn't
+        let no = contract("BUY NO", 0.60, Some(0.30));
+        assert_close(
+            net_ev(&no).unwrap_or_default(),
+            0.70 - 0.60 - 0.07 * 0.60 * 0.40,
+        );
+    }
+
+    #[test]
+    fn net_ev_needs_a_buy_signal_price_and_model() {
+        assert!(net_ev(&contract("HOLD", 0.45, Some(0.52))).is_none());
+        assert!(net_ev(&contract("BUY YES", 0.0, Some(0.52))).is_none());
+        assert!(net_ev(&contract("BUY YES", 0.45, None)).is_none());
+    }
+}
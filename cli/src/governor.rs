@@ -0,0 +1,250 @@
+//! Order throttling for automated orders: the dashboard's price rules and
+//! `basilisk auto`.
+//!
+//! Every automated order must get a permit from a [`Governor`], which caps
+//! orders per minute and orders in flight, and trips a kill switch after too
+//! many rejections in a row. The dashboard keeps one in memory for its rules;
+//! the auto-trader's counters are saved to `auto_status.json` so the
+//! diagnostics panel can show them, along with any `[auto.pause]` suspension.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::config::GovernorConfig;
+use crate::store;
+
+pub const STATUS_FILE: &str = "auto_status.json";
+
+/// Counters for `basilisk auto --paper`, kept apart from live trading
+pub const PAPER_STATUS_FILE: &str = "paper_status.json";
+
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Why an order was held back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Throttle {
+    RateLimited,
+    TooManyInFlight,
+    Killed(String),
+}
+
+impl std::fmt::Display for Throttle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Throttle::RateLimited => write!(f, "orders-per-minute limit reached"),
+            Throttle::TooManyInFlight => write!(f, "too many orders in flight"),
+            Throttle::Killed(reason) => write!(f, "kill switch engaged ({})", reason),
+        }
+    }
+}
+
+/// Counters shared with the diagnostics panel
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GovernorStatus {
+    pub updated_at: Option<DateTime<Utc>>,
+    pub submitted: u64,
+    pub filled: u64,
+    pub rejected: u64,
+    pub throttled: u64,
+    pub in_flight: usize,
+    pub orders_last_minute: usize,
+    pub consecutive_rejections: u32,
+    pub killed: Option<String>,
+    /// Set while `[auto.pause]` conditions hold entries back
+    pub paused: Option<String>,
+}
+
+pub struct Governor {
+    config: GovernorConfig,
+    /// Where `save` writes the counters (None = kept in memory)
+    status_file: Option<&'static str>,
+    recent: VecDeque<Instant>,
+    status: GovernorStatus,
+}
+
+impl Governor {
+    /// Fresh counters that live as long as the governor
+    pub fn new(config: GovernorConfig) -> Self {
+        Self {
+            config,
+            status_file: None,
+            recent: VecDeque::new(),
+            status: GovernorStatus::default(),
+        }
+    }
+
+    /// Resume from the saved status so a tripped kill switch survives restarts
+    pub fn load(config: GovernorConfig, status_file: &'static str) -> Self {
+        let status = store::read_json(status_file)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        Self {
+            config,
+            status_file: Some(status_file),
+            recent: VecDeque::new(),
+            status: GovernorStatus {
+                in_flight: 0,
+                orders_last_minute: 0,
+                ..status
+            },
+        }
+    }
+
+    pub fn status(&self) -> &GovernorStatus {
+        &self.status
+    }
+
+    pub fn reset_kill_switch(&mut self) {
+        self.status.killed = None;
+        self.status.consecutive_rejections = 0;
+    }
+
+    pub fn pause(&mut self, reason: String) {
+        self.status.paused = Some(reason);
+    }
+
+    pub fn resume(&mut self) {
+        self.status.paused = None;
+    }
+
+    /// Reserve a slot for one order, or say why it must wait
+    pub fn acquire(&mut self) -> Result<(), Throttle> {
+        let now = Instant::now();
+        while self
+            .recent
+            .front()
+            .is_some_and(|sent| now.duration_since(*sent) >= RATE_WINDOW)
+        {
+            self.recent.pop_front();
+        }
+        self.status.orders_last_minute = self.recent.len();
+
+        let verdict = if let Some(reason) = &self.status.killed {
+            Err(Throttle::Killed(reason.clone()))
+        } else if self.recent.len() >= self.config.max_orders_per_minute {
+            Err(Throttle::RateLimited)
+        } else if self.status.in_flight >= self.config.max_in_flight {
+            Err(Throttle::TooManyInFlight)
+        } else {
+            Ok(())
+        };
+
+        match verdict {
+            Ok(()) => {
+                self.recent.push_back(now);
+                self.status.orders_last_minute = self.recent.len();
+                self.status.in_flight += 1;
+                self.status.submitted += 1;
+            }
+            Err(_) => self.status.throttled += 1,
+        }
+        verdict
+    }
+
+    /// Release a slot once the order has an answer
+    pub fn complete(&mut self, accepted: bool) {
+        self.status.in_flight = self.status.in_flight.saturating_sub(1);
+
+        if accepted {
+            self.status.filled += 1;
+            self.status.consecutive_rejections = 0;
+        } else {
+            self.status.rejected += 1;
+            self.status.consecutive_rejections += 1;
+            if self.status.consecutive_rejections >= self.config.max_consecutive_rejections {
+                self.status.killed = Some(format!(
+                    "{} consecutive rejections",
+                    self.status.consecutive_rejections
+                ));
+            }
+        }
+    }
+
+    pub fn save(&mut self) -> Result<()> {
+        self.status.updated_at = Some(Utc::now());
+        match self.status_file {
+            Some(file) => store::write_json(file, &self.status),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn governor(max_orders_per_minute: usize, max_in_flight: usize) -> Governor {
+        Governor::new(GovernorConfig {
+            max_orders_per_minute,
+            max_in_flight,
+            max_consecutive_rejections: 3,
+        })
+    }
+
+    #[test]
+    fn caps_orders_in_flight() {
+        let mut governor = governor(10, 2);
+        assert_eq!(governor.acquire(), Ok(()));
+        assert_eq!(governor.acquire(), Ok(()));
+        assert_eq!(governor.acquire(), Err(Throttle::TooManyInFlight));
+
+        governor.complete(true);
+        assert_eq!(governor.acquire(), Ok(()));
+        assert_eq!(governor.status().in_flight, 2);
+        assert_eq!(governor.status().throttled, 1);
+    }
+
+    #[test]
+    fn caps_orders_per_minute() {
+        let mut governor = governor(2, 10);
+        for _ in 0..2 {
+            assert_eq!(governor.acquire(), Ok(()));
+            governor.complete(true);
+        }
+        // Completed orders still count against the window
+        assert_eq!(governor.acquire(), Err(Throttle::RateLimited));
+        assert_eq!(governor.status().orders_last_minute, 2);
+    }
+
+    #[test]
+    fn rate_window_slides() {
+        let mut governor = governor(1, 10);
+        assert_eq!(governor.acquire(), Ok(()));
+        governor.complete(true);
+        let Some(sent) = Instant::now().checked_sub(RATE_WINDOW) else {
+            return;
+        };
+        governor.recent = VecDeque::from([sent]);
+        assert_eq!(governor.acquire(), Ok(()));
+    }
+
+    #[test]
+    fn consecutive_rejections_trip_the_kill_switch() {
+        let mut governor = governor(10, 10);
+        for accepted in [false, false, true, false, false] {
+            assert_eq!(governor.acquire(), Ok(()));
+            governor.complete(accepted);
+        }
+        assert!(governor.status().killed.is_none());
+
+        assert_eq!(governor.acquire(), Ok(()));
+        governor.complete(false);
+        let killed = "3 consecutive rejections".to_string();
+        assert_eq!(governor.status().killed, Some(killed.clone()));
+        assert_eq!(governor.acquire(), Err(Throttle::Killed(killed)));
+
+        governor.reset_kill_switch();
+        assert_eq!(governor.acquire(), Ok(()));
+    }
+
+    #[test]
+    fn in_memory_governor_saves_nowhere() {
+        let mut governor = governor(1, 1);
+        assert!(governor.save().is_ok());
+        assert!(governor.status().updated_at.is_some());
+    }
+}
@@ -0,0 +1,296 @@
+//! Hedge-to-neutral plan for the front expiry.
+//!
+//! Every open position settling at the soonest expiry is reduced to its BTC
+//! delta: the change in its model value per $1 move in BTC. The plan first
+//! closes positions that add to the net delta whenever closing brings it closer
+//! to zero, then buys the opposing side of the listed strike with the most
+//! delta per contract to absorb what is left.
+//!
+//! `basilisk hedge <position_id>` works on one position instead: [`offsets`]
+//! prices the opposite side at its own strike and the strikes next to it.
+
+use basilisk_quant::pricing;
+use chrono::{DateTime, Utc};
+
+use crate::api::models::{Contract, Position};
+use crate::api::parse_timestamp;
+use crate::config::ExposureConfig;
+use crate::exposure::ExposureBook;
+use crate::fees;
+use crate::sizing;
+
+/// BTC move the deltas are quoted against in the plan
+pub const QUOTE_MOVE: f64 = 100.0;
+
+/// Offsets needing more contracts than this per contract held are left out:
+/// far from the money there is too little delta to hedge with
+const MAX_OFFSET_MULTIPLE: f64 = 10.0;
+
+#[derive(Debug, Clone)]
+pub enum HedgeLeg {
+    Close {
+        position: Position,
+        delta: f64,
+    },
+    Open {
+        contract: Contract,
+        direction: &'static str,
+        contracts: i32,
+        price: Option<f64>,
+        delta: f64,
+    },
+}
+
+impl HedgeLeg {
+    /// Net delta this leg adds to the book
+    pub fn delta_change(&self) -> f64 {
+        match self {
+            HedgeLeg::Close { delta, .. } => -delta,
+            HedgeLeg::Open { delta, .. } => *delta,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            HedgeLeg::Close { position, .. } => format!(
+                "Close #{} {} {} x{}",
+                position.trade_id, position.ticker, position.direction, position.contracts
+            ),
+            HedgeLeg::Open {
+                contract,
+                direction,
+                contracts,
+                price,
+                ..
+            } => format!(
+                "Buy {} {} x{} @ {}",
+                contract.ticker,
+                direction,
+                contracts,
+                price
+                    .map(|p| format!("${:.2}", p))
+                    .unwrap_or_else(|| "market".to_string())
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HedgePlan {
+    pub expiry: DateTime<Utc>,
+    pub positions: usize,
+    /// Net delta before and after the plan
+    pub net_delta: f64,
+    pub residual: f64,
+    pub legs: Vec<HedgeLeg>,
+    /// Why the opening leg can't be placed (exposure cap)
+    pub blocked: Option<String>,
+}
+
+impl HedgePlan {
+    pub fn expiry_label(&self) -> String {
+        self.expiry.format("%H:%M UTC").to_string()
+    }
+
+    pub fn can_execute(&self) -> bool {
+        !self.legs.is_empty() && self.blocked.is_none()
+    }
+}
+
+/// BTC delta of a whole position
+pub fn position_delta(position: &Position, spot: f64, vol: f64) -> f64 {
+    let hours = position.hours_to_expiry().unwrap_or(0.0);
+    pricing::binary_delta(&position.direction, spot, position.strike, vol, hours)
+        * position.contracts as f64
+}
+
+/// Plan for the soonest expiry with open positions (None when nothing is open)
+pub fn plan(
+    positions: &[Position],
+    contracts: &[Contract],
+    spot: f64,
+    vol: f64,
+    exposure: &ExposureConfig,
+) -> Option<HedgePlan> {
+    let expiry = positions
+        .iter()
+        .filter_map(|p| p.expiry_at.as_deref().and_then(parse_timestamp))
+        .filter(|at| *at > Utc::now())
+        .min()?;
+    let at_expiry: Vec<&Position> = positions
+        .iter()
+        .filter(|p| p.expiry_at.as_deref().and_then(parse_timestamp) == Some(expiry))
+        .collect();
+
+    let net_delta = at_expiry
+        .iter()
+        .fold(0.0, |total, p| total + position_delta(p, spot, vol));
+    let mut remaining = net_delta;
+    let mut legs = Vec::new();
+
+    // Close positions leaning the same way, biggest first, while it helps
+    let mut leaning: Vec<(&Position, f64)> = at_expiry
+        .iter()
+        .map(|p| (*p, position_delta(p, spot, vol)))
+        .filter(|(_, delta)| delta.signum() == net_delta.signum())
+        .collect();
+    leaning.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()));
+    for (position, delta) in leaning {
+        if (remaining - delta).abs() < remaining.abs() {
+            remaining -= delta;
+            legs.push(HedgeLeg::Close {
+                position: position.clone(),
+                delta,
+            });
+        }
+    }
+
+    // Offset the rest with the strike whose opposing side carries the most delta
+    let hedge = contracts
+        .iter()
+        .filter(|c| c.expiry_time.as_deref().and_then(parse_timestamp) == Some(expiry))
+        .filter_map(|c| {
+            let strike = c.strike_price?;
+            let hours = c.seconds_to_expiry()? as f64 / 3600.0;
+            let direction = if remaining > 0.0 { "NO" } else { "YES" };
+            let delta = pricing::binary_delta(direction, spot, strike, vol, hours);
+            Some((c, direction, delta))
+        })
+        .filter(|(_, _, delta)| *delta != 0.0)
+        .max_by(|a, b| a.2.abs().total_cmp(&b.2.abs()));
+
+    let mut blocked = None;
+    if let Some((contract, direction, delta)) = hedge {
+        let count = (remaining.abs() / delta.abs()).round() as i32;
+        if count > 0 {
+            let price = if direction == "NO" {
+                contract.no_price
+            } else {
+                contract.yes_price
+            }
+            .or_else(|| sizing::entry_price(contract));
+
+            // Checked against today's book; the closes above would only free room
+            let notional = price.unwrap_or(0.0) * count as f64;
+            blocked = ExposureBook::from_positions(positions)
+                .check(exposure, contract.expiry_time.as_deref(), notional)
+                .err();
+
+            remaining += delta * count as f64;
+            legs.push(HedgeLeg::Open {
+                contract: contract.clone(),
+                direction,
+                contracts: count,
+                price,
+                delta: delta * count as f64,
+            });
+        }
+    }
+
+    Some(HedgePlan {
+        expiry,
+        positions: at_expiry.len(),
+        net_delta,
+        residual: remaining,
+        legs,
+        blocked,
+    })
+}
+
+/// Offsetting buy for a single position
+#[derive(Debug, Clone)]
+pub struct Offset {
+    pub contract: Contract,
+    pub direction: &'static str,
+    pub contracts: i32,
+    pub price: Option<f64>,
+    /// Net delta of the position and the offset together
+    pub residual: f64,
+    /// Same strike as the position: each pair pays $1 whatever happens
+    pub locks: bool,
+}
+
+impl Offset {
+    /// Dollars to buy the offset, taker fees included
+    pub fn cost(&self) -> Option<f64> {
+        let price = self.price?;
+        Some(price * self.contracts as f64 + fees::taker_fee(self.contracts, price))
+    }
+
+    /// Settlement P&L a same-strike offset locks in for the hedged contracts
+    pub fn locked_pnl(&self, position: &Position) -> Option<f64> {
+        if !self.locks {
+            return None;
+        }
+        let paid = position.entry_price * self.contracts as f64;
+        Some(self.contracts as f64 - paid - self.cost()?)
+    }
+}
+
+/// Opposite-side buys at the position's strike and up to `adjacent` listed
+/// strikes either side of it, each sized to bring the position's delta to zero
+/// (a same-strike offset matches the position one for one)
+pub fn offsets(
+    position: &Position,
+    contracts: &[Contract],
+    spot: f64,
+    vol: f64,
+    adjacent: usize,
+) -> Vec<Offset> {
+    let Some(expiry) = position.expiry_at.as_deref().and_then(parse_timestamp) else {
+        return Vec::new();
+    };
+    let mut listed: Vec<(&Contract, f64)> = contracts
+        .iter()
+        .filter(|c| c.expiry_time.as_deref().and_then(parse_timestamp) == Some(expiry))
+        .filter_map(|c| Some((c, c.strike_price?)))
+        .collect();
+    listed.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let distance = |i: usize| (listed[i].1 - position.strike).abs();
+    let Some(nearest) = (0..listed.len()).min_by(|&a, &b| distance(a).total_cmp(&distance(b)))
+    else {
+        return Vec::new();
+    };
+    let window =
+        &listed[nearest.saturating_sub(adjacent)..(nearest + adjacent + 1).min(listed.len())];
+
+    let exposure = position_delta(position, spot, vol);
+    let hours = position.hours_to_expiry().unwrap_or(0.0);
+    let direction = if position.direction.eq_ignore_ascii_case("NO") {
+        "YES"
+    } else {
+        "NO"
+    };
+    window
+        .iter()
+        .filter_map(|&(contract, strike)| {
+            let per_contract = pricing::binary_delta(direction, spot, strike, vol, hours);
+            if per_contract == 0.0 {
+                return None;
+            }
+            let locks = (strike - position.strike).abs() < 0.5;
+            let needed = (exposure / per_contract).abs().round();
+            if !locks && needed > MAX_OFFSET_MULTIPLE * position.contracts as f64 {
+                return None;
+            }
+            let count = if locks {
+                position.contracts
+            } else {
+                (needed as i32).max(1)
+            };
+            let price = if direction == "NO" {
+                contract.no_price
+            } else {
+                contract.yes_price
+            };
+            Some(Offset {
+                contract: contract.clone(),
+                direction,
+                contracts: count,
+                price,
+                residual: exposure + per_contract * count as f64,
+                locks,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,212 @@
+//! Shell commands run on dashboard events (`[hooks]` in config.toml).
+//!
+//! Each event can be mapped to a command, run with `sh -c` and handed a JSON
+//! document on stdin:
+//!
+//! ```json
+//! { "event": "trade_filled", "timestamp": "2026-01-05T14:32:10Z", "data": { ... } }
+//! ```
+//!
+//! Commands run in the background with their output discarded, so a slow
+//! script or a sound never holds up the dashboard. A command that fails to
+//! start or exits non-zero is reported in the log pane.
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::api::models::TradeResponse;
+use crate::api::VolatilityData;
+use crate::config::HooksConfig;
+use crate::rules::{PriceRule, RuleStatus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    TradeFilled,
+    StopTriggered,
+    RegimeChange,
+    StreamDown,
+}
+
+impl HookEvent {
+    /// Name in `[hooks]` and in the payload's `event`
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookEvent::TradeFilled => "trade_filled",
+            HookEvent::StopTriggered => "stop_triggered",
+            HookEvent::RegimeChange => "regime_change",
+            HookEvent::StreamDown => "stream_down",
+        }
+    }
+}
+
+pub struct Hooks {
+    config: HooksConfig,
+    /// Regime at the last check, so only changes fire
+    last_regime: Option<String>,
+    /// When the stream went down, and whether this outage has fired yet
+    down_since: Option<Instant>,
+    down_fired: bool,
+    /// Failures from the background runs, drained into the log pane
+    failures: Arc<Mutex<Vec<String>>>,
+}
+
+impl Hooks {
+    pub fn new(config: &HooksConfig) -> Self {
+        Self {
+            config: config.clone(),
+            last_regime: None,
+            down_since: None,
+            down_fired: false,
+            failures: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn command(&self, event: HookEvent) -> Option<&str> {
+        match event {
+            HookEvent::TradeFilled => self.config.trade_filled.as_deref(),
+            HookEvent::StopTriggered => self.config.stop_triggered.as_deref(),
+            HookEvent::RegimeChange => self.config.regime_change.as_deref(),
+            HookEvent::StreamDown => self.config.stream_down.as_deref(),
+        }
+        .filter(|command| !command.trim().is_empty())
+    }
+
+    /// Run the event's command, if one is configured, with `data` in the payload
+    pub fn fire(&self, event: HookEvent, data: Value) {
+        let Some(command) = self.command(event) else {
+            return;
+        };
+        let command = command.to_string();
+        let payload = json!({
+            "event": event.name(),
+            "timestamp": Utc::now().to_rfc3339(),
+            "data": data,
+        })
+        .to_string();
+
+        let failures = Arc::clone(&self.failures);
+        std::thread::spawn(move || {
+            if let Err(e) = run(&command, event, &payload) {
+                if let Ok(mut failures) = failures.lock() {
+                    failures.push(format!("{} hook: {:#}", event.name(), e));
+                }
+            }
+        });
+    }
+
+    /// An order or close went out; fires only when something filled
+    pub fn trade(&self, description: &str, response: &TradeResponse) {
+        if !response.success || response.filled <= 0 {
+            return;
+        }
+        self.fire(
+            HookEvent::TradeFilled,
+            json!({
+                "description": description,
+                "trade_id": response.trade_id,
+                "order_id": response.order_id,
+                "filled": response.filled,
+                "price": response.price,
+                "cost": response.cost,
+            }),
+        );
+    }
+
+    /// A price rule's trigger was hit; `status` is how its order went
+    pub fn rule_fired(&self, rule: &PriceRule, status: &RuleStatus) {
+        self.fire(
+            HookEvent::StopTriggered,
+            json!({
+                "rule_id": rule.id,
+                "rule": rule.describe(),
+                "direction": rule.direction,
+                "strike": rule.strike,
+                "contracts": rule.contracts,
+                "trigger": rule.trigger,
+                "status": status,
+            }),
+        );
+    }
+
+    /// Latest volatility; fires when the regime differs from the last reading
+    /// (not on the first one after launch)
+    pub fn volatility(&mut self, volatility: &VolatilityData) {
+        if volatility.regime.is_empty() {
+            return;
+        }
+        match self.last_regime.as_deref() {
+            Some(previous) if previous != volatility.regime => {
+                self.fire(
+                    HookEvent::RegimeChange,
+                    json!({
+                        "from": previous,
+                        "to": volatility.regime,
+                        "realized_vol": volatility.realized_vol,
+                        "implied_vol": volatility.implied_vol,
+                        "vol_premium_pct": volatility.vol_premium_pct,
+                    }),
+                );
+            }
+            Some(_) => return,
+            None => {}
+        }
+        self.last_regime = Some(volatility.regime.clone());
+    }
+
+    /// Stream status, checked every loop; fires once per outage that lasts
+    /// `stream_down_secs`
+    pub fn stream(&mut self, connected: bool) {
+        if connected {
+            self.down_since = None;
+            self.down_fired = false;
+            return;
+        }
+        let since = *self.down_since.get_or_insert_with(Instant::now);
+        let threshold = Duration::from_secs(self.config.stream_down_secs);
+        if !self.down_fired && since.elapsed() >= threshold {
+            self.down_fired = true;
+            self.fire(
+                HookEvent::StreamDown,
+                json!({ "down_secs": since.elapsed().as_secs() }),
+            );
+        }
+    }
+
+    /// Failures reported since the last call
+    pub fn take_failures(&self) -> Vec<String> {
+        self.failures
+            .lock()
+            .map(|mut failures| std::mem::take(&mut *failures))
+            .unwrap_or_default()
+    }
+}
+
+fn run(command: &str, event: HookEvent, payload: &str) -> Result<()> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("BASILISK_HOOK_EVENT", event.name())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("failed to start `{}`", command))?;
+
+    // Commands that ignore stdin may exit before reading it; that's fine
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(payload.as_bytes());
+    }
+
+    let status = child
+        .wait()
+        .with_context(|| format!("failed to wait for `{}`", command))?;
+    if !status.success() {
+        bail!("`{}` exited with {}", command, status);
+    }
+    Ok(())
+}
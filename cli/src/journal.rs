@@ -0,0 +1,132 @@
+//! Local trade journal: one `journal.jsonl` line per order Basilisk submits,
+//! recording where the trade came from so P&L can be attributed later.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::api::models::TradeResponse;
+use crate::checklist::ChecklistRecord;
+use crate::store;
+
+const JOURNAL_FILE: &str = "journal.jsonl";
+
+/// What decided to place the trade
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Origin {
+    /// A backend signal traded by hand (CLI `trade` or the dashboard)
+    Signal { signal_id: i32 },
+    /// `manual` order with user-chosen strike/direction
+    Manual,
+    /// Auto-trader entry rule
+    Auto { rule: String },
+    /// Auto-trader entry chosen by the user's script filter
+    Script,
+    /// Price rule fired by the dashboard
+    Rule { id: u32 },
+}
+
+impl Origin {
+    /// Grouping key for attribution reports
+    pub fn label(&self) -> String {
+        match self {
+            Origin::Signal { .. } => "signal".to_string(),
+            Origin::Manual => "manual".to_string(),
+            Origin::Auto { rule } => format!("auto:{}", rule),
+            Origin::Script => "script".to_string(),
+            Origin::Rule { id } => format!("rule:{}", id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub timestamp: DateTime<Utc>,
+    pub trade_id: Option<i32>,
+    pub order_id: Option<String>,
+    pub ticker: String,
+    pub origin: Origin,
+    /// Free-form strategy tag (`--tag`)
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Why the trade was placed (`--note`)
+    #[serde(default)]
+    pub note: Option<String>,
+    pub contracts: i32,
+    pub price: Option<f64>,
+    /// Pre-trade checklist answers, when the dashboard asked for them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<ChecklistRecord>,
+}
+
+/// Journal a successful order (failed ones never reach the account)
+pub fn record(
+    ticker: &str,
+    origin: Origin,
+    tag: Option<&str>,
+    response: &TradeResponse,
+) -> Result<()> {
+    append(ticker, origin, tag, None, response, None)
+}
+
+/// Journal a successful order with a note
+pub fn record_noted(
+    ticker: &str,
+    origin: Origin,
+    tag: Option<&str>,
+    note: Option<&str>,
+    response: &TradeResponse,
+) -> Result<()> {
+    append(ticker, origin, tag, note, response, None)
+}
+
+/// Journal a successful order together with its pre-trade checklist
+pub fn record_checked(
+    ticker: &str,
+    origin: Origin,
+    tag: Option<&str>,
+    response: &TradeResponse,
+    checklist: Option<ChecklistRecord>,
+) -> Result<()> {
+    append(ticker, origin, tag, None, response, checklist)
+}
+
+fn append(
+    ticker: &str,
+    origin: Origin,
+    tag: Option<&str>,
+    note: Option<&str>,
+    response: &TradeResponse,
+    checklist: Option<ChecklistRecord>,
+) -> Result<()> {
+    if !response.success {
+        return Ok(());
+    }
+    let entry = JournalEntry {
+        timestamp: Utc::now(),
+        trade_id: response.trade_id,
+        order_id: response.order_id.clone(),
+        ticker: ticker.to_string(),
+        origin,
+        tag: tag.map(str::to_string),
+        note: note.map(str::to_string),
+        contracts: response.filled,
+        price: response.price,
+        checklist,
+    };
+    store::append_jsonl(JOURNAL_FILE, &entry)
+}
+
+pub fn load() -> Result<Vec<JournalEntry>> {
+    store::read_jsonl(JOURNAL_FILE)
+}
+
+/// Latest journal entry for each trade ID
+pub fn by_trade() -> Result<HashMap<i32, JournalEntry>> {
+    Ok(load()?
+        .into_iter()
+        .filter_map(|entry| Some((entry.trade_id?, entry)))
+        .collect())
+}
@@ -0,0 +1,185 @@
+//! How tradeable each strike is, from two sources.
+//!
+//! Market impact: every `[liquidity] sample_secs` the dashboard fetches the
+//! book for each active signal and walks the offers on the signal's side with
+//! `default_size` contracts. A strike is flagged in the signals table when that
+//! order would push the price more than `max_impact_pct` above the best offer,
+//! or when the book can't fill it at all.
+//!
+//! Liquidity score: the backend relays Kalshi's ticker channel on the stream
+//! (`ticker_update`), which carries each market's cumulative volume and open
+//! interest. [`VolumeTracker`] turns those into contracts traded over the last
+//! `volume_window_minutes` and a 0-100 score shown in the Liq column; the
+//! auto-trader skips strikes scoring under `auto_min_score`.
+
+use anyhow::Result;
+use eventsource_client as es;
+use futures::StreamExt;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::api::models::OrderBook;
+use crate::config::LiquidityConfig;
+
+/// Share of the score from recent volume; open interest makes up the rest
+const VOLUME_WEIGHT: f64 = 0.7;
+
+/// What filling one order against the book would look like
+#[derive(Debug, Clone, Copy)]
+pub struct Impact {
+    /// Best offer, in cents
+    pub best: i32,
+    /// Worst level the order reaches, in cents
+    pub worst: i32,
+    /// Average fill price, in cents
+    pub average: f64,
+    /// Contracts the book can fill (fewer than asked = not enough depth)
+    pub filled: i32,
+    pub size: i32,
+}
+
+impl Impact {
+    /// Walk the offers for a buyer of the `no` (or YES) side; None when nothing is offered
+    pub fn estimate(book: &OrderBook, no: bool, size: i32) -> Option<Self> {
+        let offers = book.offers(no);
+        let best = offers.first()?.0;
+
+        let mut worst = best;
+        let mut filled = 0;
+        let mut cost = 0.0;
+        for (price, qty) in offers {
+            if filled >= size {
+                break;
+            }
+            let take = qty.min(size - filled);
+            filled += take;
+            cost += (price * take) as f64;
+            worst = price;
+        }
+
+        Some(Self {
+            best,
+            worst,
+            average: cost / filled.max(1) as f64,
+            filled,
+            size,
+        })
+    }
+
+    /// How far the order moves the price, as a percentage of the best offer
+    pub fn move_pct(&self) -> f64 {
+        (self.worst - self.best) as f64 / self.best.max(1) as f64 * 100.0
+    }
+
+    pub fn is_illiquid(&self, config: &LiquidityConfig) -> bool {
+        self.filled < self.size || self.move_pct() > config.max_impact_pct
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct VolumeSample {
+    at: Instant,
+    volume: i64,
+    open_interest: i64,
+}
+
+/// Recent ticker-channel volume and open interest per market
+#[derive(Debug)]
+pub struct VolumeTracker {
+    window: Duration,
+    samples: HashMap<String, VecDeque<VolumeSample>>,
+}
+
+impl VolumeTracker {
+    pub fn new(config: &LiquidityConfig) -> Self {
+        Self {
+            window: Duration::from_secs(config.volume_window_minutes * 60),
+            samples: HashMap::new(),
+        }
+    }
+
+    /// Record a ticker update (volume is cumulative for the market)
+    pub fn record(&mut self, ticker: &str, volume: i64, open_interest: i64) {
+        let now = Instant::now();
+        let samples = self.samples.entry(ticker.to_string()).or_default();
+        samples.push_back(VolumeSample {
+            at: now,
+            volume,
+            open_interest,
+        });
+        // Keep the newest sample from before the window as the baseline
+        while samples.len() > 2 && now.duration_since(samples[1].at) >= self.window {
+            samples.pop_front();
+        }
+    }
+
+    /// Contracts traded over the window (less while the tracker is younger than it)
+    pub fn recent_volume(&self, ticker: &str) -> Option<i64> {
+        let samples = self.samples.get(ticker)?;
+        let (first, last) = (samples.front()?, samples.back()?);
+        Some((last.volume - first.volume).max(0))
+    }
+
+    pub fn open_interest(&self, ticker: &str) -> Option<i64> {
+        self.samples.get(ticker)?.back().map(|s| s.open_interest)
+    }
+
+    /// 0-100: recent volume against `full_volume`, open interest against
+    /// `full_open_interest`. None until the market has had a ticker update.
+    pub fn score(&self, ticker: &str, config: &LiquidityConfig) -> Option<f64> {
+        let volume = self.recent_volume(ticker)? as f64;
+        let open_interest = self.open_interest(ticker)? as f64;
+        let volume_part = (volume / config.full_volume.max(1) as f64).min(1.0);
+        let interest_part = (open_interest / config.full_open_interest.max(1) as f64).min(1.0);
+        Some((VOLUME_WEIGHT * volume_part + (1.0 - VOLUME_WEIGHT) * interest_part) * 100.0)
+    }
+}
+
+/// Follow the stream's ticker updates into `tracker` until the process exits,
+/// reconnecting on errors (the headless auto-trader has no other stream)
+pub fn spawn_volume_listener(api_url: &str, asset: &str, tracker: Arc<Mutex<VolumeTracker>>) {
+    let stream_url = format!("{}/api/v1/stream/{}", api_url, asset.to_lowercase());
+    tokio::spawn(async move {
+        loop {
+            // Errors only mean a reconnect; the auto-trader logs skips for missing data
+            listen(&stream_url, &tracker).await.ok();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        }
+    });
+}
+
+async fn listen(stream_url: &str, tracker: &Mutex<VolumeTracker>) -> Result<()> {
+    use es::Client;
+
+    let client = es::ClientBuilder::for_url(stream_url)?
+        .header("Accept", "text/event-stream")?
+        .build();
+    let mut stream = Box::pin(client.stream());
+
+    while let Some(event) = stream.next().await {
+        if let es::SSE::Event(event) = event? {
+            if event.event_type != "ticker_update" {
+                continue;
+            }
+            if let Some((ticker, volume, open_interest)) = parse_ticker_update(&event.data) {
+                if let Ok(mut tracker) = tracker.lock() {
+                    tracker.record(&ticker, volume, open_interest);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Market, cumulative volume and open interest from a `ticker_update` event
+pub fn parse_ticker_update(data: &str) -> Option<(String, i64, i64)> {
+    let data: serde_json::Value = serde_json::from_str(data).ok()?;
+    Some((
+        data.get("ticker")?.as_str()?.to_string(),
+        data.get("volume").and_then(|v| v.as_i64()).unwrap_or(0),
+        data.get("open_interest")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+    ))
+}
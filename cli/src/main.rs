@@ -1,20 +1,110 @@
+mod alerts;
+mod amend;
 mod api;
 mod app;
+mod archive;
+mod attribution;
+mod audit;
+mod auto;
+mod backtest;
+mod bankroll;
+mod batch;
+mod brackets;
+mod chart_history;
+mod checklist;
+mod compare;
+mod config;
+mod connection;
+mod consistency;
+mod correlation;
+mod diary;
+mod distribution;
+mod event_log;
 mod events;
+mod expiry_guard;
+mod exposure;
+mod feed_latency;
+mod fees;
+mod governor;
+mod hedge;
+mod hooks;
+mod journal;
+mod liquidity;
+mod market_hours;
+mod mini;
+mod model_override;
+mod momentum;
+mod monitor;
+mod mute;
+mod order_guard;
+mod paper;
+mod pins;
+mod planner;
+mod plugins;
+mod portfolio;
+mod preview;
+mod reentry;
+mod replay;
+mod report;
+mod rollover;
+mod rules;
+mod score;
+mod scripting;
+mod sizing;
+mod sizing_sim;
+mod soak;
+mod spread;
+mod store;
+mod stream_stats;
+mod stress;
+mod tick_cache;
+mod tilt;
 mod trading;
 mod ui;
+mod vol_pattern;
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc, Weekday};
 use clap::{Parser, Subcommand};
 use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
+use ratatui::{
+    backend::{CrosstermBackend, TestBackend},
+    Terminal,
+};
+use std::fs;
 use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
 
+use alerts::{handle_alerts_command, AlertsCommands};
+use api::client::ApiClient;
 use app::App;
-use trading::{handle_trading_command, TradingCommands};
+use archive::{handle_archive_command, ArchiveQuery, Outcome};
+use attribution::{handle_attribution_command, GroupBy};
+use audit::{handle_audit_command, parse_since, AuditKind};
+use auto::handle_auto_command;
+use backtest::{handle_backtest_command, BacktestConfig};
+use bankroll::{handle_balance_command, handle_funding_command, FundingCommands};
+use brackets::{handle_brackets_command, BracketsCommands};
+use config::{Config, LayoutMode, ThemeName};
+use correlation::handle_correlation_command;
+use expiry_guard::handle_guard_command;
+use exposure::handle_exposure_command;
+use mini::MiniApp;
+use monitor::{handle_monitor_command, parse_loss_limit, LossLimit};
+use plugins::handle_plugin_command;
+use report::handle_report_command;
+use rules::{handle_rules_command, RulesCommands};
+use scripting::{handle_script_command, ScriptCommands};
+use sizing_sim::{handle_sizing_sim_command, SimConfig};
+use soak::{parse_duration, Soak};
+use stress::{handle_stress_command, parse_shock};
+use trading::{handle_trading_command, OutputFormat, TradingCommands};
+use vol_pattern::handle_vol_pattern_command;
 
 #[derive(Parser, Debug)]
 #[command(name = "basilisk")]
@@ -24,9 +114,43 @@ struct Args {
     #[arg(long, default_value = "http://localhost:8000", global = true)]
     api_url: String,
 
-    /// Refresh interval in seconds (for TUI mode)
-    #[arg(long, default_value = "30", global = true)]
-    refresh: u64,
+    /// Refresh interval in seconds (for TUI mode; overrides [polling] interval_secs)
+    #[arg(long, global = true)]
+    refresh: Option<u64>,
+
+    /// Spectator mode: disable every order-placing command and TUI key
+    #[arg(long, global = true)]
+    read_only: bool,
+
+    /// Preview trade, manual and close (cost, fees, max payout and loss) without sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Resend an order under the same client order ID after a timeout or gateway error
+    /// (needs a backend that deduplicates client order IDs)
+    #[arg(long, global = true)]
+    retry: bool,
+
+    /// Print positions, orders, history, pnl and order results as a table, JSON or CSV
+    #[arg(long, value_enum, default_value = "table", global = true)]
+    output: OutputFormat,
+
+    /// Dashboard layout; auto picks compact, standard or wide from the terminal size
+    #[arg(long, value_enum, global = true)]
+    layout: Option<LayoutMode>,
+
+    /// Color palette; overrides [layout] theme (also `u` on the dashboard)
+    #[arg(long, value_enum, global = true)]
+    theme: Option<ThemeName>,
+
+    /// Start the dashboard without the volatility banner (also `b`)
+    #[arg(long, global = true)]
+    no_banner: bool,
+
+    /// Run the dashboard headless for this long (e.g. 4h), failing on memory
+    /// growth, task leaks, stream backlogs or reconnect storms
+    #[arg(long, hide = true, value_parser = parse_duration)]
+    soak: Option<Duration>,
 
     #[command(subcommand)]
     command: Option<Commands>,
@@ -38,102 +162,579 @@ enum Commands {
     #[command(name = "dashboard", alias = "tui")]
     Dashboard,
 
-    /// Execute a trade from a signal
-    #[command(name = "trade")]
-    Trade {
-        /// Signal ID to trade
-        signal_id: i32,
-        /// Number of contracts
-        #[arg(short, long, default_value = "1")]
-        size: i32,
+    /// Compact four-line view (BTC, regime, best signal, open P&L) for a small pane
+    #[command(name = "mini")]
+    Mini,
+
+    #[command(flatten)]
+    Trading(TradingCommands),
+
+    /// Simulate settlement P&L of an exported positions file
+    #[command(name = "backtest")]
+    Backtest {
+        /// File written by `positions export` (.json or .csv)
+        file: PathBuf,
+        /// Number of simulated BTC paths
+        #[arg(long, default_value = "10000")]
+        paths: usize,
+        /// Override the spot price recorded at export time
+        #[arg(long)]
+        spot: Option<f64>,
+        /// Override the annualized volatility (e.g. 0.55 for 55%)
+        #[arg(long)]
+        vol: Option<f64>,
+        /// RNG seed for reproducible runs
+        #[arg(long)]
+        seed: Option<u64>,
     },
 
-    /// List open positions
-    #[command(name = "positions")]
-    Positions,
+    /// Simulate bankroll growth under each sizing scheme from past trade outcomes
+    #[command(name = "sizing-sim")]
+    SizingSim {
+        /// Number of simulated bankroll paths per scheme
+        #[arg(long, default_value = "10000")]
+        paths: usize,
+        /// Trades per path
+        #[arg(long, default_value = "100")]
+        trades: usize,
+        /// Starting bankroll in dollars (defaults to account equity)
+        #[arg(long)]
+        bankroll: Option<f64>,
+        /// Count a path as ruined once it drops below this fraction of the start
+        #[arg(long, default_value = "0.25")]
+        ruin_below: f64,
+        /// RNG seed for reproducible runs
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+
+    /// Reprice open positions under spot/vol shocks
+    #[command(name = "stress")]
+    Stress {
+        /// Spot move, e.g. -3% (repeatable)
+        #[arg(long = "move", value_parser = parse_shock, allow_hyphen_values = true)]
+        moves: Vec<f64>,
+        /// Relative vol shock, e.g. +20% (repeatable)
+        #[arg(long = "vol", value_parser = parse_shock, allow_hyphen_values = true)]
+        vols: Vec<f64>,
+        /// Stress an exported positions file instead of live positions
+        #[arg(long)]
+        from: Option<PathBuf>,
+    },
+
+    /// Split realized P&L by trade origin or strategy tag
+    #[command(name = "attribution")]
+    Attribution {
+        /// Group trades by origin (signal/manual/auto/script) or by --tag
+        #[arg(long, value_enum, default_value = "origin")]
+        by: GroupBy,
+    },
+
+    /// Today's hourly realized vol vs the average for the same weekday
+    #[command(name = "vol-pattern")]
+    VolPattern {
+        /// Weekday to compare against (defaults to today's)
+        #[arg(long)]
+        weekday: Option<Weekday>,
+    },
+
+    /// Signal hit rate by concurrent BTC momentum, from recorded settlements
+    #[command(name = "correlation")]
+    Correlation,
+
+    /// Write a shareable markdown report of one settled hour
+    #[command(name = "report")]
+    Report {
+        /// Expiry hour in UTC (0-23); defaults to the hour that settled last
+        #[arg(long)]
+        hour: Option<u32>,
+        /// Day of the expiry (YYYY-MM-DD, UTC); defaults to today
+        #[arg(long)]
+        date: Option<NaiveDate>,
+        /// Markdown file to write (prints to stdout without it)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Search archived contracts from past hours and how they settled
+    #[command(name = "archive")]
+    Archive {
+        /// Expiry date (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        date: Option<NaiveDate>,
+        /// Expiry hour in UTC (0-23)
+        #[arg(long)]
+        hour: Option<u32>,
+        /// Strike price
+        #[arg(long)]
+        strike: Option<f64>,
+        /// Settlement result, or whether the final signal was right
+        #[arg(long, value_enum)]
+        outcome: Option<Outcome>,
+        /// Show one contract's snapshots through its hour instead
+        #[arg(long)]
+        ticker: Option<String>,
+        /// Number of contracts to list (newest expiry first)
+        #[arg(short, long, default_value = "50")]
+        limit: usize,
+    },
+
+    /// Show the local audit log of account actions
+    #[command(name = "audit")]
+    Audit {
+        /// Number of events to show (most recent)
+        #[arg(short, long, default_value = "50")]
+        limit: usize,
+        /// Only show one kind of event
+        #[arg(long, value_enum)]
+        kind: Option<AuditKind>,
+        /// Only show events on or after this date (YYYY-MM-DD, local time)
+        #[arg(long, value_parser = parse_since)]
+        since: Option<DateTime<Utc>>,
+        /// Print each event's recorded details (order responses, config)
+        #[arg(long)]
+        details: bool,
+    },
+
+    /// Show buying power, exposure in open positions and pending settlement
+    #[command(name = "balance")]
+    Balance,
+
+    /// Break open positions down by expiry and side: contracts, value at risk and max loss
+    #[command(name = "exposure")]
+    Exposure,
 
-    /// Close a position
-    #[command(name = "close")]
-    Close {
-        /// Position/trade ID to close
-        position_id: i32,
+    /// Record deposits/withdrawals and show return on capital
+    #[command(name = "funding", subcommand)]
+    Funding(FundingCommands),
+
+    /// Review and acknowledge triggered alerts
+    #[command(name = "alerts", subcommand)]
+    Alerts(AlertsCommands),
+
+    /// Price-triggered orders, fired by a running dashboard
+    #[command(name = "rules", subcommand)]
+    Rules(RulesCommands),
+
+    /// Close open positions before their contract's settlement window (headless)
+    #[command(name = "guard")]
+    Guard {
+        /// Close positions with this long or less to expiry (e.g. 3m, 90s)
+        #[arg(long, value_parser = parse_duration)]
+        close_before_expiry: Duration,
+        /// Position ID to leave open (repeatable)
+        #[arg(long, value_name = "POSITION_ID")]
+        keep: Vec<i32>,
+        /// Seconds between position checks
+        #[arg(long, default_value = "10")]
+        interval: u64,
+        /// Check once and exit
+        #[arg(long)]
+        once: bool,
     },
 
-    /// Show P&L summary
-    #[command(name = "pnl")]
-    Pnl {
-        /// Period: today, week, or all
-        #[arg(default_value = "today")]
-        period: String,
+    /// Close any open position whose unrealized loss reaches a limit (headless)
+    #[command(name = "monitor")]
+    Monitor {
+        /// Loss that closes a position: percent of its cost (30%) or dollars ($25)
+        #[arg(long, value_parser = parse_loss_limit)]
+        stop_loss: LossLimit,
+        /// Raise a dashboard alert and run the trade_filled hook on each close
+        #[arg(long)]
+        notify: bool,
+        /// Position ID to leave open (repeatable)
+        #[arg(long, value_name = "POSITION_ID")]
+        keep: Vec<i32>,
+        /// Seconds between position checks
+        #[arg(long, default_value = "5")]
+        interval: u64,
+        /// Check once and exit
+        #[arg(long)]
+        once: bool,
     },
 
-    /// Show trade history
-    #[command(name = "history")]
-    History {
-        /// Number of trades to show
-        #[arg(short, long, default_value = "20")]
-        limit: i32,
+    /// Take-profit/stop-loss exits from `trade --take-profit/--stop-loss`
+    #[command(name = "brackets", subcommand)]
+    Brackets(BracketsCommands),
+
+    /// Run the auto-trader (headless)
+    #[command(name = "auto")]
+    Auto {
+        /// Run a single decision cycle and exit
+        #[arg(long)]
+        once: bool,
+        /// Clear a tripped kill switch before starting
+        #[arg(long)]
+        reset_kill_switch: bool,
+        /// Lift an [auto.pause] suspension before starting
+        #[arg(long)]
+        resume: bool,
+        /// Shadow-trade on a local paper book instead of placing real orders
+        #[arg(long)]
+        paper: bool,
+    },
+
+    /// Inspect the strategy script configured in config.toml
+    #[command(name = "script", subcommand)]
+    Script(ScriptCommands),
+
+    /// Run an external plugin (`basilisk-<name>` on PATH)
+    #[command(name = "x")]
+    Plugin {
+        /// Plugin name, e.g. `report` runs `basilisk-report`
+        name: String,
+        /// Arguments passed through to the plugin
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
     },
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
+    let retry_orders = args.retry;
+    if args.output != OutputFormat::Table && !matches!(args.command, Some(Commands::Trading(_))) {
+        bail!("--output json/csv covers positions, orders, history, pnl, trade, manual, close and cancel");
+    }
 
     match args.command {
         // Trading commands (non-TUI)
-        Some(Commands::Trade { signal_id, size }) => {
-            handle_trading_command(
-                TradingCommands::Trade { signal_id, size },
+        Some(Commands::Trading(cmd)) => {
+            // A dry run sends nothing, so read-only doesn't stop it
+            if cmd.places_orders() && !args.dry_run {
+                ensure_can_trade(args.read_only)?;
+            }
+            handle_trading_command(cmd, &args.api_url, args.dry_run, retry_orders, args.output)
+                .await?;
+        }
+
+        Some(Commands::Backtest {
+            file,
+            paths,
+            spot,
+            vol,
+            seed,
+        }) => {
+            let config = BacktestConfig {
+                paths,
+                spot_override: spot,
+                vol_override: vol,
+                seed,
+            };
+            handle_backtest_command(&file, config)?;
+        }
+
+        Some(Commands::SizingSim {
+            paths,
+            trades,
+            bankroll,
+            ruin_below,
+            seed,
+        }) => {
+            let config = SimConfig {
+                paths,
+                trades,
+                bankroll,
+                ruin_below,
+                seed,
+            };
+            handle_sizing_sim_command(&args.api_url, config).await?;
+        }
+
+        Some(Commands::Stress { moves, vols, from }) => {
+            handle_stress_command(&args.api_url, moves, vols, from.as_deref()).await?;
+        }
+
+        Some(Commands::Balance) => {
+            handle_balance_command(&args.api_url).await?;
+        }
+
+        Some(Commands::Exposure) => {
+            handle_exposure_command(&args.api_url).await?;
+        }
+
+        Some(Commands::Funding(cmd)) => {
+            handle_funding_command(cmd, &args.api_url).await?;
+        }
+
+        Some(Commands::Alerts(cmd)) => {
+            handle_alerts_command(cmd)?;
+        }
+
+        Some(Commands::Guard {
+            close_before_expiry,
+            keep,
+            interval,
+            once,
+        }) => {
+            refuse_dry_run(args.dry_run, "guard", "")?;
+            ensure_can_trade(args.read_only)?;
+            handle_guard_command(
                 &args.api_url,
+                retry_orders,
+                close_before_expiry,
+                keep,
+                interval,
+                once,
+            )
+            .await?;
+        }
+
+        Some(Commands::Monitor {
+            stop_loss,
+            notify,
+            keep,
+            interval,
+            once,
+        }) => {
+            // A dry run only logs breaches, so read-only doesn't stop it
+            if !args.dry_run {
+                ensure_can_trade(args.read_only)?;
+            }
+            let client = ApiClient::new(args.api_url.clone(), 30)?.with_order_retry(retry_orders);
+            handle_monitor_command(
+                client,
+                stop_loss,
+                notify,
+                keep,
+                interval,
+                once,
+                args.dry_run,
             )
             .await?;
         }
 
-        Some(Commands::Positions) => {
-            handle_trading_command(TradingCommands::Positions, &args.api_url).await?;
+        Some(Commands::Brackets(cmd)) => {
+            if cmd.places_orders() {
+                refuse_dry_run(args.dry_run, "brackets watch", "")?;
+                ensure_can_trade(args.read_only)?;
+            }
+            handle_brackets_command(cmd, &args.api_url, retry_orders).await?;
+        }
+
+        Some(Commands::Rules(cmd)) => {
+            if matches!(cmd, RulesCommands::Add { .. }) {
+                ensure_can_trade(args.read_only)?;
+            }
+            handle_rules_command(cmd)?;
+        }
+
+        Some(Commands::Attribution { by }) => {
+            handle_attribution_command(&args.api_url, by).await?;
+        }
+
+        Some(Commands::VolPattern { weekday }) => {
+            handle_vol_pattern_command(weekday)?;
         }
 
-        Some(Commands::Close { position_id }) => {
-            handle_trading_command(
-                TradingCommands::Close { position_id },
+        Some(Commands::Correlation) => {
+            handle_correlation_command()?;
+        }
+
+        Some(Commands::Report { hour, date, out }) => {
+            handle_report_command(&args.api_url, hour, date, out).await?;
+        }
+
+        Some(Commands::Archive {
+            date,
+            hour,
+            strike,
+            outcome,
+            ticker,
+            limit,
+        }) => {
+            let query = ArchiveQuery {
+                date,
+                hour,
+                strike,
+                outcome,
+                ..Default::default()
+            };
+            handle_archive_command(query, ticker, limit)?;
+        }
+
+        Some(Commands::Audit {
+            limit,
+            kind,
+            since,
+            details,
+        }) => {
+            handle_audit_command(limit, kind, since, details)?;
+        }
+
+        Some(Commands::Auto {
+            once,
+            reset_kill_switch,
+            resume,
+            paper,
+        }) => {
+            // Paper runs place no real orders
+            if !paper {
+                refuse_dry_run(args.dry_run, "auto", "; --paper rehearses it")?;
+                ensure_can_trade(args.read_only)?;
+            }
+            handle_auto_command(
                 &args.api_url,
+                retry_orders,
+                once,
+                reset_kill_switch,
+                resume,
+                paper,
             )
             .await?;
         }
 
-        Some(Commands::Pnl { period }) => {
-            handle_trading_command(TradingCommands::Pnl { period }, &args.api_url).await?;
+        Some(Commands::Script(cmd)) => {
+            handle_script_command(cmd, &args.api_url).await?;
         }
 
-        Some(Commands::History { limit }) => {
-            handle_trading_command(TradingCommands::History { limit }, &args.api_url).await?;
+        Some(Commands::Plugin {
+            name,
+            args: plugin_args,
+        }) => {
+            handle_plugin_command(&args.api_url, &name, &plugin_args).await?;
         }
 
         // Dashboard/TUI mode (default)
-        Some(Commands::Dashboard) | None => {
-            run_tui(args.api_url, args.refresh).await?;
+        Some(Commands::Dashboard) | None => match args.soak {
+            Some(duration) => run_soak(args.api_url, args.refresh, args.layout, duration).await?,
+            None => {
+                refuse_dry_run(args.dry_run, "the dashboard", "; --read-only just watches")?;
+                run_tui(
+                    args.api_url,
+                    args.refresh,
+                    args.read_only,
+                    retry_orders,
+                    args.layout,
+                    args.theme,
+                    args.no_banner,
+                )
+                .await?
+            }
+        },
+
+        Some(Commands::Mini) => {
+            run_mini(args.api_url, args.refresh, args.theme).await?;
         }
     }
 
     Ok(())
 }
 
-async fn run_tui(api_url: String, refresh: u64) -> Result<()> {
-    // Initialize terminal
+/// Refuse `--dry-run` on a command that places orders but has no preview,
+/// rather than let it trade for real
+fn refuse_dry_run(dry_run: bool, command: &str, hint: &str) -> Result<()> {
+    if dry_run {
+        bail!(
+            "--dry-run previews trade, manual, close and monitor; {} has no preview and would place real orders{}",
+            command,
+            hint
+        );
+    }
+    Ok(())
+}
+
+/// Refuse order commands under `--read-only` or `read_only = true` in config.toml
+fn ensure_can_trade(read_only: bool) -> Result<()> {
+    let mut config = Config::load()?;
+    config.read_only |= read_only;
+    config.ensure_can_trade()
+}
+
+async fn run_mini(api_url: String, refresh: Option<u64>, theme: Option<ThemeName>) -> Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    let mut app = MiniApp::new(api_url, refresh, theme)?;
+    let res = app.run(&mut terminal).await;
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    if let Err(err) = res {
+        eprintln!("Error: {:?}", err);
+    }
+
+    Ok(())
+}
+
+/// Headless dashboard for `--soak`: renders to an in-memory screen and exits
+/// with an error if the run shows a leak or backlog
+async fn run_soak(
+    api_url: String,
+    refresh: Option<u64>,
+    layout: Option<LayoutMode>,
+    duration: Duration,
+) -> Result<()> {
+    // Hours of simulated data shouldn't land in the real alerts, logs and
+    // archive: the run gets a scratch data directory with only config.toml
+    // copied over, removed again at the end
+    let config = store::path("config.toml")?;
+    let home = std::env::temp_dir().join(format!("basilisk-soak-{}", std::process::id()));
+    fs::create_dir_all(&home).with_context(|| format!("Failed to create {}", home.display()))?;
+    if config.exists() {
+        fs::copy(&config, home.join("config.toml")).context("Failed to copy config.toml")?;
+    }
+    std::env::set_var("BASILISK_HOME", &home);
+
+    let backend = TestBackend::new(soak::SCREEN_WIDTH, soak::SCREEN_HEIGHT);
+    let mut terminal = Terminal::new(backend)?;
+
+    // Read-only, so armed rules can't place orders while nobody is watching
+    let result = async {
+        let mut app = App::new(api_url, refresh, true, true, layout, None, false)?;
+        app.start_soak(Soak::new(duration));
+        println!("🧪 Soak test for {} minutes...", duration.as_secs() / 60);
+        app.run(&mut terminal).await?;
+        app.finish_soak()
+    }
+    .await;
+
+    if let Err(e) = fs::remove_dir_all(&home) {
+        eprintln!("⚠️  Could not remove {}: {}", home.display(), e);
+    }
+    result
+}
+
+async fn run_tui(
+    api_url: String,
+    refresh: Option<u64>,
+    read_only: bool,
+    retry_orders: bool,
+    layout: Option<LayoutMode>,
+    theme: Option<ThemeName>,
+    no_banner: bool,
+) -> Result<()> {
+    // Initialize terminal
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
     // Create and run app
-    let mut app = App::new(api_url, refresh)?;
+    let mut app = App::new(
+        api_url,
+        refresh,
+        read_only,
+        retry_orders,
+        layout,
+        theme,
+        no_banner,
+    )?;
     let res = app.run(&mut terminal).await;
 
     // Restore terminal
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()?;
 
     if let Err(err) = res {
@@ -0,0 +1,109 @@
+//! Known market downtime: weekly maintenance windows and one-off closures.
+//!
+//! Windows come from `[market_hours]` in `config.toml` (UTC). While one is in
+//! effect the dashboard shows "market closed, next open in Nm" and stays quiet
+//! about the failed polls and stream reconnects that downtime causes.
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Recurring downtime, e.g. the exchange's weekly maintenance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceWindow {
+    /// Day the window starts on; unset = every day
+    #[serde(default)]
+    pub day: Option<Weekday>,
+    /// UTC start and end ("HH:MM"); an end before the start runs past midnight
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    #[serde(default = "default_label")]
+    pub label: String,
+}
+
+/// One-off closure, e.g. a holiday or announced outage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Closure {
+    pub from: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    #[serde(default = "default_closure_label")]
+    pub label: String,
+}
+
+fn default_label() -> String {
+    "maintenance".to_string()
+}
+
+fn default_closure_label() -> String {
+    "market closed".to_string()
+}
+
+/// The closure in effect right now
+#[derive(Debug, Clone, PartialEq)]
+pub struct Downtime {
+    pub label: String,
+    pub reopens: DateTime<Utc>,
+}
+
+impl Downtime {
+    /// "maintenance, next open in 12m"
+    pub fn summary(&self, now: DateTime<Utc>) -> String {
+        let minutes = ((self.reopens - now).num_seconds() + 59) / 60;
+        let wait = if minutes >= 60 {
+            format!("{}h{:02}m", minutes / 60, minutes % 60)
+        } else {
+            format!("{}m", minutes.max(0))
+        };
+        format!("{}, next open in {}", self.label, wait)
+    }
+}
+
+impl MaintenanceWindow {
+    /// Occurrence covering `now`, if any (checks the window starting today and yesterday)
+    fn active_at(&self, now: DateTime<Utc>) -> Option<Downtime> {
+        let today = now.date_naive();
+        [today.pred_opt(), Some(today)]
+            .into_iter()
+            .flatten()
+            .filter(|date| self.day.is_none_or(|day| date.weekday() == day))
+            .find_map(|date| {
+                let start = date.and_time(self.start).and_utc();
+                let mut end = date.and_time(self.end).and_utc();
+                if end <= start {
+                    end += Duration::days(1);
+                }
+                (start <= now && now < end).then(|| Downtime {
+                    label: self.label.clone(),
+                    reopens: end,
+                })
+            })
+    }
+}
+
+/// Kalshi's weekly maintenance: Thursdays 3:00-5:00 AM ET (08:00-10:00 UTC)
+pub fn default_windows() -> Vec<MaintenanceWindow> {
+    vec![MaintenanceWindow {
+        day: Some(Weekday::Thu),
+        start: NaiveTime::from_hms_opt(8, 0, 0).unwrap_or_default(),
+        end: NaiveTime::from_hms_opt(10, 0, 0).unwrap_or_default(),
+        label: "Kalshi maintenance".to_string(),
+    }]
+}
+
+/// Downtime in effect at `now`; overlapping windows report the latest reopening
+pub fn downtime_at(
+    windows: &[MaintenanceWindow],
+    closures: &[Closure],
+    now: DateTime<Utc>,
+) -> Option<Downtime> {
+    let recurring = windows.iter().filter_map(|window| window.active_at(now));
+    let one_off = closures
+        .iter()
+        .filter(|closure| closure.from <= now && now < closure.until)
+        .map(|closure| Downtime {
+            label: closure.label.clone(),
+            reopens: closure.until,
+        });
+    recurring
+        .chain(one_off)
+        .max_by_key(|downtime| downtime.reopens)
+}
@@ -0,0 +1,224 @@
+//! `basilisk mini`: a four-line glance view for a small terminal pane.
+//!
+//! Polls over HTTP (no SSE) at the `[polling]` cadence and shows BTC price,
+//! volatility regime, the best signal by EV, and open P&L.
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame, Terminal,
+};
+use std::time::{Duration, Instant};
+
+use crate::api::{ApiClient, Contract, VolatilityData};
+use crate::config::{Config, ThemeName};
+use crate::ui::Theme;
+
+#[derive(Default)]
+struct Snapshot {
+    btc_price: Option<f64>,
+    volatility: VolatilityData,
+    best: Option<Contract>,
+    open_pnl: Option<f64>,
+    open_positions: usize,
+}
+
+pub struct MiniApp {
+    api_client: ApiClient,
+    interval: Duration,
+    snapshot: Snapshot,
+    last_update: Option<Instant>,
+    error: Option<String>,
+    theme: Theme,
+}
+
+impl MiniApp {
+    pub fn new(
+        api_url: String,
+        refresh_interval_secs: Option<u64>,
+        theme: Option<ThemeName>,
+    ) -> Result<Self> {
+        let config = Config::load()?;
+        let secs = refresh_interval_secs.unwrap_or(config.polling.interval_secs);
+        Ok(Self {
+            api_client: ApiClient::new(api_url, 10)?,
+            interval: Duration::from_secs(secs.max(1)),
+            snapshot: Snapshot::default(),
+            last_update: None,
+            error: None,
+            theme: Theme::new(theme.unwrap_or(config.layout.theme)),
+        })
+    }
+
+    pub async fn run(&mut self, terminal: &mut Terminal<impl Backend>) -> Result<()> {
+        self.refresh().await;
+        let mut last_refresh = Instant::now();
+
+        loop {
+            terminal.draw(|frame| self.render(frame))?;
+
+            if event::poll(Duration::from_millis(250))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        match key.code {
+                            KeyCode::Char('q') | KeyCode::Esc => break,
+                            KeyCode::Char('r') => last_refresh -= self.interval,
+                            _ => {}
+                        }
+                    }
+                }
+            }
+
+            if last_refresh.elapsed() >= self.interval {
+                self.refresh().await;
+                last_refresh = Instant::now();
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn refresh(&mut self) {
+        match self.api_client.get_current_signals().await {
+            Ok(response) => {
+                self.snapshot.btc_price = response.btc_price();
+                self.snapshot.best = response
+                    .contracts
+                    .iter()
+                    .filter(|c| c.is_active && c.signal_type != "HOLD")
+                    .max_by(|a, b| a.expected_value.total_cmp(&b.expected_value))
+                    .cloned();
+                self.snapshot.volatility = response.volatility;
+                self.last_update = Some(Instant::now());
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+
+        // P&L is optional: the glance view still works without trading credentials
+        match self.api_client.get_positions().await {
+            Ok(positions) => {
+                self.snapshot.open_positions = positions.len();
+                self.snapshot.open_pnl = Some(
+                    positions
+                        .iter()
+                        .filter_map(|p| p.unrealized_pnl)
+                        .fold(0.0, |total, pnl| total + pnl),
+                );
+            }
+            Err(_) => self.snapshot.open_pnl = None,
+        }
+    }
+
+    fn render(&self, frame: &mut Frame) {
+        let theme = self.theme;
+        let area = frame.size();
+        let gray = Style::default().fg(theme.muted);
+        let snapshot = &self.snapshot;
+
+        let price = snapshot
+            .btc_price
+            .map(|p| format!("${:.0}", p))
+            .unwrap_or_else(|| "--".to_string());
+        let vol = &snapshot.volatility;
+        let regime_color = match vol.regime.as_str() {
+            "CALM" => theme.positive,
+            "NORMAL" => theme.highlight,
+            "ELEVATED" => theme.caution,
+            "CRISIS" => theme.negative,
+            _ => theme.muted,
+        };
+        let regime = if vol.regime.is_empty() {
+            "UNKNOWN"
+        } else {
+            vol.regime.as_str()
+        };
+
+        let mut market = vec![
+            Span::styled("BTC ", gray),
+            Span::styled(
+                price,
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  "),
+        ];
+        // An empty volatility endpoint reads as zeros; don't show them as data
+        if vol.is_available() {
+            market.push(Span::styled(regime, Style::default().fg(regime_color)));
+            market.push(Span::styled(
+                format!(
+                    " RV {:.0}% IV {:.0}%",
+                    vol.realized_vol * 100.0,
+                    vol.implied_vol * 100.0
+                ),
+                gray,
+            ));
+        } else {
+            market.push(Span::styled("vol unavailable", gray));
+        }
+        let market = Line::from(market);
+
+        let best = match &snapshot.best {
+            Some(c) => {
+                let color = if c.signal_type.contains("YES") {
+                    theme.positive
+                } else {
+                    theme.negative
+                };
+                Line::from(vec![
+                    Span::styled("Best ", gray),
+                    Span::styled(
+                        c.signal_type.clone(),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(format!(
+                        " {} EV {} ({})",
+                        c.strike_display(),
+                        c.ev_display(),
+                        c.time_left_display()
+                    )),
+                ])
+            }
+            None => Line::from(Span::styled("Best --", gray)),
+        };
+
+        let pnl = match snapshot.open_pnl {
+            Some(pnl) => {
+                let color = if pnl >= 0.0 {
+                    theme.positive
+                } else {
+                    theme.negative
+                };
+                Line::from(vec![
+                    Span::styled("Open P&L ", gray),
+                    Span::styled(format!("${:+.2}", pnl), Style::default().fg(color)),
+                    Span::styled(format!(" ({} pos)", snapshot.open_positions), gray),
+                ])
+            }
+            None => Line::from(Span::styled("Open P&L --", gray)),
+        };
+
+        let status = match (&self.error, self.last_update) {
+            (Some(e), _) => Line::from(Span::styled(
+                format!("● {}", e),
+                Style::default().fg(theme.negative),
+            )),
+            (None, Some(at)) => Line::from(Span::styled(
+                format!("● {}s ago  [r] refresh [q] quit", at.elapsed().as_secs()),
+                gray,
+            )),
+            (None, None) => Line::from(Span::styled("● Connecting...", gray)),
+        };
+
+        let lines = vec![market, best, pnl, status];
+        let height = (lines.len() as u16).min(area.height);
+        frame.render_widget(Paragraph::new(lines), Rect { height, ..area });
+    }
+}
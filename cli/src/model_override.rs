@@ -0,0 +1,119 @@
+//! The user's own view on the local probability engine.
+//!
+//! Set from the dashboard's `o` popup and kept in `model_override.json`. While
+//! enabled, the signals table shows a "My%" column next to the backend's Mod%:
+//! the YES probability from `pricing.rs` with the chosen vol source, an IV bump
+//! and a drift assumption applied.
+
+use anyhow::Result;
+use basilisk_quant::pricing;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Contract, VolatilityData};
+use crate::store;
+
+const OVERRIDE_FILE: &str = "model_override.json";
+
+/// Where the engine's volatility comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VolSource {
+    /// Backend implied vol (DVOL), falling back to its realized vol
+    #[default]
+    Implied,
+    /// Backend realized vol
+    Realized,
+    /// RV from the last hour of streamed ticks
+    Ticks,
+    /// `fixed_vol` as entered
+    Fixed,
+}
+
+impl VolSource {
+    const CYCLE: [VolSource; 4] = [
+        VolSource::Implied,
+        VolSource::Realized,
+        VolSource::Ticks,
+        VolSource::Fixed,
+    ];
+
+    /// Step through the sources (`delta` of +1 or -1)
+    pub fn step(self, delta: i32) -> Self {
+        let index = Self::CYCLE.iter().position(|s| *s == self).unwrap_or(0) as i32;
+        let len = Self::CYCLE.len() as i32;
+        Self::CYCLE[(index + delta).rem_euclid(len) as usize]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            VolSource::Implied => "Implied (DVOL)",
+            VolSource::Realized => "Realized",
+            VolSource::Ticks => "1h ticks RV",
+            VolSource::Fixed => "Fixed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelOverride {
+    pub enabled: bool,
+    pub vol_source: VolSource,
+    /// Annualized vol used with `VolSource::Fixed` (0.50 = 50%)
+    pub fixed_vol: f64,
+    /// Added to the chosen vol (0.05 = +5 vol points)
+    pub iv_bump: f64,
+    /// Expected BTC drift per hour (0.001 = +0.1%/h)
+    pub drift_per_hour: f64,
+}
+
+impl Default for ModelOverride {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            vol_source: VolSource::Implied,
+            fixed_vol: 0.50,
+            iv_bump: 0.0,
+            drift_per_hour: 0.0,
+        }
+    }
+}
+
+impl ModelOverride {
+    /// Saved settings (a missing or unreadable file means the defaults)
+    pub fn load() -> Self {
+        store::read_json(OVERRIDE_FILE)
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        store::write_json(OVERRIDE_FILE, self)
+    }
+
+    /// Annualized vol after the source and bump; None when the source has no reading
+    pub fn vol(&self, volatility: &VolatilityData, tick_rv: Option<f64>) -> Option<f64> {
+        let base = match self.vol_source {
+            VolSource::Implied => volatility.effective_vol(),
+            VolSource::Realized => Some(volatility.realized_vol).filter(|v| *v > 0.0),
+            VolSource::Ticks => tick_rv,
+            VolSource::Fixed => Some(self.fixed_vol),
+        }?;
+        Some((base + self.iv_bump).max(0.01))
+    }
+
+    /// YES probability for `contract` under these settings
+    pub fn probability(&self, contract: &Contract, vol: f64) -> Option<f64> {
+        let spot = contract.current_btc_price?;
+        let strike = contract.strike_price?;
+        let hours = contract.seconds_to_expiry()? as f64 / 3600.0;
+        Some(pricing::prob_above_with_drift(
+            spot,
+            strike,
+            vol,
+            hours,
+            self.drift_per_hour,
+        ))
+    }
+}
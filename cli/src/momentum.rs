@@ -0,0 +1,164 @@
+//! Quote momentum: how each contract's implied probability has moved over the
+//! last few minutes, relative to where the model says it should be, the edge
+//! history behind the detail view, plus the short-term BTC trend recorded
+//! alongside settlements.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::api::Contract;
+
+/// Lookback for the Δ column
+pub const WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Moves smaller than this (probability units) count as flat
+const FLAT_THRESHOLD: f64 = 0.005;
+
+/// Lookback for the BTC trend
+pub const TREND_WINDOW: Duration = Duration::from_secs(15 * 60);
+
+/// Which way the market is moving compared to the model's view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Drift {
+    Toward,
+    Away,
+    Flat,
+}
+
+/// Implied probability change over the window
+#[derive(Debug, Clone, Copy)]
+pub struct Momentum {
+    pub delta: f64,
+    pub drift: Drift,
+}
+
+/// Rolling implied probability samples per ticker
+#[derive(Debug, Default)]
+pub struct MomentumTracker {
+    samples: HashMap<String, VecDeque<(Instant, f64)>>,
+}
+
+impl MomentumTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sample a snapshot; tickers no longer listed are dropped
+    pub fn record(&mut self, contracts: &[Contract]) {
+        let now = Instant::now();
+        self.samples
+            .retain(|ticker, _| contracts.iter().any(|c| &c.ticker == ticker));
+
+        for contract in contracts {
+            let Some(implied) = contract.implied_probability else {
+                continue;
+            };
+            let history = self.samples.entry(contract.ticker.clone()).or_default();
+            history.push_back((now, implied));
+            while history
+                .front()
+                .is_some_and(|(at, _)| now.duration_since(*at) > WINDOW)
+            {
+                history.pop_front();
+            }
+        }
+    }
+
+    pub fn momentum(&self, contract: &Contract) -> Option<Momentum> {
+        let history = self.samples.get(&contract.ticker)?;
+        if history.len() < 2 {
+            return None;
+        }
+        let (_, oldest) = history.front()?;
+        let (_, latest) = history.back()?;
+
+        let delta = latest - oldest;
+        let drift = match contract.model_probability {
+            _ if delta.abs() < FLAT_THRESHOLD => Drift::Flat,
+            Some(model) if (model - oldest) * delta > 0.0 => Drift::Toward,
+            Some(_) => Drift::Away,
+            None => Drift::Flat,
+        };
+
+        Some(Momentum { delta, drift })
+    }
+}
+
+/// Edge samples kept per contract (about an hour of SSE updates)
+const EDGE_CAPACITY: usize = 240;
+
+/// Model minus implied probability per ticker, for as long as it's listed
+#[derive(Debug, Default)]
+pub struct EdgeHistory {
+    samples: HashMap<String, VecDeque<f64>>,
+}
+
+impl EdgeHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sample a snapshot; tickers no longer listed are dropped
+    pub fn record(&mut self, contracts: &[Contract]) {
+        self.samples
+            .retain(|ticker, _| contracts.iter().any(|c| &c.ticker == ticker));
+
+        for contract in contracts {
+            let (Some(model), Some(implied)) =
+                (contract.model_probability, contract.implied_probability)
+            else {
+                continue;
+            };
+            let history = self.samples.entry(contract.ticker.clone()).or_default();
+            history.push_back(model - implied);
+            while history.len() > EDGE_CAPACITY {
+                history.pop_front();
+            }
+        }
+    }
+
+    /// Edge samples for a ticker, oldest first
+    pub fn edges(&self, ticker: &str) -> Vec<f64> {
+        self.samples
+            .get(ticker)
+            .map(|history| history.iter().copied().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Rolling BTC spot samples for short-term trend
+#[derive(Debug, Default)]
+pub struct PriceTrend {
+    samples: VecDeque<(Instant, f64)>,
+}
+
+impl PriceTrend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, price: f64) {
+        if price <= 0.0 {
+            return;
+        }
+        let now = Instant::now();
+        self.samples.push_back((now, price));
+        while self
+            .samples
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) > TREND_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Percent change over the window (None until there are two samples)
+    pub fn change_pct(&self) -> Option<f64> {
+        if self.samples.len() < 2 {
+            return None;
+        }
+        let (_, oldest) = self.samples.front()?;
+        let (_, latest) = self.samples.back()?;
+        Some((latest - oldest) / oldest * 100.0)
+    }
+}
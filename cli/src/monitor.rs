@@ -0,0 +1,427 @@
+//! Headless stop-loss (`basilisk monitor`).
+//!
+//! The monitor polls open positions, marks each one at the latest quote for
+//! its side and closes at market any whose unrealized loss reaches
+//! `--stop-loss`: a share of what the position cost (`30%`) or a dollar
+//! amount (`$25`). Output is one `key=value` line per event so it can be
+//! grepped or shipped to a log collector. With `--notify`, a close (or a
+//! close that failed) also raises a critical alert, which the dashboard shows
+//! until acknowledged, and a fill runs the `trade_filled` hook. A failed
+//! close is retried on the next check.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{SecondsFormat, Utc};
+use serde_json::json;
+
+use crate::alerts::AlertEngine;
+use crate::api::client::ApiClient;
+use crate::api::models::{Contract, Position};
+use crate::audit::{self, AuditKind};
+use crate::config::Config;
+use crate::hooks::Hooks;
+
+/// How much a position may lose before it is closed
+#[derive(Debug, Clone, Copy)]
+pub enum LossLimit {
+    /// Percent of the position's cost
+    Percent(f64),
+    Dollars(f64),
+}
+
+impl LossLimit {
+    fn breached(&self, mark: &Mark) -> bool {
+        let loss = -mark.pnl;
+        match *self {
+            LossLimit::Percent(pct) => mark.cost > 0.0 && loss >= mark.cost * pct / 100.0 - 1e-9,
+            LossLimit::Dollars(dollars) => loss >= dollars - 1e-9,
+        }
+    }
+}
+
+impl fmt::Display for LossLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LossLimit::Percent(pct) => write!(f, "{}%", pct),
+            LossLimit::Dollars(dollars) => write!(f, "${:.2}", dollars),
+        }
+    }
+}
+
+/// Parse "30%", "$25" or "25" (dollars)
+pub fn parse_loss_limit(s: &str) -> Result<LossLimit, String> {
+    let s = s.trim();
+    let (value, percent) = match s.strip_suffix('%') {
+        Some(value) => (value, true),
+        None => (s.strip_prefix('$').unwrap_or(s), false),
+    };
+    let value: f64 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid loss limit '{}' (e.g. 30% or $25)", s))?;
+    if value <= 0.0 || (percent && value > 100.0) {
+        return Err(format!(
+            "loss limit must be above 0{} (got {})",
+            if percent { "% and at most 100%" } else { "" },
+            s
+        ));
+    }
+    Ok(if percent {
+        LossLimit::Percent(value)
+    } else {
+        LossLimit::Dollars(value)
+    })
+}
+
+/// A position valued at the latest quote
+struct Mark {
+    /// Quote for the position's side, when one was available
+    price: Option<f64>,
+    pnl: f64,
+    cost: f64,
+}
+
+impl Mark {
+    fn loss_pct(&self) -> f64 {
+        if self.cost > 0.0 {
+            -self.pnl / self.cost * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Value a position from the signals' quote for its side, falling back to
+/// what the backend reports (None when there's nothing to go on)
+fn mark(position: &Position, contracts: &[Contract]) -> Option<Mark> {
+    let quote = contracts
+        .iter()
+        .find(|c| c.ticker == position.ticker)
+        .and_then(|c| {
+            if position.direction.eq_ignore_ascii_case("NO") {
+                c.no_price
+            } else {
+                c.yes_price
+            }
+        })
+        .or(position.current_price);
+    let cost = position.entry_price * position.contracts as f64;
+    let pnl = match quote {
+        Some(price) => (price - position.entry_price) * position.contracts as f64,
+        None => position.unrealized_pnl?,
+    };
+    Some(Mark {
+        price: quote,
+        pnl,
+        cost,
+    })
+}
+
+/// One structured line: timestamp, event, then `key=value` fields
+fn log(event: &str, fields: &[(&str, String)]) {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| {
+            if value.is_empty() || value.contains(char::is_whitespace) {
+                format!("{}={:?}", key, value)
+            } else {
+                format!("{}={}", key, value)
+            }
+        })
+        .collect();
+    println!(
+        "{} {:<8} {}",
+        Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        event,
+        fields.join(" ")
+    );
+}
+
+fn position_fields(position: &Position, mark: &Mark) -> Vec<(&'static str, String)> {
+    vec![
+        ("trade_id", position.trade_id.to_string()),
+        ("ticker", position.ticker.clone()),
+        ("side", position.direction.to_uppercase()),
+        ("contracts", position.contracts.to_string()),
+        ("entry", format!("{:.2}", position.entry_price)),
+        (
+            "mark",
+            mark.price
+                .map(|p| format!("{:.2}", p))
+                .unwrap_or_else(|| "--".to_string()),
+        ),
+        ("pnl", format!("{:+.2}", mark.pnl)),
+        ("loss_pct", format!("{:.1}", mark.loss_pct())),
+    ]
+}
+
+struct Monitor {
+    client: ApiClient,
+    limit: LossLimit,
+    keep: HashSet<i32>,
+    notify: bool,
+    dry_run: bool,
+    hooks: Hooks,
+    /// Positions seen on the last check, to log arrivals and departures
+    watching: HashSet<i32>,
+    /// Positions already alerted on (or, in a dry run, logged), so a failing
+    /// close alerts once
+    alerted: HashSet<i32>,
+}
+
+impl Monitor {
+    async fn check(&mut self) -> Result<()> {
+        let positions = self.client.get_positions().await?;
+        let contracts = match self.client.get_current_signals().await {
+            Ok(current) => current.contracts,
+            Err(e) => {
+                log("WARN", &[("error", format!("no quotes: {}", e))]);
+                Vec::new()
+            }
+        };
+
+        let open: HashSet<i32> = positions
+            .iter()
+            .map(|p| p.trade_id)
+            .filter(|id| !self.keep.contains(id))
+            .collect();
+        for trade_id in self.watching.difference(&open) {
+            log("GONE", &[("trade_id", trade_id.to_string())]);
+        }
+        self.alerted.retain(|id| open.contains(id));
+
+        for position in &positions {
+            if self.keep.contains(&position.trade_id) {
+                continue;
+            }
+            let Some(mark) = mark(position, &contracts) else {
+                if self.watching.insert(position.trade_id) {
+                    log(
+                        "WATCH",
+                        &[
+                            ("trade_id", position.trade_id.to_string()),
+                            ("ticker", position.ticker.clone()),
+                            ("mark", "--".to_string()),
+                        ],
+                    );
+                }
+                continue;
+            };
+            if self.watching.insert(position.trade_id) {
+                log("WATCH", &position_fields(position, &mark));
+            }
+            if self.limit.breached(&mark) {
+                self.close(position, &mark).await;
+            }
+        }
+        self.watching = open;
+
+        for failure in self.hooks.take_failures() {
+            log("WARN", &[("error", failure)]);
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self, position: &Position, mark: &Mark) {
+        let mut fields = position_fields(position, mark);
+        fields.push(("limit", self.limit.to_string()));
+        if self.dry_run {
+            if self.alerted.insert(position.trade_id) {
+                fields.push(("action", "dry-run".to_string()));
+                log("BREACH", &fields);
+            }
+            return;
+        }
+
+        let description = format!(
+            "monitor: stop-loss close position #{} ({} {} x{}, {:.1}% down, limit {})",
+            position.trade_id,
+            position.ticker,
+            position.direction,
+            position.contracts,
+            mark.loss_pct(),
+            self.limit
+        );
+        let result = self.client.close_position(position.trade_id).await;
+        if let Err(e) = audit::record_result(AuditKind::Close, "monitor", &description, &result) {
+            log(
+                "WARN",
+                &[("error", format!("could not write audit log: {}", e))],
+            );
+        }
+
+        let message = match &result {
+            Ok(response) if response.success => {
+                fields.push(("filled", response.filled.to_string()));
+                if let Some(price) = response.price {
+                    fields.push(("exit", format!("{:.2}", price)));
+                }
+                if let Some(pnl) = response.cost {
+                    fields.push(("realized", format!("{:+.2}", pnl)));
+                }
+                log("CLOSE", &fields);
+                if self.notify {
+                    self.hooks.trade(&description, response);
+                }
+                format!(
+                    "Stop-loss closed #{} {} {} x{} ({:.1}% down)",
+                    position.trade_id,
+                    position.ticker,
+                    position.direction,
+                    response.filled,
+                    mark.loss_pct()
+                )
+            }
+            Ok(response) => {
+                let error = response
+                    .error
+                    .clone()
+                    .unwrap_or_else(|| "close rejected".to_string());
+                fields.push(("error", error.clone()));
+                log("FAILED", &fields);
+                format!(
+                    "Stop-loss close of #{} {} failed: {}",
+                    position.trade_id, position.ticker, error
+                )
+            }
+            Err(e) => {
+                fields.push(("error", e.to_string()));
+                log("FAILED", &fields);
+                format!(
+                    "Stop-loss close of #{} {} failed: {}",
+                    position.trade_id, position.ticker, e
+                )
+            }
+        };
+
+        if self.notify && self.alerted.insert(position.trade_id) {
+            let context = json!({
+                "trade_id": position.trade_id,
+                "ticker": position.ticker,
+                "direction": position.direction,
+                "contracts": position.contracts,
+                "entry_price": position.entry_price,
+                "mark": mark.price,
+                "pnl": mark.pnl,
+                "limit": self.limit.to_string(),
+                "closed": matches!(&result, Ok(response) if response.success),
+            });
+            if let Err(e) = AlertEngine::load().stop_loss(message, context) {
+                log(
+                    "WARN",
+                    &[("error", format!("could not raise alert: {}", e))],
+                );
+            }
+        }
+    }
+}
+
+pub async fn handle_monitor_command(
+    client: ApiClient,
+    stop_loss: LossLimit,
+    notify: bool,
+    keep: Vec<i32>,
+    interval: u64,
+    once: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let config = Config::load()?;
+    let mut monitor = Monitor {
+        client,
+        limit: stop_loss,
+        keep: keep.into_iter().collect(),
+        notify,
+        dry_run,
+        hooks: Hooks::new(&config.hooks),
+        watching: HashSet::new(),
+        alerted: HashSet::new(),
+    };
+
+    let mut kept: Vec<i32> = monitor.keep.iter().copied().collect();
+    kept.sort_unstable();
+    let mut fields = vec![
+        ("stop_loss", stop_loss.to_string()),
+        ("interval", format!("{}s", interval.max(1))),
+        ("notify", notify.to_string()),
+    ];
+    if !kept.is_empty() {
+        let kept: Vec<String> = kept.iter().map(i32::to_string).collect();
+        fields.push(("keep", kept.join(",")));
+    }
+    if dry_run {
+        fields.push(("dry_run", "true".to_string()));
+    }
+    log("START", &fields);
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval.max(1)));
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = tokio::signal::ctrl_c() => {
+                log("STOP", &[("reason", "interrupted".to_string())]);
+                break;
+            }
+        }
+        if let Err(e) = monitor.check().await {
+            log("ERROR", &[("error", e.to_string())]);
+        }
+        if once {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mark(pnl: f64, cost: f64) -> Mark {
+        Mark {
+            price: None,
+            pnl,
+            cost,
+        }
+    }
+
+    #[test]
+    fn parse_loss_limit_reads_percent_and_dollars() {
+        assert!(matches!(parse_loss_limit("30%"), Ok(LossLimit::Percent(p)) if p == 30.0));
+        assert!(matches!(parse_loss_limit(" 12.5 % "), Ok(LossLimit::Percent(p)) if p == 12.5));
+        assert!(matches!(parse_loss_limit("$25"), Ok(LossLimit::Dollars(d)) if d == 25.0));
+        assert!(matches!(parse_loss_limit("25"), Ok(LossLimit::Dollars(d)) if d == 25.0));
+        assert!(matches!(parse_loss_limit("100%"), Ok(LossLimit::Percent(p)) if p == 100.0));
+    }
+
+    #[test]
+    fn parse_loss_limit_rejects_bad_values() {
+        for bad in ["", "%", "$", "abc", "0", "$0", "-5", "0%", "101%", "$-1"] {
+            assert!(
+                parse_loss_limit(bad).is_err(),
+                "{:?} should be rejected",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn percent_limit_is_a_share_of_cost() {
+        let limit = LossLimit::Percent(30.0);
+        assert!(limit.breached(&mark(-3.0, 10.0)));
+        assert!(limit.breached(&mark(-4.0, 10.0)));
+        assert!(!limit.breached(&mark(-2.9, 10.0)));
+        assert!(!limit.breached(&mark(-3.0, 0.0)));
+    }
+
+    #[test]
+    fn dollar_limit_is_an_absolute_loss() {
+        let limit = LossLimit::Dollars(25.0);
+        assert!(limit.breached(&mark(-25.0, 100.0)));
+        assert!(!limit.breached(&mark(-24.99, 100.0)));
+        assert!(!limit.breached(&mark(5.0, 100.0)));
+    }
+}
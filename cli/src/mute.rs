@@ -0,0 +1,103 @@
+//! Snoozed strikes and signal types.
+//!
+//! A muted contract raises no alerts and is dimmed in the signals table until
+//! its mute runs out. Mutes are kept in `mutes.json` so they survive a restart.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::Contract;
+use crate::store;
+
+const MUTES_FILE: &str = "mutes.json";
+
+/// Pressing mute again on the same target steps through these, then unmutes
+const DURATIONS_MINUTES: [i64; 3] = [15, 60, 240];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum MuteTarget {
+    Ticker(String),
+    SignalType(String),
+}
+
+impl MuteTarget {
+    fn matches(&self, contract: &Contract) -> bool {
+        match self {
+            MuteTarget::Ticker(ticker) => &contract.ticker == ticker,
+            MuteTarget::SignalType(signal_type) => &contract.signal_type == signal_type,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            MuteTarget::Ticker(ticker) => ticker.clone(),
+            MuteTarget::SignalType(signal_type) => format!("all {} signals", signal_type),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Mute {
+    target: MuteTarget,
+    until: DateTime<Utc>,
+    /// Index into `DURATIONS_MINUTES`
+    step: usize,
+}
+
+impl Mute {
+    fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.until > now
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MuteList {
+    mutes: Vec<Mute>,
+}
+
+impl MuteList {
+    /// Load saved mutes (a missing or unreadable file means none)
+    pub fn load() -> Self {
+        store::read_json(MUTES_FILE)
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    pub fn is_muted(&self, contract: &Contract) -> bool {
+        let now = Utc::now();
+        self.mutes
+            .iter()
+            .any(|mute| mute.is_active(now) && mute.target.matches(contract))
+    }
+
+    /// Step the target's mute through 15m → 1h → 4h → off and save.
+    /// Returns when the mute now ends (None once unmuted).
+    pub fn cycle(&mut self, target: MuteTarget) -> Result<Option<DateTime<Utc>>> {
+        let now = Utc::now();
+        self.mutes.retain(|mute| mute.is_active(now));
+
+        let step = match self.mutes.iter().position(|mute| mute.target == target) {
+            Some(index) => {
+                let mute = self.mutes.remove(index);
+                mute.step + 1
+            }
+            None => 0,
+        };
+
+        let until = DURATIONS_MINUTES.get(step).map(|minutes| {
+            let until = now + Duration::minutes(*minutes);
+            self.mutes.push(Mute {
+                target,
+                until,
+                step,
+            });
+            until
+        });
+
+        store::write_json(MUTES_FILE, self)?;
+        Ok(until)
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::config::{GuardMode, OrderGuardConfig};
+
+/// Number of recent round-trip samples kept for smoothing
+const LATENCY_WINDOW: usize = 5;
+
+/// Rolling round-trip time to the trade endpoint
+#[derive(Debug, Default)]
+pub struct LatencyMonitor {
+    samples: VecDeque<Duration>,
+}
+
+impl LatencyMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, rtt: Duration) {
+        self.samples.push_back(rtt);
+        if self.samples.len() > LATENCY_WINDOW {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Smoothed round-trip time in milliseconds (mean of recent samples)
+    pub fn current_ms(&self) -> Option<u64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let total: Duration = self.samples.iter().sum();
+        Some((total / self.samples.len() as u32).as_millis() as u64)
+    }
+}
+
+/// Outcome of the pre-submission checks
+#[derive(Debug, Default)]
+pub struct GuardVerdict {
+    pub warnings: Vec<String>,
+    pub blocked: bool,
+}
+
+impl GuardVerdict {
+    pub fn is_clear(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+/// Flags orders likely to fill late: slow trade endpoint or imminent expiry
+pub struct OrderGuard<'a> {
+    config: &'a OrderGuardConfig,
+}
+
+impl<'a> OrderGuard<'a> {
+    pub fn new(config: &'a OrderGuardConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn is_latency_high(&self, latency_ms: u64) -> bool {
+        latency_ms > self.config.max_latency_ms
+    }
+
+    pub fn check(&self, latency_ms: Option<u64>, hours_to_expiry: Option<f64>) -> GuardVerdict {
+        let mut warnings = Vec::new();
+        let mut blocking = false;
+
+        match latency_ms {
+            Some(ms) if self.is_latency_high(ms) => {
+                warnings.push(format!(
+                    "Trade endpoint latency {}ms exceeds {}ms - price may move before the fill",
+                    ms, self.config.max_latency_ms
+                ));
+                blocking = true;
+            }
+            Some(_) => {}
+            // A probe that didn't answer says nothing about the order path, so
+            // it only warns, even in block mode
+            None => warnings.push("Trade endpoint latency unknown (probe failed)".to_string()),
+        }
+
+        if let Some(hours) = hours_to_expiry {
+            let seconds = (hours * 3600.0).max(0.0) as u64;
+            if seconds < self.config.min_seconds_to_expiry {
+                warnings.push(format!(
+                    "Contract expires in {}s (< {}s) - order may land after the market closes",
+                    seconds, self.config.min_seconds_to_expiry
+                ));
+                blocking = true;
+            }
+        }
+
+        GuardVerdict {
+            blocked: blocking && self.config.mode == GuardMode::Block,
+            warnings,
+        }
+    }
+}
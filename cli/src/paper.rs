@@ -0,0 +1,252 @@
+//! Paper trading engine for `basilisk auto --paper`.
+//!
+//! Orders fill instantly at the signal's entry price and settle on their
+//! contract's recorded result in `settlements.jsonl` (written at each hourly
+//! rollover by the dashboard and by `auto --paper` itself). A position whose
+//! result never gets recorded is voided an hour after expiry, its entry
+//! refunded. The book is kept in `paper.json`; every fill, settlement and void
+//! is appended to `paper_trades.jsonl`. Fees are not modelled.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::api::models::{Contract, TradeResponse};
+use crate::api::parse_timestamp;
+use crate::rollover::Settlement;
+use crate::sizing;
+use crate::store;
+
+const BOOK_FILE: &str = "paper.json";
+const TRADES_FILE: &str = "paper_trades.jsonl";
+
+/// Hours an expired position waits for its contract's result before it's voided
+const RESULT_WAIT_HOURS: i64 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperPosition {
+    pub id: i32,
+    pub opened_at: DateTime<Utc>,
+    pub signal_id: i32,
+    pub ticker: String,
+    /// "YES" or "NO"
+    pub side: String,
+    pub strike: Option<f64>,
+    pub expiry_time: Option<String>,
+    pub contracts: i32,
+    pub entry_price: f64,
+}
+
+impl PaperPosition {
+    fn settles_by(&self, now: DateTime<Utc>) -> bool {
+        self.expiry_time
+            .as_deref()
+            .and_then(parse_timestamp)
+            .is_some_and(|expiry| expiry <= now)
+    }
+
+    /// Recorded outcome of this position's contract, if its hour has settled
+    fn result<'a>(&self, results: &'a [Settlement]) -> Option<&'a Settlement> {
+        results.iter().find(|s| {
+            s.ticker == self.ticker
+                && (s.expiry_time.is_none()
+                    || self.expiry_time.is_none()
+                    || s.expiry_time == self.expiry_time)
+        })
+    }
+
+    /// Current value per contract from the latest quote for this ticker
+    fn mark(&self, contracts: &[Contract]) -> Option<f64> {
+        let contract = contracts.iter().find(|c| c.ticker == self.ticker)?;
+        if self.side == "YES" {
+            contract.yes_price
+        } else {
+            contract.no_price
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+enum PaperTrade<'a> {
+    Fill {
+        timestamp: DateTime<Utc>,
+        position: &'a PaperPosition,
+    },
+    Settle {
+        timestamp: DateTime<Utc>,
+        position: &'a PaperPosition,
+        settle_price: f64,
+        won: bool,
+        pnl: f64,
+    },
+    Void {
+        timestamp: DateTime<Utc>,
+        position: &'a PaperPosition,
+    },
+}
+
+/// A settled paper position and its P&L
+pub struct Settled {
+    pub position: PaperPosition,
+    pub settle_price: f64,
+    pub won: bool,
+    pub pnl: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaperBook {
+    next_id: i32,
+    pub positions: Vec<PaperPosition>,
+    pub realized_pnl: f64,
+    pub wins: u32,
+    pub losses: u32,
+    /// Contracts seen on the previous cycle, to notice an hour rolling off
+    #[serde(skip)]
+    pub last_contracts: Vec<Contract>,
+}
+
+impl PaperBook {
+    pub fn load() -> Result<Self> {
+        Ok(store::read_json(BOOK_FILE)?.unwrap_or_default())
+    }
+
+    pub fn save(&self) -> Result<()> {
+        store::write_json(BOOK_FILE, self)
+    }
+
+    pub fn held_tickers(&self) -> HashSet<&str> {
+        self.positions.iter().map(|p| p.ticker.as_str()).collect()
+    }
+
+    /// Fill an order on the signal, answering the way the trade endpoint would
+    pub fn execute(&mut self, contract: &Contract, size: i32) -> Result<TradeResponse> {
+        let Some(price) = sizing::entry_price(contract) else {
+            return Ok(TradeResponse {
+                success: false,
+                trade_id: None,
+                order_id: None,
+                client_order_id: None,
+                filled: 0,
+                price: None,
+                cost: None,
+                error: Some("no entry price on signal".to_string()),
+            });
+        };
+
+        self.next_id += 1;
+        let position = PaperPosition {
+            id: self.next_id,
+            opened_at: Utc::now(),
+            signal_id: contract.id,
+            ticker: contract.ticker.clone(),
+            side: if contract.signal_type.contains("NO") {
+                "NO"
+            } else {
+                "YES"
+            }
+            .to_string(),
+            strike: contract.strike_price,
+            expiry_time: contract.expiry_time.clone(),
+            contracts: size,
+            entry_price: price,
+        };
+        store::append_jsonl(
+            TRADES_FILE,
+            &PaperTrade::Fill {
+                timestamp: Utc::now(),
+                position: &position,
+            },
+        )?;
+        self.positions.push(position);
+
+        Ok(TradeResponse {
+            success: true,
+            trade_id: Some(self.next_id),
+            order_id: None,
+            client_order_id: None,
+            filled: size,
+            price: Some(price),
+            cost: Some(price * size as f64),
+            error: None,
+        })
+    }
+
+    /// Whether any position is past expiry, so waiting on a result
+    pub fn awaiting_results(&self) -> bool {
+        let now = Utc::now();
+        self.positions.iter().any(|p| p.settles_by(now))
+    }
+
+    /// Settle every position past its expiry whose contract has a recorded result
+    pub fn settle_expired(&mut self, results: &[Settlement]) -> Result<Vec<Settled>> {
+        let now = Utc::now();
+        let mut settled = Vec::new();
+        for position in std::mem::take(&mut self.positions) {
+            let result = match position.result(results) {
+                Some(result) if position.settles_by(now) => result,
+                _ => {
+                    self.positions.push(position);
+                    continue;
+                }
+            };
+            let won = result.outcome == position.side;
+            let payout = if won { 1.0 } else { 0.0 };
+            let pnl = (payout - position.entry_price) * position.contracts as f64;
+
+            store::append_jsonl(
+                TRADES_FILE,
+                &PaperTrade::Settle {
+                    timestamp: now,
+                    position: &position,
+                    settle_price: result.settle_price,
+                    won,
+                    pnl,
+                },
+            )?;
+            self.realized_pnl += pnl;
+            if won {
+                self.wins += 1;
+            } else {
+                self.losses += 1;
+            }
+            settled.push(Settled {
+                position,
+                settle_price: result.settle_price,
+                won,
+                pnl,
+            });
+        }
+        Ok(settled)
+    }
+
+    /// Drop positions still without a result `RESULT_WAIT_HOURS` past expiry
+    /// (nothing saw their hour roll over). The entry is refunded, not scored.
+    pub fn void_unresolved(&mut self) -> Result<Vec<PaperPosition>> {
+        let cutoff = Utc::now() - Duration::hours(RESULT_WAIT_HOURS);
+        let (voided, open): (Vec<_>, Vec<_>) = std::mem::take(&mut self.positions)
+            .into_iter()
+            .partition(|p| p.settles_by(cutoff));
+        self.positions = open;
+
+        for position in &voided {
+            store::append_jsonl(
+                TRADES_FILE,
+                &PaperTrade::Void {
+                    timestamp: Utc::now(),
+                    position,
+                },
+            )?;
+        }
+        Ok(voided)
+    }
+
+    /// Open P&L at current quotes (positions without a quote are left out)
+    pub fn unrealized_pnl(&self, contracts: &[Contract]) -> f64 {
+        self.positions
+            .iter()
+            .filter_map(|p| Some((p.mark(contracts)? - p.entry_price) * p.contracts as f64))
+            .fold(0.0, |total, pnl| total + pnl)
+    }
+}
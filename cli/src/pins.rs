@@ -0,0 +1,76 @@
+//! Strikes pinned to the top of the signals table (`p` on the dashboard).
+//!
+//! Pins are kept by ticker in `pins.json` so they survive refreshes and
+//! restarts, and are dropped once their contract has expired.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{parse_timestamp, Contract};
+use crate::store;
+
+const PINS_FILE: &str = "pins.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Pin {
+    ticker: String,
+    /// When the contract expires (None when the backend didn't say)
+    expires: Option<DateTime<Utc>>,
+}
+
+impl Pin {
+    fn is_live(&self, now: DateTime<Utc>) -> bool {
+        self.expires.is_none_or(|expires| expires > now)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PinList {
+    pins: Vec<Pin>,
+}
+
+impl PinList {
+    /// Load saved pins (a missing or unreadable file means none)
+    pub fn load() -> Self {
+        let mut list: Self = store::read_json(PINS_FILE)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        let now = Utc::now();
+        list.pins.retain(|pin| pin.is_live(now));
+        list
+    }
+
+    pub fn is_pinned(&self, contract: &Contract) -> bool {
+        self.pins.iter().any(|pin| pin.ticker == contract.ticker)
+    }
+
+    /// Pin the contract, or unpin it if it already is, and save.
+    /// Returns whether it is now pinned.
+    pub fn toggle(&mut self, contract: &Contract) -> Result<bool> {
+        let now = Utc::now();
+        self.pins.retain(|pin| pin.is_live(now));
+
+        let pinned = match self
+            .pins
+            .iter()
+            .position(|pin| pin.ticker == contract.ticker)
+        {
+            Some(index) => {
+                self.pins.remove(index);
+                false
+            }
+            None => {
+                self.pins.push(Pin {
+                    ticker: contract.ticker.clone(),
+                    expires: contract.expiry_time.as_deref().and_then(parse_timestamp),
+                });
+                true
+            }
+        };
+
+        store::write_json(PINS_FILE, self)?;
+        Ok(pinned)
+    }
+}
@@ -0,0 +1,176 @@
+//! Capital allocation across the front expiry's open signals.
+//!
+//! `[planner] hourly_budget` is spread over every BUY YES / BUY NO signal at
+//! the soonest expiry with at least `min_ev`, in proportion to EV × confidence.
+//! No leg gets more than `max_per_contract`; what a capped leg can't take is
+//! shared out again among the others. Notional already open at that expiry
+//! counts against the budget, and so does `[exposure] max_per_expiry`.
+
+use chrono::{DateTime, Utc};
+
+use crate::api::models::Contract;
+use crate::api::parse_timestamp;
+use crate::config::{ExposureConfig, PlannerConfig};
+use crate::exposure::ExposureBook;
+use crate::sizing;
+
+/// One leg of the basket
+#[derive(Debug, Clone)]
+pub struct Allocation {
+    pub contract: Contract,
+    pub contracts: i32,
+    pub price: f64,
+    /// Share of the candidates' combined EV × confidence
+    pub weight: f64,
+}
+
+impl Allocation {
+    pub fn cost(&self) -> f64 {
+        self.price * self.contracts as f64
+    }
+
+    pub fn describe(&self) -> String {
+        format!(
+            "{} {} x{} @ ${:.2}",
+            self.contract.ticker, self.contract.signal_type, self.contracts, self.price
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AllocationPlan {
+    pub expiry: DateTime<Utc>,
+    /// Budget left for this expiry after open positions and the exposure cap
+    pub budget: f64,
+    /// Notional already open at the expiry
+    pub committed: f64,
+    pub legs: Vec<Allocation>,
+    /// Signals that qualified but rounded down to zero contracts
+    pub skipped: usize,
+}
+
+impl AllocationPlan {
+    pub fn expiry_label(&self) -> String {
+        self.expiry.format("%H:%M UTC").to_string()
+    }
+
+    pub fn total_cost(&self) -> f64 {
+        self.legs.iter().map(Allocation::cost).sum()
+    }
+
+    /// Expected profit of the basket: EV per dollar times dollars spent
+    pub fn expected_profit(&self) -> f64 {
+        self.legs
+            .iter()
+            .map(|leg| leg.contract.expected_value * leg.cost())
+            .sum()
+    }
+
+    pub fn can_execute(&self) -> bool {
+        !self.legs.is_empty()
+    }
+}
+
+/// Basket for the soonest expiry with qualifying signals (None when there are none).
+/// `skip` filters out contracts the user has muted.
+pub fn plan(
+    contracts: &[Contract],
+    book: &ExposureBook,
+    config: &PlannerConfig,
+    exposure: &ExposureConfig,
+    skip: impl Fn(&Contract) -> bool,
+) -> Option<AllocationPlan> {
+    let candidates: Vec<(&Contract, DateTime<Utc>, f64)> = contracts
+        .iter()
+        .filter(|c| c.signal_type.starts_with("BUY "))
+        .filter(|c| c.expected_value >= config.min_ev && !skip(c))
+        .filter_map(|c| {
+            let expiry = c.expiry_time.as_deref().and_then(parse_timestamp)?;
+            let price = sizing::entry_price(c)?;
+            (expiry > Utc::now()).then_some((c, expiry, price))
+        })
+        .collect();
+    let expiry = candidates.iter().map(|(_, at, _)| *at).min()?;
+
+    let mut candidates: Vec<(&Contract, f64)> = candidates
+        .into_iter()
+        .filter(|(_, at, _)| *at == expiry)
+        .map(|(c, _, price)| (c, price))
+        .collect();
+    // Best first, so a leg cap or max_legs drops the weakest
+    candidates.sort_by(|a, b| weight(b.0).total_cmp(&weight(a.0)));
+    candidates.truncate(config.max_legs.max(1));
+
+    let expiry_text = candidates.first().and_then(|(c, _)| c.expiry_time.clone());
+    let committed = book.notional_at(expiry_text.as_deref());
+    let mut budget = (config.hourly_budget - committed).max(0.0);
+    if let Some(cap) = exposure.max_per_expiry {
+        budget = budget.min((cap - committed).max(0.0));
+    }
+
+    let dollars = share(
+        &candidates
+            .iter()
+            .map(|(c, _)| weight(c))
+            .collect::<Vec<_>>(),
+        budget,
+        config.max_per_contract,
+    );
+    let total_weight: f64 = candidates.iter().map(|(c, _)| weight(c)).sum();
+
+    let mut legs = Vec::new();
+    let mut skipped = 0;
+    for ((contract, price), dollars) in candidates.into_iter().zip(dollars) {
+        let count = (dollars / price).floor() as i32;
+        if count <= 0 {
+            skipped += 1;
+            continue;
+        }
+        legs.push(Allocation {
+            contract: contract.clone(),
+            contracts: count,
+            price,
+            weight: weight(contract) / total_weight,
+        });
+    }
+
+    Some(AllocationPlan {
+        expiry,
+        budget,
+        committed,
+        legs,
+        skipped,
+    })
+}
+
+/// EV scaled by the backend's confidence (0-1)
+fn weight(contract: &Contract) -> f64 {
+    contract.expected_value.max(0.0) * contract.confidence_score.clamp(0.0, 1.0)
+}
+
+/// Split `budget` by `weights`, capping each share at `cap` and handing the
+/// excess to the uncapped shares until nothing is left over
+fn share(weights: &[f64], budget: f64, cap: f64) -> Vec<f64> {
+    let mut dollars = vec![0.0; weights.len()];
+    let mut open: Vec<usize> = (0..weights.len()).filter(|i| weights[*i] > 0.0).collect();
+    let mut left = budget;
+    while left > 0.005 && !open.is_empty() {
+        let total: f64 = open.iter().map(|i| weights[*i]).sum();
+        let mut spent = 0.0;
+        let mut capped = Vec::new();
+        for &i in &open {
+            let add = (left * weights[i] / total).min(cap - dollars[i]);
+            dollars[i] += add;
+            spent += add;
+            if dollars[i] >= cap - 0.005 {
+                capped.push(i);
+            }
+        }
+        left -= spent;
+        if capped.is_empty() {
+            break;
+        }
+        open.retain(|i| !capped.contains(i));
+    }
+    dollars
+}
@@ -0,0 +1,87 @@
+//! External command plugins.
+//!
+//! `basilisk x <name> [args...]` runs `basilisk-<name>` from PATH, passing the
+//! remaining arguments through and a JSON context document on stdin:
+//!
+//! ```json
+//! { "api_url": "...", "config": { ... }, "signals": { "contracts": [...], "volatility": { ... } } }
+//! ```
+//!
+//! `signals` is `null` when the backend can't be reached, so plugins that only
+//! need the config still work offline.
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::api::client::ApiClient;
+use crate::api::models::CurrentResponse;
+use crate::config::Config;
+
+const PLUGIN_PREFIX: &str = "basilisk-";
+
+/// Everything a plugin gets on stdin
+#[derive(Debug, Serialize)]
+struct PluginContext<'a> {
+    api_url: &'a str,
+    config: &'a Config,
+    signals: Option<CurrentResponse>,
+}
+
+pub async fn handle_plugin_command(api_url: &str, name: &str, args: &[String]) -> Result<()> {
+    let config = Config::load()?;
+    let client = ApiClient::new(api_url.to_string(), 30)?;
+
+    let program = format!("{}{}", PLUGIN_PREFIX, name);
+    let mut child = match Command::new(&program)
+        .args(args)
+        .env("BASILISK_API_URL", api_url)
+        .stdin(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            bail!("Plugin '{}' not found: no `{}` on PATH", name, program)
+        }
+        Err(e) => return Err(e).with_context(|| format!("Failed to start {}", program)),
+    };
+
+    // Spawned first so a missing plugin fails fast; it waits on stdin meanwhile
+    let signals = match client.get_current_signals().await {
+        Ok(signals) => Some(signals),
+        Err(e) => {
+            eprintln!("⚠️  Could not fetch signals for plugin context: {}", e);
+            None
+        }
+    };
+
+    let context = PluginContext {
+        api_url,
+        config: &config,
+        signals,
+    };
+    let payload = serde_json::to_vec(&context)?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // Plugins that don't read their context may exit before we finish writing
+        if let Err(e) = stdin.write_all(&payload) {
+            if e.kind() != std::io::ErrorKind::BrokenPipe {
+                return Err(e).with_context(|| format!("Failed to send context to {}", program));
+            }
+        }
+    }
+
+    let status = child
+        .wait()
+        .with_context(|| format!("Failed to wait for {}", program))?;
+
+    if !status.success() {
+        match status.code() {
+            Some(code) => bail!("{} exited with status {}", program, code),
+            None => bail!("{} was terminated by a signal", program),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use anyhow::{Context, Result};
+use basilisk_quant::pricing;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::api::models::Position;
+
+/// File format for position exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    /// Guess the format from a file extension (defaults to JSON)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ExportFormat::Csv,
+            _ => ExportFormat::Json,
+        }
+    }
+}
+
+/// An open position enriched with risk fields for offline analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionRecord {
+    pub trade_id: i32,
+    pub ticker: String,
+    pub asset: String,
+    pub direction: String,
+    pub strike: f64,
+    pub contracts: i32,
+    pub entry_price: f64,
+    pub current_price: Option<f64>,
+    pub unrealized_pnl: Option<f64>,
+    pub expiry_at: Option<String>,
+    pub opened_at: String,
+    // Market context captured at export time
+    pub hours_to_expiry: Option<f64>,
+    pub spot_price: Option<f64>,
+    pub volatility: Option<f64>,
+    // Computed risk fields
+    pub breakeven: f64,  // Win probability needed to break even (= entry price)
+    pub max_loss: f64,   // Premium paid, lost if the contract settles against us
+    pub max_profit: f64, // Payout minus premium if the contract settles our way
+    pub vol_adjusted_distance: Option<f64>, // Std devs to strike, positive = winning
+}
+
+impl PositionRecord {
+    pub fn from_position(position: &Position, spot: Option<f64>, vol: Option<f64>) -> Self {
+        let contracts = position.contracts as f64;
+        let hours_to_expiry = position.hours_to_expiry();
+
+        let vol_adjusted_distance = match (spot, vol, hours_to_expiry) {
+            (Some(spot), Some(vol), Some(hours)) => pricing::vol_adjusted_distance(
+                &position.direction,
+                spot,
+                position.strike,
+                vol,
+                hours,
+            ),
+            _ => None,
+        };
+
+        Self {
+            trade_id: position.trade_id,
+            ticker: position.ticker.clone(),
+            asset: position.asset.clone(),
+            direction: position.direction.clone(),
+            strike: position.strike,
+            contracts: position.contracts,
+            entry_price: position.entry_price,
+            current_price: position.current_price,
+            unrealized_pnl: position.unrealized_pnl,
+            expiry_at: position.expiry_at.clone(),
+            opened_at: position.opened_at.clone(),
+            hours_to_expiry,
+            spot_price: spot,
+            volatility: vol,
+            breakeven: position.entry_price,
+            max_loss: position.entry_price * contracts,
+            max_profit: (1.0 - position.entry_price) * contracts,
+            vol_adjusted_distance,
+        }
+    }
+}
+
+/// Build export records from positions plus the current BTC spot and volatility.
+///
+/// Spot and vol only describe BTC, so other assets export without the
+/// market-dependent fields.
+pub fn build_records(
+    positions: &[Position],
+    spot: Option<f64>,
+    vol: Option<f64>,
+) -> Vec<PositionRecord> {
+    positions
+        .iter()
+        .map(|position| {
+            if position.asset.eq_ignore_ascii_case("BTC") {
+                PositionRecord::from_position(position, spot, vol)
+            } else {
+                PositionRecord::from_position(position, None, None)
+            }
+        })
+        .collect()
+}
+
+/// Write records to a file, or stdout when no path is given
+pub fn write_records(
+    records: &[PositionRecord],
+    format: ExportFormat,
+    out: Option<&Path>,
+) -> Result<()> {
+    let writer: Box<dyn Write> = match out {
+        Some(path) => Box::new(
+            File::create(path).with_context(|| format!("Failed to create {}", path.display()))?,
+        ),
+        None => Box::new(io::stdout()),
+    };
+
+    match format {
+        ExportFormat::Json => {
+            let mut writer = writer;
+            serde_json::to_writer_pretty(&mut writer, records)
+                .context("Failed to write JSON export")?;
+            writeln!(writer)?;
+        }
+        ExportFormat::Csv => {
+            let mut csv_writer = csv::Writer::from_writer(writer);
+            for record in records {
+                csv_writer
+                    .serialize(record)
+                    .context("Failed to write CSV export")?;
+            }
+            csv_writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Load records previously written by `positions export`
+pub fn read_records(path: &Path) -> Result<Vec<PositionRecord>> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+
+    match ExportFormat::from_path(path) {
+        ExportFormat::Json => serde_json::from_reader(file).context("Failed to parse JSON export"),
+        ExportFormat::Csv => csv::Reader::from_reader(file)
+            .deserialize()
+            .collect::<Result<Vec<PositionRecord>, _>>()
+            .context("Failed to parse CSV export"),
+    }
+}
@@ -0,0 +1,93 @@
+//! `--dry-run`: what an order would cost and risk, without sending it.
+//!
+//! Prices come from the order's limit or the latest quote for its side, and
+//! fees assume the order takes liquidity (see `fees`). Latency, tilt and
+//! exposure guards only run when the order is really sent.
+
+use crate::api::models::Position;
+use crate::fees;
+
+/// Expected outcome of buying contracts
+pub struct EntryPreview {
+    pub contracts: i32,
+    /// Dollars per contract
+    pub price: f64,
+    pub cost: f64,
+    pub fees: f64,
+    /// What the contracts pay if they settle in the money
+    pub max_payout: f64,
+    /// Cost plus fees, lost if they settle out of the money
+    pub max_loss: f64,
+}
+
+impl EntryPreview {
+    pub fn new(contracts: i32, price: f64) -> Self {
+        let cost = price * contracts as f64;
+        let fees = fees::taker_fee(contracts, price);
+        Self {
+            contracts,
+            price,
+            cost,
+            fees,
+            max_payout: contracts as f64,
+            max_loss: cost + fees,
+        }
+    }
+
+    /// P&L after both sides' fees if the contracts are later sold at `price`
+    pub fn exit_pnl(&self, price: f64) -> f64 {
+        (price - self.price) * self.contracts as f64
+            - self.fees
+            - fees::taker_fee(self.contracts, price)
+    }
+
+    pub fn print(&self) {
+        println!("🧪 Dry Run: nothing was sent");
+        println!("   Contracts:  {} @ ${:.2}", self.contracts, self.price);
+        println!("   Cost:       ${:.2}", self.cost);
+        println!("   Fees:       ${:.2} (est.)", self.fees);
+        println!("   Max Payout: ${:.2}", self.max_payout);
+        println!(
+            "   Max Profit: \x1b[32m${:+.2}\x1b[0m",
+            self.max_payout - self.max_loss
+        );
+        println!("   Max Loss:   \x1b[31m${:.2}\x1b[0m", self.max_loss);
+    }
+}
+
+/// Expected outcome of closing a position at `price`
+pub struct ExitPreview {
+    pub contracts: i32,
+    pub price: f64,
+    pub proceeds: f64,
+    pub fees: f64,
+    /// Against the entry price, after fees
+    pub pnl: f64,
+}
+
+impl ExitPreview {
+    pub fn new(position: &Position, price: f64) -> Self {
+        let proceeds = price * position.contracts as f64;
+        let fees = fees::taker_fee(position.contracts, price);
+        Self {
+            contracts: position.contracts,
+            price,
+            proceeds,
+            fees,
+            pnl: proceeds - position.entry_price * position.contracts as f64 - fees,
+        }
+    }
+
+    pub fn print(&self) {
+        let pnl_color = if self.pnl >= 0.0 {
+            "\x1b[32m"
+        } else {
+            "\x1b[31m"
+        };
+        println!("🧪 Dry Run: nothing was sent");
+        println!("   Contracts:  {} @ ${:.2}", self.contracts, self.price);
+        println!("   Proceeds:   ${:.2}", self.proceeds);
+        println!("   Fees:       ${:.2} (est.)", self.fees);
+        println!("   P&L:        {}${:+.2}\x1b[0m", pnl_color, self.pnl);
+    }
+}
@@ -0,0 +1,70 @@
+//! Re-entry cooldown after a stop-loss.
+//!
+//! A position closed early at a loss leaves its ticker in a cooldown of
+//! `[reentry] cooldown_minutes`: the auto-trader skips it, and manual entries
+//! (CLI and dashboard) get a warning. Settlements at expiry don't count, since
+//! an expired ticker can't be traded again anyway.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+use crate::api::client::ApiClient;
+use crate::api::models::TradeHistory;
+use crate::api::parse_timestamp;
+use crate::config::ReentryConfig;
+
+/// Recent trades scanned for stop-outs
+const HISTORY_LIMIT: i32 = 100;
+
+#[derive(Debug, Default)]
+pub struct ReentryGuard {
+    /// Latest losing close per ticker
+    stop_outs: HashMap<String, DateTime<Utc>>,
+    cooldown: Duration,
+}
+
+impl ReentryGuard {
+    pub fn from_history(history: &[TradeHistory], config: &ReentryConfig) -> Self {
+        let mut stop_outs: HashMap<String, DateTime<Utc>> = HashMap::new();
+        for trade in history {
+            if !trade.pnl.is_some_and(|pnl| pnl < 0.0) {
+                continue;
+            }
+            let Some(closed_at) = trade.closed_at.as_deref().and_then(parse_timestamp) else {
+                continue;
+            };
+            let latest = stop_outs.entry(trade.ticker.clone()).or_insert(closed_at);
+            *latest = (*latest).max(closed_at);
+        }
+
+        Self {
+            stop_outs,
+            cooldown: Duration::minutes(config.cooldown_minutes as i64),
+        }
+    }
+
+    /// Guard built from recent history; disabled (empty) when the cooldown is 0
+    pub async fn fetch(client: &ApiClient, config: &ReentryConfig) -> anyhow::Result<Self> {
+        if config.cooldown_minutes == 0 {
+            return Ok(Self::default());
+        }
+        let history = client.get_trade_history(HISTORY_LIMIT).await?;
+        Ok(Self::from_history(&history, config))
+    }
+
+    /// Why `ticker` is still cooling down, if it is
+    pub fn check(&self, ticker: &str) -> Option<String> {
+        let closed_at = self.stop_outs.get(ticker)?;
+        let since = Utc::now() - *closed_at;
+        if since >= self.cooldown {
+            return None;
+        }
+        Some(format!(
+            "{} was stopped out {}m ago (re-entry cooldown {}m, {}m left)",
+            ticker,
+            since.num_minutes(),
+            self.cooldown.num_minutes(),
+            (self.cooldown - since).num_minutes() + 1
+        ))
+    }
+}
@@ -0,0 +1,155 @@
+//! Bounded buffer of recent stream events.
+//!
+//! Every BTC price and contracts update from the SSE stream is kept for up to
+//! an hour, so a tab opened mid-session can draw its charts straight from the
+//! buffer instead of starting empty and waiting for the next update. On
+//! startup the buffer is seeded from the on-disk tick cache.
+
+use basilisk_quant::volatility;
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use std::collections::VecDeque;
+
+use crate::api::VolatilityData;
+use crate::events::AppEvent;
+use crate::tick_cache::Tick;
+
+/// Events kept at most (the stream sends roughly one price per second)
+const CAPACITY: usize = 4096;
+
+/// Oldest event kept
+const MAX_AGE_MINUTES: i64 = 60;
+
+/// Kalshi settles hourly BTC contracts on the average of the final minute
+pub const SETTLEMENT_WINDOW_SECS: i64 = 60;
+
+/// Shortest stretch of ticks worth computing a realized vol over
+const MIN_RV_SPAN_SECS: f64 = 600.0;
+
+#[derive(Debug, Clone)]
+enum Buffered {
+    BtcPrice(f64),
+    Volatility {
+        btc_price: Option<f64>,
+        volatility: VolatilityData,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct ReplayBuffer {
+    events: VecDeque<(DateTime<Utc>, Buffered)>,
+}
+
+impl ReplayBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefill with cached ticks from a previous session (only the last hour is kept)
+    pub fn seed(&mut self, ticks: &[Tick]) {
+        let cutoff = Utc::now() - Duration::minutes(MAX_AGE_MINUTES);
+        let mut seeded: VecDeque<_> = ticks
+            .iter()
+            .filter(|tick| tick.timestamp >= cutoff)
+            .map(|tick| (tick.timestamp, Buffered::BtcPrice(tick.price)))
+            .collect();
+        seeded.extend(self.events.drain(..));
+        while seeded.len() > CAPACITY {
+            seeded.pop_front();
+        }
+        self.events = seeded;
+    }
+
+    /// Keep the market data carried by a stream event (others are ignored)
+    pub fn record(&mut self, event: &AppEvent) {
+        let buffered = match event {
+            AppEvent::BtcPriceUpdate { price, .. } => Buffered::BtcPrice(*price),
+            AppEvent::ContractsUpdate {
+                asset,
+                contracts,
+                volatility,
+                ..
+            } if asset == "BTC" => Buffered::Volatility {
+                btc_price: contracts.iter().find_map(|c| c.current_btc_price),
+                volatility: volatility.clone(),
+            },
+            _ => return,
+        };
+
+        let now = Utc::now();
+        self.events.push_back((now, buffered));
+
+        let cutoff = now - Duration::minutes(MAX_AGE_MINUTES);
+        while self
+            .events
+            .front()
+            .is_some_and(|(at, _)| *at < cutoff || self.events.len() > CAPACITY)
+        {
+            self.events.pop_front();
+        }
+    }
+
+    /// BTC prices since the top of the current hour, oldest first
+    pub fn btc_this_hour(&self) -> Vec<f64> {
+        let now = Utc::now();
+        let hour_start = now.duration_trunc(Duration::hours(1)).unwrap_or(now);
+        self.btc_since(hour_start)
+            .into_iter()
+            .map(|(_, price)| price)
+            .collect()
+    }
+
+    /// Timestamped BTC prices (ticks and snapshots) since `since`, oldest first
+    pub fn btc_since(&self, since: DateTime<Utc>) -> Vec<(DateTime<Utc>, f64)> {
+        self.events
+            .iter()
+            .filter(|(at, _)| *at >= since)
+            .filter_map(|(at, event)| match event {
+                Buffered::BtcPrice(price) => Some((*at, *price)),
+                Buffered::Volatility { btc_price, .. } => btc_price.map(|price| (*at, price)),
+            })
+            .collect()
+    }
+
+    /// Streamed BTC prices (not snapshot prices), oldest first
+    fn btc_ticks(&self) -> impl Iterator<Item = (DateTime<Utc>, f64)> + '_ {
+        self.events.iter().filter_map(|(at, event)| match event {
+            Buffered::BtcPrice(price) if *price > 0.0 => Some((*at, *price)),
+            _ => None,
+        })
+    }
+
+    /// Annualized realized vol of the buffered ticks (None until there are ten
+    /// minutes of them)
+    pub fn realized_vol(&self) -> Option<f64> {
+        let ticks = self
+            .btc_ticks()
+            .map(|(at, price)| (at.timestamp_millis() as f64 / 1000.0, price));
+        volatility::realized_vol(ticks, MIN_RV_SPAN_SECS)
+    }
+
+    /// Average BTC price over the settlement window ending at `expiry` (the
+    /// running average while the window is still open)
+    pub fn settlement_average(&self, expiry: DateTime<Utc>) -> Option<f64> {
+        let start = expiry - Duration::seconds(SETTLEMENT_WINDOW_SECS);
+        let (total, count) = self
+            .btc_ticks()
+            .filter(|(at, _)| *at > start && *at <= expiry)
+            .fold((0.0, 0), |(total, count), (_, price)| {
+                (total + price, count + 1)
+            });
+        (count > 0).then(|| total / count as f64)
+    }
+
+    /// Implied vol readings over the buffer, oldest first
+    pub fn implied_vol(&self) -> Vec<f64> {
+        self.events
+            .iter()
+            .filter_map(|(_, event)| match event {
+                Buffered::Volatility { volatility, .. } if volatility.implied_vol > 0.0 => {
+                    Some(volatility.implied_vol)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
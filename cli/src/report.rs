@@ -0,0 +1,273 @@
+//! Shareable markdown report for one settled hour (`basilisk report`): the
+//! hour's signals and how they settled, my trades on it, and a text chart of
+//! BTC from the tick cache. Everything but the trades comes from files the
+//! dashboard records, so an hour it wasn't running for has little to show.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Timelike, Utc};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::api::client::ApiClient;
+use crate::api::models::TradeHistory;
+use crate::api::parse_timestamp;
+use crate::diary::{self, HourSnapshot};
+use crate::journal::{self, JournalEntry};
+use crate::rollover::{self, Settlement};
+use crate::tick_cache;
+
+/// Trades fetched from history to find the hour's
+const HISTORY_LIMIT: i32 = 500;
+
+/// Columns in the BTC sparkline (one per minute)
+const CHART_WIDTH: usize = 60;
+
+const SPARK: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+pub async fn handle_report_command(
+    api_url: &str,
+    hour: Option<u32>,
+    date: Option<NaiveDate>,
+    out: Option<PathBuf>,
+) -> Result<()> {
+    let expiry = expiry_for(hour, date)?;
+    let start = expiry - Duration::hours(1);
+
+    let settled: Vec<Settlement> = rollover::settlements()
+        .context("Failed to read settlements")?
+        .into_iter()
+        .filter(|s| s.expiry_time.as_deref().and_then(parse_timestamp) == Some(expiry))
+        .collect();
+
+    // Trades on the hour's contracts, or opened during it
+    let client = ApiClient::new(api_url.to_string(), 30)?;
+    let (trades, trades_note) = match client.get_trade_history(HISTORY_LIMIT).await {
+        Ok(history) => (
+            history
+                .into_iter()
+                .filter(|trade| {
+                    settled.iter().any(|s| s.ticker == trade.ticker)
+                        || parse_timestamp(&trade.opened_at)
+                            .is_some_and(|opened| opened >= start && opened < expiry)
+                })
+                .collect(),
+            None,
+        ),
+        Err(e) => (
+            Vec::new(),
+            Some(format!("Trade history unavailable: {}", e)),
+        ),
+    };
+    let journal = journal::load().unwrap_or_default();
+
+    let ticks: Vec<(DateTime<Utc>, f64)> = tick_cache::load(start)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|tick| tick.timestamp < expiry)
+        .map(|tick| (tick.timestamp, tick.price))
+        .collect();
+
+    let snapshot = diary::entries(expiry.date_naive())
+        .unwrap_or_default()
+        .into_iter()
+        .find(|s| s.expiry_time.as_deref().and_then(parse_timestamp) == Some(expiry));
+
+    let report = markdown(
+        expiry,
+        &settled,
+        &trades,
+        trades_note.as_deref(),
+        &journal,
+        &ticks,
+        snapshot.as_ref(),
+    );
+
+    match out {
+        Some(path) => {
+            std::fs::write(&path, report)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!(
+                "📝 Report for the {} hour written to {}",
+                expiry.format("%Y-%m-%d %H:%M UTC"),
+                path.display()
+            );
+        }
+        None => print!("{}", report),
+    }
+    Ok(())
+}
+
+/// Expiry of the hour asked for: `hour`:00 UTC on `date` (today by default),
+/// or the most recent top of the hour when no hour is given
+fn expiry_for(hour: Option<u32>, date: Option<NaiveDate>) -> Result<DateTime<Utc>> {
+    let now = Utc::now();
+    let hour = hour.unwrap_or_else(|| now.hour());
+    if hour > 23 {
+        bail!("--hour must be 0-23 (UTC), got {}", hour);
+    }
+    let day = date.unwrap_or_else(|| now.date_naive());
+    day.and_hms_opt(hour, 0, 0)
+        .map(|naive| Utc.from_utc_datetime(&naive))
+        .context("Invalid hour")
+}
+
+fn markdown(
+    expiry: DateTime<Utc>,
+    settled: &[Settlement],
+    trades: &[TradeHistory],
+    trades_note: Option<&str>,
+    journal: &[JournalEntry],
+    ticks: &[(DateTime<Utc>, f64)],
+    snapshot: Option<&HourSnapshot>,
+) -> String {
+    let mut text = format!(
+        "# Basilisk report: {} hour\n\n",
+        expiry.format("%Y-%m-%d %H:%M UTC")
+    );
+
+    // Headline numbers
+    let settle_price = settled.first().map(|s| s.settle_price);
+    let called: Vec<bool> = settled.iter().filter_map(|s| s.signal_correct).collect();
+    let pnl: f64 = trades.iter().filter_map(|t| t.pnl).sum();
+    if let Some(price) = settle_price {
+        text.push_str(&format!("- **Settled at:** ${:.2}\n", price));
+    }
+    text.push_str(&format!(
+        "- **Signals right:** {}/{}\n",
+        called.iter().filter(|c| **c).count(),
+        called.len()
+    ));
+    text.push_str(&format!(
+        "- **My trades:** {} (P&L ${:+.2})\n",
+        trades.len(),
+        pnl
+    ));
+    if let Some(snapshot) = snapshot {
+        text.push_str(&format!(
+            "- **Vol:** RV {:.1}% │ IV {:.1}% │ {}\n",
+            snapshot.vol.realized_vol * 100.0,
+            snapshot.vol.implied_vol * 100.0,
+            snapshot.vol.regime
+        ));
+    }
+
+    text.push_str("\n## BTC\n\n");
+    match chart(ticks) {
+        Some(chart) => text.push_str(&chart),
+        None => text.push_str("_No cached ticks for this hour (tick cache off, pruned, or the dashboard wasn't running)._\n"),
+    }
+
+    text.push_str("\n## Signals and settlement\n\n");
+    if settled.is_empty() {
+        text.push_str("_No settlements recorded for this hour. They're written by the dashboard at rollover._\n");
+    } else {
+        text.push_str("| Strike | Signal | Implied | Model | Outcome | Signal right |\n");
+        text.push_str("|--------|--------|---------|-------|---------|--------------|\n");
+        let mut rows: Vec<&Settlement> = settled.iter().collect();
+        rows.sort_by(|a, b| a.strike.unwrap_or(0.0).total_cmp(&b.strike.unwrap_or(0.0)));
+        for s in rows {
+            text.push_str(&format!(
+                "| ${:.0} | {} | {} | {} | {} | {} |\n",
+                s.strike.unwrap_or(0.0),
+                s.signal_type,
+                percent(s.implied_probability),
+                percent(s.model_probability),
+                s.outcome,
+                match s.signal_correct {
+                    Some(true) => "✅",
+                    Some(false) => "❌",
+                    None => "-",
+                }
+            ));
+        }
+    }
+
+    text.push_str("\n## My trades\n\n");
+    if let Some(note) = trades_note {
+        text.push_str(&format!("_{}_\n", note));
+    } else if trades.is_empty() {
+        text.push_str("_None._\n");
+    } else {
+        let by_trade_id: HashMap<i32, &JournalEntry> = journal
+            .iter()
+            .filter_map(|entry| entry.trade_id.map(|id| (id, entry)))
+            .collect();
+        text.push_str("| Trade | Ticker | Side | Qty | Entry | Exit | P&L | Origin |\n");
+        text.push_str("|-------|--------|------|-----|-------|------|-----|--------|\n");
+        for trade in trades {
+            let origin = by_trade_id
+                .get(&trade.id)
+                .map(|entry| match &entry.tag {
+                    Some(tag) => format!("{} ({})", entry.origin.label(), tag),
+                    None => entry.origin.label(),
+                })
+                .unwrap_or_else(|| "-".to_string());
+            text.push_str(&format!(
+                "| #{} | {} | {} | {} | ${:.2} | {} | {} | {} |\n",
+                trade.id,
+                trade.ticker,
+                trade.direction,
+                trade.contracts,
+                trade.entry_price,
+                trade
+                    .exit_price
+                    .map(|p| format!("${:.2}", p))
+                    .unwrap_or_else(|| "-".to_string()),
+                trade.pnl_display(),
+                origin
+            ));
+        }
+    }
+
+    text.push_str(&format!(
+        "\n_Generated {} by basilisk-cli._\n",
+        Utc::now().format("%Y-%m-%d %H:%M UTC")
+    ));
+    text
+}
+
+/// Minute-by-minute sparkline of the hour with open/high/low/close
+fn chart(ticks: &[(DateTime<Utc>, f64)]) -> Option<String> {
+    let (first_at, open) = *ticks.first()?;
+    let close = ticks.last()?.1;
+    let high = ticks.iter().map(|(_, p)| *p).fold(f64::MIN, f64::max);
+    let low = ticks.iter().map(|(_, p)| *p).fold(f64::MAX, f64::min);
+
+    // Last price in each minute, carried forward over gaps
+    let start = first_at - Duration::seconds(first_at.timestamp() % 60);
+    let mut columns: Vec<Option<f64>> = vec![None; CHART_WIDTH];
+    for (at, price) in ticks {
+        let minute = ((*at - start).num_seconds() / 60) as usize;
+        if let Some(column) = columns.get_mut(minute) {
+            *column = Some(*price);
+        }
+    }
+    let mut last = open;
+    let range = (high - low).max(f64::EPSILON);
+    let line: String = columns
+        .iter()
+        .map(|price| {
+            last = price.unwrap_or(last);
+            let level = ((last - low) / range * (SPARK.len() - 1) as f64).round() as usize;
+            SPARK[level.min(SPARK.len() - 1)]
+        })
+        .collect();
+
+    Some(format!(
+        "```\n{}\n```\n\nOpen ${:.2} │ High ${:.2} │ Low ${:.2} │ Close ${:.2} ({:+.2}%) │ {} ticks from {}\n",
+        line,
+        open,
+        high,
+        low,
+        close,
+        (close - open) / open * 100.0,
+        ticks.len(),
+        start.format("%H:%M UTC")
+    ))
+}
+
+fn percent(value: Option<f64>) -> String {
+    value
+        .map(|v| format!("{:.1}%", v * 100.0))
+        .unwrap_or_else(|| "-".to_string())
+}
@@ -0,0 +1,175 @@
+//! End-of-hour rollover: notices when an expiry drops out of the contract
+//! list, records how the expired set settled, and maps old strikes onto the
+//! next hour's contracts.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::api::{self, Contract};
+use crate::store;
+
+const SETTLEMENTS_FILE: &str = "settlements.jsonl";
+
+/// Observed outcome of one expired contract
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Settlement {
+    pub recorded_at: DateTime<Utc>,
+    pub ticker: String,
+    pub expiry_time: Option<String>,
+    pub strike: Option<f64>,
+    /// Average BTC price over the final minute, from streamed ticks (the last
+    /// price seen when the ticks don't cover it)
+    pub settle_price: f64,
+    /// "YES" if BTC finished above the strike, else "NO"
+    pub outcome: String,
+    pub signal_type: String,
+    pub implied_probability: Option<f64>,
+    pub model_probability: Option<f64>,
+    /// Whether the signal's side won (None for HOLD)
+    pub signal_correct: Option<bool>,
+    /// BTC % change over the trend window before expiry
+    #[serde(default)]
+    pub btc_momentum_pct: Option<f64>,
+}
+
+impl Settlement {
+    fn observe(
+        contract: &Contract,
+        settle_price: f64,
+        btc_momentum_pct: Option<f64>,
+    ) -> Option<Self> {
+        let strike = contract.strike_price?;
+        let outcome = if settle_price > strike { "YES" } else { "NO" };
+        let signal_correct = if contract.signal_type.contains("YES") {
+            Some(outcome == "YES")
+        } else if contract.signal_type.contains("NO") {
+            Some(outcome == "NO")
+        } else {
+            None
+        };
+
+        Some(Self {
+            recorded_at: Utc::now(),
+            ticker: contract.ticker.clone(),
+            expiry_time: contract.expiry_time.clone(),
+            strike: Some(strike),
+            settle_price,
+            outcome: outcome.to_string(),
+            signal_type: contract.signal_type.clone(),
+            implied_probability: contract.implied_probability,
+            model_probability: contract.model_probability,
+            signal_correct,
+            btc_momentum_pct,
+        })
+    }
+}
+
+/// Every settlement recorded so far
+pub fn settlements() -> Result<Vec<Settlement>> {
+    store::read_jsonl(SETTLEMENTS_FILE)
+}
+
+/// An expiry that has left the list, and the front expiry that replaced it
+#[derive(Debug)]
+pub struct Rollover {
+    pub expired: Vec<Contract>,
+    pub next_expiry: Option<String>,
+}
+
+impl Rollover {
+    /// Toast text, e.g. "14:00 UTC expired (12 contracts) → now trading 15:00 UTC"
+    pub fn summary(&self) -> String {
+        let old = self
+            .expired
+            .first()
+            .and_then(|c| c.expiry_time.as_deref())
+            .map(hour_label)
+            .unwrap_or_else(|| "Previous hour".to_string());
+        let mut text = format!("{} expired ({} contracts)", old, self.expired.len());
+        if let Some(next) = &self.next_expiry {
+            text.push_str(&format!(" → now trading {}", hour_label(next)));
+        }
+        text
+    }
+
+    /// Append the expired contracts' outcomes to `settlements.jsonl`, skipping
+    /// any already there (the dashboard and `auto --paper` both record them)
+    pub fn record_settlements(
+        &self,
+        settle_price: f64,
+        btc_momentum_pct: Option<f64>,
+    ) -> Result<Vec<Settlement>> {
+        if settle_price <= 0.0 {
+            return Ok(Vec::new());
+        }
+        let recorded: HashSet<(String, Option<String>)> = settlements()?
+            .into_iter()
+            .map(|s| (s.ticker, s.expiry_time))
+            .collect();
+        let settlements: Vec<Settlement> = self
+            .expired
+            .iter()
+            .filter(|c| !recorded.contains(&(c.ticker.clone(), c.expiry_time.clone())))
+            .filter_map(|c| Settlement::observe(c, settle_price, btc_momentum_pct))
+            .collect();
+        for settlement in &settlements {
+            store::append_jsonl(SETTLEMENTS_FILE, settlement)?;
+        }
+        Ok(settlements)
+    }
+
+    /// Contract in the new front expiry closest in strike to an expired one
+    pub fn equivalent<'a>(&self, old: &Contract, current: &'a [Contract]) -> Option<&'a Contract> {
+        let strike = old.strike_price?;
+        current
+            .iter()
+            .filter(|c| self.next_expiry.is_none() || c.expiry_time == self.next_expiry)
+            .filter_map(|c| c.strike_price.map(|s| (c, (s - strike).abs())))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(c, _)| c)
+    }
+}
+
+/// Compare consecutive snapshots; Some when a whole expiry has disappeared
+pub fn detect(previous: &[Contract], current: &[Contract]) -> Option<Rollover> {
+    if previous.is_empty() || current.is_empty() {
+        // Startup or an empty/failed fetch, not a rollover
+        return None;
+    }
+
+    let current_expiries: HashSet<Option<&str>> =
+        current.iter().map(|c| c.expiry_time.as_deref()).collect();
+
+    let expired: Vec<Contract> = previous
+        .iter()
+        .filter(|c| c.expiry_time.is_some())
+        .filter(|c| !current_expiries.contains(&c.expiry_time.as_deref()))
+        .cloned()
+        .collect();
+    if expired.is_empty() {
+        return None;
+    }
+
+    // The soonest expiry still listed is the one now being traded
+    let next_expiry = current
+        .iter()
+        .filter_map(|c| {
+            let time = c.expiry_time.as_deref()?;
+            Some((api::parse_timestamp(time)?, time))
+        })
+        .min_by_key(|(at, _)| *at)
+        .map(|(_, time)| time.to_string());
+
+    Some(Rollover {
+        expired,
+        next_expiry,
+    })
+}
+
+fn hour_label(expiry: &str) -> String {
+    api::parse_timestamp(expiry)
+        .map(|at| at.format("%H:%M UTC").to_string())
+        .unwrap_or_else(|| expiry.to_string())
+}
@@ -0,0 +1,390 @@
+//! Price-triggered orders evaluated locally (`basilisk rules`).
+//!
+//! A rule like "buy YES 98500 x3 if BTC trades above 98300 before :45" is
+//! kept in `rules.json` and watched by the dashboard, which has the live BTC
+//! stream: once the price crosses the trigger it places a market order on the
+//! listed contract at that strike with the soonest expiry. A rule fires once;
+//! past its deadline it expires instead. Rules only fire while a dashboard is
+//! running (and never in read-only mode).
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, Local, Timelike, Utc};
+use clap::{Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::models::{Contract, TradeRequest};
+use crate::api::parse_timestamp;
+use crate::store;
+
+const RULES_FILE: &str = "rules.json";
+
+/// Finished rules stay on the Rules tab this long after their last change
+const SHOW_FINISHED_FOR: Duration = Duration::hours(6);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "price", rename_all = "snake_case")]
+pub enum Trigger {
+    Above(f64),
+    Below(f64),
+}
+
+impl Trigger {
+    /// Did BTC reach the trigger anywhere in `low..=high`?
+    fn hit(&self, low: f64, high: f64) -> bool {
+        match *self {
+            Trigger::Above(level) => high > level,
+            Trigger::Below(level) => low < level,
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Trigger::Above(level) => format!("BTC > ${:.0}", level),
+            Trigger::Below(level) => format!("BTC < ${:.0}", level),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum RuleStatus {
+    Armed,
+    Fired {
+        trade_id: Option<i32>,
+        filled: i32,
+        price: Option<f64>,
+    },
+    Failed {
+        error: String,
+    },
+    Expired,
+    Cancelled,
+}
+
+impl RuleStatus {
+    pub fn label(&self) -> String {
+        match self {
+            RuleStatus::Armed => "armed".to_string(),
+            RuleStatus::Fired { filled, price, .. } => format!(
+                "fired: {} @ {}",
+                filled,
+                price
+                    .map(|p| format!("${:.2}", p))
+                    .unwrap_or_else(|| "--".to_string())
+            ),
+            RuleStatus::Failed { error } => format!("failed: {}", error),
+            RuleStatus::Expired => "expired".to_string(),
+            RuleStatus::Cancelled => "cancelled".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceRule {
+    pub id: u32,
+    /// "YES" or "NO"
+    pub direction: String,
+    pub strike: f64,
+    pub contracts: i32,
+    pub trigger: Trigger,
+    /// Expires unfired at this time
+    pub before: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub status: RuleStatus,
+    /// When the status last changed
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl PriceRule {
+    pub fn is_armed(&self) -> bool {
+        self.status == RuleStatus::Armed
+    }
+
+    /// "buy YES $98500 x3 if BTC > $98300 before 14:45"
+    pub fn describe(&self) -> String {
+        let mut text = format!(
+            "buy {} ${:.0} x{} if {}",
+            self.direction,
+            self.strike,
+            self.contracts,
+            self.trigger.label()
+        );
+        if let Some(before) = self.before {
+            text.push_str(&format!(
+                " before {}",
+                before.with_timezone(&Local).format("%H:%M")
+            ));
+        }
+        text
+    }
+
+    /// Listed contract at the rule's strike with the soonest expiry
+    pub fn contract<'a>(&self, contracts: &'a [Contract]) -> Option<&'a Contract> {
+        contracts
+            .iter()
+            .filter(|c| c.strike_price == Some(self.strike))
+            .filter_map(|c| Some((c, c.expiry_time.as_deref().and_then(parse_timestamp)?)))
+            .filter(|(_, expiry)| *expiry > Utc::now())
+            .min_by_key(|(_, expiry)| *expiry)
+            .map(|(c, _)| c)
+    }
+
+    /// Market order for the rule on `contract`; the client order ID keeps a
+    /// rule from filling twice if it's ever re-sent
+    pub fn order(&self, contract: &Contract) -> Result<TradeRequest> {
+        Ok(TradeRequest {
+            ticker: contract.ticker.clone(),
+            asset: contract.asset()?,
+            direction: self.direction.clone(),
+            strike: self.strike,
+            contracts: self.contracts,
+            order_type: "market".to_string(),
+            limit_price: None,
+            signal_id: None,
+            client_order_id: Some(format!("rule-{}-{}", self.id, self.created_at.timestamp())),
+            tag: None,
+            note: None,
+        })
+    }
+}
+
+/// Every rule ever added, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleBook {
+    rules: Vec<PriceRule>,
+}
+
+impl RuleBook {
+    /// Load saved rules (a missing or unreadable file means none)
+    pub fn load() -> Self {
+        store::read_json(RULES_FILE)
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        store::write_json(RULES_FILE, self)
+    }
+
+    pub fn rules(&self) -> &[PriceRule] {
+        &self.rules
+    }
+
+    /// Armed rules plus recently finished ones, newest first
+    pub fn recent(&self) -> Vec<&PriceRule> {
+        let cutoff = Utc::now() - SHOW_FINISHED_FOR;
+        self.rules
+            .iter()
+            .rev()
+            .filter(|r| r.is_armed() || r.updated_at.unwrap_or(r.created_at) >= cutoff)
+            .collect()
+    }
+
+    pub fn add(
+        &mut self,
+        direction: &str,
+        strike: f64,
+        contracts: i32,
+        trigger: Trigger,
+        before: Option<DateTime<Utc>>,
+    ) -> PriceRule {
+        let id = self.rules.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+        let rule = PriceRule {
+            id,
+            direction: direction.to_string(),
+            strike,
+            contracts,
+            trigger,
+            before,
+            created_at: Utc::now(),
+            status: RuleStatus::Armed,
+            updated_at: None,
+        };
+        self.rules.push(rule.clone());
+        rule
+    }
+
+    pub fn cancel(&mut self, id: u32) -> Result<()> {
+        let Some(rule) = self.rules.iter_mut().find(|r| r.id == id) else {
+            bail!("No rule #{}", id);
+        };
+        if !rule.is_armed() {
+            bail!("Rule #{} is already {}", id, rule.status.label());
+        }
+        rule.status = RuleStatus::Cancelled;
+        rule.updated_at = Some(Utc::now());
+        Ok(())
+    }
+
+    pub fn set_status(&mut self, id: u32, status: RuleStatus) {
+        if let Some(rule) = self.rules.iter_mut().find(|r| r.id == id) {
+            rule.status = status;
+            rule.updated_at = Some(Utc::now());
+        }
+    }
+
+    /// Expire armed rules past their deadline; true if any changed
+    pub fn expire(&mut self, now: DateTime<Utc>) -> bool {
+        let mut changed = false;
+        for rule in &mut self.rules {
+            if rule.is_armed() && rule.before.is_some_and(|before| now >= before) {
+                rule.status = RuleStatus::Expired;
+                rule.updated_at = Some(now);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Armed rules whose trigger BTC reached, having traded between `low` and `high`
+    pub fn due(&self, low: f64, high: f64) -> Vec<PriceRule> {
+        self.rules
+            .iter()
+            .filter(|r| r.is_armed() && r.trigger.hit(low, high))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Deadline for `--before MM`: that minute of the current hour
+fn deadline(minute: u32) -> Result<DateTime<Utc>> {
+    if minute > 59 {
+        bail!("--before is a minute of the hour (0-59), got {}", minute);
+    }
+    let now = Utc::now();
+    let at = now
+        .with_minute(minute)
+        .and_then(|at| at.with_second(0))
+        .and_then(|at| at.with_nanosecond(0))
+        .context("Invalid --before minute")?;
+    if at <= now {
+        bail!(
+            "--before :{:02} has already passed this hour ({} now)",
+            minute,
+            now.format("%H:%M UTC")
+        );
+    }
+    Ok(at)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Side {
+    Yes,
+    No,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RulesCommands {
+    /// Add a rule: buy SIDE at STRIKE when BTC crosses a level
+    #[command(name = "add")]
+    Add {
+        /// Contract side to buy
+        #[arg(value_enum)]
+        side: Side,
+        /// Strike of the contract (the soonest listed expiry is used)
+        strike: f64,
+        /// Number of contracts
+        #[arg(short, long, default_value = "1")]
+        size: i32,
+        /// Fire when BTC trades above this price
+        #[arg(long, required_unless_present = "below", conflicts_with = "below")]
+        above: Option<f64>,
+        /// Fire when BTC trades below this price
+        #[arg(long)]
+        below: Option<f64>,
+        /// Expire unfired at this minute of the current hour (e.g. 45 for :45)
+        #[arg(long)]
+        before: Option<u32>,
+    },
+
+    /// Show rules (armed ones, or every rule with --all)
+    #[command(name = "list")]
+    List {
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Cancel an armed rule
+    #[command(name = "cancel")]
+    Cancel {
+        /// Rule ID
+        id: u32,
+    },
+}
+
+pub fn handle_rules_command(cmd: RulesCommands) -> Result<()> {
+    let mut book = RuleBook::load();
+
+    match cmd {
+        RulesCommands::Add {
+            side,
+            strike,
+            size,
+            above,
+            below,
+            before,
+        } => {
+            if size <= 0 {
+                bail!("--size must be at least 1");
+            }
+            let trigger = match (above, below) {
+                (Some(level), _) => Trigger::Above(level),
+                (None, Some(level)) => Trigger::Below(level),
+                (None, None) => bail!("Give --above or --below"),
+            };
+            let before = before.map(deadline).transpose()?;
+            let direction = match side {
+                Side::Yes => "YES",
+                Side::No => "NO",
+            };
+            let rule = book.add(direction, strike, size, trigger, before);
+            book.save()?;
+            println!("👁 Rule #{} armed: {}", rule.id, rule.describe());
+            println!("   It fires from a running dashboard (Rules tab).");
+        }
+
+        RulesCommands::List { all } => {
+            book.expire(Utc::now());
+            let rules: Vec<&PriceRule> = book
+                .rules()
+                .iter()
+                .rev()
+                .filter(|rule| all || rule.is_armed())
+                .collect();
+            if rules.is_empty() {
+                println!("👁 No {}rules.", if all { "" } else { "armed " });
+                return Ok(());
+            }
+
+            println!("👁 Rules ({}):", rules.len());
+            println!("{}", "─".repeat(90));
+            println!("{:<5} {:<48} Status", "ID", "Rule");
+            println!("{}", "─".repeat(90));
+            for rule in rules {
+                let color = match rule.status {
+                    RuleStatus::Armed => "\x1b[36m",
+                    RuleStatus::Fired { .. } => "\x1b[32m",
+                    RuleStatus::Failed { .. } => "\x1b[31m",
+                    RuleStatus::Expired | RuleStatus::Cancelled => "\x1b[90m",
+                };
+                println!(
+                    "{:<5} {:<48} {}{}\x1b[0m",
+                    format!("#{}", rule.id),
+                    rule.describe(),
+                    color,
+                    rule.status.label()
+                );
+            }
+            println!("{}", "─".repeat(90));
+        }
+
+        RulesCommands::Cancel { id } => {
+            book.cancel(id)?;
+            book.save()?;
+            println!("✅ Rule #{} cancelled.", id);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! Composite 0-100 score per contract.
+//!
+//! Five parts, each scaled to 0..1 with 1 the most attractive: expected value,
+//! model confidence, liquidity, time remaining and the vol regime. The score is
+//! their weighted average using `[score]` in `config.toml`, unless the strategy
+//! script defines `score(contract, parts)`, in which case its result is used.
+//!
+//! The feed carries quotes but no order book depth, so liquidity is judged by
+//! the spread alone: how far YES + NO ask prices sum above $1.
+
+use serde::Serialize;
+
+use crate::api::models::Contract;
+use crate::config::ScoreConfig;
+use crate::scripting::ScriptHooks;
+
+/// Each factor scaled to 0..1 (handed to the script's `score()` as a map)
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Parts {
+    pub ev: f64,
+    pub confidence: f64,
+    pub liquidity: f64,
+    pub time: f64,
+    pub regime: f64,
+}
+
+impl Parts {
+    pub fn new(contract: &Contract, regime: &str, config: &ScoreConfig) -> Self {
+        let ev = if config.full_ev > 0.0 {
+            contract.expected_value / config.full_ev
+        } else {
+            0.0
+        };
+
+        // No quotes on either side reads as no liquidity
+        let liquidity = match contract.spread() {
+            Some(spread) if config.max_spread > 0.0 => 1.0 - spread.max(0.0) / config.max_spread,
+            _ => 0.0,
+        };
+
+        let time = match contract.seconds_to_expiry() {
+            Some(secs) if config.full_time_minutes > 0.0 => {
+                secs as f64 / 60.0 / config.full_time_minutes
+            }
+            _ => 0.0,
+        };
+
+        let regime = match regime {
+            "CALM" => 1.0,
+            "NORMAL" => 0.8,
+            "ELEVATED" => 0.4,
+            "CRISIS" => 0.1,
+            _ => 0.5,
+        };
+
+        Self {
+            ev: ev.clamp(0.0, 1.0),
+            confidence: contract.confidence_score.clamp(0.0, 1.0),
+            liquidity: liquidity.clamp(0.0, 1.0),
+            time: time.clamp(0.0, 1.0),
+            regime,
+        }
+    }
+
+    /// Weighted average on the 0-100 scale (0 when every weight is zero)
+    pub fn weighted(&self, config: &ScoreConfig) -> f64 {
+        let w = &config.weights;
+        let total = w.ev + w.confidence + w.liquidity + w.time + w.regime;
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let sum = self.ev * w.ev
+            + self.confidence * w.confidence
+            + self.liquidity * w.liquidity
+            + self.time * w.time
+            + self.regime * w.regime;
+        sum / total * 100.0
+    }
+}
+
+/// Scores contracts under one vol regime
+pub struct Scorer<'a> {
+    config: &'a ScoreConfig,
+    regime: &'a str,
+    script: Option<&'a ScriptHooks>,
+}
+
+impl<'a> Scorer<'a> {
+    pub fn new(config: &'a ScoreConfig, regime: &'a str, script: Option<&'a ScriptHooks>) -> Self {
+        Self {
+            config,
+            regime,
+            script,
+        }
+    }
+
+    /// 0-100; a script `score()` that fails falls back to the weighted formula
+    pub fn score(&self, contract: &Contract) -> f64 {
+        let parts = Parts::new(contract, self.regime, self.config);
+        self.script
+            .and_then(|s| s.score(contract, &parts).ok().flatten())
+            .unwrap_or_else(|| parts.weighted(self.config))
+            .clamp(0.0, 100.0)
+    }
+}
@@ -0,0 +1,224 @@
+//! User strategy hooks written in Rhai.
+//!
+//! The script named by `[scripting] script` in `config.toml` may define:
+//!
+//! ```text
+//! fn filter(contract) { contract.expected_value > 0.08 && contract.implied_probability < 0.3 }
+//! fn size(contract, bankroll) { (bankroll * 0.02 / contract.recommended_price).to_int() }
+//!
+//! fn score(contract, parts) { parts.ev * 60.0 + parts.liquidity * 40.0 }
+//! ```
+//!
+//! `contract` is a map with the same fields as the API's contract JSON, and
+//! `parts` holds the 0..1 components of the built-in score (see `score.rs`).
+//! Every function is optional; callers fall back to built-in behaviour for any
+//! hook the script doesn't define or that fails to evaluate.
+
+use anyhow::{anyhow, Context, Result};
+use clap::Subcommand;
+use rhai::{Dynamic, Engine, Scope, AST};
+
+use crate::api::client::ApiClient;
+use crate::api::models::Contract;
+use crate::bankroll;
+use crate::config::{Config, ScriptingConfig};
+use crate::score::{Parts, Scorer};
+use crate::store;
+
+/// Operation budget per hook call, so a runaway loop can't hang the dashboard
+const MAX_OPERATIONS: u64 = 100_000;
+
+pub struct ScriptHooks {
+    engine: Engine,
+    ast: AST,
+    has_filter: bool,
+    has_size: bool,
+    has_score: bool,
+}
+
+impl ScriptHooks {
+    /// Compile the configured script (None when no script is configured)
+    pub fn load(config: &ScriptingConfig) -> Result<Option<Self>> {
+        let Some(path) = &config.script else {
+            return Ok(None);
+        };
+
+        // Relative paths are looked up in the data directory
+        let path = if path.is_absolute() {
+            path.clone()
+        } else {
+            store::data_dir()?.join(path)
+        };
+
+        let source = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read script {}", path.display()))?;
+
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+
+        let ast = engine
+            .compile(&source)
+            .map_err(|e| anyhow!("Failed to compile script {}: {}", path.display(), e))?;
+
+        let defines = |name: &str, arity: usize| {
+            ast.iter_functions()
+                .any(|f| f.name == name && f.params.len() == arity)
+        };
+        let has_filter = defines("filter", 1);
+        let has_size = defines("size", 2);
+        let has_score = defines("score", 2);
+
+        Ok(Some(Self {
+            engine,
+            ast,
+            has_filter,
+            has_size,
+            has_score,
+        }))
+    }
+
+    pub fn has_filter(&self) -> bool {
+        self.has_filter
+    }
+
+    pub fn has_size(&self) -> bool {
+        self.has_size
+    }
+
+    /// Run `filter(contract)`; Ok(None) if the script doesn't define it
+    pub fn filter(&self, contract: &Contract) -> Result<Option<bool>> {
+        if !self.has_filter {
+            return Ok(None);
+        }
+        let result = self.call("filter", (contract_to_dynamic(contract)?,))?;
+        result
+            .as_bool()
+            .map(Some)
+            .map_err(|ty| anyhow!("filter() must return a bool, got {}", ty))
+    }
+
+    /// Run `size(contract, bankroll)`; Ok(None) if the script doesn't define it
+    pub fn size(&self, contract: &Contract, bankroll: f64) -> Result<Option<i32>> {
+        if !self.has_size {
+            return Ok(None);
+        }
+        let result = self.call("size", (contract_to_dynamic(contract)?, bankroll))?;
+
+        // Accept either an integer or a float (rounded down)
+        let contracts = match result.as_int() {
+            Ok(n) => n,
+            Err(_) => result
+                .as_float()
+                .map(|f| f.floor() as i64)
+                .map_err(|ty| anyhow!("size() must return a number, got {}", ty))?,
+        };
+        Ok(Some(contracts.clamp(0, i32::MAX as i64) as i32))
+    }
+
+    /// Run `score(contract, parts)`; Ok(None) if the script doesn't define it
+    pub fn score(&self, contract: &Contract, parts: &Parts) -> Result<Option<f64>> {
+        if !self.has_score {
+            return Ok(None);
+        }
+        let parts = rhai::serde::to_dynamic(parts)
+            .map_err(|e| anyhow!("Failed to convert score parts: {}", e))?;
+        let result = self.call("score", (contract_to_dynamic(contract)?, parts))?;
+        match result.as_float() {
+            Ok(score) => Ok(Some(score)),
+            Err(_) => result
+                .as_int()
+                .map(|n| Some(n as f64))
+                .map_err(|ty| anyhow!("score() must return a number, got {}", ty)),
+        }
+    }
+
+    fn call(&self, name: &str, args: impl rhai::FuncArgs) -> Result<Dynamic> {
+        self.engine
+            .call_fn::<Dynamic>(&mut Scope::new(), &self.ast, name, args)
+            .map_err(|e| anyhow!("Script {}() failed: {}", name, e))
+    }
+}
+
+fn contract_to_dynamic(contract: &Contract) -> Result<Dynamic> {
+    rhai::serde::to_dynamic(contract).map_err(|e| anyhow!("Failed to convert contract: {}", e))
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ScriptCommands {
+    /// Run the configured script's hooks against current signals
+    #[command(name = "check")]
+    Check {
+        /// Bankroll passed to size() (defaults to the account balance)
+        #[arg(long)]
+        bankroll: Option<f64>,
+    },
+}
+
+pub async fn handle_script_command(cmd: ScriptCommands, api_url: &str) -> Result<()> {
+    let config = Config::load()?;
+    let Some(hooks) = ScriptHooks::load(&config.scripting)? else {
+        println!("📜 No script configured. Set `script` under [scripting] in config.toml.");
+        return Ok(());
+    };
+
+    match cmd {
+        ScriptCommands::Check { bankroll } => {
+            let client = ApiClient::new(api_url.to_string(), 30)?;
+            let bankroll = match bankroll {
+                Some(bankroll) => bankroll,
+                None => bankroll::bankroll(&client).await?,
+            };
+            let signals = client.get_current_signals().await?;
+            let builtin = Scorer::new(&config.score, &signals.volatility.regime, None);
+
+            println!(
+                "📜 Script hooks: filter() {}, size() {}, score() {}",
+                if hooks.has_filter { "✓" } else { "—" },
+                if hooks.has_size { "✓" } else { "—" },
+                if hooks.has_score { "✓" } else { "—" }
+            );
+            println!("Bankroll: ${:.2}", bankroll);
+            println!("{}", "─".repeat(88));
+            println!(
+                "{:<32} {:<10} {:<8} {:<8} {:<8} {:<8} Size",
+                "Ticker", "Signal", "EV", "Built-in", "Score", "Filter"
+            );
+            println!("{}", "─".repeat(88));
+
+            for contract in &signals.contracts {
+                let filter = match hooks.filter(contract) {
+                    Ok(Some(true)) => "pass".to_string(),
+                    Ok(Some(false)) => "skip".to_string(),
+                    Ok(None) => "—".to_string(),
+                    Err(e) => format!("error: {}", e),
+                };
+                let size = match hooks.size(contract, bankroll) {
+                    Ok(Some(n)) => n.to_string(),
+                    Ok(None) => "—".to_string(),
+                    Err(e) => format!("error: {}", e),
+                };
+
+                let parts = Parts::new(contract, &signals.volatility.regime, &config.score);
+                let score = match hooks.score(contract, &parts) {
+                    Ok(Some(score)) => format!("{:.0}", score),
+                    Ok(None) => "—".to_string(),
+                    Err(e) => format!("error: {}", e),
+                };
+
+                println!(
+                    "{:<32} {:<10} {:<8} {:<8.0} {:<8} {:<8} {}",
+                    contract.ticker,
+                    contract.signal_type,
+                    contract.ev_display(),
+                    builtin.score(contract),
+                    score,
+                    filter,
+                    size
+                );
+            }
+            println!("{}", "─".repeat(88));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Order sizing presets (`[sizing]` in config.toml).
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::models::Contract;
+
+/// One quick-size option, e.g. `{ contracts = 5 }`, `{ kelly = 0.25 }`, `{ notional = 100 }`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizePreset {
+    /// Fixed number of contracts
+    Contracts(i32),
+    /// Fraction of the full Kelly stake (0.25 = quarter Kelly)
+    Kelly(f64),
+    /// Dollar amount to spend at the current entry price
+    Notional(f64),
+    /// Percentage of the bankroll to spend at the current entry price
+    Percent(f64),
+}
+
+impl SizePreset {
+    pub fn label(&self) -> String {
+        match self {
+            SizePreset::Contracts(n) => format!("{} contracts", n),
+            SizePreset::Kelly(fraction) => format!("Kelly {:.0}%", fraction * 100.0),
+            SizePreset::Notional(dollars) => format!("${:.0} notional", dollars),
+            SizePreset::Percent(pct) => format!("{}% of bankroll", pct),
+        }
+    }
+
+    /// Contracts this preset buys on `contract` (None when inputs are missing)
+    pub fn contracts(&self, contract: &Contract, bankroll: Option<f64>) -> Option<i32> {
+        match *self {
+            SizePreset::Contracts(n) => Some(n),
+            SizePreset::Notional(dollars) => {
+                let price = entry_price(contract)?;
+                Some((dollars / price).floor() as i32)
+            }
+            SizePreset::Percent(pct) => {
+                let price = entry_price(contract)?;
+                Some((bankroll? * pct / 100.0 / price).floor() as i32)
+            }
+            SizePreset::Kelly(fraction) => {
+                let price = entry_price(contract)?;
+                let stake =
+                    kelly_fraction(win_probability(contract)?, price) * fraction * bankroll?;
+                Some((stake / price).floor() as i32)
+            }
+        }
+    }
+}
+
+pub fn default_presets() -> Vec<SizePreset> {
+    vec![
+        SizePreset::Contracts(1),
+        SizePreset::Contracts(5),
+        SizePreset::Kelly(0.25),
+        SizePreset::Notional(100.0),
+    ]
+}
+
+/// Price per contract for the signal's side, in dollars
+pub fn entry_price(contract: &Contract) -> Option<f64> {
+    (contract.recommended_price > 0.0 && contract.recommended_price < 1.0)
+        .then_some(contract.recommended_price)
+}
+
+/// Model probability that the signal's side wins
+pub fn win_probability(contract: &Contract) -> Option<f64> {
+    let p_yes = contract.model_probability?;
+    Some(if contract.signal_type.contains("NO") {
+        1.0 - p_yes
+    } else {
+        p_yes
+    })
+}
+
+/// Full-Kelly fraction of bankroll for a binary contract bought at `price`
+pub fn kelly_fraction(win_prob: f64, price: f64) -> f64 {
+    if price <= 0.0 || price >= 1.0 {
+        return 0.0;
+    }
+    ((win_prob - price) / (1.0 - price)).max(0.0)
+}
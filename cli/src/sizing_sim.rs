@@ -0,0 +1,290 @@
+//! Bankroll growth simulation from past trade outcomes (`basilisk sizing-sim`).
+//!
+//! Each closed trade is reduced to its return per dollar staked (P&L over
+//! premium paid). Every simulated path draws that many trades with replacement
+//! and compounds them under one sizing scheme, so the schemes are compared on
+//! the same edge: median growth, the spread of outcomes, drawdown and the
+//! chance of the bankroll falling below the ruin line.
+//!
+//! The configured `[sizing] presets` are simulated alongside a reference set of
+//! fixed-fraction and fractional-Kelly schemes. Kelly here is estimated from the
+//! outcomes themselves: the bankroll fraction that maximizes mean log growth.
+
+use anyhow::{bail, Result};
+use basilisk_quant::monte_carlo::percentile;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::api::client::ApiClient;
+use crate::api::models::TradeHistory;
+use crate::bankroll;
+use crate::config::Config;
+use crate::sizing::SizePreset;
+
+/// Trades fetched from history to resample
+const HISTORY_LIMIT: i32 = 1000;
+
+/// Fewest closed trades worth resampling
+const MIN_TRADES: usize = 10;
+
+/// Settings for one simulation run
+#[derive(Debug, Clone)]
+pub struct SimConfig {
+    pub paths: usize,
+    /// Trades per path
+    pub trades: usize,
+    /// Starting bankroll (None = current account equity)
+    pub bankroll: Option<f64>,
+    /// Ruin once the bankroll drops below this fraction of the start
+    pub ruin_below: f64,
+    pub seed: Option<u64>,
+}
+
+/// How much of the bankroll each trade puts up
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scheme {
+    /// Fixed dollars per trade
+    Flat(f64),
+    /// Fixed fraction of the current bankroll
+    Fraction(f64),
+    /// Fraction of the estimated full-Kelly stake
+    Kelly(f64),
+}
+
+impl Scheme {
+    fn label(&self) -> String {
+        match self {
+            Scheme::Flat(dollars) => format!("Flat ${:.2}", dollars),
+            Scheme::Fraction(fraction) => format!("Fixed {:.1}%", fraction * 100.0),
+            Scheme::Kelly(fraction) => format!("Kelly {:.0}%", fraction * 100.0),
+        }
+    }
+
+    /// Stake for the next trade, never more than the bankroll
+    fn stake(&self, bankroll: f64, full_kelly: f64) -> f64 {
+        let stake = match *self {
+            Scheme::Flat(dollars) => dollars,
+            Scheme::Fraction(fraction) => bankroll * fraction,
+            Scheme::Kelly(fraction) => bankroll * full_kelly * fraction,
+        };
+        stake.clamp(0.0, bankroll.max(0.0))
+    }
+}
+
+/// Schemes always compared against the configured presets
+const REFERENCE: [Scheme; 6] = [
+    Scheme::Fraction(0.01),
+    Scheme::Fraction(0.02),
+    Scheme::Fraction(0.05),
+    Scheme::Kelly(0.25),
+    Scheme::Kelly(0.5),
+    Scheme::Kelly(1.0),
+];
+
+#[derive(Debug, Clone)]
+struct Outcome {
+    median_final: f64,
+    p5_final: f64,
+    p95_final: f64,
+    median_drawdown: f64,
+    risk_of_ruin: f64,
+}
+
+/// Return per dollar staked for each closed trade with a known P&L
+fn trade_returns(history: &[TradeHistory]) -> (Vec<f64>, f64) {
+    let closed: Vec<_> = history
+        .iter()
+        .filter(|t| t.entry_price > 0.0 && t.contracts > 0)
+        .filter_map(|t| Some((t.pnl?, t.entry_price * t.contracts as f64, t.entry_price)))
+        .collect();
+    let returns = closed
+        .iter()
+        .map(|(pnl, premium, _)| pnl / premium)
+        .collect();
+    let avg_price = closed
+        .iter()
+        .fold(0.0, |total, (_, _, price)| total + price)
+        / closed.len().max(1) as f64;
+    (returns, avg_price)
+}
+
+/// Bankroll fraction maximizing mean log growth over the outcomes (0 = no edge)
+fn full_kelly(returns: &[f64]) -> f64 {
+    (0..100)
+        .map(|i| i as f64 / 100.0)
+        .map(|f| {
+            let growth = returns
+                .iter()
+                .fold(0.0, |total, r| total + (1.0 + f * r).max(1e-9).ln());
+            (f, growth)
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(0.0, |(f, _)| f)
+}
+
+fn simulate(
+    returns: &[f64],
+    scheme: Scheme,
+    full_kelly: f64,
+    start: f64,
+    config: &SimConfig,
+    rng: &mut StdRng,
+) -> Outcome {
+    let ruin_line = start * config.ruin_below;
+    let mut finals = Vec::with_capacity(config.paths);
+    let mut drawdowns = Vec::with_capacity(config.paths);
+    let mut ruined = 0usize;
+
+    for _ in 0..config.paths {
+        let mut bankroll = start;
+        let mut peak = start;
+        let mut max_drawdown: f64 = 0.0;
+        let mut hit_ruin = false;
+
+        for _ in 0..config.trades {
+            let r = returns[rng.gen_range(0..returns.len())];
+            bankroll = (bankroll + scheme.stake(bankroll, full_kelly) * r).max(0.0);
+            peak = peak.max(bankroll);
+            max_drawdown = max_drawdown.max(1.0 - bankroll / peak);
+            if bankroll < ruin_line {
+                hit_ruin = true;
+            }
+        }
+
+        finals.push(bankroll);
+        drawdowns.push(max_drawdown);
+        if hit_ruin {
+            ruined += 1;
+        }
+    }
+
+    finals.sort_by(|a, b| a.total_cmp(b));
+    drawdowns.sort_by(|a, b| a.total_cmp(b));
+    let n = finals.len();
+
+    Outcome {
+        median_final: percentile(&finals, 0.5),
+        p5_final: percentile(&finals, 0.05),
+        p95_final: percentile(&finals, 0.95),
+        median_drawdown: percentile(&drawdowns, 0.5),
+        risk_of_ruin: ruined as f64 / n as f64,
+    }
+}
+
+pub async fn handle_sizing_sim_command(api_url: &str, config: SimConfig) -> Result<()> {
+    if config.paths == 0 || config.trades == 0 {
+        bail!("--paths and --trades must be at least 1");
+    }
+
+    let settings = Config::load()?;
+    let client = ApiClient::new(api_url.to_string(), 30)?;
+    let history = client.get_trade_history(HISTORY_LIMIT).await?;
+    let (returns, avg_price) = trade_returns(&history);
+    if returns.len() < MIN_TRADES {
+        bail!(
+            "Need at least {} closed trades with recorded P&L to resample (found {})",
+            MIN_TRADES,
+            returns.len()
+        );
+    }
+
+    let start = match config.bankroll {
+        Some(bankroll) => bankroll,
+        None => bankroll::bankroll(&client).await?,
+    };
+    if start <= 0.0 {
+        bail!("Starting bankroll must be positive (pass --bankroll)");
+    }
+
+    // Configured presets first, labelled with their trade-dialog key
+    let mut schemes: Vec<(String, Scheme)> = settings
+        .sizing
+        .presets
+        .iter()
+        .take(9)
+        .enumerate()
+        .map(|(i, preset)| {
+            let scheme = match *preset {
+                SizePreset::Contracts(n) => Scheme::Flat(n as f64 * avg_price),
+                SizePreset::Notional(dollars) => Scheme::Flat(dollars),
+                SizePreset::Percent(pct) => Scheme::Fraction(pct / 100.0),
+                SizePreset::Kelly(fraction) => Scheme::Kelly(fraction),
+            };
+            (format!("[{}] {}", i + 1, preset.label()), scheme)
+        })
+        .collect();
+    for scheme in REFERENCE {
+        if !schemes.iter().any(|(_, s)| *s == scheme) {
+            schemes.push((scheme.label(), scheme));
+        }
+    }
+
+    let kelly = full_kelly(&returns);
+    let wins = returns.iter().filter(|r| **r > 0.0).count();
+    let mean_return = returns.iter().fold(0.0, |total, r| total + r) / returns.len() as f64;
+
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    println!(
+        "🎲 Sizing Simulation - {} past trades resampled, {} paths x {} trades from ${:.2}",
+        returns.len(),
+        config.paths,
+        config.trades,
+        start
+    );
+    println!(
+        "   Win rate {:.1}%, mean return {:+.1}% per $ staked, full Kelly {:.0}% of bankroll",
+        wins as f64 / returns.len() as f64 * 100.0,
+        mean_return * 100.0,
+        kelly * 100.0
+    );
+    println!("{}", "─".repeat(96));
+    println!(
+        "{:<26} {:>12} {:>9} {:>12} {:>12} {:>8} {:>8}",
+        "Scheme", "Median End", "Growth", "5th pct", "95th pct", "Max DD", "Ruin"
+    );
+    println!("{}", "─".repeat(96));
+
+    for (label, scheme) in &schemes {
+        let outcome = simulate(&returns, *scheme, kelly, start, &config, &mut rng);
+        let growth = outcome.median_final / start - 1.0;
+        let growth_color = if growth >= 0.0 {
+            "\x1b[32m"
+        } else {
+            "\x1b[31m"
+        };
+        let ruin_color = if outcome.risk_of_ruin >= 0.05 {
+            "\x1b[31m"
+        } else if outcome.risk_of_ruin > 0.0 {
+            "\x1b[33m"
+        } else {
+            "\x1b[0m"
+        };
+
+        println!(
+            "{:<26} {:>12} {}{:>9}\x1b[0m {:>12} {:>12} {:>8} {}{:>8}\x1b[0m",
+            label,
+            format!("${:.2}", outcome.median_final),
+            growth_color,
+            format!("{:+.1}%", growth * 100.0),
+            format!("${:.2}", outcome.p5_final),
+            format!("${:.2}", outcome.p95_final),
+            format!("{:.1}%", outcome.median_drawdown * 100.0),
+            ruin_color,
+            format!("{:.1}%", outcome.risk_of_ruin * 100.0)
+        );
+    }
+    println!("{}", "─".repeat(96));
+    println!(
+        "Ruin = bankroll below {:.0}% of the start at any point. Max DD is the median path's worst drawdown.",
+        config.ruin_below * 100.0
+    );
+    if kelly == 0.0 {
+        println!("⚠️  No edge in these outcomes: Kelly schemes stake nothing.");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+//! Soak test: the dashboard run headless for hours (hidden `--soak <duration>`).
+//!
+//! Everything but the terminal runs as usual: the SSE tasks, polling, rule
+//! checks, alerts and rendering (to an in-memory screen). Point `--api-url` at
+//! the backend's demo simulator so the stream never goes quiet. Once a minute
+//! the run samples its resident memory and live tokio tasks; at the end it
+//! fails (non-zero exit) if, measured from the end of the warm-up:
+//!
+//! - resident memory grew more than `MAX_RSS_GROWTH_MB`
+//! - live tasks grew by more than `MAX_TASK_GROWTH`
+//!
+//! or, over the whole run, more than `MAX_BACKLOG` stream events sat in the
+//! channel at once or the stream reconnected more than `MAX_RECONNECTS` times.
+//! Those are the symptoms of a history that is never trimmed, a task spawned
+//! per reconnect, or a loop that falls behind its stream.
+
+use anyhow::{bail, Result};
+use std::time::{Duration, Instant};
+
+/// Size of the in-memory screen the dashboard renders to
+pub const SCREEN_WIDTH: u16 = 160;
+pub const SCREEN_HEIGHT: u16 = 48;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Caches and histories fill during the first stretch of a run; growth is
+/// measured from the end of this (or a quarter of the run, if shorter)
+const WARMUP: Duration = Duration::from_secs(15 * 60);
+
+const MAX_RSS_GROWTH_MB: f64 = 64.0;
+/// Pooled HTTP connections each hold a task, so a few come and go
+const MAX_TASK_GROWTH: usize = 8;
+const MAX_BACKLOG: usize = 1_000;
+const MAX_RECONNECTS: u32 = 20;
+
+/// Parse a duration given as a number with an `s`, `m` or `h` suffix (e.g.
+/// `4h` for `--soak`, `3m` for `guard --close-before-expiry`)
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let unit_at = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(unit_at);
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("expected e.g. 90s, 30m or 4h, got '{}'", value))?;
+    let secs = match unit {
+        "s" => number,
+        "m" => number * 60,
+        "h" => number * 3600,
+        _ => return Err(format!("expected e.g. 90s, 30m or 4h, got '{}'", value)),
+    };
+    if secs == 0 {
+        return Err("duration must be positive".to_string());
+    }
+    Ok(Duration::from_secs(secs))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Usage {
+    /// Resident memory in MB (None where /proc isn't available)
+    rss_mb: Option<f64>,
+    tasks: usize,
+}
+
+impl Usage {
+    fn now() -> Self {
+        Self {
+            rss_mb: resident_mb(),
+            tasks: tokio::runtime::Handle::current()
+                .metrics()
+                .num_alive_tasks(),
+        }
+    }
+}
+
+pub struct Soak {
+    duration: Duration,
+    started: Instant,
+    last_sample: Instant,
+    /// Usage at the end of the warm-up
+    baseline: Option<Usage>,
+    peak_backlog: usize,
+    reconnects: u32,
+}
+
+impl Soak {
+    pub fn new(duration: Duration) -> Self {
+        let now = Instant::now();
+        Self {
+            duration,
+            started: now,
+            last_sample: now,
+            baseline: None,
+            peak_backlog: 0,
+            reconnects: 0,
+        }
+    }
+
+    fn warmup(&self) -> Duration {
+        WARMUP.min(self.duration / 4)
+    }
+
+    /// Stream events waiting in the channel before this loop drains it
+    pub fn observe_backlog(&mut self, pending: usize) {
+        self.peak_backlog = self.peak_backlog.max(pending);
+    }
+
+    /// Called every loop; samples once a minute and returns true when the run is over
+    pub fn tick(&mut self, reconnects: u32) -> bool {
+        self.reconnects = reconnects;
+        let elapsed = self.started.elapsed();
+
+        if self.baseline.is_none() && elapsed >= self.warmup() {
+            self.baseline = Some(Usage::now());
+        }
+        if self.last_sample.elapsed() >= SAMPLE_INTERVAL {
+            let usage = Usage::now();
+            self.last_sample = Instant::now();
+            println!(
+                "⏱  {:>4}m │ RSS {} │ tasks {} │ peak backlog {} │ reconnects {}",
+                elapsed.as_secs() / 60,
+                usage
+                    .rss_mb
+                    .map_or("n/a".to_string(), |mb| format!("{:.1} MB", mb)),
+                usage.tasks,
+                self.peak_backlog,
+                self.reconnects
+            );
+        }
+
+        elapsed >= self.duration
+    }
+
+    /// Print the summary and fail if any threshold was exceeded
+    pub fn finish(&self) -> Result<()> {
+        let end = Usage::now();
+        let baseline = self.baseline.unwrap_or(end);
+        let mut failures = Vec::new();
+
+        println!();
+        println!(
+            "\x1b[1;36m🧪 Soak test: {} minutes\x1b[0m",
+            self.started.elapsed().as_secs() / 60
+        );
+
+        match (baseline.rss_mb, end.rss_mb) {
+            (Some(start), Some(end)) => {
+                let growth = end - start;
+                println!(
+                    "   RSS:          {:.1} MB → {:.1} MB ({:+.1} MB after warm-up)",
+                    start, end, growth
+                );
+                if growth > MAX_RSS_GROWTH_MB {
+                    failures.push(format!(
+                        "memory grew {:.1} MB after warm-up (limit {:.0} MB)",
+                        growth, MAX_RSS_GROWTH_MB
+                    ));
+                }
+            }
+            _ => println!("   RSS:          not available on this platform (not checked)"),
+        }
+
+        println!("   Tasks:        {} → {}", baseline.tasks, end.tasks);
+        if end.tasks > baseline.tasks + MAX_TASK_GROWTH {
+            failures.push(format!(
+                "live tasks grew from {} to {} after warm-up (limit +{})",
+                baseline.tasks, end.tasks, MAX_TASK_GROWTH
+            ));
+        }
+
+        println!("   Peak backlog: {} events", self.peak_backlog);
+        if self.peak_backlog > MAX_BACKLOG {
+            failures.push(format!(
+                "{} stream events were waiting at once (limit {})",
+                self.peak_backlog, MAX_BACKLOG
+            ));
+        }
+
+        println!("   Reconnects:   {}", self.reconnects);
+        if self.reconnects > MAX_RECONNECTS {
+            failures.push(format!(
+                "the stream reconnected {} times (limit {})",
+                self.reconnects, MAX_RECONNECTS
+            ));
+        }
+
+        if !failures.is_empty() {
+            bail!("soak test failed: {}", failures.join("; "));
+        }
+        println!("\x1b[32m✅ Soak test passed\x1b[0m");
+        Ok(())
+    }
+}
+
+/// Resident set size from /proc (Linux only)
+fn resident_mb() -> Option<f64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kb: f64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kb / 1024.0)
+}
@@ -0,0 +1,254 @@
+//! Two-leg range positions (`basilisk spread`).
+//!
+//! A spread buys YES at one strike and NO at another of the same expiry and
+//! is handled as one position: the legs go out back to back, and if the
+//! second one fails the first is closed again rather than left on its own.
+//! The second leg is sized to what the first filled, and if it fills short
+//! both legs are closed again too.
+//! Each spread is saved in `spreads.json` with its legs' trade IDs, so
+//! `positions` and the dashboard's Positions tab can show the pair together
+//! with its combined P&L, max loss and max profit.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::models::{Position, TradeResponse};
+use crate::fees;
+use crate::store;
+
+const SPREADS_FILE: &str = "spreads.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadLeg {
+    pub trade_id: i32,
+    pub ticker: String,
+    /// "YES" or "NO"
+    pub direction: String,
+    pub strike: f64,
+    pub contracts: i32,
+    /// Fill price in dollars
+    pub entry_price: f64,
+}
+
+impl SpreadLeg {
+    /// A leg to be bought at `price`, before it has a trade ID
+    pub fn planned(ticker: &str, direction: &str, strike: f64, contracts: i32, price: f64) -> Self {
+        Self {
+            trade_id: 0,
+            ticker: ticker.to_string(),
+            direction: direction.to_uppercase(),
+            strike,
+            contracts,
+            entry_price: price,
+        }
+    }
+
+    /// The leg as the order filled it
+    pub fn filled(self, response: &TradeResponse) -> Self {
+        Self {
+            trade_id: response.trade_id.unwrap_or_default(),
+            contracts: response.filled,
+            entry_price: response.price.unwrap_or(self.entry_price),
+            ..self
+        }
+    }
+
+    /// What the leg pays per contract if BTC settles at `settle`
+    fn pays(&self, settle: f64) -> f64 {
+        let above = settle > self.strike;
+        if above == (self.direction == "YES") {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Spread {
+    pub id: u32,
+    pub legs: Vec<SpreadLeg>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+impl Spread {
+    /// A spread that hasn't been placed yet, for previews
+    pub fn planned(legs: Vec<SpreadLeg>) -> Self {
+        Self {
+            id: 0,
+            legs,
+            created_at: Utc::now(),
+            tag: None,
+        }
+    }
+
+    /// "YES $97000 / NO $98000"
+    pub fn describe(&self) -> String {
+        self.legs
+            .iter()
+            .map(|leg| format!("{} ${:.0}", leg.direction, leg.strike))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    /// What the legs cost, with estimated taker fees
+    pub fn outlay(&self) -> f64 {
+        self.legs
+            .iter()
+            .map(|leg| {
+                leg.entry_price * leg.contracts as f64
+                    + fees::taker_fee(leg.contracts, leg.entry_price)
+            })
+            .sum()
+    }
+
+    /// Settlement payout over every BTC price: (least, most)
+    fn payout_range(&self) -> (f64, f64) {
+        // Payouts only change at a strike, so one price either side of each covers every case
+        self.legs
+            .iter()
+            .flat_map(|leg| [leg.strike - 0.5, leg.strike + 0.5])
+            .map(|settle| {
+                self.legs
+                    .iter()
+                    .map(|leg| leg.pays(settle) * leg.contracts as f64)
+                    .sum::<f64>()
+            })
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), payout| {
+                (low.min(payout), high.max(payout))
+            })
+    }
+
+    /// Most the spread can lose if held to settlement, fees included
+    pub fn max_loss(&self) -> f64 {
+        (self.outlay() - self.payout_range().0).max(0.0)
+    }
+
+    /// Most the spread can make if held to settlement, fees included
+    pub fn max_profit(&self) -> f64 {
+        self.payout_range().1 - self.outlay()
+    }
+
+    /// Legs still open among `positions`
+    pub fn open_legs<'a>(&self, positions: &'a [Position]) -> Vec<&'a Position> {
+        positions
+            .iter()
+            .filter(|p| self.legs.iter().any(|leg| leg.trade_id == p.trade_id))
+            .collect()
+    }
+
+    /// Unrealized P&L of the open legs, each valued by `pnl` (None if any can't be)
+    pub fn pnl(
+        &self,
+        positions: &[Position],
+        pnl: impl Fn(&Position) -> Option<f64>,
+    ) -> Option<f64> {
+        self.open_legs(positions).into_iter().map(pnl).sum()
+    }
+}
+
+/// Every spread opened, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpreadBook {
+    spreads: Vec<Spread>,
+}
+
+impl SpreadBook {
+    /// Load saved spreads (a missing or unreadable file means none)
+    pub fn load() -> Self {
+        store::read_json(SPREADS_FILE)
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        store::write_json(SPREADS_FILE, self)
+    }
+
+    pub fn add(&mut self, legs: Vec<SpreadLeg>, tag: Option<String>) -> Spread {
+        let spread = Spread {
+            id: self.spreads.iter().map(|s| s.id).max().unwrap_or(0) + 1,
+            legs,
+            created_at: Utc::now(),
+            tag,
+        };
+        self.spreads.push(spread.clone());
+        spread
+    }
+
+    /// Spreads with at least one leg still open
+    pub fn open<'a>(&'a self, positions: &[Position]) -> Vec<&'a Spread> {
+        self.spreads
+            .iter()
+            .filter(|s| !s.open_legs(positions).is_empty())
+            .collect()
+    }
+
+    /// The spread a position is a leg of
+    pub fn spread_of(&self, trade_id: i32) -> Option<&Spread> {
+        self.spreads
+            .iter()
+            .find(|s| s.legs.iter().any(|leg| leg.trade_id == trade_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spread(legs: &[(&str, f64, f64)], contracts: i32) -> Spread {
+        Spread::planned(
+            legs.iter()
+                .map(|(direction, strike, price)| {
+                    SpreadLeg::planned("KXBTCD", direction, *strike, contracts, *price)
+                })
+                .collect(),
+        )
+    }
+
+    fn fees(legs: &[(i32, f64)]) -> f64 {
+        legs.iter()
+            .map(|(contracts, price)| fees::taker_fee(*contracts, *price))
+            .sum()
+    }
+
+    #[test]
+    fn range_pays_both_legs_between_the_strikes() {
+        // Below 97k only NO pays, between both do, above 98k only YES does
+        let s = spread(&[("YES", 97000.0, 0.60), ("NO", 98000.0, 0.70)], 10);
+        let outlay = 13.0 + fees(&[(10, 0.60), (10, 0.70)]);
+        assert!((s.outlay() - outlay).abs() < 1e-9);
+        assert!((s.max_loss() - (outlay - 10.0)).abs() < 1e-9);
+        assert!((s.max_profit() - (20.0 - outlay)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inverted_range_can_pay_nothing() {
+        // YES above 98k and NO below 97k: a settle in between pays neither
+        let s = spread(&[("YES", 98000.0, 0.20), ("NO", 97000.0, 0.25)], 10);
+        let outlay = 4.5 + fees(&[(10, 0.20), (10, 0.25)]);
+        assert!((s.max_loss() - outlay).abs() < 1e-9);
+        assert!((s.max_profit() - (10.0 - outlay)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn max_loss_is_never_negative() {
+        // Priced below the guaranteed payout: the worst case is still a gain
+        let s = spread(&[("YES", 97000.0, 0.30), ("NO", 98000.0, 0.30)], 1);
+        assert_eq!(s.max_loss(), 0.0);
+        assert!(s.max_profit() > 1.0);
+    }
+
+    #[test]
+    fn settling_on_a_strike_counts_as_below() {
+        let leg = SpreadLeg::planned("KXBTCD", "yes", 97000.0, 1, 0.5);
+        assert_eq!(leg.pays(97000.0), 0.0);
+        assert_eq!(leg.pays(97000.01), 1.0);
+        let leg = SpreadLeg::planned("KXBTCD", "no", 97000.0, 1, 0.5);
+        assert_eq!(leg.pays(97000.0), 1.0);
+    }
+}
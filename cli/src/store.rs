@@ -0,0 +1,127 @@
+//! Local data directory for state that outlives a session (alerts, logs, etc).
+//!
+//! Lives in `$BASILISK_HOME` if set, otherwise `~/.basilisk`.
+
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+/// Resolve (and create) the data directory
+pub fn data_dir() -> Result<PathBuf> {
+    let dir = match std::env::var_os("BASILISK_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let home = std::env::var_os("HOME").context("HOME is not set")?;
+            PathBuf::from(home).join(".basilisk")
+        }
+    };
+
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create data directory {}", dir.display()))?;
+    Ok(dir)
+}
+
+/// Path of a file inside the data directory
+pub fn path(name: &str) -> Result<PathBuf> {
+    Ok(data_dir()?.join(name))
+}
+
+/// Append one record as a JSON line
+pub fn append_jsonl<T: Serialize>(name: &str, record: &T) -> Result<()> {
+    let path = path(name)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+
+    let line = serde_json::to_string(record)?;
+    writeln!(file, "{}", line).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// Append several records with a single open and write
+pub fn append_jsonl_all<T: Serialize>(name: &str, records: &[T]) -> Result<()> {
+    let path = path(name)?;
+    let mut lines = String::new();
+    for record in records {
+        lines.push_str(&serde_json::to_string(record)?);
+        lines.push('\n');
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    file.write_all(lines.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// Read all records from a JSON-lines file (missing file = no records).
+///
+/// Malformed lines are skipped so one torn write can't hide the whole history.
+pub fn read_jsonl<T: DeserializeOwned>(name: &str) -> Result<Vec<T>> {
+    let path = path(name)?;
+    let file = match File::open(&path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to open {}", path.display())),
+    };
+
+    let mut records = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Ok(record) = serde_json::from_str(&line) {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+/// Replace a JSON-lines file atomically (write to temp, then rename)
+pub fn write_jsonl<T: Serialize>(name: &str, records: &[T]) -> Result<()> {
+    let path = path(name)?;
+    let tmp = path.with_extension("tmp");
+
+    {
+        let mut file =
+            File::create(&tmp).with_context(|| format!("Failed to create {}", tmp.display()))?;
+        for record in records {
+            writeln!(file, "{}", serde_json::to_string(record)?)?;
+        }
+    }
+
+    fs::rename(&tmp, &path).with_context(|| format!("Failed to replace {}", path.display()))?;
+    Ok(())
+}
+
+/// Read a single JSON document (None if the file doesn't exist)
+pub fn read_json<T: DeserializeOwned>(name: &str) -> Result<Option<T>> {
+    let path = path(name)?;
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    let value = serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+    Ok(Some(value))
+}
+
+/// Replace a JSON document atomically (write to temp, then rename)
+pub fn write_json<T: Serialize>(name: &str, value: &T) -> Result<()> {
+    let path = path(name)?;
+    let tmp = path.with_extension("tmp");
+
+    fs::write(&tmp, serde_json::to_string_pretty(value)?)
+        .with_context(|| format!("Failed to write {}", tmp.display()))?;
+    fs::rename(&tmp, &path).with_context(|| format!("Failed to replace {}", path.display()))?;
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Window for the events-per-minute rate
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// No data event for this long looks like a stalled stream
+pub const QUIET_AFTER: Duration = Duration::from_secs(10);
+pub const STALLED_AFTER: Duration = Duration::from_secs(30);
+
+/// Counters the primary SSE task updates as it receives data, read by the
+/// status bar. Keep-alive comments don't count: a stream can be connected
+/// and pinging while no prices or contracts flow.
+#[derive(Debug, Default)]
+pub struct StreamStats {
+    inner: Mutex<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    last_event: Option<Instant>,
+    /// Arrival times within the rate window
+    recent: VecDeque<Instant>,
+    reconnects: u32,
+}
+
+impl Counters {
+    fn prune(&mut self, now: Instant) {
+        while self
+            .recent
+            .front()
+            .is_some_and(|at| now.duration_since(*at) > RATE_WINDOW)
+        {
+            self.recent.pop_front();
+        }
+    }
+}
+
+impl StreamStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A data event arrived
+    pub fn event(&self) {
+        let now = Instant::now();
+        if let Ok(mut counters) = self.inner.lock() {
+            counters.last_event = Some(now);
+            counters.recent.push_back(now);
+            counters.prune(now);
+        }
+    }
+
+    /// The task is about to reconnect
+    pub fn reconnecting(&self) {
+        if let Ok(mut counters) = self.inner.lock() {
+            counters.reconnects += 1;
+        }
+    }
+
+    /// Time since the last data event (None before the first)
+    pub fn since_last_event(&self) -> Option<Duration> {
+        self.inner
+            .lock()
+            .ok()
+            .and_then(|counters| counters.last_event)
+            .map(|at| at.elapsed())
+    }
+
+    /// Data events received over the last minute
+    pub fn events_per_minute(&self) -> usize {
+        match self.inner.lock() {
+            Ok(mut counters) => {
+                counters.prune(Instant::now());
+                counters.recent.len()
+            }
+            Err(_) => 0,
+        }
+    }
+
+    /// Reconnects since the dashboard started
+    pub fn reconnects(&self) -> u32 {
+        self.inner
+            .lock()
+            .map(|counters| counters.reconnects)
+            .unwrap_or(0)
+    }
+}
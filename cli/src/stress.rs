@@ -0,0 +1,202 @@
+use anyhow::{bail, Result};
+use basilisk_quant::pricing;
+use std::path::Path;
+
+use crate::api::client::ApiClient;
+use crate::portfolio::{self, PositionRecord};
+
+/// Spot moves used when no scenario is given explicitly
+pub const DEFAULT_MOVES: [f64; 7] = [-0.05, -0.03, -0.01, 0.0, 0.01, 0.03, 0.05];
+
+/// Relative vol shocks used when no scenario is given explicitly
+pub const DEFAULT_VOL_SHOCKS: [f64; 4] = [-0.25, 0.0, 0.25, 0.5];
+
+/// Portfolio value under a single spot/vol shock
+#[derive(Debug, Clone)]
+pub struct ScenarioResult {
+    pub spot_move: f64,  // Relative spot change (-0.03 = -3%)
+    pub vol_change: f64, // Relative vol change (0.20 = vol * 1.2)
+    pub value: f64,      // Model value of all positions after the shock
+    pub pnl_change: f64, // Change versus the unshocked model value
+    /// (trade_id, P&L change) per position
+    pub per_position: Vec<(i32, f64)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StressReport {
+    pub positions: usize,
+    pub skipped: usize,
+    pub base_value: f64,
+    pub results: Vec<ScenarioResult>,
+}
+
+impl StressReport {
+    pub fn worst(&self) -> Option<&ScenarioResult> {
+        self.results
+            .iter()
+            .min_by(|a, b| a.pnl_change.total_cmp(&b.pnl_change))
+    }
+
+    /// Look up the result for a grid cell
+    pub fn get(&self, spot_move: f64, vol_change: f64) -> Option<&ScenarioResult> {
+        self.results.iter().find(|r| {
+            (r.spot_move - spot_move).abs() < 1e-9 && (r.vol_change - vol_change).abs() < 1e-9
+        })
+    }
+}
+
+/// Parse a shock like "-3%", "+20%", or "0.05" into a relative change
+pub fn parse_shock(value: &str) -> Result<f64, String> {
+    let trimmed = value.trim();
+    let (number, is_percent) = match trimmed.strip_suffix('%') {
+        Some(number) => (number, true),
+        None => (trimmed, false),
+    };
+
+    let parsed: f64 = number
+        .trim_start_matches('+')
+        .parse()
+        .map_err(|_| format!("invalid shock '{}' (expected e.g. -3% or +20%)", value))?;
+
+    Ok(if is_percent { parsed / 100.0 } else { parsed })
+}
+
+/// Reprice every position under each combination of spot moves and vol shocks
+pub fn run(records: &[PositionRecord], moves: &[f64], vol_shocks: &[f64]) -> StressReport {
+    let priceable: Vec<(&PositionRecord, f64, f64, f64)> = records
+        .iter()
+        .filter_map(|r| Some((r, r.spot_price?, r.volatility?, r.hours_to_expiry?.max(0.0))))
+        .collect();
+
+    let value_of = |record: &PositionRecord, spot: f64, vol: f64, hours: f64| {
+        pricing::binary_value(&record.direction, spot, record.strike, vol, hours)
+            * record.contracts as f64
+    };
+
+    let base_values: Vec<f64> = priceable
+        .iter()
+        .map(|(r, spot, vol, hours)| value_of(r, *spot, *vol, *hours))
+        .collect();
+    let base_value: f64 = base_values.iter().sum();
+
+    let mut results = Vec::with_capacity(moves.len() * vol_shocks.len());
+    for &spot_move in moves {
+        for &vol_change in vol_shocks {
+            let per_position: Vec<(i32, f64)> = priceable
+                .iter()
+                .zip(&base_values)
+                .map(|((r, spot, vol, hours), base)| {
+                    let shocked_spot = spot * (1.0 + spot_move);
+                    let shocked_vol = (vol * (1.0 + vol_change)).max(0.0);
+                    (
+                        r.trade_id,
+                        value_of(r, shocked_spot, shocked_vol, *hours) - base,
+                    )
+                })
+                .collect();
+
+            let pnl_change: f64 = per_position.iter().map(|(_, change)| change).sum();
+            results.push(ScenarioResult {
+                spot_move,
+                vol_change,
+                value: base_value + pnl_change,
+                pnl_change,
+                per_position,
+            });
+        }
+    }
+
+    StressReport {
+        positions: priceable.len(),
+        skipped: records.len() - priceable.len(),
+        base_value,
+        results,
+    }
+}
+
+/// Fetch open positions (or load an export) and print the scenario table
+pub async fn handle_stress_command(
+    api_url: &str,
+    moves: Vec<f64>,
+    vol_shocks: Vec<f64>,
+    from: Option<&Path>,
+) -> Result<()> {
+    let records = match from {
+        Some(path) => portfolio::read_records(path)?,
+        None => {
+            let client = ApiClient::new(api_url.to_string(), 30)?;
+            let positions = client.get_positions().await?;
+            let market = client.get_current_signals().await.ok();
+            portfolio::build_records(
+                &positions,
+                market.as_ref().and_then(|m| m.btc_price()),
+                market.as_ref().and_then(|m| m.volatility.effective_vol()),
+            )
+        }
+    };
+
+    if records.is_empty() {
+        println!("📂 No open positions to stress.");
+        return Ok(());
+    }
+
+    // Fill in whichever axis was left out so a single flag still works
+    let (moves, vol_shocks) = match (moves.is_empty(), vol_shocks.is_empty()) {
+        (true, true) => (DEFAULT_MOVES.to_vec(), DEFAULT_VOL_SHOCKS.to_vec()),
+        (true, false) => (vec![0.0], vol_shocks),
+        (false, true) => (moves, vec![0.0]),
+        (false, false) => (moves, vol_shocks),
+    };
+
+    let report = run(&records, &moves, &vol_shocks);
+    if report.positions == 0 {
+        bail!("No positions could be priced (missing spot, volatility, or expiry)");
+    }
+
+    println!(
+        "🧪 Stress Test - {} positions, model value ${:.2}",
+        report.positions, report.base_value
+    );
+    if report.skipped > 0 {
+        println!(
+            "   ({} positions skipped: missing spot/vol/expiry)",
+            report.skipped
+        );
+    }
+    println!("{}", "─".repeat(50));
+    println!(
+        "{:<10} {:<10} {:<14} {:<12}",
+        "Spot", "Vol", "P&L Impact", "Value"
+    );
+    println!("{}", "─".repeat(50));
+
+    for result in &report.results {
+        let pnl_color = if result.pnl_change >= 0.0 {
+            "\x1b[32m"
+        } else {
+            "\x1b[31m"
+        };
+        println!(
+            "{:<10} {:<10} {}{:<14}\x1b[0m ${:<11.2}",
+            format!("{:+.1}%", result.spot_move * 100.0),
+            format!("{:+.0}%", result.vol_change * 100.0),
+            pnl_color,
+            format!("${:+.2}", result.pnl_change),
+            result.value
+        );
+    }
+    println!("{}", "─".repeat(50));
+
+    if let Some(worst) = report.worst() {
+        println!(
+            "   Worst case: spot {:+.1}%, vol {:+.0}%",
+            worst.spot_move * 100.0,
+            worst.vol_change * 100.0
+        );
+        for (trade_id, change) in &worst.per_position {
+            println!("     #{:<6} ${:+.2}", trade_id, change);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+//! On-disk cache of every BTC tick from the stream (`ticks.jsonl`).
+//!
+//! The SSE task hands each price to a writer thread, which appends them in
+//! batches, so a burst of ticks never waits on the disk. The writer prunes the
+//! file to `[tick_cache] max_age_hours` and `max_mb`. On startup the dashboard
+//! seeds its replay buffer from the cache, so local RV, settlement averaging and
+//! the hour charts pick up where the last session left off.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Instant;
+
+use crate::config::TickCacheConfig;
+use crate::store;
+
+const TICKS_FILE: &str = "ticks.jsonl";
+
+/// Longest a tick waits in memory before it is written
+const FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How often old ticks are pruned even when the file is under the size cap
+const PRUNE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(600);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Tick {
+    pub timestamp: DateTime<Utc>,
+    pub price: f64,
+}
+
+/// Handle to the writer thread; cheap to clone into each SSE task
+#[derive(Debug, Clone)]
+pub struct TickWriter {
+    tx: Sender<Tick>,
+}
+
+impl TickWriter {
+    /// Start the writer thread (None when the cache is disabled)
+    pub fn spawn(config: &TickCacheConfig) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+        let (tx, rx) = mpsc::channel();
+        let max_age = Duration::hours(config.max_age_hours as i64);
+        let max_bytes = config.max_mb * 1024 * 1024;
+        thread::spawn(move || run_writer(rx, max_age, max_bytes));
+        Some(Self { tx })
+    }
+
+    /// Queue a tick; never blocks
+    pub fn record(&self, price: f64, timestamp: DateTime<Utc>) {
+        self.tx.send(Tick { timestamp, price }).ok();
+    }
+}
+
+/// The cache is best-effort: a batch that fails to write is dropped rather than
+/// surfaced, since the live data never depends on it.
+fn run_writer(rx: Receiver<Tick>, max_age: Duration, max_bytes: u64) {
+    let mut batch = Vec::new();
+    let mut last_flush = Instant::now();
+    let mut last_prune: Option<Instant> = None;
+
+    loop {
+        let closed = match rx.recv_timeout(FLUSH_INTERVAL) {
+            Ok(tick) => {
+                batch.push(tick);
+                false
+            }
+            Err(RecvTimeoutError::Timeout) => false,
+            Err(RecvTimeoutError::Disconnected) => true,
+        };
+
+        if !batch.is_empty() && (closed || last_flush.elapsed() >= FLUSH_INTERVAL) {
+            store::append_jsonl_all(TICKS_FILE, &batch).ok();
+            batch.clear();
+            last_flush = Instant::now();
+        }
+
+        let oversized = file_size().is_some_and(|size| size > max_bytes);
+        if oversized || last_prune.is_none_or(|at| at.elapsed() >= PRUNE_INTERVAL) {
+            prune(max_age, max_bytes).ok();
+            last_prune = Some(Instant::now());
+        }
+
+        if closed {
+            return;
+        }
+    }
+}
+
+fn file_size() -> Option<u64> {
+    let path = store::path(TICKS_FILE).ok()?;
+    fs::metadata(path).ok().map(|meta| meta.len())
+}
+
+/// Drop ticks older than `max_age`, then the oldest until the file is back
+/// under three quarters of `max_bytes` (so it isn't rewritten every batch)
+fn prune(max_age: Duration, max_bytes: u64) -> Result<()> {
+    let Some(size) = file_size() else {
+        return Ok(());
+    };
+    let mut ticks: Vec<Tick> = store::read_jsonl(TICKS_FILE)?;
+    if ticks.is_empty() {
+        return Ok(());
+    }
+    let before = ticks.len();
+
+    let cutoff = Utc::now() - max_age;
+    ticks.retain(|tick| tick.timestamp >= cutoff);
+
+    let bytes_per_tick = (size / before as u64).max(1);
+    let keep = (max_bytes * 3 / 4 / bytes_per_tick) as usize;
+    if ticks.len() > keep {
+        ticks.drain(..ticks.len() - keep);
+    }
+
+    if ticks.len() < before {
+        store::write_jsonl(TICKS_FILE, &ticks)?;
+    }
+    Ok(())
+}
+
+/// Cached ticks at or after `since`, oldest first (missing file = none)
+pub fn load(since: DateTime<Utc>) -> Result<Vec<Tick>> {
+    let mut ticks: Vec<Tick> = store::read_jsonl(TICKS_FILE)?;
+    ticks.retain(|tick| tick.timestamp >= since);
+    Ok(ticks)
+}
@@ -0,0 +1,135 @@
+//! Win/loss streaks and the tilt guard.
+//!
+//! Trades closed in the last `[tilt] session_hours` are read newest first to
+//! find the running streak. From `warn_after` losses in a row, new entries are
+//! flagged with a warning that gets sharper with every further loss. From
+//! `cooloff_after` losses, entries are blocked until `cooloff_minutes` after
+//! the last one: the dashboard and CLI refuse the order and the auto-trader
+//! skips its cycle. Scratch trades (zero P&L) neither extend nor break a streak.
+
+use chrono::{DateTime, Duration, Utc};
+use std::cmp::Reverse;
+
+use crate::api::client::ApiClient;
+use crate::api::models::TradeHistory;
+use crate::api::parse_timestamp;
+use crate::config::TiltConfig;
+
+/// Recent trades scanned for the streak
+const HISTORY_LIMIT: i32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Streak {
+    #[default]
+    None,
+    Wins(u32),
+    Losses(u32),
+}
+
+impl Streak {
+    /// Short form for the status bar ("3W", "2L")
+    pub fn label(self) -> Option<String> {
+        match self {
+            Streak::None => None,
+            Streak::Wins(n) => Some(format!("{}W", n)),
+            Streak::Losses(n) => Some(format!("{}L", n)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TiltGuard {
+    streak: Streak,
+    /// Close time of the latest losing trade in the streak
+    last_loss: Option<DateTime<Utc>>,
+    config: TiltConfig,
+}
+
+impl TiltGuard {
+    pub fn from_history(history: &[TradeHistory], config: &TiltConfig) -> Self {
+        let cutoff = Utc::now() - Duration::hours(config.session_hours as i64);
+        let mut closed: Vec<(DateTime<Utc>, f64)> = history
+            .iter()
+            .filter_map(|trade| {
+                let closed_at = trade.closed_at.as_deref().and_then(parse_timestamp)?;
+                let pnl = trade.pnl.filter(|pnl| *pnl != 0.0)?;
+                (closed_at >= cutoff).then_some((closed_at, pnl))
+            })
+            .collect();
+        closed.sort_by_key(|(closed_at, _)| Reverse(*closed_at));
+
+        let mut streak = Streak::None;
+        for (_, pnl) in &closed {
+            streak = match (streak, *pnl > 0.0) {
+                (Streak::None, true) => Streak::Wins(1),
+                (Streak::None, false) => Streak::Losses(1),
+                (Streak::Wins(n), true) => Streak::Wins(n + 1),
+                (Streak::Losses(n), false) => Streak::Losses(n + 1),
+                _ => break,
+            };
+        }
+        let last_loss = match streak {
+            Streak::Losses(_) => closed.first().map(|(closed_at, _)| *closed_at),
+            _ => None,
+        };
+
+        Self {
+            streak,
+            last_loss,
+            config: config.clone(),
+        }
+    }
+
+    /// Guard built from recent history; disabled (no streak) when `warn_after` is 0
+    pub async fn fetch(client: &ApiClient, config: &TiltConfig) -> anyhow::Result<Self> {
+        if config.warn_after == 0 {
+            return Ok(Self::default());
+        }
+        let history = client.get_trade_history(HISTORY_LIMIT).await?;
+        Ok(Self::from_history(&history, config))
+    }
+
+    pub fn streak(&self) -> Streak {
+        self.streak
+    }
+
+    fn losses(&self) -> u32 {
+        match self.streak {
+            Streak::Losses(n) => n,
+            _ => 0,
+        }
+    }
+
+    /// Escalating warning once the losing streak reaches `warn_after`
+    pub fn warning(&self) -> Option<String> {
+        let losses = self.losses();
+        if self.config.warn_after == 0 || losses < self.config.warn_after {
+            return None;
+        }
+        let advice = match losses - self.config.warn_after {
+            0 => "check you're still following the plan",
+            1 => "consider sizing down or stepping away",
+            _ => "you may be on tilt; stop for the session",
+        };
+        Some(format!("{} losses in a row: {}", losses, advice))
+    }
+
+    /// Why entries are blocked, while a cooling-off period is running
+    pub fn cooling_off(&self) -> Option<String> {
+        let losses = self.losses();
+        if self.config.cooloff_after == 0 || losses < self.config.cooloff_after {
+            return None;
+        }
+        let cooloff = Duration::minutes(self.config.cooloff_minutes as i64);
+        let since = Utc::now() - self.last_loss?;
+        if since >= cooloff {
+            return None;
+        }
+        Some(format!(
+            "cooling off after {} losses in a row ({}m left of {}m)",
+            losses,
+            (cooloff - since).num_minutes() + 1,
+            cooloff.num_minutes()
+        ))
+    }
+}
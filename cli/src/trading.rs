@@ -1,9 +1,67 @@
-use anyhow::Result;
-use clap::Subcommand;
+use anyhow::{bail, Context, Result};
+use chrono::{Local, Utc};
+use clap::{Subcommand, ValueEnum};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::amend;
 use crate::api::client::ApiClient;
-use crate::api::models::TradeRequest;
+use crate::api::models::{
+    parse_timestamp, Contract, Order, PnLSummary, Position, TradeHistory, TradeRequest,
+    TradeResponse,
+};
+use crate::audit::{self, AuditKind};
+use crate::bankroll::{self, Capital};
+use crate::batch;
+use crate::brackets::{self, BracketBook};
+use crate::config::{Config, ExposureConfig, OrderGuardConfig};
+use crate::exposure::ExposureBook;
+use crate::fees;
+use crate::hedge::{self, QUOTE_MOVE};
+use crate::journal::{self, Origin};
+use crate::order_guard::{LatencyMonitor, OrderGuard};
+use crate::portfolio::{self, ExportFormat};
+use crate::preview::{EntryPreview, ExitPreview};
+use crate::reentry::ReentryGuard;
+use crate::score::Scorer;
+use crate::scripting::ScriptHooks;
+use crate::sizing;
+use crate::soak::parse_duration;
+use crate::spread::{Spread, SpreadBook, SpreadLeg};
+use crate::tilt::TiltGuard;
 
+/// Round-trip probes taken before each order
+const LATENCY_PROBES: usize = 3;
+
+/// Kelly multiples `size` always lists, next to the one asked for
+const KELLY_FRACTIONS: [f64; 4] = [1.0, 0.5, 0.25, 0.1];
+
+/// Closed trades scanned when history or P&L is filtered by tag
+const TAGGED_HISTORY_SCAN: i32 = 1000;
+
+/// Spacing between tranches when neither --every nor --step is given
+const DEFAULT_TRANCHE_SPACING: Duration = Duration::from_secs(60);
+
+/// How often a price-triggered tranche re-checks the quote
+const TRANCHE_POLL: Duration = Duration::from_secs(2);
+
+/// How positions, orders, history, pnl and order results print (`--output`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned, colored text for a terminal
+    #[default]
+    Table,
+    /// Pretty-printed JSON: an array for lists, an object for a single result
+    Json,
+    /// A header row, then a row per record
+    Csv,
+}
+
+/// Every trading subcommand, flattened into the top-level CLI
 #[derive(Subcommand, Debug)]
 pub enum TradingCommands {
     /// Execute a trade from a signal
@@ -11,9 +69,24 @@ pub enum TradingCommands {
     Trade {
         /// Signal ID to trade
         signal_id: i32,
-        /// Number of contracts
-        #[arg(short, long, default_value = "1")]
-        size: i32,
+        /// Number of contracts (defaults to the script's size(), else 1)
+        #[arg(short, long)]
+        size: Option<i32>,
+        /// Limit price in cents (1-99); without it the order goes at the signal price
+        #[arg(long, value_name = "CENTS", value_parser = clap::value_parser!(i32).range(1..=99))]
+        limit: Option<i32>,
+        /// Close the position once its side's quote reaches this many cents
+        #[arg(long, value_name = "CENTS", value_parser = clap::value_parser!(i32).range(1..=99))]
+        take_profit: Option<i32>,
+        /// Close the position once its side's quote falls to this many cents
+        #[arg(long, value_name = "CENTS", value_parser = clap::value_parser!(i32).range(1..=99))]
+        stop_loss: Option<i32>,
+        /// Strategy tag, sent with the order and kept in the trade journal
+        #[arg(long)]
+        tag: Option<String>,
+        /// Free-form note on why you're taking the trade
+        #[arg(long)]
+        note: Option<String>,
     },
 
     /// Execute a manual trade
@@ -34,17 +107,168 @@ pub enum TradingCommands {
         /// Number of contracts
         #[arg(long, default_value = "1")]
         size: i32,
+        /// Limit price in cents (1-99); without it the order is a market order
+        #[arg(long, value_name = "CENTS", value_parser = clap::value_parser!(i32).range(1..=99))]
+        limit: Option<i32>,
+        /// Close the position once its side's quote reaches this many cents
+        #[arg(long, value_name = "CENTS", value_parser = clap::value_parser!(i32).range(1..=99))]
+        take_profit: Option<i32>,
+        /// Close the position once its side's quote falls to this many cents
+        #[arg(long, value_name = "CENTS", value_parser = clap::value_parser!(i32).range(1..=99))]
+        stop_loss: Option<i32>,
+        /// Strategy tag, sent with the order and kept in the trade journal
+        #[arg(long)]
+        tag: Option<String>,
+        /// Free-form note on why you're taking the trade
+        #[arg(long)]
+        note: Option<String>,
+    },
+
+    /// Kelly-size a signal from its model probability and price
+    #[command(name = "size")]
+    Size {
+        /// Signal ID to size
+        signal_id: i32,
+        /// Bankroll in dollars (defaults to account equity)
+        #[arg(long)]
+        bankroll: Option<f64>,
+        /// Share of the full Kelly stake to use (0.25 = quarter Kelly)
+        #[arg(long, default_value = "0.25")]
+        kelly_fraction: f64,
+        /// Place the trade at that size
+        #[arg(long)]
+        execute: bool,
+    },
+
+    /// Place a batch of orders from a JSON or CSV file, or stdin
+    #[command(name = "trade-batch")]
+    TradeBatch {
+        /// JSON array or CSV with a header row: signal_id or ticker+direction,
+        /// plus optional size, limit, strike, asset, tag, note (omit or `-` for stdin)
+        file: Option<PathBuf>,
+        /// Stop after this many orders fail
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        max_failures: Option<u64>,
+    },
+
+    /// Trade the best current signals you aren't already in
+    #[command(name = "trade-auto")]
+    TradeAuto {
+        /// Minimum EV in percent (defaults to [auto] min_ev)
+        #[arg(long)]
+        min_ev: Option<f64>,
+        /// Minimum model confidence, 0-1 (defaults to [auto] min_confidence)
+        #[arg(long)]
+        min_confidence: Option<f64>,
+        /// Enter at most this many contracts (defaults to [auto] max_open_positions)
+        #[arg(long)]
+        max_positions: Option<usize>,
+        /// Contracts per trade (defaults to [auto] size)
+        #[arg(short, long)]
+        size: Option<i32>,
+        /// Place the trades; without it only the plan is printed
+        #[arg(long)]
+        yes: bool,
     },
 
     /// List open positions
     #[command(name = "positions")]
-    Positions,
+    Positions {
+        #[command(subcommand)]
+        action: Option<PositionsAction>,
+    },
 
-    /// Close a position
+    /// Close a position, at once or in tranches
     #[command(name = "close")]
     Close {
         /// Position/trade ID to close
         position_id: i32,
+        /// Close in fixed-size tranches, e.g. 3x10 for three closes of 10 contracts
+        #[arg(long, value_name = "COUNTxSIZE", value_parser = parse_scale, conflicts_with = "tranches")]
+        scale: Option<(usize, i32)>,
+        /// Close the whole position in this many equal tranches
+        #[arg(long, value_parser = clap::value_parser!(u64).range(2..))]
+        tranches: Option<u64>,
+        /// Time between tranches (e.g. 30s, 2m; 1m by default)
+        #[arg(long, value_parser = parse_duration, conflicts_with = "step")]
+        every: Option<Duration>,
+        /// Hold each later tranche until the side's quote is this many cents above the first fill
+        #[arg(long, value_name = "CENTS", value_parser = clap::value_parser!(i32).range(1..=98))]
+        step: Option<i32>,
+    },
+
+    /// Close a position and reopen it on the next hour's contract
+    #[command(name = "roll")]
+    Roll {
+        /// Position/trade ID to roll
+        position_id: i32,
+        /// Contracts to open on the new contract (defaults to the position's size)
+        #[arg(short, long)]
+        size: Option<i32>,
+    },
+
+    /// Price offsetting contracts for a position, and optionally buy one
+    #[command(name = "hedge")]
+    Hedge {
+        /// Position/trade ID to hedge
+        position_id: i32,
+        /// Listed strikes to consider either side of the position's own
+        #[arg(long, default_value = "2")]
+        strikes: usize,
+        /// Buy the cheapest offset (or the one chosen with --pick)
+        #[arg(long)]
+        execute: bool,
+        /// Row number from the table to buy with --execute
+        #[arg(long, requires = "execute")]
+        pick: Option<usize>,
+    },
+
+    /// Buy YES at one strike and NO at another of the same expiry as one position
+    #[command(name = "spread")]
+    Spread {
+        /// Strike to buy YES on (pays if BTC settles above it)
+        #[arg(long = "yes", value_name = "STRIKE")]
+        yes_strike: f64,
+        /// Strike to buy NO on (pays if BTC settles at or below it)
+        #[arg(long = "no", value_name = "STRIKE")]
+        no_strike: f64,
+        /// Contracts per leg
+        #[arg(short, long, default_value = "1")]
+        size: i32,
+        /// Strategy tag for both legs (defaults to "spread")
+        #[arg(long)]
+        tag: Option<String>,
+        /// Free-form note on why you're taking the trade
+        #[arg(long)]
+        note: Option<String>,
+    },
+
+    /// Change a resting limit order's price and/or size (cancel/replace)
+    #[command(name = "amend")]
+    Amend {
+        /// Exchange order ID
+        order_id: String,
+        /// New limit price in dollars (e.g. 0.34)
+        #[arg(long)]
+        price: Option<f64>,
+        /// New number of contracts (defaults to what is still unfilled)
+        #[arg(long)]
+        size: Option<i32>,
+    },
+
+    /// List resting limit orders
+    #[command(name = "orders")]
+    Orders {
+        /// Include canceled and executed orders
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Cancel a resting limit order
+    #[command(name = "cancel")]
+    Cancel {
+        /// Exchange order ID
+        order_id: String,
     },
 
     /// Show P&L summary
@@ -53,6 +277,9 @@ pub enum TradingCommands {
         /// Period: today, week, or all
         #[arg(default_value = "today")]
         period: String,
+        /// Only trades with this tag
+        #[arg(long)]
+        tag: Option<String>,
     },
 
     /// Show trade history
@@ -61,36 +288,1156 @@ pub enum TradingCommands {
         /// Number of trades to show
         #[arg(short, long, default_value = "20")]
         limit: i32,
+        /// Only trades with this tag
+        #[arg(long)]
+        tag: Option<String>,
     },
 }
 
-pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Result<()> {
-    let client = ApiClient::new(api_url.to_string(), 30)?;
+impl TradingCommands {
+    /// Whether the command sends orders (refused in read-only mode)
+    pub fn places_orders(&self) -> bool {
+        matches!(
+            self,
+            TradingCommands::Trade { .. }
+                | TradingCommands::Manual { .. }
+                | TradingCommands::Close { .. }
+                | TradingCommands::Roll { .. }
+                | TradingCommands::Hedge { execute: true, .. }
+                | TradingCommands::Spread { .. }
+                | TradingCommands::Amend { .. }
+                | TradingCommands::Cancel { .. }
+                | TradingCommands::Size { execute: true, .. }
+                | TradingCommands::TradeBatch { .. }
+                | TradingCommands::TradeAuto { yes: true, .. }
+        )
+    }
+
+    /// Whether the command has records for `--output json/csv` (the rest only print text)
+    pub fn supports_output(&self) -> bool {
+        matches!(
+            self,
+            TradingCommands::Trade { .. }
+                | TradingCommands::Manual { .. }
+                | TradingCommands::Positions { action: None }
+                | TradingCommands::Close {
+                    scale: None,
+                    tranches: None,
+                    ..
+                }
+                | TradingCommands::Orders { .. }
+                | TradingCommands::Cancel { .. }
+                | TradingCommands::Pnl { .. }
+                | TradingCommands::History { .. }
+        )
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PositionsAction {
+    /// Export open positions with computed risk fields
+    #[command(name = "export")]
+    Export {
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: ExportFormat,
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+}
+
+/// Time-to-expiry of the first live contract matching a predicate
+async fn lookup_contract(
+    client: &ApiClient,
+    matches: impl Fn(&Contract) -> bool,
+) -> Option<Contract> {
+    let current = client.get_current_signals().await.ok()?;
+    current
+        .contracts
+        .into_iter()
+        .find(|contract| matches(contract))
+}
+
+/// Ask the user's script how many contracts to trade (None if it has no size hook)
+async fn script_size(
+    client: &ApiClient,
+    config: &Config,
+    contract: Option<&Contract>,
+) -> Result<Option<i32>> {
+    let (Some(hooks), Some(contract)) = (ScriptHooks::load(&config.scripting)?, contract) else {
+        return Ok(None);
+    };
+    if !hooks.has_size() {
+        return Ok(None);
+    }
+    let bankroll = bankroll::bankroll(client).await?;
+    hooks.size(contract, bankroll)
+}
+
+/// Record a filled order's origin; a journal failure shouldn't fail the trade
+fn journal_trade(
+    ticker: &str,
+    origin: Origin,
+    tag: Option<&str>,
+    note: Option<&str>,
+    result: &TradeResponse,
+) {
+    if let Err(e) = journal::record_noted(ticker, origin, tag, note, result) {
+        eprintln!("⚠️  Could not write trade journal: {}", e);
+    }
+}
+
+fn audit_result(kind: AuditKind, description: &str, result: &Result<TradeResponse>) {
+    if let Err(e) = audit::record_result(kind, "cli", description, result) {
+        eprintln!("⚠️  Could not write audit log: {}", e);
+    }
+}
+
+/// How an order went: filled, resting on the book (limit orders that didn't
+/// fill completely), or rejected
+fn print_order_result(result: &TradeResponse, size: i32, limit: Option<i32>) {
+    if !result.success {
+        println!("❌ Trade Failed!");
+        if let Some(error) = &result.error {
+            println!("   Error: {}", error);
+        }
+        return;
+    }
+
+    let resting = result
+        .order_id
+        .as_deref()
+        .filter(|_| limit.is_some() && result.filled < size);
+    match resting {
+        Some(order_id) => {
+            println!("⏳ Limit Order Resting");
+            println!("   Order ID: {}", order_id);
+            println!("   Filled: {} of {} contracts", result.filled, size);
+        }
+        None => {
+            println!("✅ Trade Executed Successfully!");
+            println!("   Trade ID: {}", result.trade_id.unwrap_or(0));
+            println!("   Filled: {} contracts", result.filled);
+        }
+    }
+    if let Some(price) = result.price {
+        println!("   Price: ${:.2}", price);
+    }
+    if let Some(cost) = result.cost {
+        println!("   Cost: ${:.2}", cost);
+    }
+    if let Some(order_id) = resting {
+        println!(
+            "   The rest waits at {}¢; reprice or resize with `basilisk amend {} --price <dollars>`",
+            limit.unwrap_or_default(),
+            order_id
+        );
+    }
+}
+
+/// Save the take-profit/stop-loss legs for the position an order opened
+fn attach_bracket(
+    contract: Option<&Contract>,
+    ticker: &str,
+    direction: &str,
+    result: &TradeResponse,
+    take_profit: Option<i32>,
+    stop_loss: Option<i32>,
+    output: OutputFormat,
+) {
+    if (take_profit.is_none() && stop_loss.is_none()) || !result.success {
+        return;
+    }
+    let bracket = BracketBook::load().and_then(|mut book| {
+        let bracket = book.add(contract, ticker, direction, result, take_profit, stop_loss)?;
+        book.save()?;
+        Ok(bracket)
+    });
+    match bracket {
+        Ok(bracket) if output == OutputFormat::Table => {
+            println!();
+            println!("🎯 Bracket set: {}", bracket.describe());
+            println!("   It fires while `basilisk brackets watch` is running.");
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("⚠️  No bracket: {}", e),
+    }
+}
+
+/// Print records for `--output json` (an array) or `--output csv`
+fn print_records<T: Serialize>(records: &[T], output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Table => {}
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(records).context("Failed to write JSON")?
+            );
+        }
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(io::stdout());
+            for record in records {
+                writer.serialize(record).context("Failed to write CSV")?;
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Print one result: a JSON object, or a single CSV row under its header
+fn print_record<T: Serialize>(record: &T, output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(record).context("Failed to write JSON")?
+            );
+            Ok(())
+        }
+        _ => print_records(std::slice::from_ref(record), output),
+    }
+}
+
+/// P&L at each bracket leg, for `--dry-run`
+fn print_bracket_preview(preview: &EntryPreview, take_profit: Option<i32>, stop_loss: Option<i32>) {
+    for (label, cents) in [("Take Profit", take_profit), ("Stop Loss", stop_loss)] {
+        if let Some(cents) = cents {
+            let pnl = preview.exit_pnl(cents as f64 / 100.0);
+            let color = if pnl >= 0.0 { "\x1b[32m" } else { "\x1b[31m" };
+            println!(
+                "   {:<12} {}¢ → {}${:+.2}\x1b[0m",
+                format!("{}:", label),
+                cents,
+                color,
+                pnl
+            );
+        }
+    }
+}
+
+/// Probe trade latency and check time-to-expiry before an order goes out
+async fn check_order_guard(
+    client: &ApiClient,
+    config: &OrderGuardConfig,
+    hours_to_expiry: Option<f64>,
+) -> Result<()> {
+    let mut monitor = LatencyMonitor::new();
+    for _ in 0..LATENCY_PROBES {
+        if let Ok(rtt) = client.ping_trade_endpoint().await {
+            monitor.record(rtt);
+        }
+    }
+
+    let verdict = OrderGuard::new(config).check(monitor.current_ms(), hours_to_expiry);
+    for warning in &verdict.warnings {
+        eprintln!("⚠️  {}", warning);
+    }
+    if verdict.blocked {
+        let message = format!("order blocked: {}", verdict.warnings.join("; "));
+        if let Err(e) = audit::record(AuditKind::Block, "cli", message, Value::Null) {
+            eprintln!("⚠️  Could not write audit log: {}", e);
+        }
+        bail!("Order blocked by latency/expiry guard (set [orders] mode = \"warn\" in config.toml to allow)");
+    }
+    if !verdict.is_clear() {
+        eprintln!();
+    }
+
+    Ok(())
+}
+
+/// Warn (without blocking) when re-entering a ticker soon after a stop-loss
+async fn warn_reentry(client: &ApiClient, config: &Config, ticker: &str) {
+    match ReentryGuard::fetch(client, &config.reentry).await {
+        Ok(guard) => {
+            if let Some(reason) = guard.check(ticker) {
+                eprintln!("⚠️  {}", reason);
+                eprintln!();
+            }
+        }
+        Err(e) => eprintln!("⚠️  Could not check re-entry cooldown: {}", e),
+    }
+}
+
+/// Warn on a losing streak, and refuse orders during a tilt cooling-off period
+async fn check_tilt(client: &ApiClient, config: &Config) -> Result<()> {
+    let guard = match TiltGuard::fetch(client, &config.tilt).await {
+        Ok(guard) => guard,
+        Err(e) => {
+            eprintln!("⚠️  Could not check losing streak: {}", e);
+            return Ok(());
+        }
+    };
+    if let Some(warning) = guard.warning() {
+        eprintln!("⚠️  {}", warning);
+        eprintln!();
+    }
+    if let Some(reason) = guard.cooling_off() {
+        let message = format!("order blocked: {}", reason);
+        if let Err(e) = audit::record(AuditKind::Block, "cli", message, Value::Null) {
+            eprintln!("⚠️  Could not write audit log: {}", e);
+        }
+        bail!(
+            "Order blocked: {} (set [tilt] cooloff_after = 0 in config.toml to only warn)",
+            reason
+        );
+    }
+    Ok(())
+}
+
+/// Refuse orders that would push an expiry past `[exposure] max_per_expiry`
+async fn check_exposure(
+    client: &ApiClient,
+    config: &ExposureConfig,
+    expiry: Option<&str>,
+    notional: f64,
+) -> Result<()> {
+    if config.max_per_expiry.is_none() {
+        return Ok(());
+    }
+    let positions = client.get_positions().await?;
+    if let Err(reason) = ExposureBook::from_positions(&positions).check(config, expiry, notional) {
+        let message = format!("order blocked: {}", reason);
+        if let Err(e) = audit::record(AuditKind::Block, "cli", message, Value::Null) {
+            eprintln!("⚠️  Could not write audit log: {}", e);
+        }
+        bail!(
+            "Order blocked: {} (raise [exposure] max_per_expiry in config.toml to allow)",
+            reason
+        );
+    }
+    Ok(())
+}
+
+/// Parse `--scale COUNTxSIZE` (e.g. `3x10`)
+fn parse_scale(value: &str) -> Result<(usize, i32), String> {
+    let invalid = || format!("expected COUNTxSIZE, e.g. 3x10, got '{}'", value);
+    let (count, size) = value.split_once(['x', 'X']).ok_or_else(invalid)?;
+    let count: usize = count.trim().parse().map_err(|_| invalid())?;
+    let size: i32 = size.trim().parse().map_err(|_| invalid())?;
+    if count < 2 || size < 1 {
+        return Err("needs at least 2 tranches of at least 1 contract".to_string());
+    }
+    Ok((count, size))
+}
+
+/// Contracts per tranche: `scale` fixed-size tranches, or `tranches` as even as
+/// possible (earlier ones take the remainder)
+fn plan_tranches(
+    contracts: i32,
+    scale: Option<(usize, i32)>,
+    tranches: Option<u64>,
+) -> Result<Vec<i32>> {
+    match (scale, tranches) {
+        (Some((count, size)), _) => {
+            let total = count as i64 * size as i64;
+            if total > contracts as i64 {
+                bail!(
+                    "{}x{} is {} contracts, but the position holds {}",
+                    count,
+                    size,
+                    total,
+                    contracts
+                );
+            }
+            Ok(vec![size; count])
+        }
+        (None, Some(count)) => {
+            if count > contracts as u64 {
+                bail!(
+                    "Can't split {} contracts into {} tranches",
+                    contracts,
+                    count
+                );
+            }
+            let count = count as i32;
+            Ok((0..count)
+                .map(|i| contracts / count + i32::from(i < contracts % count))
+                .collect())
+        }
+        (None, None) => Ok(vec![contracts]),
+    }
+}
+
+/// What releases the tranches after the first
+#[derive(Debug, Clone, Copy)]
+enum Spacing {
+    Time(Duration),
+    /// Cents above the first tranche's fill, per tranche
+    Price(i32),
+}
+
+impl Spacing {
+    fn describe(&self, tranche: usize) -> String {
+        match self {
+            Spacing::Time(every) => format!("+{}s", every.as_secs() * tranche as u64),
+            Spacing::Price(step) => format!("first fill +{}¢", step * tranche as i32),
+        }
+    }
+}
+
+/// Latest quote for the position's side, else the backend's last mark
+async fn position_quote(client: &ApiClient, position: &Position) -> Option<f64> {
+    let quote = lookup_contract(client, |c| c.ticker == position.ticker)
+        .await
+        .and_then(|c| {
+            if position.direction.eq_ignore_ascii_case("NO") {
+                c.no_price
+            } else {
+                c.yes_price
+            }
+        });
+    quote.or(position.current_price)
+}
+
+/// Fill in tags and notes the backend didn't return from the local journal
+fn annotate(history: Vec<TradeHistory>) -> Vec<TradeHistory> {
+    let journal = journal::by_trade().unwrap_or_default();
+    history
+        .into_iter()
+        .map(|mut trade| {
+            if let Some(entry) = journal.get(&trade.id) {
+                trade.tag = trade.tag.or_else(|| entry.tag.clone());
+                trade.note = trade.note.or_else(|| entry.note.clone());
+            }
+            trade
+        })
+        .collect()
+}
+
+fn has_tag(trade: &TradeHistory, tag: &str) -> bool {
+    trade
+        .tag
+        .as_deref()
+        .is_some_and(|t| t.eq_ignore_ascii_case(tag))
+}
+
+/// The backend's P&L summary can't filter by tag, so add up the tagged trades
+/// closed in the period from history instead
+fn tagged_summary(history: &[TradeHistory], period: &str, tag: &str) -> Result<PnLSummary> {
+    let since = match period {
+        "today" => Local::now()
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+            .map(|midnight| midnight.with_timezone(&Utc)),
+        "week" => Some(Utc::now() - chrono::Duration::days(7)),
+        "all" => None,
+        _ => bail!("--tag works with the today, week and all periods"),
+    };
+    let closed: Vec<&TradeHistory> = history
+        .iter()
+        .filter(|trade| has_tag(trade, tag) && trade.pnl.is_some())
+        .filter(|trade| match since {
+            Some(since) => trade
+                .closed_at
+                .as_deref()
+                .and_then(parse_timestamp)
+                .is_some_and(|closed_at| closed_at >= since),
+            None => true,
+        })
+        .collect();
+
+    let total_pnl: f64 = closed.iter().filter_map(|t| t.pnl).sum();
+    let total_fees: f64 = closed.iter().filter_map(|t| t.fees).sum();
+    let wins = closed
+        .iter()
+        .filter(|t| t.pnl.is_some_and(|pnl| pnl > 0.0))
+        .count() as i32;
+    let losses = closed
+        .iter()
+        .filter(|t| t.pnl.is_some_and(|pnl| pnl < 0.0))
+        .count() as i32;
+    let trade_count = closed.len() as i32;
+    Ok(PnLSummary {
+        period: period.to_string(),
+        total_pnl,
+        total_fees,
+        net_pnl: total_pnl - total_fees,
+        trade_count,
+        wins,
+        losses,
+        win_rate: if trade_count > 0 {
+            wins as f64 / trade_count as f64
+        } else {
+            0.0
+        },
+    })
+}
+
+/// Contract at the first expiry after `position`'s, closest in strike
+fn next_hour_contract<'a>(position: &Position, contracts: &'a [Contract]) -> Option<&'a Contract> {
+    let after = parse_timestamp(position.expiry_at.as_deref()?)?;
+    let next = contracts
+        .iter()
+        .filter_map(|c| parse_timestamp(c.expiry_time.as_deref()?))
+        .filter(|at| *at > after)
+        .min()?;
+    contracts
+        .iter()
+        .filter(|c| c.expiry_time.as_deref().and_then(parse_timestamp) == Some(next))
+        .filter_map(|c| c.strike_price.map(|s| (c, (s - position.strike).abs())))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(c, _)| c)
+}
+
+/// The YES and NO contracts of a spread, from the soonest expiry listing both strikes
+fn spread_contracts(
+    contracts: &[Contract],
+    yes_strike: f64,
+    no_strike: f64,
+) -> Option<(&Contract, &Contract)> {
+    let at =
+        |strike: f64| move |c: &&Contract| c.strike_price.is_some_and(|s| (s - strike).abs() < 0.5);
+    contracts
+        .iter()
+        .filter(at(yes_strike))
+        .filter_map(|yes| {
+            let no = contracts
+                .iter()
+                .filter(at(no_strike))
+                .find(|no| no.expiry_time.is_some() && no.expiry_time == yes.expiry_time)?;
+            Some((yes, no))
+        })
+        .min_by(|a, b| {
+            let hours = |c: &Contract| c.time_to_expiry_hours.unwrap_or(f64::MAX);
+            hours(a.0).total_cmp(&hours(b.0))
+        })
+}
+
+/// Wait for a tranche's trigger. False when interrupted; true once it's due,
+/// or early when the contract gets within `[orders] min_seconds_to_expiry`
+async fn wait_for_tranche(
+    client: &ApiClient,
+    position: &Position,
+    spacing: Spacing,
+    target: Option<f64>,
+    flush_secs: u64,
+) -> bool {
+    let near_expiry = || {
+        position
+            .hours_to_expiry()
+            .is_some_and(|hours| hours * 3600.0 <= flush_secs as f64)
+    };
+    if near_expiry() {
+        println!("⏰ Contract is about to expire; closing the next tranche now");
+        return true;
+    }
+    match (spacing, target) {
+        (Spacing::Time(every), _) => {
+            println!("⏳ Next tranche in {}s (Ctrl-C to stop)", every.as_secs());
+            tokio::select! {
+                _ = tokio::time::sleep(every) => true,
+                _ = tokio::signal::ctrl_c() => false,
+            }
+        }
+        (Spacing::Price(_), Some(target)) => {
+            println!(
+                "⏳ Next tranche at ${:.2} or better (Ctrl-C to stop)",
+                target
+            );
+            let mut ticker = tokio::time::interval(TRANCHE_POLL);
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    _ = tokio::signal::ctrl_c() => return false,
+                }
+                if near_expiry() {
+                    println!("⏰ Contract is about to expire; closing the next tranche now");
+                    return true;
+                }
+                if position_quote(client, position)
+                    .await
+                    .is_some_and(|quote| quote >= target - 1e-9)
+                {
+                    return true;
+                }
+            }
+        }
+        (Spacing::Price(_), None) => true,
+    }
+}
+
+/// Close a position in tranches, each one audited like a regular close. The
+/// final tranche closes whatever is left when the plan covers the position.
+async fn scale_out(
+    client: &ApiClient,
+    config: &Config,
+    position: &Position,
+    tranches: &[i32],
+    spacing: Spacing,
+) -> Result<()> {
+    let count = tranches.len();
+    let planned: i32 = tranches.iter().sum();
+    let mut closed = 0;
+    let mut first_fill: Option<f64> = None;
+
+    for (i, &size) in tranches.iter().enumerate() {
+        if i > 0 {
+            let target = match (spacing, first_fill) {
+                (Spacing::Price(step), Some(fill)) => Some(fill + (step * i as i32) as f64 / 100.0),
+                _ => None,
+            };
+            if !wait_for_tranche(
+                client,
+                position,
+                spacing,
+                target,
+                config.orders.min_seconds_to_expiry,
+            )
+            .await
+            {
+                println!();
+                println!(
+                    "⏹  Stopped: {} of {} contracts still open in position #{}",
+                    position.contracts - closed,
+                    position.contracts,
+                    position.trade_id
+                );
+                return Ok(());
+            }
+        }
+
+        let rest = i + 1 == count && planned == position.contracts;
+        let description = format!(
+            "close position #{} tranche {}/{} x{}",
+            position.trade_id,
+            i + 1,
+            count,
+            size
+        );
+        let result = if rest {
+            client.close_position(position.trade_id).await
+        } else {
+            client.close_contracts(position.trade_id, size).await
+        };
+        audit_result(AuditKind::Close, &description, &result);
+        let result = result?;
+        if !result.success {
+            println!(
+                "❌ Tranche {}/{} failed: {}",
+                i + 1,
+                count,
+                result.error.as_deref().unwrap_or("close rejected")
+            );
+            bail!(
+                "Scale-out stopped; {} of {} contracts still open in position #{}",
+                position.contracts - closed,
+                position.contracts,
+                position.trade_id
+            );
+        }
+
+        // A backend without partial closes sells the whole position instead
+        if result.filled > size {
+            bail!(
+                "Tranche {}/{} sold {} contracts, not {}; the backend closed all of position #{} \
+                 (partial closes need a backend that takes ?contracts=)",
+                i + 1,
+                count,
+                result.filled,
+                size,
+                position.trade_id
+            );
+        }
+        closed += result.filled;
+        if first_fill.is_none() {
+            first_fill = match result.price {
+                Some(price) => Some(price),
+                None => position_quote(client, position).await,
+            };
+        }
+        let pnl = result
+            .cost
+            .map(|pnl| {
+                let color = if pnl >= 0.0 { "[32m" } else { "[31m" };
+                format!(" (P&L {}${:+.2}[0m)", color, pnl)
+            })
+            .unwrap_or_default();
+        println!(
+            "✅ Tranche {}/{}: {} contracts @ {}{}",
+            i + 1,
+            count,
+            result.filled,
+            result
+                .price
+                .map(|p| format!("${:.2}", p))
+                .unwrap_or_else(|| "--".to_string()),
+            pnl
+        );
+        if closed >= position.contracts {
+            break;
+        }
+    }
+
+    println!();
+    if closed >= position.contracts {
+        println!(
+            "✅ Position #{} Closed in {} tranches",
+            position.trade_id, count
+        );
+    } else {
+        println!(
+            "✅ Scaled out of {} of {} contracts; {} stay open",
+            closed,
+            position.contracts,
+            position.contracts - closed
+        );
+    }
+    Ok(())
+}
+
+pub async fn handle_trading_command(
+    cmd: TradingCommands,
+    api_url: &str,
+    dry_run: bool,
+    retry_orders: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    if output != OutputFormat::Table && (dry_run || !cmd.supports_output()) {
+        bail!(
+            "--output json/csv covers positions, orders, history, pnl and the results of trade, manual, close and cancel; \
+             previews and other commands only print text"
+        );
+    }
+    let client = ApiClient::new(api_url.to_string(), 30)?.with_order_retry(retry_orders);
+    let config = Config::load()?;
+    let table = output == OutputFormat::Table;
+
+    match cmd {
+        TradingCommands::Amend { .. } | TradingCommands::Cancel { .. } if dry_run => {
+            bail!("--dry-run previews trade, manual and close; amend and cancel have nothing to preview");
+        }
+
+        TradingCommands::Trade {
+            signal_id,
+            size,
+            limit,
+            take_profit,
+            stop_loss,
+            tag,
+            note,
+        } => {
+            let contract = lookup_contract(&client, |c| c.id == signal_id).await;
+            let (size, source) = match size {
+                Some(size) => (size, ""),
+                None => match script_size(&client, &config, contract.as_ref()).await? {
+                    Some(size) => (size, " (from script)"),
+                    None => (1, ""),
+                },
+            };
+            if size < 1 {
+                bail!(
+                    "Script sized this trade at {} contracts; pass --size to override",
+                    size
+                );
+            }
+            // A limit order goes out as a regular order, so it needs the signal's contract
+            let limit_contract = match (limit, &contract) {
+                (None, _) => None,
+                (Some(_), None) => bail!(
+                    "Signal #{} isn't in the current signals; a limit order needs its ticker and strike",
+                    signal_id
+                ),
+                (Some(_), Some(contract)) if !contract.signal_type.starts_with("BUY ") => bail!(
+                    "Signal #{} is {}; use `manual --limit` to pick a side",
+                    signal_id,
+                    contract.signal_type
+                ),
+                (Some(_), Some(contract)) => Some(contract),
+            };
+            brackets::validate(take_profit, stop_loss)?;
+            let bracketed = take_profit.is_some() || stop_loss.is_some();
+            if bracketed
+                && !contract
+                    .as_ref()
+                    .is_some_and(|c| c.signal_type.starts_with("BUY "))
+            {
+                bail!(
+                    "Signal #{} isn't a BUY in the current signals; a bracket needs the position's ticker and side",
+                    signal_id
+                );
+            }
+
+            if dry_run {
+                let price = limit
+                    .map(|cents| cents as f64 / 100.0)
+                    .or_else(|| contract.as_ref().and_then(sizing::entry_price));
+                let Some(price) = price else {
+                    bail!(
+                        "Signal #{} isn't in the current signals, so there's no price to preview",
+                        signal_id
+                    );
+                };
+                println!("Previewing trade from signal #{}...", signal_id);
+                if let Some(contract) = &contract {
+                    println!("{} {}", contract.signal_type, contract.ticker);
+                }
+                println!("Contracts: {}{}", size, source);
+                println!();
+                let preview = EntryPreview::new(size, price);
+                preview.print();
+                print_bracket_preview(&preview, take_profit, stop_loss);
+                return Ok(());
+            }
+
+            if table {
+                println!("Executing trade from signal #{}...", signal_id);
+                println!("Contracts: {}{}", size, source);
+                if let Some(cents) = limit {
+                    println!("Limit: {}¢", cents);
+                }
+                println!();
+            }
+
+            let hours_to_expiry = contract.as_ref().and_then(|c| c.time_to_expiry_hours);
+            check_order_guard(&client, &config.orders, hours_to_expiry).await?;
+            check_tilt(&client, &config).await?;
+            if let Some(contract) = &contract {
+                warn_reentry(&client, &config, &contract.ticker).await;
+                let price = match limit {
+                    Some(cents) => Some(cents as f64 / 100.0),
+                    None => sizing::entry_price(contract),
+                };
+                let notional = price.unwrap_or(0.0) * size as f64;
+                check_exposure(
+                    &client,
+                    &config.exposure,
+                    contract.expiry_time.as_deref(),
+                    notional,
+                )
+                .await?;
+            }
+
+            let mut description = format!("signal #{} x{}", signal_id, size);
+            let result = match (limit, limit_contract) {
+                (Some(cents), Some(contract)) => {
+                    description.push_str(&format!(" limit ${:.2}", cents as f64 / 100.0));
+                    let request = TradeRequest {
+                        ticker: contract.ticker.clone(),
+                        asset: contract.asset()?,
+                        direction: contract.signal_type.trim_start_matches("BUY ").to_string(),
+                        strike: contract.strike_price.unwrap_or_default(),
+                        contracts: size,
+                        order_type: "limit".to_string(),
+                        limit_price: Some(cents),
+                        signal_id: Some(signal_id.to_string()),
+                        client_order_id: None,
+                        tag: tag.clone(),
+                        note: note.clone(),
+                    };
+                    client.execute_trade(request).await
+                }
+                _ => client.execute_from_signal(signal_id, size).await,
+            };
+            audit_result(AuditKind::Order, &description, &result);
+            let result = result?;
+            let ticker = contract
+                .as_ref()
+                .map(|c| c.ticker.as_str())
+                .unwrap_or_default();
+            journal_trade(
+                ticker,
+                Origin::Signal { signal_id },
+                tag.as_deref(),
+                note.as_deref(),
+                &result,
+            );
+
+            if table {
+                print_order_result(&result, size, limit);
+            } else {
+                print_record(&result, output)?;
+            }
+            if let Some(contract) = &contract {
+                let direction = contract.signal_type.trim_start_matches("BUY ");
+                attach_bracket(
+                    Some(contract),
+                    &contract.ticker,
+                    direction,
+                    &result,
+                    take_profit,
+                    stop_loss,
+                    output,
+                );
+            }
+            if !result.success {
+                bail!("Order was not placed");
+            }
+        }
+
+        TradingCommands::Size {
+            signal_id,
+            bankroll,
+            kelly_fraction,
+            execute,
+        } => {
+            if !(kelly_fraction > 0.0 && kelly_fraction <= 1.0) {
+                bail!(
+                    "--kelly-fraction must be above 0 and at most 1 (got {})",
+                    kelly_fraction
+                );
+            }
+            let Some(contract) = lookup_contract(&client, |c| c.id == signal_id).await else {
+                bail!("Signal #{} isn't in the current signals", signal_id);
+            };
+            if !contract.signal_type.starts_with("BUY ") {
+                bail!(
+                    "Signal #{} is {}; there's no side to size",
+                    signal_id,
+                    contract.signal_type
+                );
+            }
+            let (Some(price), Some(win_prob)) = (
+                sizing::entry_price(&contract),
+                sizing::win_probability(&contract),
+            ) else {
+                bail!(
+                    "Signal #{} has no price or model probability to size from",
+                    signal_id
+                );
+            };
+            let (bankroll, source) = match bankroll {
+                Some(bankroll) => (bankroll, "--bankroll"),
+                None => (
+                    bankroll::bankroll(&client)
+                        .await
+                        .context("Could not fetch account equity; pass --bankroll")?,
+                    "account equity",
+                ),
+            };
+            let full = sizing::kelly_fraction(win_prob, price);
+
+            println!("📐 Kelly Sizing - signal #{}", signal_id);
+            println!("{}", "─".repeat(60));
+            println!(
+                "   {} {} @ ${:.2}",
+                contract.signal_type, contract.ticker, price
+            );
+            println!(
+                "   Model: {:.1}%   Market: {:.1}%   Edge: {:+.1}pp",
+                win_prob * 100.0,
+                price * 100.0,
+                (win_prob - price) * 100.0
+            );
+            println!("   Bankroll: ${:.2} ({})", bankroll, source);
+            println!("{}", "─".repeat(60));
+            if full <= 0.0 {
+                println!("   No edge at this price: Kelly stakes nothing.");
+                if execute {
+                    bail!("Nothing to execute");
+                }
+                return Ok(());
+            }
+
+            let mut fractions = KELLY_FRACTIONS.to_vec();
+            if !fractions.iter().any(|f| (f - kelly_fraction).abs() < 1e-9) {
+                fractions.push(kelly_fraction);
+                fractions.sort_by(|a, b| b.total_cmp(a));
+            }
+            println!(
+                "   {:<10} {:<10} {:<10} {:<10} {:<10}",
+                "Kelly", "Bankroll", "Stake", "Contracts", "Max Loss"
+            );
+            let contracts_at = |fraction: f64| (full * fraction * bankroll / price).floor() as i32;
+            for fraction in &fractions {
+                let contracts = contracts_at(*fraction);
+                let chosen = (fraction - kelly_fraction).abs() < 1e-9;
+                println!(
+                    "   {:<10} {:<10} {:<10} {:<10} {:<10}{}",
+                    format!("{:.0}%", fraction * 100.0),
+                    format!("{:.1}%", full * fraction * 100.0),
+                    format!("${:.2}", full * fraction * bankroll),
+                    contracts,
+                    format!("${:.2}", EntryPreview::new(contracts, price).max_loss),
+                    if chosen { "  ◀" } else { "" }
+                );
+            }
+            println!("{}", "─".repeat(60));
+
+            let size = contracts_at(kelly_fraction);
+            if !execute {
+                println!(
+                    "   Trade it with `basilisk trade {} --size {}` (or rerun with --execute)",
+                    signal_id, size
+                );
+                return Ok(());
+            }
+            if size < 1 {
+                bail!(
+                    "{:.0}% Kelly rounds down to 0 contracts; nothing to execute",
+                    kelly_fraction * 100.0
+                );
+            }
+            println!();
+            let trade = TradingCommands::Trade {
+                signal_id,
+                size: Some(size),
+                limit: None,
+                take_profit: None,
+                stop_loss: None,
+                tag: None,
+                note: None,
+            };
+            Box::pin(handle_trading_command(
+                trade,
+                api_url,
+                dry_run,
+                retry_orders,
+                output,
+            ))
+            .await?;
+        }
 
-    match cmd {
-        TradingCommands::Trade { signal_id, size } => {
-            println!("Executing trade from signal #{}...", signal_id);
-            println!("Contracts: {}", size);
+        TradingCommands::TradeBatch { file, max_failures } => {
+            let entries = batch::load(file.as_deref())?;
+            println!(
+                "{} batch of {} orders from {}...",
+                if dry_run { "Previewing" } else { "Running" },
+                entries.len(),
+                file.as_deref()
+                    .filter(|p| p.as_os_str() != "-")
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "stdin".to_string())
+            );
             println!();
+            batch::run(
+                entries,
+                api_url,
+                dry_run,
+                retry_orders,
+                max_failures.map(|max| max as usize),
+            )
+            .await?;
+        }
 
-            let result = client.execute_from_signal(signal_id, size).await?;
+        TradingCommands::TradeAuto {
+            min_ev,
+            min_confidence,
+            max_positions,
+            size,
+            yes,
+        } => {
+            let min_ev = min_ev.map(|pct| pct / 100.0).unwrap_or(config.auto.min_ev);
+            let min_confidence = min_confidence.unwrap_or(config.auto.min_confidence);
+            let max_positions = max_positions.unwrap_or(config.auto.max_open_positions);
+            let size = size.unwrap_or(config.auto.size);
+            if size < 1 {
+                bail!("--size must be at least 1");
+            }
 
-            if result.success {
-                println!("✅ Trade Executed Successfully!");
-                println!("   Trade ID: {}", result.trade_id.unwrap_or(0));
-                println!("   Filled: {} contracts", result.filled);
-                if let Some(price) = result.price {
-                    println!("   Price: ${:.2}", price);
-                }
-                if let Some(cost) = result.cost {
-                    println!("   Cost: ${:.2}", cost);
-                }
-            } else {
-                println!("❌ Trade Failed!");
-                if let Some(error) = result.error {
-                    println!("   Error: {}", error);
+            let signals = client.get_current_signals().await?;
+            let held: HashSet<String> = client
+                .get_positions()
+                .await?
+                .into_iter()
+                .map(|p| p.ticker)
+                .collect();
+            // Same ranking as `basilisk auto`: composite score, EV breaking ties
+            let script = ScriptHooks::load(&config.scripting)?;
+            let scorer = Scorer::new(&config.score, &signals.volatility.regime, script.as_ref());
+            let mut ranked: Vec<(&Contract, f64)> = signals
+                .contracts
+                .iter()
+                .filter(|c| {
+                    c.is_active
+                        && c.signal_type.starts_with("BUY ")
+                        && c.expected_value >= min_ev
+                        && c.confidence_score >= min_confidence
+                        && !held.contains(&c.ticker)
+                })
+                .map(|c| (c, scorer.score(c)))
+                .collect();
+            ranked.sort_by(|a, b| {
+                b.1.total_cmp(&a.1)
+                    .then(b.0.expected_value.total_cmp(&a.0.expected_value))
+            });
+            let qualifying = ranked.len();
+            ranked.truncate(max_positions);
+
+            println!(
+                "🤖 Trade Plan: EV ≥ {:.1}%, confidence ≥ {:.2}, best {}, {} contracts each",
+                min_ev * 100.0,
+                min_confidence,
+                max_positions,
+                size
+            );
+            println!("{}", "─".repeat(100));
+            if ranked.is_empty() {
+                println!(
+                    "   No signals qualify{}.",
+                    if held.is_empty() {
+                        ""
+                    } else {
+                        " that you don't already hold"
+                    }
+                );
+                return Ok(());
+            }
+            println!(
+                "   {:<8} {:<26} {:<9} {:<8} {:<8} {:<8} {:<6} {:<6} {:<8} Fees",
+                "Signal", "Ticker", "Side", "Price", "EV", "Net EV", "Conf", "Score", "Cost"
+            );
+            let mut total_cost = 0.0;
+            let mut total_fees = 0.0;
+            for (contract, score) in &ranked {
+                let price = sizing::entry_price(contract);
+                let cost = price.map(|p| p * size as f64);
+                let fee = price.map(|p| fees::taker_fee(size, p));
+                total_cost += cost.unwrap_or(0.0);
+                total_fees += fee.unwrap_or(0.0);
+                println!(
+                    "   {:<8} {:<26} {:<9} {:<8} {:<8} {:<8} {:<6.2} {:<6.0} {:<8} {}",
+                    format!("#{}", contract.id),
+                    contract.ticker,
+                    contract.signal_type.trim_start_matches("BUY "),
+                    price
+                        .map(|p| format!("${:.2}", p))
+                        .unwrap_or_else(|| "--".to_string()),
+                    contract.ev_display(),
+                    fees::net_ev(contract)
+                        .map(|ev| format!("{:+.1}%", ev * 100.0))
+                        .unwrap_or_else(|| "--".to_string()),
+                    contract.confidence_score,
+                    score,
+                    cost.map(|c| format!("${:.2}", c))
+                        .unwrap_or_else(|| "--".to_string()),
+                    fee.map(|f| format!("${:.2}", f))
+                        .unwrap_or_else(|| "--".to_string())
+                );
+            }
+            println!("{}", "─".repeat(100));
+            println!(
+                "   {} trades, ${:.2} total plus ~${:.2} fees{}",
+                ranked.len(),
+                total_cost,
+                total_fees,
+                if qualifying > ranked.len() {
+                    format!(
+                        " ({} more qualify below the cut)",
+                        qualifying - ranked.len()
+                    )
+                } else {
+                    String::new()
                 }
+            );
+
+            if !yes {
+                println!("   Rerun with --yes to place them (add --dry-run to preview each first)");
+                return Ok(());
             }
+            println!();
+            let entries = ranked
+                .iter()
+                .map(|(contract, _)| batch::BatchEntry {
+                    signal_id: Some(contract.id),
+                    size: Some(size),
+                    ..Default::default()
+                })
+                .collect();
+            batch::run(entries, api_url, dry_run, retry_orders, None).await?;
         }
 
         TradingCommands::Manual {
@@ -99,45 +1446,167 @@ pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Resu
             strike,
             ticker,
             size,
-        } => {
-            println!("Executing manual trade...");
-            println!("Asset: {}, Direction: {}, Strike: ${:.0}", asset, direction, strike);
+            limit,
+            take_profit,
+            stop_loss,
+            ..
+        } if dry_run => {
+            brackets::validate(take_profit, stop_loss)?;
+            let quote = lookup_contract(&client, |c| c.ticker == ticker)
+                .await
+                .and_then(|c| {
+                    if direction.eq_ignore_ascii_case("NO") {
+                        c.no_price
+                    } else {
+                        c.yes_price
+                    }
+                });
+            let Some(price) = limit.map(|cents| cents as f64 / 100.0).or(quote) else {
+                bail!(
+                    "No quote for {} in the current signals; pass --limit to preview at a price",
+                    ticker
+                );
+            };
+            println!("Previewing manual trade...");
+            println!(
+                "Asset: {}, Direction: {}, Strike: ${:.0}",
+                asset,
+                direction.to_uppercase(),
+                strike
+            );
             println!("Ticker: {}, Size: {}", ticker, size);
             println!();
+            let preview = EntryPreview::new(size, price);
+            preview.print();
+            print_bracket_preview(&preview, take_profit, stop_loss);
+        }
+
+        TradingCommands::Manual {
+            asset,
+            direction,
+            strike,
+            ticker,
+            size,
+            limit,
+            take_profit,
+            stop_loss,
+            tag,
+            note,
+        } => {
+            brackets::validate(take_profit, stop_loss)?;
+            if table {
+                println!("Executing manual trade...");
+                println!(
+                    "Asset: {}, Direction: {}, Strike: ${:.0}",
+                    asset, direction, strike
+                );
+                match limit {
+                    Some(cents) => {
+                        println!("Ticker: {}, Size: {}, Limit: {}¢", ticker, size, cents)
+                    }
+                    None => println!("Ticker: {}, Size: {}", ticker, size),
+                }
+                println!();
+            }
+
+            let contract = lookup_contract(&client, |c| c.ticker == ticker).await;
+            let hours_to_expiry = contract.as_ref().and_then(|c| c.time_to_expiry_hours);
+            check_order_guard(&client, &config.orders, hours_to_expiry).await?;
+            check_tilt(&client, &config).await?;
+            warn_reentry(&client, &config, &ticker).await;
+            if let Some(contract) = &contract {
+                let quote = if direction.eq_ignore_ascii_case("NO") {
+                    contract.no_price
+                } else {
+                    contract.yes_price
+                };
+                let price = limit.map(|cents| cents as f64 / 100.0).or(quote);
+                let notional = price.unwrap_or(0.0) * size as f64;
+                check_exposure(
+                    &client,
+                    &config.exposure,
+                    contract.expiry_time.as_deref(),
+                    notional,
+                )
+                .await?;
+            }
 
+            let mut description = format!("{} {} x{}", ticker, direction.to_uppercase(), size);
+            if let Some(cents) = limit {
+                description.push_str(&format!(" limit ${:.2}", cents as f64 / 100.0));
+            }
             let request = TradeRequest {
-                ticker,
+                ticker: ticker.clone(),
                 asset: asset.to_uppercase(),
                 direction: direction.to_uppercase(),
                 strike,
                 contracts: size,
-                order_type: "market".to_string(),
-                limit_price: None,
+                order_type: if limit.is_some() { "limit" } else { "market" }.to_string(),
+                limit_price: limit,
                 signal_id: None,
+                client_order_id: None,
+                tag: tag.clone(),
+                note: note.clone(),
             };
 
-            let result = client.execute_trade(request).await?;
+            let result = client.execute_trade(request).await;
+            audit_result(AuditKind::Order, &description, &result);
+            let result = result?;
+            journal_trade(
+                &ticker,
+                Origin::Manual,
+                tag.as_deref(),
+                note.as_deref(),
+                &result,
+            );
 
-            if result.success {
-                println!("✅ Trade Executed Successfully!");
-                println!("   Trade ID: {}", result.trade_id.unwrap_or(0));
-                println!("   Filled: {} contracts", result.filled);
-                if let Some(price) = result.price {
-                    println!("   Price: ${:.2}", price);
-                }
-                if let Some(cost) = result.cost {
-                    println!("   Cost: ${:.2}", cost);
-                }
+            if table {
+                print_order_result(&result, size, limit);
             } else {
-                println!("❌ Trade Failed!");
-                if let Some(error) = result.error {
-                    println!("   Error: {}", error);
-                }
+                print_record(&result, output)?;
+            }
+            attach_bracket(
+                contract.as_ref(),
+                &ticker,
+                &direction,
+                &result,
+                take_profit,
+                stop_loss,
+                output,
+            );
+            if !result.success {
+                bail!("Order was not placed");
+            }
+        }
+
+        TradingCommands::Positions {
+            action: Some(PositionsAction::Export { format, out }),
+        } => {
+            let positions = client.get_positions().await?;
+            // Market context is optional: export still works without it
+            let market = client.get_current_signals().await.ok();
+            let records = portfolio::build_records(
+                &positions,
+                market.as_ref().and_then(|m| m.btc_price()),
+                market.as_ref().and_then(|m| m.volatility.effective_vol()),
+            );
+
+            portfolio::write_records(&records, format, out.as_deref())?;
+
+            if let Some(path) = out {
+                println!(
+                    "✅ Exported {} positions to {}",
+                    records.len(),
+                    path.display()
+                );
             }
         }
 
-        TradingCommands::Positions => {
+        TradingCommands::Positions { action: None } => {
             let positions = client.get_positions().await?;
+            if !table {
+                return print_records(&positions, output);
+            }
 
             if positions.is_empty() {
                 println!("📂 No open positions.");
@@ -152,7 +1621,7 @@ pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Resu
             );
             println!("{}", "─".repeat(80));
 
-            for pos in positions {
+            for pos in &positions {
                 let pnl_color = match pos.unrealized_pnl {
                     Some(pnl) if pnl > 0.0 => "\x1b[32m", // Green
                     Some(pnl) if pnl < 0.0 => "\x1b[31m", // Red
@@ -174,13 +1643,145 @@ pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Resu
                 );
             }
             println!("{}", "─".repeat(80));
+
+            let spreads = SpreadBook::load();
+            let open = spreads.open(&positions);
+            if !open.is_empty() {
+                println!();
+                println!("🔀 Spreads ({}):", open.len());
+                for spread in open {
+                    let ids: Vec<String> = spread
+                        .legs
+                        .iter()
+                        .map(|leg| format!("#{}", leg.trade_id))
+                        .collect();
+                    let pnl = match spread.pnl(&positions, |p| p.unrealized_pnl) {
+                        Some(pnl) => {
+                            let color = if pnl >= 0.0 { "\x1b[32m" } else { "\x1b[31m" };
+                            format!("{}${:+.2}\x1b[0m", color, pnl)
+                        }
+                        None => "--".to_string(),
+                    };
+                    println!(
+                        "   {:<4} {:<24} {:<14} P&L {}  Max Loss ${:.2}  Max Profit ${:.2}",
+                        format!("#{}", spread.id),
+                        spread.describe(),
+                        ids.join(" + "),
+                        pnl,
+                        spread.max_loss(),
+                        spread.max_profit()
+                    );
+                }
+            }
+        }
+
+        TradingCommands::Close {
+            scale: None,
+            tranches: None,
+            every,
+            step,
+            ..
+        } if every.is_some() || step.is_some() => {
+            bail!("--every and --step space out tranches; add --scale or --tranches");
+        }
+
+        TradingCommands::Close {
+            position_id,
+            scale,
+            tranches,
+            every,
+            step,
+        } if dry_run => {
+            let positions = client.get_positions().await?;
+            let Some(position) = positions.iter().find(|p| p.trade_id == position_id) else {
+                bail!("Position #{} isn't open", position_id);
+            };
+            let plan = plan_tranches(position.contracts, scale, tranches)?;
+            let Some(price) = position_quote(&client, position).await else {
+                bail!("No price for {} to preview the close at", position.ticker);
+            };
+            println!("Previewing close of position #{}...", position_id);
+            println!(
+                "{} {} x{} @ ${:.2}",
+                position.ticker, position.direction, position.contracts, position.entry_price
+            );
+            println!();
+            if plan.len() > 1 {
+                let spacing = step
+                    .map(Spacing::Price)
+                    .unwrap_or(Spacing::Time(every.unwrap_or(DEFAULT_TRANCHE_SPACING)));
+                println!("Tranches:");
+                for (i, size) in plan.iter().enumerate() {
+                    let when = if i == 0 {
+                        "now".to_string()
+                    } else {
+                        spacing.describe(i)
+                    };
+                    println!(
+                        "   {}/{}  {:>4} contracts  {}",
+                        i + 1,
+                        plan.len(),
+                        size,
+                        when
+                    );
+                }
+                println!();
+            }
+            // Every tranche priced at today's quote; later ones should do better
+            let closing = Position {
+                contracts: plan.iter().sum(),
+                ..position.clone()
+            };
+            ExitPreview::new(&closing, price).print();
         }
 
-        TradingCommands::Close { position_id } => {
-            println!("Closing position #{}...", position_id);
+        TradingCommands::Close {
+            position_id,
+            scale,
+            tranches,
+            every,
+            step,
+        } if scale.is_some() || tranches.is_some() => {
+            let positions = client.get_positions().await?;
+            let Some(position) = positions.into_iter().find(|p| p.trade_id == position_id) else {
+                bail!("Position #{} isn't open", position_id);
+            };
+            let plan = plan_tranches(position.contracts, scale, tranches)?;
+            let spacing = step
+                .map(Spacing::Price)
+                .unwrap_or(Spacing::Time(every.unwrap_or(DEFAULT_TRANCHE_SPACING)));
+            println!(
+                "Scaling out of position #{} ({} {} x{}) in {} tranches: {}",
+                position_id,
+                position.ticker,
+                position.direction,
+                position.contracts,
+                plan.len(),
+                plan.iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" + ")
+            );
             println!();
+            scale_out(&client, &config, &position, &plan, spacing).await?;
+        }
 
-            let result = client.close_position(position_id).await?;
+        TradingCommands::Close { position_id, .. } => {
+            if table {
+                println!("Closing position #{}...", position_id);
+                println!();
+            }
+
+            let result = client.close_position(position_id).await;
+            audit_result(
+                AuditKind::Close,
+                &format!("close position #{}", position_id),
+                &result,
+            );
+            let result = result?;
+            if !table {
+                return print_record(&result, output);
+            }
 
             if result.success {
                 println!("✅ Position Closed!");
@@ -200,8 +1801,575 @@ pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Resu
             }
         }
 
-        TradingCommands::Pnl { period } => {
-            let summary = client.get_pnl_summary(&period).await?;
+        TradingCommands::Roll { position_id, size } => {
+            let positions = client.get_positions().await?;
+            let Some(position) = positions.into_iter().find(|p| p.trade_id == position_id) else {
+                bail!("Position #{} isn't open", position_id);
+            };
+            let size = size.unwrap_or(position.contracts);
+            if size < 1 {
+                bail!("--size must be at least 1");
+            }
+            let signals = client.get_current_signals().await?;
+            let Some(next) = next_hour_contract(&position, &signals.contracts) else {
+                bail!(
+                    "No contract after {}'s expiry is listed yet; nothing to roll into",
+                    position.ticker
+                );
+            };
+            let next_quote = if position.direction.eq_ignore_ascii_case("NO") {
+                next.no_price
+            } else {
+                next.yes_price
+            };
+            let next_strike = next.strike_price.unwrap_or(position.strike);
+
+            println!(
+                "{} position #{}: {} {} x{} ({} left)",
+                if dry_run {
+                    "Previewing roll of"
+                } else {
+                    "Rolling"
+                },
+                position_id,
+                position.ticker,
+                position.direction,
+                position.contracts,
+                position.time_left_display()
+            );
+            println!(
+                "   → {} {} x{} (strike ${:.0}, {})",
+                next.ticker,
+                position.direction,
+                size,
+                next_strike,
+                next_quote
+                    .map(|p| format!("quoted ${:.2}", p))
+                    .unwrap_or_else(|| "no quote".to_string())
+            );
+            println!();
+
+            if dry_run {
+                let (Some(exit_price), Some(entry_price)) =
+                    (position_quote(&client, &position).await, next_quote)
+                else {
+                    bail!("Missing a quote for one of the legs, so there's nothing to preview");
+                };
+                let exit = ExitPreview::new(&position, exit_price);
+                let entry = EntryPreview::new(size, entry_price);
+                println!("Close:");
+                exit.print();
+                println!();
+                println!("Open:");
+                entry.print();
+                println!();
+                println!(
+                    "   Net Cash:   ${:+.2} (close proceeds − new cost, after fees)",
+                    exit.proceeds - exit.fees - entry.max_loss
+                );
+                return Ok(());
+            }
+
+            // Check the new leg first: a blocked order shouldn't leave the position closed
+            check_order_guard(&client, &config.orders, next.time_to_expiry_hours).await?;
+            check_tilt(&client, &config).await?;
+            let notional = next_quote.unwrap_or(0.0) * size as f64;
+            check_exposure(
+                &client,
+                &config.exposure,
+                next.expiry_time.as_deref(),
+                notional,
+            )
+            .await?;
+
+            let result = client.close_position(position_id).await;
+            audit_result(
+                AuditKind::Close,
+                &format!("roll: close position #{}", position_id),
+                &result,
+            );
+            let closed = result?;
+            if !closed.success {
+                println!("❌ Close Failed!");
+                if let Some(error) = &closed.error {
+                    println!("   Error: {}", error);
+                }
+                bail!(
+                    "Roll stopped before opening {}; position #{} is still open",
+                    next.ticker,
+                    position_id
+                );
+            }
+            println!(
+                "✅ Closed {} x{} @ {}",
+                position.ticker,
+                closed.filled,
+                closed
+                    .price
+                    .map(|p| format!("${:.2}", p))
+                    .unwrap_or_else(|| "--".to_string())
+            );
+
+            let request = TradeRequest {
+                ticker: next.ticker.clone(),
+                asset: position.asset.to_uppercase(),
+                direction: position.direction.to_uppercase(),
+                strike: next_strike,
+                contracts: size,
+                order_type: "market".to_string(),
+                limit_price: None,
+                signal_id: None,
+                client_order_id: None,
+                tag: None,
+                note: None,
+            };
+            let description = format!("roll: {} {} x{}", next.ticker, request.direction, size);
+            let result = client.execute_trade(request).await;
+            audit_result(AuditKind::Order, &description, &result);
+            let opened = match result {
+                Ok(opened) if opened.success => opened,
+                Ok(opened) => {
+                    println!(
+                        "❌ Open Failed: {}",
+                        opened.error.as_deref().unwrap_or("order rejected")
+                    );
+                    bail!(
+                        "Position #{} was closed but {} wasn't opened",
+                        position_id,
+                        next.ticker
+                    );
+                }
+                Err(e) => {
+                    println!("❌ Open Failed: {}", e);
+                    bail!(
+                        "Position #{} was closed but {} wasn't opened",
+                        position_id,
+                        next.ticker
+                    );
+                }
+            };
+            journal_trade(&next.ticker, Origin::Manual, None, None, &opened);
+            println!(
+                "✅ Opened {} {} x{} @ {} (Trade ID: {})",
+                next.ticker,
+                position.direction,
+                opened.filled,
+                opened
+                    .price
+                    .map(|p| format!("${:.2}", p))
+                    .unwrap_or_else(|| "--".to_string()),
+                opened.trade_id.unwrap_or(0)
+            );
+
+            println!();
+            println!("🔁 Position Rolled!");
+            if let Some(pnl) = closed.cost {
+                let color = if pnl >= 0.0 { "\x1b[32m" } else { "\x1b[31m" };
+                println!("   Realized P&L: {}${:+.2}\x1b[0m", color, pnl);
+            }
+            let proceeds = closed.price.map(|p| p * closed.filled as f64);
+            let cost = opened
+                .cost
+                .or_else(|| opened.price.map(|p| p * opened.filled as f64));
+            if let Some(cost) = cost {
+                println!("   New Cost:     ${:.2}", cost);
+            }
+            if let (Some(proceeds), Some(cost)) = (proceeds, cost) {
+                println!("   Net Cash:     ${:+.2}", proceeds - cost);
+            }
+        }
+
+        TradingCommands::Hedge {
+            position_id,
+            strikes,
+            execute,
+            pick,
+        } => {
+            let positions = client.get_positions().await?;
+            let Some(position) = positions.into_iter().find(|p| p.trade_id == position_id) else {
+                bail!("Position #{} isn't open", position_id);
+            };
+            let signals = client.get_current_signals().await?;
+            let (Some(spot), Some(vol)) = (signals.btc_price(), signals.volatility.effective_vol())
+            else {
+                bail!("Hedging needs a BTC price and a volatility reading; the backend returned neither");
+            };
+            let offsets = hedge::offsets(&position, &signals.contracts, spot, vol, strikes);
+            if offsets.is_empty() {
+                bail!(
+                    "No listed strike at {}'s expiry can offset it",
+                    position.ticker
+                );
+            }
+            let cheapest = offsets
+                .iter()
+                .enumerate()
+                .filter_map(|(i, offset)| Some((i, offset.cost()?)))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(i, _)| i);
+
+            let delta = hedge::position_delta(&position, spot, vol);
+            println!(
+                "🛡  Hedge - position #{}: {} {} x{} @ ${:.2} ({} left)",
+                position_id,
+                position.ticker,
+                position.direction,
+                position.contracts,
+                position.entry_price,
+                position.time_left_display()
+            );
+            println!(
+                "   BTC ${:.0}, vol {:.0}%: the position moves ${:+.2} per ${:.0} BTC",
+                spot,
+                vol * 100.0,
+                delta * QUOTE_MOVE,
+                QUOTE_MOVE
+            );
+            println!("{}", "─".repeat(96));
+            println!(
+                "   {:<3} {:<26} {:<5} {:<10} {:<6} {:<8} {:<9} {:<12} Note",
+                "#", "Contract", "Side", "Strike", "Qty", "Price", "Cost", "Left/$100"
+            );
+            for (i, offset) in offsets.iter().enumerate() {
+                let note = match offset.locked_pnl(&position) {
+                    Some(pnl) => format!("locks ${:+.2}", pnl),
+                    None => String::new(),
+                };
+                println!(
+                    "   {:<3} {:<26} {:<5} {:<10} {:<6} {:<8} {:<9} {:<12} {}{}",
+                    i + 1,
+                    offset.contract.ticker,
+                    offset.direction,
+                    offset.contract.strike_display(),
+                    offset.contracts,
+                    offset
+                        .price
+                        .map(|p| format!("${:.2}", p))
+                        .unwrap_or_else(|| "--".to_string()),
+                    offset
+                        .cost()
+                        .map(|c| format!("${:.2}", c))
+                        .unwrap_or_else(|| "--".to_string()),
+                    format!("${:+.2}", offset.residual * QUOTE_MOVE),
+                    note,
+                    if Some(i) == cheapest {
+                        "  ◀ cheapest"
+                    } else {
+                        ""
+                    }
+                );
+            }
+            println!("{}", "─".repeat(96));
+            println!("   Cost includes taker fees. Left/$100: what the hedged pair still moves per $100 BTC.");
+
+            if !execute {
+                if cheapest.is_some() {
+                    println!("   Buy the cheapest with `basilisk hedge {} --execute` (or add --pick <#>)", position_id);
+                }
+                return Ok(());
+            }
+            let index = match pick {
+                Some(row) if (1..=offsets.len()).contains(&row) => row - 1,
+                Some(row) => bail!("--pick {} isn't in the table (1-{})", row, offsets.len()),
+                None => {
+                    cheapest.context("No offset has a quote to buy at; choose one with --pick")?
+                }
+            };
+            let offset = &offsets[index];
+            println!();
+            let order = TradingCommands::Manual {
+                asset: position.asset.clone(),
+                direction: offset.direction.to_string(),
+                strike: offset.contract.strike_price.unwrap_or(position.strike),
+                ticker: offset.contract.ticker.clone(),
+                size: offset.contracts,
+                limit: None,
+                take_profit: None,
+                stop_loss: None,
+                tag: Some("hedge".to_string()),
+                note: Some(format!("hedge for position #{}", position_id)),
+            };
+            Box::pin(handle_trading_command(
+                order,
+                api_url,
+                dry_run,
+                retry_orders,
+                output,
+            ))
+            .await?;
+        }
+
+        TradingCommands::Spread {
+            yes_strike,
+            no_strike,
+            size,
+            tag,
+            note,
+        } => {
+            if size < 1 {
+                bail!("--size must be at least 1");
+            }
+            if (yes_strike - no_strike).abs() < 0.5 {
+                bail!("YES and NO on the same strike always pay $1 together; pick two strikes");
+            }
+            let signals = client.get_current_signals().await?;
+            let Some((yes, no)) = spread_contracts(&signals.contracts, yes_strike, no_strike)
+            else {
+                bail!(
+                    "No listed expiry has both a ${:.0} and a ${:.0} strike",
+                    yes_strike,
+                    no_strike
+                );
+            };
+            let (Some(yes_quote), Some(no_quote)) = (yes.yes_price, no.no_price) else {
+                bail!("Missing a quote for one of the legs; try again when both are quoted");
+            };
+            let planned = Spread::planned(vec![
+                SpreadLeg::planned(&yes.ticker, "YES", yes_strike, size, yes_quote),
+                SpreadLeg::planned(&no.ticker, "NO", no_strike, size, no_quote),
+            ]);
+
+            println!(
+                "{} spread {} x{} ({} left)",
+                if dry_run { "Previewing" } else { "Opening" },
+                planned.describe(),
+                size,
+                yes.time_left_display()
+            );
+            println!("   YES leg: {} @ ${:.2}", yes.ticker, yes_quote);
+            println!("   NO leg:  {} @ ${:.2}", no.ticker, no_quote);
+            println!();
+
+            if dry_run {
+                for (label, price) in [("YES leg:", yes_quote), ("NO leg:", no_quote)] {
+                    println!("{}", label);
+                    EntryPreview::new(size, price).print();
+                    println!();
+                }
+                println!("Combined:");
+                println!("   Outlay:     ${:.2} (after fees)", planned.outlay());
+                println!("   Max Loss:   \x1b[31m${:.2}\x1b[0m", planned.max_loss());
+                println!("   Max Profit: \x1b[32m${:.2}\x1b[0m", planned.max_profit());
+                return Ok(());
+            }
+
+            check_order_guard(&client, &config.orders, yes.time_to_expiry_hours).await?;
+            check_tilt(&client, &config).await?;
+            let notional = (yes_quote + no_quote) * size as f64;
+            check_exposure(
+                &client,
+                &config.exposure,
+                yes.expiry_time.as_deref(),
+                notional,
+            )
+            .await?;
+
+            let tag = tag.unwrap_or_else(|| "spread".to_string());
+            let asset = yes.asset()?;
+            let mut legs: Vec<SpreadLeg> = Vec::new();
+            // Later legs are sized to what the earlier ones filled
+            let mut contracts = size;
+            for leg in planned.legs {
+                let request = TradeRequest {
+                    ticker: leg.ticker.clone(),
+                    asset: asset.clone(),
+                    direction: leg.direction.clone(),
+                    strike: leg.strike,
+                    contracts,
+                    order_type: "market".to_string(),
+                    limit_price: None,
+                    signal_id: None,
+                    client_order_id: None,
+                    tag: Some(tag.clone()),
+                    note: note.clone(),
+                };
+                let description =
+                    format!("spread: {} {} x{}", leg.ticker, leg.direction, contracts);
+                let result = client.execute_trade(request).await;
+                audit_result(AuditKind::Order, &description, &result);
+                let direction = leg.direction.clone();
+                let error = match result {
+                    Ok(filled)
+                        if filled.success && filled.filled > 0 && filled.trade_id.is_none() =>
+                    {
+                        format!(
+                            "{} filled but no position ID came back; check `basilisk positions`",
+                            filled.filled
+                        )
+                    }
+                    Ok(filled) if filled.success && filled.filled > 0 => {
+                        journal_trade(
+                            &leg.ticker,
+                            Origin::Manual,
+                            Some(&tag),
+                            note.as_deref(),
+                            &filled,
+                        );
+                        let leg = leg.filled(&filled);
+                        println!(
+                            "✅ {} leg: {} x{} @ ${:.2} (Trade ID: {})",
+                            leg.direction, leg.ticker, leg.contracts, leg.entry_price, leg.trade_id
+                        );
+                        // A short first leg just sizes the next one; a short later
+                        // leg can't be evened out, since positions only close whole
+                        let short = leg.contracts < contracts;
+                        if short {
+                            println!("⚠️  Only {} of {} filled", leg.contracts, contracts);
+                        }
+                        let uneven = short && !legs.is_empty();
+                        contracts = leg.contracts;
+                        legs.push(leg);
+                        if !uneven {
+                            continue;
+                        }
+                        "filled short of the other leg".to_string()
+                    }
+                    Ok(filled) if filled.success => "nothing filled".to_string(),
+                    Ok(filled) => filled.error.unwrap_or_else(|| "order rejected".to_string()),
+                    Err(e) => e.to_string(),
+                };
+                println!("❌ {} leg failed: {}", direction, error);
+
+                // Don't leave half a spread open
+                for opened in &legs {
+                    let result = client.close_position(opened.trade_id).await;
+                    audit_result(
+                        AuditKind::Close,
+                        &format!("spread: unwind position #{}", opened.trade_id),
+                        &result,
+                    );
+                    match result {
+                        Ok(closed) if closed.success => {
+                            println!(
+                                "↩️  Closed the {} leg (position #{})",
+                                opened.direction, opened.trade_id
+                            )
+                        }
+                        Ok(closed) => println!(
+                            "⚠️  Could not close position #{}: {}",
+                            opened.trade_id,
+                            closed.error.as_deref().unwrap_or("close rejected")
+                        ),
+                        Err(e) => {
+                            println!("⚠️  Could not close position #{}: {}", opened.trade_id, e)
+                        }
+                    }
+                }
+                bail!("Spread not opened");
+            }
+
+            let mut book = SpreadBook::load();
+            let spread = book.add(legs, Some(tag));
+            if let Err(e) = book.save() {
+                println!("⚠️  Could not save spread: {}", e);
+            }
+
+            println!();
+            println!("🔀 Spread #{} Opened: {}", spread.id, spread.describe());
+            println!("   Outlay:     ${:.2} (after fees)", spread.outlay());
+            println!("   Max Loss:   \x1b[31m${:.2}\x1b[0m", spread.max_loss());
+            println!("   Max Profit: \x1b[32m${:.2}\x1b[0m", spread.max_profit());
+        }
+
+        TradingCommands::Amend {
+            order_id,
+            price,
+            size,
+        } => {
+            amend::handle_amend_command(api_url, retry_orders, &order_id, price, size).await?;
+        }
+
+        TradingCommands::Orders { all } => {
+            let orders: Vec<Order> = client
+                .get_orders()
+                .await?
+                .into_iter()
+                .filter(|order| all || order.is_resting())
+                .collect();
+            if !table {
+                return print_records(&orders, output);
+            }
+
+            if orders.is_empty() {
+                println!("📂 No {}orders.", if all { "" } else { "resting " });
+                return Ok(());
+            }
+
+            println!(
+                "📋 {} ({}):",
+                if all { "Orders" } else { "Resting Orders" },
+                orders.len()
+            );
+            println!("{}", "─".repeat(100));
+            println!(
+                "{:<38} {:<26} {:<4} {:<8} {:<9} {:<9}",
+                "Order ID", "Ticker", "Dir", "Limit", "Filled", "Status"
+            );
+            println!("{}", "─".repeat(100));
+
+            for order in orders {
+                let filled = format!("{}/{}", order.filled(), order.contracts);
+                let status_color = if order.is_resting() {
+                    "\x1b[33m" // Yellow
+                } else {
+                    "\x1b[0m"
+                };
+                println!(
+                    "{:<38} {:<26} {:<4} {:<8} {:<9} {}{}\x1b[0m",
+                    order.order_id,
+                    order.ticker,
+                    order.direction,
+                    order.limit_display(),
+                    filled,
+                    status_color,
+                    order.status
+                );
+            }
+            println!("{}", "─".repeat(100));
+        }
+
+        TradingCommands::Cancel { order_id } => {
+            if table {
+                println!("Cancelling order {}...", order_id);
+                println!();
+            }
+
+            let result = client.cancel_order(&order_id).await;
+            if let Err(e) =
+                audit::record_cancel("cli", &format!("cancel order {}", order_id), &result)
+            {
+                eprintln!("⚠️  Could not write audit log: {}", e);
+            }
+            let order = result?;
+            if !table {
+                return print_record(&order, output);
+            }
+
+            println!("🗑  Order Cancelled!");
+            println!(
+                "   {} {} @ {}",
+                order.ticker,
+                order.direction,
+                order.limit_display()
+            );
+            println!(
+                "   Filled before the cancel: {} of {} contracts",
+                order.filled(),
+                order.contracts
+            );
+        }
+
+        TradingCommands::Pnl { period, tag } => {
+            let summary = match &tag {
+                Some(tag) => {
+                    let history = annotate(client.get_trade_history(TAGGED_HISTORY_SCAN).await?);
+                    tagged_summary(&history, &period, tag)?
+                }
+                None => client.get_pnl_summary(&period).await?,
+            };
+            if !table {
+                return print_record(&summary, output);
+            }
 
             let period_label = match period.as_str() {
                 "today" => "Today",
@@ -216,7 +2384,10 @@ pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Resu
                 "\x1b[31m"
             };
 
-            println!("💰 P&L Summary - {}", period_label);
+            match &tag {
+                Some(tag) => println!("💰 P&L Summary - {} (tag {})", period_label, tag),
+                None => println!("💰 P&L Summary - {}", period_label),
+            }
             println!("{}", "─".repeat(40));
             println!(
                 "   Net P&L:    {}${:+.2}\x1b[0m",
@@ -228,24 +2399,52 @@ pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Resu
             println!("   Wins:       {} ✅", summary.wins);
             println!("   Losses:     {} ❌", summary.losses);
             println!("   Win Rate:   {:.0}%", summary.win_rate * 100.0);
+            if period == "all" && tag.is_none() {
+                // Only all-time P&L lines up with funding recorded since the account opened
+                if let Ok(capital) = Capital::fetch(&client).await {
+                    if let Some(pct) = capital.return_pct() {
+                        println!(
+                            "   On Capital: {:+.1}% (net deposits ${:.2})",
+                            pct,
+                            capital.net_deposits.unwrap_or_default()
+                        );
+                    }
+                }
+            }
             println!("{}", "─".repeat(40));
         }
 
-        TradingCommands::History { limit } => {
-            let history = client.get_trade_history(limit).await?;
+        TradingCommands::History { limit, tag } => {
+            let history = match &tag {
+                Some(tag) => annotate(client.get_trade_history(TAGGED_HISTORY_SCAN).await?)
+                    .into_iter()
+                    .filter(|trade| has_tag(trade, tag))
+                    .take(limit.max(0) as usize)
+                    .collect(),
+                None => annotate(client.get_trade_history(limit).await?),
+            };
+            if !table {
+                return print_records(&history, output);
+            }
 
             if history.is_empty() {
-                println!("📂 No trade history.");
+                match &tag {
+                    Some(tag) => println!("📂 No trades tagged {}.", tag),
+                    None => println!("📂 No trade history."),
+                }
                 return Ok(());
             }
 
-            println!("📜 Trade History (last {}):", history.len());
-            println!("{}", "─".repeat(90));
+            match &tag {
+                Some(tag) => println!("📜 Trade History (last {}, tag {}):", history.len(), tag),
+                None => println!("📜 Trade History (last {}):", history.len()),
+            }
+            println!("{}", "─".repeat(100));
             println!(
-                "{:<6} {:<6} {:<4} {:<10} {:<6} {:<10} {:<10} {:<10} {:<8}",
+                "{:<6} {:<6} {:<4} {:<10} {:<6} {:<10} {:<10} {:<10} {:<8} Tag",
                 "ID", "Asset", "Dir", "Strike", "Qty", "Entry", "Exit", "P&L", "Status"
             );
-            println!("{}", "─".repeat(90));
+            println!("{}", "─".repeat(100));
 
             for trade in history {
                 let pnl_color = match trade.pnl {
@@ -260,7 +2459,7 @@ pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Resu
                     .unwrap_or_else(|| "N/A".to_string());
 
                 println!(
-                    "{:<6} {:<6} {:<4} ${:<9.0} {:<6} ${:<9.2} {:<10} {}{:<10}\x1b[0m {:<8}",
+                    "{:<6} {:<6} {:<4} ${:<9.0} {:<6} ${:<9.2} {:<10} {}{:<10}\x1b[0m {:<8} {}",
                     trade.id,
                     trade.asset,
                     trade.direction,
@@ -270,10 +2469,14 @@ pub async fn handle_trading_command(cmd: TradingCommands, api_url: &str) -> Resu
                     exit_price,
                     pnl_color,
                     trade.pnl_display(),
-                    trade.status
+                    trade.status,
+                    trade.tag.as_deref().unwrap_or("")
                 );
+                if let Some(note) = &trade.note {
+                    println!("       \x1b[2m└ {}\x1b[0m", note);
+                }
             }
-            println!("{}", "─".repeat(90));
+            println!("{}", "─".repeat(100));
         }
     }
 
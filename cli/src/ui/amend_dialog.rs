@@ -0,0 +1,198 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::api::models::Order;
+use crate::ui::dialog::Dialog;
+use crate::ui::theme::Theme;
+
+/// Most contracts the popup will size a replacement to
+const MAX_SIZE: i32 = 10_000;
+
+/// Price/size editor for a resting order ('i' on the Orders tab). Enter hands
+/// the new terms to a confirmation; nothing is sent from here.
+pub struct AmendDialog {
+    pub order: Order,
+    /// New limit in cents
+    pub limit: i32,
+    /// New number of contracts
+    pub size: i32,
+    /// 0 = price, 1 = size
+    selected: usize,
+}
+
+impl AmendDialog {
+    /// None when the order has no limit price to start from
+    pub fn new(order: &Order) -> Option<Self> {
+        Some(Self {
+            limit: order.limit_price?,
+            size: order.remaining.max(1),
+            order: order.clone(),
+            selected: 0,
+        })
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = 1;
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = 0;
+    }
+
+    /// Nudge the selected field by `delta` (cents or contracts)
+    pub fn adjust(&mut self, delta: i32) {
+        if self.selected == 0 {
+            self.limit = (self.limit + delta).clamp(1, 99);
+        } else {
+            self.size = (self.size + delta).clamp(1, MAX_SIZE);
+        }
+    }
+
+    /// Whether the terms differ from what's resting
+    pub fn changed(&self) -> bool {
+        Some(self.limit) != self.order.limit_price || self.size != self.order.remaining
+    }
+
+    /// The changed terms as `amend::amend` takes them: (price in dollars, size)
+    pub fn terms(&self) -> (Option<f64>, Option<i32>) {
+        let price = (Some(self.limit) != self.order.limit_price).then(|| self.limit as f64 / 100.0);
+        let size = (self.size != self.order.remaining).then_some(self.size);
+        (price, size)
+    }
+
+    pub fn render(&self, frame: &mut Frame, theme: &Theme) {
+        let area = frame.size();
+        let popup_width = 76.min(area.width);
+        let popup_height = 10.min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let order = &self.order;
+        let fields = [
+            (
+                "Limit",
+                format!("${:.2}", self.limit as f64 / 100.0),
+                order.limit_display(),
+            ),
+            (
+                "Contracts",
+                self.size.to_string(),
+                order.remaining.to_string(),
+            ),
+        ];
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(
+                    format!("    {:<14}", "Order"),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    format!("{} {}", order.ticker, order.direction),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+        ];
+        for (i, (label, value, was)) in fields.iter().enumerate() {
+            let selected = i == self.selected;
+            let style = if selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::raw(if selected { "  ▶ " } else { "    " }),
+                Span::styled(format!("{:<14}", label), Style::default().fg(theme.muted)),
+                Span::styled(format!("◀ {} ▶", value), style),
+                Span::styled(format!("  (now {})", was), Style::default().fg(theme.muted)),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    Replacement cost: ${:.2}",
+                self.limit as f64 / 100.0 * self.size as f64
+            ),
+            Style::default().fg(theme.muted),
+        )));
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(" AMEND ORDER ")
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(
+                " [↑/↓] field │ [←/→] ±1 │ [PgUp/PgDn] ±10 │ [Enter] review │ [ESC] cancel ",
+            );
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
+
+/// Last check before the cancel/replace goes out
+pub fn confirm_amend(dialog: &AmendDialog, theme: &Theme) -> Dialog {
+    let label = |text: &'static str| {
+        Span::styled(format!("  {:<14}", text), Style::default().fg(theme.muted))
+    };
+    let order = &dialog.order;
+
+    let body = vec![
+        Line::from(vec![
+            label("Order"),
+            Span::styled(
+                order.order_id.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![label("Contract"), Span::raw(order.ticker.clone())]),
+        Line::from(vec![
+            label("Now"),
+            Span::raw(format!(
+                "{} x{} @ {}",
+                order.direction,
+                order.remaining,
+                order.limit_display()
+            )),
+        ]),
+        Line::from(vec![
+            label("Becomes"),
+            Span::styled(
+                format!(
+                    "{} x{} @ ${:.2}",
+                    order.direction,
+                    dialog.size,
+                    dialog.limit as f64 / 100.0
+                ),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  The order is cancelled and placed again, losing its queue spot.",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+
+    Dialog::confirm("AMEND ORDER", body, "cancel & replace").width(72)
+}
@@ -0,0 +1,219 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::archive::{self, ArchiveEntry, ArchiveQuery};
+use crate::ui::theme::Theme;
+
+/// Snapshots listed under the selected contract
+const TIMELINE_ROWS: usize = 8;
+
+/// Archive browser popup: search past hours' contracts and see how they settled
+pub struct ArchiveBrowser {
+    pub query: String,
+    entries: Vec<ArchiveEntry>,
+    /// Indices into `entries` matching the query
+    matches: Vec<usize>,
+    selected: usize,
+}
+
+impl ArchiveBrowser {
+    pub fn new(entries: Vec<ArchiveEntry>) -> Self {
+        let mut browser = Self {
+            query: String::new(),
+            entries,
+            matches: Vec::new(),
+            selected: 0,
+        };
+        browser.refilter();
+        browser
+    }
+
+    fn refilter(&mut self) {
+        let query = ArchiveQuery::parse(&self.query);
+        self.matches = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| query.matches(entry))
+            .map(|(i, _)| i)
+            .collect();
+        self.selected = 0;
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + 1).min(self.matches.len() - 1);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    fn selected_entry(&self) -> Option<&ArchiveEntry> {
+        self.matches.get(self.selected).map(|i| &self.entries[*i])
+    }
+
+    pub fn render(&self, frame: &mut Frame, theme: &Theme) {
+        let area = frame.size();
+        let popup_width = 110.min(area.width);
+        let popup_height = 32.min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("  / ", Style::default().fg(theme.highlight)),
+                Span::styled(
+                    format!("{}_", self.query),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(
+                    "  {:<17} {:<26} {:<8} {:<20} {:>8}  {:<14} {}",
+                    "Expiry (UTC)", "Ticker", "Strike", "Signal", "Last EV", "Settled", "Call"
+                ),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )),
+        ];
+
+        // Borders, query, header and the timeline below take the rest
+        let page = (popup_height as usize)
+            .saturating_sub(7 + TIMELINE_ROWS)
+            .max(1);
+        let offset = self.selected.saturating_sub(page - 1);
+        for (row, i) in self.matches.iter().enumerate().skip(offset).take(page) {
+            let entry = &self.entries[*i];
+            let style = if row == self.selected {
+                Style::default().bg(theme.selection)
+            } else {
+                Style::default()
+            };
+            let signal = if entry.first_signal() == entry.last_signal() {
+                entry.last_signal().to_string()
+            } else {
+                format!("{} → {}", entry.first_signal(), entry.last_signal())
+            };
+            let verdict_color = match entry.signal_correct() {
+                Some(true) => theme.positive,
+                Some(false) => theme.negative,
+                None => theme.muted,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "  {:<17} {:<26} {:<8} {:<20} {:>8}  {:<14} ",
+                        entry.expiry_label(),
+                        entry.ticker,
+                        entry.strike_label(),
+                        signal,
+                        entry
+                            .last_ev()
+                            .map(|ev| format!("{:+.1}%", ev * 100.0))
+                            .unwrap_or_else(|| "--".to_string()),
+                        entry.result_label()
+                    ),
+                    style,
+                ),
+                Span::styled(entry.verdict_label(), style.fg(verdict_color)),
+            ]));
+        }
+        if self.matches.is_empty() {
+            lines.push(Line::from(Span::styled(
+                if self.entries.is_empty() {
+                    "  Nothing archived yet: contracts are recorded while the dashboard runs"
+                } else {
+                    "  No matches"
+                },
+                Style::default().fg(theme.muted),
+            )));
+        }
+
+        if let Some(entry) = self.selected_entry() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("  {} ({} snapshots)", entry.ticker, entry.snapshots.len()),
+                Style::default().fg(theme.accent),
+            )));
+            // The last few snapshots before expiry are the interesting ones
+            let skip = entry.snapshots.len().saturating_sub(TIMELINE_ROWS - 2);
+            for s in entry.snapshots.iter().skip(skip) {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "    {}  BTC {:<8} {:<9} EV {:+6.1}%  yes {}  no {}",
+                        s.recorded_at.format("%H:%M:%S"),
+                        s.btc_price
+                            .map(|p| format!("${:.0}", p))
+                            .unwrap_or_else(|| "--".to_string()),
+                        s.signal_type,
+                        s.expected_value * 100.0,
+                        s.yes_price
+                            .map(|p| format!("${:.2}", p))
+                            .unwrap_or_else(|| "--".to_string()),
+                        s.no_price
+                            .map(|p| format!("${:.2}", p))
+                            .unwrap_or_else(|| "--".to_string())
+                    ),
+                    Style::default().fg(theme.muted),
+                )));
+            }
+        }
+
+        let matched: Vec<&ArchiveEntry> = self.matches.iter().map(|i| &self.entries[*i]).collect();
+        let (hits, scored) = archive::hit_rate(&matched);
+        let mut title = format!(
+            " ARCHIVE ({} of {}) ",
+            self.matches.len(),
+            self.entries.len()
+        );
+        if scored > 0 {
+            title = format!(
+                " ARCHIVE ({} of {}, signals {}/{} hit) ",
+                self.matches.len(),
+                self.entries.len(),
+                hits,
+                scored
+            );
+        }
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(
+                " YYYY-MM-DD, 14h, strike, yes/no/hit/miss/unsettled │ [↑/↓] move │ [ESC] close ",
+            );
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
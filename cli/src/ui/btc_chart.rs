@@ -0,0 +1,156 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
+    Frame,
+};
+
+use crate::api::Contract;
+use crate::ui::theme::Theme;
+
+/// Minutes of BTC history plotted
+pub const WINDOW_MINUTES: i64 = 30;
+
+/// A strike drawn across the whole window
+struct StrikeLine {
+    color: Color,
+    label: Option<String>,
+    points: [(f64, f64); 2],
+}
+
+/// BTC price over the last half hour with the active strikes drawn across it
+pub struct BtcChart;
+
+impl BtcChart {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `points` are (seconds before now, price), oldest first. The y range
+    /// always reaches the nearest strike on each side of spot; other strikes
+    /// are drawn when they fall inside it.
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        points: &[(f64, f64)],
+        contracts: &[Contract],
+        selected: Option<i32>,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" BTC {}m │ strikes ", WINDOW_MINUTES));
+
+        let Some(&(_, spot)) = points.last().filter(|_| points.len() >= 2) else {
+            frame.render_widget(
+                Paragraph::new("Waiting for BTC prices...")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(theme.faint))
+                    .block(block),
+                area,
+            );
+            return;
+        };
+
+        let strikes: Vec<(f64, &Contract)> = contracts
+            .iter()
+            .filter(|c| c.is_active)
+            .filter_map(|c| c.strike_price.map(|s| (s, c)))
+            .collect();
+        let below = strikes
+            .iter()
+            .map(|(s, _)| *s)
+            .filter(|s| *s <= spot)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let above = strikes
+            .iter()
+            .map(|(s, _)| *s)
+            .filter(|s| *s > spot)
+            .fold(f64::INFINITY, f64::min);
+
+        let mut low = points.iter().map(|(_, p)| *p).fold(f64::INFINITY, f64::min);
+        let mut high = points
+            .iter()
+            .map(|(_, p)| *p)
+            .fold(f64::NEG_INFINITY, f64::max);
+        if below.is_finite() {
+            low = low.min(below);
+        }
+        if above.is_finite() {
+            high = high.max(above);
+        }
+        let pad = ((high - low) * 0.05).max(5.0);
+        let (low, high) = (low - pad, high + pad);
+
+        let start = -(WINDOW_MINUTES as f64 * 60.0);
+        let strike_lines: Vec<StrikeLine> = strikes
+            .iter()
+            .filter(|(s, _)| (low..=high).contains(s))
+            .map(|(strike, contract)| {
+                let highlighted = Some(contract.id) == selected;
+                let color = match contract.signal_type.as_str() {
+                    _ if highlighted => theme.highlight,
+                    "BUY YES" => theme.positive,
+                    "BUY NO" => theme.negative,
+                    _ => theme.faint,
+                };
+                StrikeLine {
+                    color,
+                    // Only the highlighted strike gets a legend entry
+                    label: highlighted.then(|| contract.strike_display()),
+                    points: [(start, *strike), (0.0, *strike)],
+                }
+            })
+            .collect();
+
+        let mut datasets: Vec<Dataset> = strike_lines
+            .iter()
+            .map(|line| {
+                let dataset = Dataset::default()
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(line.color))
+                    .data(&line.points);
+                match &line.label {
+                    Some(label) => dataset.name(label.clone()),
+                    None => dataset,
+                }
+            })
+            .collect();
+        datasets.push(
+            Dataset::default()
+                .name(format!("${:.0}", spot))
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(theme.accent))
+                .data(points),
+        );
+
+        let chart = Chart::new(datasets)
+            .block(block)
+            .x_axis(
+                Axis::default()
+                    .style(Style::default().fg(theme.faint))
+                    .bounds([start, 0.0])
+                    .labels(vec![
+                        Span::raw(format!("-{}m", WINDOW_MINUTES)),
+                        Span::raw(format!("-{}m", WINDOW_MINUTES / 2)),
+                        Span::raw("now"),
+                    ]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(theme.faint))
+                    .bounds([low, high])
+                    .labels(vec![
+                        Span::raw(format!("${:.0}", low)),
+                        Span::raw(format!("${:.0}", (low + high) / 2.0)),
+                        Span::raw(format!("${:.0}", high)),
+                    ]),
+            );
+        frame.render_widget(chart, area);
+    }
+}
@@ -0,0 +1,70 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::api::models::Position;
+use crate::ui::dialog::Dialog;
+use crate::ui::positions::{pnl_color, Mark};
+use crate::ui::theme::Theme;
+
+/// Confirmation before closing a position from the Positions tab, with the
+/// estimated exit (from the live quote when there is one)
+pub fn confirm_close(position: &Position, mark: &Mark, theme: &Theme) -> Dialog {
+    let label = |text: &'static str| {
+        Span::styled(format!("  {:<14}", text), Style::default().fg(theme.muted))
+    };
+    let source = if mark.live {
+        "live quote"
+    } else {
+        "last fetch"
+    };
+
+    let body = vec![
+        Line::from(vec![
+            label("Position"),
+            Span::styled(
+                format!("#{} {}", position.trade_id, position.ticker),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            label("Side"),
+            Span::raw(format!(
+                "{} x{} @ ${:.2}",
+                position.direction, position.contracts, position.entry_price
+            )),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            label("Est. exit"),
+            Span::styled(
+                mark.price
+                    .map(|p| format!("${:.2}", p))
+                    .unwrap_or_else(|| "N/A".to_string()),
+                Style::default().fg(theme.highlight),
+            ),
+            Span::styled(format!("  ({})", source), Style::default().fg(theme.muted)),
+        ]),
+        Line::from(vec![
+            label("Est. P&L"),
+            Span::styled(
+                mark.pnl
+                    .map(|p| format!("${:+.2}", p))
+                    .unwrap_or_else(|| "N/A".to_string()),
+                Style::default()
+                    .fg(pnl_color(mark.pnl, theme))
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Sends a market close; the fill may differ from the estimate.",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+
+    Dialog::confirm("CLOSE POSITION", body, "close position")
+        .destructive()
+        .width(66)
+}
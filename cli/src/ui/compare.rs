@@ -0,0 +1,254 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Sparkline},
+    Frame,
+};
+
+use crate::compare::{Comparison, Profile, BINS};
+use crate::ui::theme::Theme;
+
+/// Popup with two marked contracts side by side
+pub struct CompareView;
+
+impl CompareView {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, comparison: &Comparison, theme: &Theme) {
+        // Centered popup (90% width, 80% height)
+        let area = frame.size();
+        let popup_width = (area.width * 90) / 100;
+        let popup_height = (area.height * 80) / 100;
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(" COMPARE (Esc to close) ")
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(inner);
+
+        let (left, right) = (&comparison.left, &comparison.right);
+        self.render_side(frame, columns[0], left, right, comparison.range, theme);
+        self.render_side(frame, columns[1], right, left, comparison.range, theme);
+    }
+
+    fn render_side(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        profile: &Profile,
+        other: &Profile,
+        range: (f64, f64),
+        theme: &Theme,
+    ) {
+        let contract = &profile.contract;
+        let block = Block::default().borders(Borders::ALL).title(format!(
+            " {} · {} ",
+            contract.strike_display(),
+            contract.expiry_display()
+        ));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(10), // Metrics
+                Constraint::Length(1),  // Distribution title
+                Constraint::Min(3),     // Histogram
+                Constraint::Length(1),  // Strike marker
+                Constraint::Length(1),  // Range labels
+            ])
+            .split(inner);
+
+        let label =
+            |text: &str| Span::styled(format!("{:<14}", text), Style::default().fg(theme.muted));
+        // Green where this side beats the other on the metric
+        let versus = |value: Option<f64>, rival: Option<f64>, text: String| {
+            let color = match (value, rival) {
+                (Some(value), Some(rival)) if value > rival => theme.positive,
+                _ => theme.text,
+            };
+            Span::styled(text, Style::default().fg(color))
+        };
+        let percent = |value: Option<f64>| {
+            value
+                .map(|v| format!("{:.1}%", v * 100.0))
+                .unwrap_or_else(|| "--".to_string())
+        };
+        let dollars = |value: Option<f64>| {
+            value
+                .map(|v| format!("${:.2}", v))
+                .unwrap_or_else(|| "--".to_string())
+        };
+
+        let edge = |p: &Profile| {
+            p.model
+                .zip(p.implied)
+                .map(|(model, implied)| model - implied)
+        };
+        let signal_color = match contract.signal_type.as_str() {
+            "BUY YES" => theme.positive,
+            "BUY NO" => theme.negative,
+            _ => theme.muted,
+        };
+
+        let mut lines = vec![
+            Line::from(vec![
+                label("Signal"),
+                Span::styled(
+                    contract.signal_type.clone(),
+                    Style::default()
+                        .fg(signal_color)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  (side {})", profile.side),
+                    Style::default().fg(theme.muted),
+                ),
+            ]),
+            Line::from(vec![
+                label("Quote Y/N"),
+                Span::raw(format!(
+                    "{} / {}",
+                    dollars(contract.yes_price),
+                    dollars(contract.no_price)
+                )),
+            ]),
+            Line::from(vec![
+                label("Time left"),
+                Span::raw(contract.time_left_display()),
+            ]),
+            Line::from(vec![label("Implied"), Span::raw(percent(profile.implied))]),
+            Line::from(vec![
+                label("Model"),
+                Span::raw(percent(profile.model)),
+                Span::styled("  edge ", Style::default().fg(theme.muted)),
+                versus(
+                    edge(profile),
+                    edge(other),
+                    edge(profile)
+                        .map(|e| format!("{:+.1}pp", e * 100.0))
+                        .unwrap_or_else(|| "--".to_string()),
+                ),
+            ]),
+            Line::from(vec![
+                label("MC win rate"),
+                versus(
+                    profile.mc_win_rate,
+                    other.mc_win_rate,
+                    percent(profile.mc_win_rate),
+                ),
+            ]),
+            Line::from(vec![
+                label("Payoff"),
+                versus(
+                    profile.win_payoff(),
+                    other.win_payoff(),
+                    format!(
+                        "{} win / {} loss",
+                        dollars(profile.win_payoff()),
+                        dollars(profile.price.map(|p| -p))
+                    ),
+                ),
+            ]),
+            Line::from(vec![
+                label("Reward/risk"),
+                versus(
+                    profile.reward_risk(),
+                    other.reward_risk(),
+                    profile
+                        .reward_risk()
+                        .map(|r| format!("{:.2}x", r))
+                        .unwrap_or_else(|| "--".to_string()),
+                ),
+            ]),
+            Line::from(vec![
+                label("EV"),
+                versus(
+                    Some(contract.expected_value),
+                    Some(other.contract.expected_value),
+                    contract.ev_display(),
+                ),
+            ]),
+        ];
+        if let Some([p5, p50, p95]) = profile.terminal {
+            lines.push(Line::from(vec![
+                label("BTC at expiry"),
+                Span::raw(format!("${:.0} · ${:.0} · ${:.0}", p5, p50, p95)),
+                Span::styled("  (p5 · p50 · p95)", Style::default().fg(theme.faint)),
+            ]));
+        }
+        frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "BTC distribution at expiry (Monte Carlo)",
+                Style::default().fg(theme.muted),
+            )),
+            chunks[1],
+        );
+
+        if profile.terminal.is_none() {
+            frame.render_widget(
+                Paragraph::new("Waiting for BTC price and volatility...")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(theme.faint)),
+                chunks[2],
+            );
+            return;
+        }
+
+        // One histogram bin per column, so the strike caret lines up below
+        let width = (BINS as u16).min(chunks[2].width);
+        let plot = Rect { width, ..chunks[2] };
+        let sparkline = Sparkline::default()
+            .data(&profile.histogram)
+            .style(Style::default().fg(theme.accent));
+        frame.render_widget(sparkline, plot);
+
+        if let Some(bin) = profile.strike_bin {
+            let caret = Line::from(vec![
+                Span::raw(" ".repeat(bin)),
+                Span::styled(
+                    format!("▲ strike {}", contract.strike_display()),
+                    Style::default().fg(theme.highlight),
+                ),
+            ]);
+            frame.render_widget(Paragraph::new(caret), chunks[3]);
+        }
+
+        let (low, high) = range;
+        let low_label = format!("${:.0}", low);
+        let high_label = format!("${:.0}", high);
+        let gap = (width as usize).saturating_sub(low_label.len() + high_label.len());
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                format!("{}{}{}", low_label, " ".repeat(gap), high_label),
+                Style::default().fg(theme.faint),
+            )),
+            chunks[4],
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        Axis, Block, Borders, Chart, Clear, Dataset, Gauge, GraphType, Paragraph, Sparkline,
+    },
+    Frame,
+};
+
+use crate::api::Contract;
+use crate::liquidity::Impact;
+use crate::ui::theme::Theme;
+
+/// Full-screen drill-down for the highlighted signal (Enter on the Signals tab)
+pub struct DetailView;
+
+impl DetailView {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `edges` is the contract's model − implied history, `btc` this hour's
+    /// BTC prices; both oldest first. `impact` is the last orderbook sample's
+    /// estimate for the default size, when there is one.
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        contract: &Contract,
+        edges: &[f64],
+        btc: &[f64],
+        impact: Option<&Impact>,
+        theme: &Theme,
+    ) {
+        let area = frame.size();
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(format!(
+                " {} · {} · {} ",
+                contract.ticker,
+                contract.strike_display(),
+                contract.expiry_display()
+            ))
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(" [↑/↓] next signal │ [t] trade │ [Enter/ESC] back ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(10), // Quote and countdown
+                Constraint::Length(7),  // Edge history
+                Constraint::Min(8),     // BTC vs strike
+            ])
+            .split(inner);
+
+        let top = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(rows[0]);
+
+        self.render_quote(frame, top[0], contract, impact, theme);
+        self.render_countdown(frame, top[1], contract, theme);
+        self.render_edges(frame, rows[1], edges, theme);
+        self.render_btc(frame, rows[2], contract, btc, theme);
+    }
+
+    fn render_quote(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        contract: &Contract,
+        impact: Option<&Impact>,
+        theme: &Theme,
+    ) {
+        let label =
+            |text: &str| Span::styled(format!("{:<14}", text), Style::default().fg(theme.muted));
+        let percent = |value: Option<f64>| {
+            value
+                .map(|v| format!("{:.1}%", v * 100.0))
+                .unwrap_or_else(|| "--".to_string())
+        };
+        let dollars = |value: Option<f64>| {
+            value
+                .map(|v| format!("${:.2}", v))
+                .unwrap_or_else(|| "--".to_string())
+        };
+
+        let signal_color = match contract.signal_type.as_str() {
+            "BUY YES" => theme.positive,
+            "BUY NO" => theme.negative,
+            _ => theme.muted,
+        };
+        let edge = contract
+            .model_probability
+            .zip(contract.implied_probability)
+            .map(|(model, implied)| model - implied);
+        let edge_color = match edge {
+            Some(e) if e > 0.0 => theme.positive,
+            Some(e) if e < 0.0 => theme.negative,
+            _ => theme.text,
+        };
+
+        let lines = vec![
+            Line::from(vec![
+                label("Signal"),
+                Span::styled(
+                    contract.signal_type.clone(),
+                    Style::default()
+                        .fg(signal_color)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  EV {}", contract.ev_display()),
+                    Style::default().fg(theme.highlight),
+                ),
+            ]),
+            Line::from(vec![label("Strike"), Span::raw(contract.strike_display())]),
+            Line::from(vec![
+                label("YES"),
+                Span::styled(
+                    dollars(contract.yes_price),
+                    Style::default().fg(theme.positive),
+                ),
+                Span::styled("    NO ", Style::default().fg(theme.muted)),
+                Span::styled(
+                    dollars(contract.no_price),
+                    Style::default().fg(theme.negative),
+                ),
+            ]),
+            Line::from(vec![
+                label("Implied"),
+                Span::raw(percent(contract.implied_probability)),
+            ]),
+            Line::from(vec![
+                label("Model"),
+                Span::raw(percent(contract.model_probability)),
+            ]),
+            Line::from(vec![
+                label("Edge"),
+                Span::styled(
+                    edge.map(|e| format!("{:+.1}pp", e * 100.0))
+                        .unwrap_or_else(|| "--".to_string()),
+                    Style::default().fg(edge_color).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                label("Confidence"),
+                Span::raw(format!("{:.2}", contract.confidence_score)),
+            ]),
+            Line::from(vec![
+                label("Impact"),
+                match impact {
+                    Some(impact) if impact.filled < impact.size => Span::styled(
+                        format!("only {} of {} on the book", impact.filled, impact.size),
+                        Style::default().fg(theme.caution),
+                    ),
+                    Some(impact) => Span::raw(format!(
+                        "x{} avg {:.1}¢, {}¢ → {}¢ ({:+.1}%)",
+                        impact.size,
+                        impact.average,
+                        impact.best,
+                        impact.worst,
+                        impact.move_pct()
+                    )),
+                    None => Span::raw("--"),
+                },
+            ]),
+        ];
+
+        let block = Block::default().borders(Borders::ALL).title(" Quote ");
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn render_countdown(&self, frame: &mut Frame, area: Rect, contract: &Contract, theme: &Theme) {
+        let block = Block::default().borders(Borders::ALL).title(" Time Decay ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2), // Countdown
+                Constraint::Length(1), // Hour elapsed
+                Constraint::Min(1),    // Distance
+            ])
+            .split(inner);
+
+        let seconds = contract.seconds_to_expiry();
+        let (countdown, color) = match seconds {
+            Some(s) if s <= 0 => ("EXPIRED".to_string(), theme.faint),
+            Some(s) => (
+                format!("{:02}:{:02} to expiry", s / 60, s % 60),
+                if s < 300 {
+                    theme.negative
+                } else if s < 900 {
+                    theme.highlight
+                } else {
+                    theme.positive
+                },
+            ),
+            None => ("--:-- to expiry".to_string(), theme.muted),
+        };
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                countdown,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
+            chunks[0],
+        );
+
+        // Share of the contract's final hour already gone
+        let elapsed = seconds
+            .map(|s| 1.0 - s.clamp(0, 3600) as f64 / 3600.0)
+            .unwrap_or(0.0);
+        frame.render_widget(
+            Gauge::default()
+                .gauge_style(Style::default().fg(color).bg(theme.gauge_track))
+                .ratio(elapsed)
+                .label(format!("{:.0}% of the hour elapsed", elapsed * 100.0)),
+            chunks[1],
+        );
+
+        let distance = if contract.strike_price.is_some() && contract.current_btc_price.is_some() {
+            let dollars = contract.distance_dollars();
+            let side = if dollars > 0.0 { "above" } else { "below" };
+            format!(
+                "BTC {} strike by ${:.0} ({:+.2}%)",
+                side,
+                dollars.abs(),
+                contract.distance_percent()
+            )
+        } else {
+            "BTC distance to strike unknown".to_string()
+        };
+        frame.render_widget(
+            Paragraph::new(vec![Line::from(""), Line::from(distance)]).alignment(Alignment::Center),
+            chunks[2],
+        );
+    }
+
+    fn render_edges(&self, frame: &mut Frame, area: Rect, edges: &[f64], theme: &Theme) {
+        let title = match (
+            edges.iter().copied().reduce(f64::min),
+            edges.iter().copied().reduce(f64::max),
+            edges.last(),
+        ) {
+            (Some(low), Some(high), Some(now)) => format!(
+                " Edge History  now {:+.1}pp · low {:+.1}pp · high {:+.1}pp ",
+                now * 100.0,
+                low * 100.0,
+                high * 100.0
+            ),
+            _ => " Edge History ".to_string(),
+        };
+        let block = Block::default().borders(Borders::ALL).title(title);
+
+        if edges.len() < 2 {
+            frame.render_widget(
+                Paragraph::new("Collecting quotes...")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(theme.faint))
+                    .block(block),
+                area,
+            );
+            return;
+        }
+
+        // Sparklines can't go negative: draw relative to the lowest edge seen
+        let low = edges.iter().copied().fold(f64::INFINITY, f64::min);
+        let data: Vec<u64> = edges
+            .iter()
+            .map(|edge| ((edge - low) * 1000.0).round() as u64 + 1)
+            .collect();
+        let start = data
+            .len()
+            .saturating_sub(area.width.saturating_sub(2) as usize);
+        frame.render_widget(
+            Sparkline::default()
+                .block(block)
+                .data(&data[start..])
+                .style(Style::default().fg(theme.highlight)),
+            area,
+        );
+    }
+
+    fn render_btc(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        contract: &Contract,
+        btc: &[f64],
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" BTC This Hour vs Strike ");
+
+        let Some(strike) = contract.strike_price.filter(|_| btc.len() >= 2) else {
+            frame.render_widget(
+                Paragraph::new("Waiting for BTC prices...")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(theme.faint))
+                    .block(block),
+                area,
+            );
+            return;
+        };
+
+        let path: Vec<(f64, f64)> = btc
+            .iter()
+            .enumerate()
+            .map(|(i, price)| (i as f64, *price))
+            .collect();
+        let last = (path.len() - 1) as f64;
+        let strike_line = [(0.0, strike), (last, strike)];
+
+        let low = btc.iter().copied().fold(strike, f64::min);
+        let high = btc.iter().copied().fold(strike, f64::max);
+        let pad = ((high - low) * 0.1).max(10.0);
+        let (low, high) = (low - pad, high + pad);
+
+        let above = btc.last().is_some_and(|price| *price > strike);
+        let datasets = vec![
+            Dataset::default()
+                .name("BTC")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(if above {
+                    theme.positive
+                } else {
+                    theme.negative
+                }))
+                .data(&path),
+            Dataset::default()
+                .name(format!("strike {}", contract.strike_display()))
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(theme.highlight))
+                .data(&strike_line),
+        ];
+
+        let chart = Chart::new(datasets)
+            .block(block)
+            .x_axis(
+                Axis::default()
+                    .style(Style::default().fg(theme.faint))
+                    .bounds([0.0, last.max(1.0)])
+                    .labels(vec![Span::raw("top of hour"), Span::raw("now")]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(theme.faint))
+                    .bounds([low, high])
+                    .labels(vec![
+                        Span::raw(format!("${:.0}", low)),
+                        Span::raw(format!("${:.0}", high)),
+                    ]),
+            );
+        frame.render_widget(chart, area);
+    }
+}
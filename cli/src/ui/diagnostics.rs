@@ -0,0 +1,206 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::connection::ConnectionState;
+use crate::consistency::Discrepancy;
+use crate::feed_latency::{FeedLatency, STALE_AFTER_MS};
+use crate::governor::GovernorStatus;
+use crate::ui::theme::Theme;
+
+/// Snapshot of app health shown in the diagnostics popup
+pub struct Diagnostics<'a> {
+    pub connection: ConnectionState,
+    pub trade_latency_ms: Option<u64>,
+    pub feed_latency: &'a FeedLatency,
+    pub divergence: Option<&'a Discrepancy>,
+    pub auto_status: Option<&'a GovernorStatus>,
+    /// The dashboard's own governor over price-rule orders
+    pub rule_status: &'a GovernorStatus,
+}
+
+/// Popup with connection health and the rule and auto-trader governor counters
+pub struct DiagnosticsView;
+
+impl DiagnosticsView {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, diagnostics: &Diagnostics, theme: &Theme) {
+        // Centered popup (60% width, 60% height)
+        let area = frame.size();
+        let popup_width = (area.width * 60) / 100;
+        let popup_height = (area.height * 60) / 100;
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let heading = |text: &'static str| {
+            Line::from(Span::styled(
+                text,
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        };
+        let field = |label: &'static str, value: String, color: Color| {
+            Line::from(vec![
+                Span::styled(format!("  {:<24}", label), Style::default().fg(theme.muted)),
+                Span::styled(value, Style::default().fg(color)),
+            ])
+        };
+
+        let connection = match diagnostics.connection {
+            ConnectionState::Connected => ("Live (SSE)".to_string(), theme.positive),
+            ConnectionState::DegradedPolling => {
+                ("Polling (stream down)".to_string(), theme.highlight)
+            }
+            ConnectionState::Reconnecting { attempt } => (
+                format!("Reconnecting (attempt {})", attempt),
+                theme.highlight,
+            ),
+            ConnectionState::Offline { since } => (
+                format!("Offline for {}s", since.elapsed().as_secs()),
+                theme.negative,
+            ),
+        };
+
+        let mut lines = vec![
+            heading("DATA"),
+            field("Connection", connection.0, connection.1),
+            field(
+                "Trade endpoint RTT",
+                diagnostics
+                    .trade_latency_ms
+                    .map(|ms| format!("{}ms", ms))
+                    .unwrap_or_else(|| "--".to_string()),
+                theme.text,
+            ),
+            match diagnostics.feed_latency.average_ms() {
+                Some(average) => field(
+                    "SSE data age (avg/last)",
+                    format!(
+                        "{}ms / {}ms{}",
+                        average,
+                        diagnostics.feed_latency.last_ms().unwrap_or(average),
+                        if diagnostics.feed_latency.is_stale() {
+                            format!("  STALE (> {}s behind)", STALE_AFTER_MS / 1000)
+                        } else {
+                            String::new()
+                        }
+                    ),
+                    if diagnostics.feed_latency.is_stale() {
+                        theme.negative
+                    } else if average > STALE_AFTER_MS / 3 {
+                        theme.highlight
+                    } else {
+                        theme.positive
+                    },
+                ),
+                None => field("SSE data age (avg/last)", "--".to_string(), theme.muted),
+            },
+            match diagnostics.divergence {
+                Some(d) => field(
+                    "Stream vs poll",
+                    format!(
+                        "{} issue(s), {} {}s stale",
+                        d.issues.len(),
+                        d.stale_source.label(),
+                        d.lag_secs
+                    ),
+                    theme.highlight,
+                ),
+                None => field("Stream vs poll", "consistent".to_string(), theme.positive),
+            },
+            Line::from(""),
+            heading("RULE GOVERNOR"),
+        ];
+        lines.extend(governor_lines(diagnostics.rule_status, theme));
+        lines.push(Line::from(""));
+        lines.push(heading("AUTO-TRADER GOVERNOR"));
+
+        match diagnostics.auto_status {
+            Some(status) => {
+                let updated = status
+                    .updated_at
+                    .map(|t| format!("{}s ago", (chrono::Utc::now() - t).num_seconds()))
+                    .unwrap_or_else(|| "never".to_string());
+
+                lines.push(field("Last cycle", updated, theme.text));
+                lines.extend(governor_lines(status, theme));
+            }
+            None => lines.push(field(
+                "Status",
+                "not running (start with `basilisk auto`)".to_string(),
+                theme.muted,
+            )),
+        }
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(" DIAGNOSTICS ")
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(" [d/ESC] close ");
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
+
+/// Kill switch, pause and order counters for one governor
+fn governor_lines(status: &GovernorStatus, theme: &Theme) -> Vec<Line<'static>> {
+    let field = |label: &'static str, value: String, color: Color| {
+        Line::from(vec![
+            Span::styled(format!("  {:<24}", label), Style::default().fg(theme.muted)),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    };
+
+    let mut lines = vec![match &status.killed {
+        Some(reason) => field(
+            "Kill switch",
+            format!("ENGAGED - {}", reason),
+            theme.negative,
+        ),
+        None => field("Kill switch", "armed".to_string(), theme.positive),
+    }];
+    if let Some(reason) = &status.paused {
+        lines.push(field("Paused", reason.clone(), theme.highlight));
+    }
+    lines.push(field(
+        "Orders last minute",
+        status.orders_last_minute.to_string(),
+        theme.text,
+    ));
+    lines.push(field("In flight", status.in_flight.to_string(), theme.text));
+    lines.push(field(
+        "Submitted / filled",
+        format!("{} / {}", status.submitted, status.filled),
+        theme.text,
+    ));
+    lines.push(field(
+        "Rejected (in a row)",
+        format!("{} ({})", status.rejected, status.consecutive_rejections),
+        if status.consecutive_rejections > 0 {
+            theme.caution
+        } else {
+            theme.text
+        },
+    ));
+    lines.push(field("Throttled", status.throttled.to_string(), theme.text));
+    lines
+}
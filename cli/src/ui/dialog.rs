@@ -0,0 +1,200 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::ui::theme::Theme;
+
+/// What a key press did to a dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogResponse {
+    /// Still waiting for an answer
+    Pending,
+    Confirmed,
+    Cancelled,
+    /// A key after the outcome was shown
+    Dismissed,
+}
+
+enum DialogKind {
+    /// Enter or y confirms, Esc or n cancels
+    Confirm,
+    /// One line of text; Enter confirms once it matches `expected` (if set)
+    Input {
+        prompt: String,
+        value: String,
+        expected: Option<String>,
+    },
+}
+
+/// Modal popup asking to confirm an action, optionally by typing a value.
+/// After confirming, the caller can show the outcome in the same popup with
+/// `set_result`; the next key then dismisses it.
+pub struct Dialog {
+    title: String,
+    body: Vec<Line<'static>>,
+    kind: DialogKind,
+    /// What Enter does, for the hint line ("close position")
+    action: String,
+    /// Red border and title instead of the highlight color
+    destructive: bool,
+    width: u16,
+    result: Option<Result<String, String>>,
+}
+
+impl Dialog {
+    /// Yes/No question
+    pub fn confirm(title: &str, body: Vec<Line<'static>>, action: &str) -> Self {
+        Self {
+            title: format!(" {} ", title),
+            body,
+            kind: DialogKind::Confirm,
+            action: action.to_string(),
+            destructive: false,
+            width: 60,
+            result: None,
+        }
+    }
+
+    /// Text prompt; with `expected`, Enter only confirms once the typed text matches it
+    pub fn input(
+        title: &str,
+        body: Vec<Line<'static>>,
+        action: &str,
+        prompt: &str,
+        expected: Option<String>,
+    ) -> Self {
+        Self {
+            kind: DialogKind::Input {
+                prompt: prompt.to_string(),
+                value: String::new(),
+                expected,
+            },
+            ..Self::confirm(title, body, action)
+        }
+    }
+
+    /// Red border and title, for actions that can't be undone
+    pub fn destructive(mut self) -> Self {
+        self.destructive = true;
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.result.is_some()
+    }
+
+    pub fn set_result(&mut self, result: Result<String, String>) {
+        self.result = Some(result);
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> DialogResponse {
+        if self.is_done() {
+            return DialogResponse::Dismissed;
+        }
+        match &mut self.kind {
+            DialogKind::Confirm => match key {
+                KeyCode::Enter | KeyCode::Char('y' | 'Y') => DialogResponse::Confirmed,
+                KeyCode::Esc | KeyCode::Char('n' | 'N') => DialogResponse::Cancelled,
+                _ => DialogResponse::Pending,
+            },
+            DialogKind::Input {
+                value, expected, ..
+            } => match key {
+                KeyCode::Esc => DialogResponse::Cancelled,
+                KeyCode::Enter if expected.as_ref().is_none_or(|e| e == value.trim()) => {
+                    DialogResponse::Confirmed
+                }
+                KeyCode::Backspace => {
+                    value.pop();
+                    DialogResponse::Pending
+                }
+                KeyCode::Char(c) => {
+                    value.push(c);
+                    DialogResponse::Pending
+                }
+                _ => DialogResponse::Pending,
+            },
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, theme: &Theme) {
+        let color = if self.destructive {
+            theme.negative
+        } else {
+            theme.highlight
+        };
+        let mut lines = self.body.clone();
+        lines.push(Line::from(""));
+
+        if let DialogKind::Input {
+            prompt,
+            value,
+            expected,
+        } = &self.kind
+        {
+            let matches = expected.as_ref().is_none_or(|e| e == value.trim());
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", prompt), Style::default().fg(theme.muted)),
+                Span::styled(
+                    format!("{}_", value),
+                    Style::default()
+                        .fg(if matches { theme.positive } else { theme.text })
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            lines.push(Line::from(""));
+        }
+
+        match &self.result {
+            Some(Ok(message)) => lines.push(Line::from(Span::styled(
+                format!("  ✅ {}", message),
+                Style::default().fg(theme.positive),
+            ))),
+            Some(Err(message)) => lines.push(Line::from(Span::styled(
+                format!("  ❌ {}", message),
+                Style::default().fg(theme.negative),
+            ))),
+            None => {}
+        }
+
+        let hint = match (&self.kind, self.is_done()) {
+            (_, true) => " [any key] close ".to_string(),
+            (DialogKind::Confirm, false) => format!(" [Enter/y] {} │ [ESC/n] cancel ", self.action),
+            (DialogKind::Input { .. }, false) => {
+                format!(" [Enter] {} │ [ESC] cancel ", self.action)
+            }
+        };
+
+        // Sized to the lines, plus borders
+        let area = frame.size();
+        let popup_width = self.width.min(area.width);
+        let popup_height = (lines.len() as u16 + 2).min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+            .title(self.title.clone())
+            .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+            .title_bottom(hint);
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
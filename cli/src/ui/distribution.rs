@@ -0,0 +1,143 @@
+use chrono::Local;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::distribution::Distribution;
+use crate::ui::theme::Theme;
+
+/// Model and market differing by this much in a range is highlighted (5pp)
+const HIGHLIGHT_EDGE: f64 = 0.05;
+
+/// Model vs market probability of BTC settling in each range between strikes
+pub struct DistributionView;
+
+impl DistributionView {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        distribution: Option<&Distribution>,
+        spot: f64,
+        theme: &Theme,
+    ) {
+        let Some(distribution) = distribution else {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" DISTRIBUTION ");
+            frame.render_widget(
+                Paragraph::new("Waiting for strikes with both model and market probabilities...")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(theme.faint))
+                    .block(block),
+                area,
+            );
+            return;
+        };
+
+        // Label, two bars with their percentages and the edge share the width
+        let bar_width = (area.width as usize).saturating_sub(58) / 2;
+        let largest = distribution
+            .buckets
+            .iter()
+            .map(|b| b.model.max(b.market))
+            .fold(0.0, f64::max)
+            .max(0.01);
+        let bar = |mass: f64| {
+            let filled = ((mass / largest) * bar_width as f64).round() as usize;
+            format!("{:<width$}", "█".repeat(filled), width = bar_width)
+        };
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!(
+                    "  {:<16} {:<w$} {:>6}   {:<w$} {:>6}   {:>8}",
+                    "BTC at expiry",
+                    "Model",
+                    "",
+                    "Market",
+                    "",
+                    "Edge",
+                    w = bar_width
+                ),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+
+        // Highest range at the top, like a price axis
+        for bucket in distribution.buckets.iter().rev() {
+            let edge = bucket.edge();
+            let highlighted = edge.abs() >= HIGHLIGHT_EDGE;
+            let edge_style = match (highlighted, edge > 0.0) {
+                (true, true) => Style::default()
+                    .fg(theme.positive)
+                    .add_modifier(Modifier::BOLD),
+                (true, false) => Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD),
+                (false, _) => Style::default().fg(theme.muted),
+            };
+            let label_style = if highlighted {
+                edge_style
+            } else {
+                Style::default()
+            };
+            let spot_marker = if spot > 0.0 && bucket.contains(spot) {
+                "  ◀ BTC"
+            } else {
+                ""
+            };
+
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<16} ", bucket.label()), label_style),
+                Span::styled(bar(bucket.model), Style::default().fg(theme.accent)),
+                Span::raw(format!(" {:>5.1}%   ", bucket.model * 100.0)),
+                Span::styled(bar(bucket.market), Style::default().fg(theme.special)),
+                Span::raw(format!(" {:>5.1}%   ", bucket.market * 100.0)),
+                Span::styled(format!("{:>+6.1}pp", edge * 100.0), edge_style),
+                Span::styled(spot_marker, Style::default().fg(theme.highlight)),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  Model and market put {:.1}% of the probability in different ranges. Green: the model \
+                 sees more chance of BTC settling there than the market; red: less.",
+                distribution.disagreement() * 100.0
+            ),
+            Style::default().fg(theme.muted),
+        )));
+
+        let title = format!(
+            " DISTRIBUTION │ BTC at {} expiry: model vs market ",
+            distribution.expiry.with_timezone(&Local).format("%H:%M")
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(ratatui::widgets::Wrap { trim: false }),
+            area,
+        );
+    }
+}
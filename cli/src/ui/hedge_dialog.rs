@@ -0,0 +1,161 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::hedge::{HedgePlan, QUOTE_MOVE};
+use crate::ui::theme::Theme;
+
+/// Confirmation popup for the hedge-to-neutral plan
+pub struct HedgeDialog {
+    pub plan: HedgePlan,
+    /// Outcome per executed leg, in plan order
+    results: Vec<Result<String, String>>,
+    done: bool,
+}
+
+impl HedgeDialog {
+    pub fn new(plan: HedgePlan) -> Self {
+        Self {
+            plan,
+            results: Vec::new(),
+            done: false,
+        }
+    }
+
+    pub fn can_submit(&self) -> bool {
+        !self.done && self.plan.can_execute()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    pub fn push_result(&mut self, result: Result<String, String>) {
+        self.results.push(result);
+    }
+
+    pub fn finish(&mut self) {
+        self.done = true;
+    }
+
+    pub fn render(&self, frame: &mut Frame, theme: &Theme) {
+        let area = frame.size();
+        let popup_width = 72.min(area.width);
+        let popup_height =
+            (12 + self.plan.legs.len() as u16 + self.results.len() as u16).min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let label = |text: &'static str| {
+            Span::styled(format!("  {:<14}", text), Style::default().fg(theme.muted))
+        };
+        let delta_text = |delta: f64| format!("${:+.2}", delta * QUOTE_MOVE);
+
+        let mut lines = vec![
+            Line::from(vec![
+                label("Expiry"),
+                Span::styled(
+                    self.plan.expiry_label(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("  ({} positions)", self.plan.positions)),
+            ]),
+            Line::from(vec![
+                label("Net delta"),
+                Span::styled(
+                    delta_text(self.plan.net_delta),
+                    Style::default().fg(theme.highlight),
+                ),
+                Span::styled(
+                    format!(" per +${:.0} BTC", QUOTE_MOVE),
+                    Style::default().fg(theme.muted),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        if self.plan.legs.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  Already as close to neutral as whole contracts allow.",
+                Style::default().fg(theme.positive),
+            )));
+        }
+        for (i, leg) in self.plan.legs.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}. ", i + 1),
+                    Style::default().fg(theme.highlight),
+                ),
+                Span::styled(format!("{:<44}", leg.describe()), Style::default()),
+                Span::styled(
+                    format!("Δ {}", delta_text(leg.delta_change())),
+                    Style::default().fg(theme.accent),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            label("After hedge"),
+            Span::styled(
+                delta_text(self.plan.residual),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+
+        if let Some(reason) = &self.plan.blocked {
+            lines.push(Line::from(Span::styled(
+                format!("  ⚠ {}", reason),
+                Style::default().fg(theme.negative),
+            )));
+        }
+
+        for result in &self.results {
+            lines.push(match result {
+                Ok(message) => Line::from(Span::styled(
+                    format!("  ✅ {}", message),
+                    Style::default().fg(theme.positive),
+                )),
+                Err(message) => Line::from(Span::styled(
+                    format!("  ❌ {}", message),
+                    Style::default().fg(theme.negative),
+                )),
+            });
+        }
+
+        let hint = if self.done {
+            " [any key] close "
+        } else if self.plan.blocked.is_some() {
+            " Blocked by exposure cap │ [ESC] cancel "
+        } else if self.plan.legs.is_empty() {
+            " [ESC] close "
+        } else {
+            " [Enter] execute │ [ESC] cancel "
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.special))
+            .title(" HEDGE TO NEUTRAL ")
+            .title_style(
+                Style::default()
+                    .fg(theme.special)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(hint);
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
@@ -0,0 +1,128 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+
+/// Scroll position of the help overlay, clamped to its wrapped content.
+/// Sizes come from the last render, like the log pane's page.
+pub struct HelpScroll {
+    offset: u16,
+    page: u16,          // Rows that fit inside the popup
+    max: u16,           // Offset that puts the last row at the bottom
+    sections: Vec<u16>, // Row each section heading starts at
+}
+
+impl HelpScroll {
+    pub fn new() -> Self {
+        Self {
+            offset: 0,
+            page: 1,
+            max: u16::MAX,
+            sections: Vec::new(),
+        }
+    }
+
+    pub fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    pub fn page(&self) -> u16 {
+        self.page
+    }
+
+    pub fn scroll_up(&mut self, rows: u16) {
+        self.offset = self.offset.saturating_sub(rows);
+    }
+
+    pub fn scroll_down(&mut self, rows: u16) {
+        self.offset = self.offset.saturating_add(rows).min(self.max);
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset = self.max;
+    }
+
+    /// Bring section `index` (0-based, in the order headings were given to
+    /// `layout`) to the top
+    pub fn jump_to(&mut self, index: usize) {
+        if let Some(row) = self.sections.get(index) {
+            self.offset = (*row).min(self.max);
+        }
+    }
+
+    /// Measure `lines` wrapped to `inner`, clamp the offset and find the rows
+    /// of the lines starting with each of `headings`
+    pub fn layout(&mut self, lines: &[Line], inner: Rect, headings: &[&str]) {
+        let width = inner.width.max(1) as usize;
+        let mut rows = 0usize;
+        self.sections = vec![0; headings.len()];
+        for line in lines {
+            let text: String = line
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect();
+            if let Some(index) = headings
+                .iter()
+                .position(|heading| text.starts_with(heading))
+            {
+                self.sections[index] = rows.min(u16::MAX as usize) as u16;
+            }
+            rows += wrapped_rows(&text, width);
+        }
+
+        self.page = inner.height.max(1);
+        self.max = rows
+            .saturating_sub(self.page as usize)
+            .min(u16::MAX as usize) as u16;
+        self.offset = self.offset.min(self.max);
+    }
+
+    /// Scrollbar down the popup's right border
+    pub fn render_scrollbar(&self, frame: &mut Frame, area: Rect) {
+        if self.max == 0 {
+            return;
+        }
+        // Thumb reaches the bottom once the last page is in view
+        let mut state = ScrollbarState::new(self.max as usize + 1)
+            .viewport_content_length(self.page as usize)
+            .position(self.offset as usize);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None);
+        let track = Rect {
+            y: area.y + 1,
+            height: area.height.saturating_sub(2),
+            ..area
+        };
+        frame.render_stateful_widget(scrollbar, track, &mut state);
+    }
+}
+
+/// Rows a line takes when word-wrapped to `width` columns
+fn wrapped_rows(text: &str, width: usize) -> usize {
+    let mut rows = 1;
+    let mut used = 0;
+    for word in text.split_inclusive(' ') {
+        let visible = Span::raw(word.trim_end()).width();
+        if used > 0 && used + visible > width {
+            rows += 1;
+            used = 0;
+        }
+        if visible > width {
+            // Broken across rows
+            rows += (visible - 1) / width;
+            used = visible - (visible - 1) / width * width;
+        } else {
+            used += visible;
+        }
+        used += Span::raw(word).width() - visible;
+    }
+    rows
+}
@@ -0,0 +1,391 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::api::models::TradeHistory;
+use crate::api::parse_timestamp;
+use crate::journal::JournalEntry;
+use crate::ui::positions::pnl_color;
+use crate::ui::theme::Theme;
+
+/// History tab: recent trades, newest first as the backend returns them.
+/// `/` filters by ticker, asset, direction or status; `Enter` opens a trade.
+pub struct HistoryView {
+    table_state: TableState,
+    visible_ids: Vec<i32>, // Trade IDs in display order, from the last render
+    selected_id: Option<i32>, // Keeps the selection on the same trade across refreshes
+    filter: String,
+    editing_filter: bool,
+    page_rows: usize, // Table rows that fit, from the last render
+    detail: Option<Option<JournalEntry>>, // Open popup, with the trade's journal entry if any
+    area: Rect,       // Where the table was last drawn, for mouse clicks
+}
+
+impl HistoryView {
+    pub fn new() -> Self {
+        Self {
+            table_state: TableState::default(),
+            visible_ids: Vec::new(),
+            selected_id: None,
+            filter: String::new(),
+            editing_filter: false,
+            page_rows: 10,
+            detail: None,
+            area: Rect::default(),
+        }
+    }
+
+    pub fn page_down(&mut self) {
+        self.step(self.page_rows as isize);
+    }
+
+    pub fn page_up(&mut self) {
+        self.step(-(self.page_rows as isize));
+    }
+
+    pub fn select_first(&mut self) {
+        self.step(isize::MIN / 2);
+    }
+
+    pub fn select_last(&mut self) {
+        self.step(isize::MAX / 2);
+    }
+
+    pub fn is_editing_filter(&self) -> bool {
+        self.editing_filter
+    }
+
+    /// Start typing a filter (`/`)
+    pub fn edit_filter(&mut self) {
+        self.editing_filter = true;
+    }
+
+    /// Stop typing; `clear` drops the filter as well
+    pub fn finish_filter(&mut self, clear: bool) {
+        self.editing_filter = false;
+        if clear {
+            self.filter.clear();
+        }
+    }
+
+    pub fn push_filter_char(&mut self, c: char) {
+        self.filter.push(c);
+    }
+
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+    }
+
+    pub fn selected_id(&self) -> Option<i32> {
+        self.selected_id
+    }
+
+    pub fn is_detail_open(&self) -> bool {
+        self.detail.is_some()
+    }
+
+    /// Show the selected trade in a popup, with its journal entry if Basilisk placed it
+    pub fn open_detail(&mut self, journal: Option<JournalEntry>) {
+        if self.selected_id.is_some() {
+            self.detail = Some(journal);
+        }
+    }
+
+    pub fn close_detail(&mut self) {
+        self.detail = None;
+    }
+
+    /// Every space-separated filter term must appear in the ticker, asset,
+    /// direction or status
+    fn matches(&self, trade: &TradeHistory) -> bool {
+        let haystack = format!(
+            "{} {} {} {}",
+            trade.ticker, trade.asset, trade.direction, trade.status
+        )
+        .to_lowercase();
+        self.filter
+            .to_lowercase()
+            .split_whitespace()
+            .all(|term| haystack.contains(term))
+    }
+
+    pub fn select_next(&mut self) {
+        self.step(1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.step(-1);
+    }
+
+    /// Select the row under a mouse click, if any
+    pub fn click(&mut self, row: u16) {
+        let offset = self.table_state.offset();
+        match super::table_row_at(self.area, offset, row) {
+            Some(index) if index < self.visible_ids.len() => {
+                self.table_state.select(Some(index));
+                self.selected_id = Some(self.visible_ids[index]);
+            }
+            _ => {}
+        }
+    }
+
+    fn step(&mut self, delta: isize) {
+        if self.visible_ids.is_empty() {
+            return;
+        }
+        let last = self.visible_ids.len() as isize - 1;
+        let index = match self.table_state.selected() {
+            Some(i) => (i as isize + delta).clamp(0, last) as usize,
+            // Nothing selected yet: count from just above the first row
+            None => (delta - 1).clamp(0, last) as usize,
+        };
+        self.table_state.select(Some(index));
+        self.selected_id = Some(self.visible_ids[index]);
+    }
+
+    /// Highlight a trade on the next render
+    pub fn select_id(&mut self, id: i32) {
+        self.selected_id = Some(id);
+    }
+
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        history: Option<&[TradeHistory]>,
+        theme: &Theme,
+    ) {
+        self.area = area;
+        let trades: Vec<&TradeHistory> = history
+            .unwrap_or_default()
+            .iter()
+            .filter(|t| self.matches(t))
+            .collect();
+        self.visible_ids = trades.iter().map(|t| t.id).collect();
+        // Follow the selected trade as new ones push it down the list
+        let index = self
+            .selected_id
+            .and_then(|id| self.visible_ids.iter().position(|v| *v == id))
+            .or_else(|| {
+                let last = self.visible_ids.len().checked_sub(1)?;
+                self.table_state.selected().map(|i| i.min(last))
+            });
+        self.table_state.select(index);
+        self.selected_id = index.map(|i| self.visible_ids[i]);
+        // Borders and the header with its margin
+        self.page_rows = (area.height as usize).saturating_sub(4).max(1);
+
+        let mut title = " TRADE HISTORY ".to_string();
+        if let Some(i) = index {
+            title.push_str(&format!("│ {} of {} ", i + 1, trades.len()));
+        }
+        if self.editing_filter {
+            title.push_str(&format!("│ filter: {}█ ", self.filter));
+        } else if !self.filter.is_empty() {
+            title.push_str(&format!("│ filter: {} ", self.filter));
+        }
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_bottom(" [/] filter │ [PgUp/PgDn] page │ [Enter] details ");
+
+        let Some(history) = history else {
+            let paragraph = Paragraph::new("Loading trade history...")
+                .block(block)
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        };
+        if trades.is_empty() {
+            let message = if history.is_empty() {
+                "No trade history."
+            } else {
+                "No trades match the filter."
+            };
+            let paragraph = Paragraph::new(message)
+                .block(block)
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let header = Row::new(vec![
+            "ID", "Ticker", "Dir", "Strike", "Qty", "Entry", "Exit", "P&L", "Status",
+        ])
+        .style(
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
+
+        let rows: Vec<Row> = trades
+            .iter()
+            .map(|trade| {
+                Row::new(vec![
+                    Cell::from(trade.id.to_string()),
+                    Cell::from(trade.ticker.clone()),
+                    Cell::from(trade.direction.clone()),
+                    Cell::from(format!("${:.0}", trade.strike)),
+                    Cell::from(trade.contracts.to_string()),
+                    Cell::from(format!("${:.2}", trade.entry_price)),
+                    Cell::from(
+                        trade
+                            .exit_price
+                            .map(|p| format!("${:.2}", p))
+                            .unwrap_or_else(|| "N/A".to_string()),
+                    ),
+                    Cell::from(trade.pnl_display())
+                        .style(Style::default().fg(pnl_color(trade.pnl, theme))),
+                    Cell::from(trade.status.clone()),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(6),  // ID
+                Constraint::Length(24), // Ticker
+                Constraint::Length(5),  // Dir
+                Constraint::Length(10), // Strike
+                Constraint::Length(6),  // Qty
+                Constraint::Length(9),  // Entry
+                Constraint::Length(9),  // Exit
+                Constraint::Length(10), // P&L
+                Constraint::Length(10), // Status
+            ],
+        )
+        .header(header)
+        .highlight_style(Style::default().bg(theme.selection))
+        .highlight_symbol("▶ ")
+        .block(block);
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+
+        if let Some(journal) = &self.detail {
+            let trade = trades.iter().find(|t| Some(t.id) == self.selected_id);
+            match trade {
+                Some(trade) => render_detail(frame, trade, journal.as_ref(), theme),
+                None => self.detail = None,
+            }
+        }
+    }
+}
+
+fn render_detail(
+    frame: &mut Frame,
+    trade: &TradeHistory,
+    journal: Option<&JournalEntry>,
+    theme: &Theme,
+) {
+    let area = frame.size();
+    let popup_width = 60.min(area.width);
+    let popup_height = 19.min(area.height);
+    let popup_area = Rect {
+        x: (area.width - popup_width) / 2,
+        y: (area.height - popup_height) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let row = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("  {:<12}", label), Style::default().fg(theme.muted)),
+            Span::raw(value),
+        ])
+    };
+    let money = |value: Option<f64>| {
+        value
+            .map(|v| format!("${:.2}", v))
+            .unwrap_or_else(|| "N/A".to_string())
+    };
+
+    let opened = parse_timestamp(&trade.opened_at);
+    let closed = trade.closed_at.as_deref().and_then(parse_timestamp);
+    let held = match (opened, closed) {
+        (Some(opened), Some(closed)) => {
+            let minutes = (closed - opened).num_minutes();
+            format!("{}h{:02}m", minutes / 60, minutes % 60)
+        }
+        _ => "open".to_string(),
+    };
+    let cost = trade.entry_price * trade.contracts as f64;
+    let pnl_pct = trade
+        .pnl
+        .filter(|_| cost > 0.0)
+        .map(|pnl| format!(" ({:+.1}%)", pnl / cost * 100.0))
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(""),
+        row("Ticker", trade.ticker.clone()),
+        row("Asset", trade.asset.clone()),
+        row(
+            "Side",
+            format!(
+                "{} {} @ ${:.0}",
+                trade.direction, trade.contracts, trade.strike
+            ),
+        ),
+        row("Entry", format!("${:.2}", trade.entry_price)),
+        row("Exit", money(trade.exit_price)),
+        row("Fees", money(trade.fees)),
+        Line::from(vec![
+            Span::styled(format!("  {:<12}", "P&L"), Style::default().fg(theme.muted)),
+            Span::styled(
+                format!("{}{}", trade.pnl_display(), pnl_pct),
+                Style::default().fg(pnl_color(trade.pnl, theme)),
+            ),
+        ]),
+        row("Status", trade.status.clone()),
+        row("Opened", trade.opened_at.clone()),
+        row(
+            "Closed",
+            trade.closed_at.clone().unwrap_or_else(|| "N/A".to_string()),
+        ),
+        row("Held", held),
+    ];
+    match journal {
+        Some(entry) => {
+            lines.push(row("Origin", entry.origin.label()));
+            lines.push(row(
+                "Tag",
+                entry.tag.clone().unwrap_or_else(|| "-".to_string()),
+            ));
+            if let Some(note) = &entry.note {
+                lines.push(row("Note", note.clone()));
+            }
+            if let Some(checklist) = &entry.checklist {
+                let yes = checklist.answers.iter().filter(|a| a.yes).count();
+                lines.push(row(
+                    "Checklist",
+                    format!("{}/{} yes", yes, checklist.answers.len()),
+                ));
+                if let Some(reason) = &checklist.reason {
+                    lines.push(row("Reason", reason.clone()));
+                }
+            }
+        }
+        None => lines.push(row("Origin", "not in the local journal".to_string())),
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .title(format!(" TRADE #{} ", trade.id))
+        .title_style(
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(" [↑/↓] prev/next │ [Enter/ESC] close ");
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
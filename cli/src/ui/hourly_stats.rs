@@ -1,49 +1,99 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph, Sparkline},
     Frame,
 };
 
 use crate::api::HourlyStats;
+use crate::ui::theme::Theme;
 
-pub struct HourlyStatsView;
+pub struct HourlyStatsView {
+    hour_path: Vec<f64>, // BTC prices since the top of the hour (from the stream buffer)
+}
 
 impl HourlyStatsView {
     pub fn new() -> Self {
-        Self
+        Self {
+            hour_path: Vec::new(),
+        }
+    }
+
+    /// BTC path for the current hour, oldest first
+    pub fn set_hour_path(&mut self, prices: Vec<f64>) {
+        self.hour_path = prices;
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect, stats: &HourlyStats) {
+    pub fn render(&self, frame: &mut Frame, area: Rect, stats: &HourlyStats, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3),   // Title
-                Constraint::Length(8),   // Summary stats
-                Constraint::Min(0),      // Distribution chart
+                Constraint::Length(3), // Title
+                Constraint::Length(8), // Summary stats
+                Constraint::Length(6), // This hour so far
+                Constraint::Min(0),    // Distribution chart
             ])
             .split(area);
 
         // Title
-        self.render_title(frame, chunks[0]);
+        self.render_title(frame, chunks[0], theme);
 
         // Summary statistics
-        self.render_summary(frame, chunks[1], stats);
+        self.render_summary(frame, chunks[1], stats, theme);
+
+        // Current hour's path
+        self.render_hour_path(frame, chunks[2], theme);
 
         // Distribution chart
-        self.render_distribution(frame, chunks[2], stats);
+        self.render_distribution(frame, chunks[3], stats, theme);
     }
 
-    fn render_title(&self, frame: &mut Frame, area: Rect) {
-        let title_text = Line::from(vec![
-            Span::styled(
-                "HOURLY PRICE MOVEMENT STATISTICS",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]);
+    fn render_hour_path(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let (Some(first), Some(last)) = (self.hour_path.first(), self.hour_path.last()) else {
+            let paragraph = Paragraph::new("Waiting for stream data...")
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" BTC This Hour "),
+                )
+                .alignment(ratatui::layout::Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        };
+
+        // Plot relative to the hour's low so small moves are visible
+        let low = self.hour_path.iter().copied().fold(f64::INFINITY, f64::min);
+        let data: Vec<u64> = self
+            .hour_path
+            .iter()
+            .map(|p| (p - low) as u64 + 1)
+            .collect();
+        let change = (last - first) / first * 100.0;
+        let color = if change >= 0.0 {
+            theme.positive
+        } else {
+            theme.negative
+        };
+
+        let sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                " BTC This Hour: ${:.0} → ${:.0} ({:+.2}%) ",
+                first, last, change
+            )))
+            .data(&data)
+            .style(Style::default().fg(color));
+
+        frame.render_widget(sparkline, area);
+    }
+
+    fn render_title(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let title_text = Line::from(vec![Span::styled(
+            "HOURLY PRICE MOVEMENT STATISTICS",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]);
 
         let paragraph = Paragraph::new(title_text)
             .block(Block::default().borders(Borders::ALL))
@@ -52,7 +102,7 @@ impl HourlyStatsView {
         frame.render_widget(paragraph, area);
     }
 
-    fn render_summary(&self, frame: &mut Frame, area: Rect, stats: &HourlyStats) {
+    fn render_summary(&self, frame: &mut Frame, area: Rect, stats: &HourlyStats, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
@@ -66,31 +116,31 @@ impl HourlyStatsView {
         // Basic stats (left)
         let basic_stats = vec![
             Line::from(vec![
-                Span::styled("Mean: ", Style::default().fg(Color::Gray)),
+                Span::styled("Mean: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:+.2}%", stats.mean_return * 100.0),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.highlight),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Median: ", Style::default().fg(Color::Gray)),
+                Span::styled("Median: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:+.2}%", stats.median_return * 100.0),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.highlight),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Std Dev: ", Style::default().fg(Color::Gray)),
+                Span::styled("Std Dev: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:.2}%", stats.std_return * 100.0),
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.accent),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Samples: ", Style::default().fg(Color::Gray)),
+                Span::styled("Samples: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{}", stats.total_samples),
-                    Style::default().fg(Color::Green),
+                    Style::default().fg(theme.positive),
                 ),
             ]),
         ];
@@ -102,84 +152,83 @@ impl HourlyStatsView {
         // Lower percentiles (middle-left)
         let lower_percentiles = vec![
             Line::from(vec![
-                Span::styled("5th: ", Style::default().fg(Color::Gray)),
+                Span::styled("5th: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:+.2}%", stats.percentile_5 * 100.0),
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(theme.negative),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("25th: ", Style::default().fg(Color::Gray)),
+                Span::styled("25th: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:+.2}%", stats.percentile_25 * 100.0),
-                    Style::default().fg(Color::LightRed),
+                    Style::default().fg(theme.caution),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("50th: ", Style::default().fg(Color::Gray)),
+                Span::styled("50th: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:+.2}%", stats.percentile_50 * 100.0),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.highlight),
                 ),
             ]),
         ];
 
-        let lower_para = Paragraph::new(lower_percentiles)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Downside Risk "),
-            );
+        let lower_para = Paragraph::new(lower_percentiles).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Downside Risk "),
+        );
         frame.render_widget(lower_para, chunks[1]);
 
         // Upper percentiles (middle-right)
         let upper_percentiles = vec![
             Line::from(vec![
-                Span::styled("75th: ", Style::default().fg(Color::Gray)),
+                Span::styled("75th: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:+.2}%", stats.percentile_75 * 100.0),
-                    Style::default().fg(Color::LightGreen),
+                    Style::default().fg(theme.strong_positive),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("95th: ", Style::default().fg(Color::Gray)),
+                Span::styled("95th: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:+.2}%", stats.percentile_95 * 100.0),
-                    Style::default().fg(Color::Green),
+                    Style::default().fg(theme.positive),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Max: ", Style::default().fg(Color::Gray)),
+                Span::styled("Max: ", Style::default().fg(theme.muted)),
                 Span::styled(
                     format!("{:+.2}%", stats.max_hourly_move * 100.0),
-                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(theme.accent),
                 ),
             ]),
         ];
 
-        let upper_para = Paragraph::new(upper_percentiles)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Upside Potential "),
-            );
+        let upper_para = Paragraph::new(upper_percentiles).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Upside Potential "),
+        );
         frame.render_widget(upper_para, chunks[2]);
 
         // Interpretation (right)
         let vol_level = if stats.std_return * 100.0 < 0.5 {
-            ("LOW", Color::Green)
+            ("LOW", theme.positive)
         } else if stats.std_return * 100.0 < 1.0 {
-            ("MODERATE", Color::Yellow)
+            ("MODERATE", theme.highlight)
         } else if stats.std_return * 100.0 < 2.0 {
-            ("HIGH", Color::LightRed)
+            ("HIGH", theme.caution)
         } else {
-            ("EXTREME", Color::Red)
+            ("EXTREME", theme.negative)
         };
 
         let interpretation = vec![
-            Line::from(vec![
-                Span::styled("Volatility:", Style::default().fg(Color::Gray)),
-            ]),
+            Line::from(vec![Span::styled(
+                "Volatility:",
+                Style::default().fg(theme.muted),
+            )]),
             Line::from(vec![Span::styled(
                 vol_level.0,
                 Style::default()
@@ -189,7 +238,7 @@ impl HourlyStatsView {
             Line::from(""),
             Line::from(vec![Span::styled(
                 format!("±{:.2}%/hr", stats.std_return * 100.0),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.accent),
             )]),
         ];
 
@@ -199,7 +248,13 @@ impl HourlyStatsView {
         frame.render_widget(interp_para, chunks[3]);
     }
 
-    fn render_distribution(&self, frame: &mut Frame, area: Rect, stats: &HourlyStats) {
+    fn render_distribution(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        stats: &HourlyStats,
+        theme: &Theme,
+    ) {
         // Create a visual distribution using the percentiles
         let percentile_data = vec![
             ("5%", (stats.percentile_5 * 100.0).abs() as u64),
@@ -216,13 +271,13 @@ impl HourlyStatsView {
             .enumerate()
             .map(|(i, (label, value))| {
                 let color = match i {
-                    0 => Color::Red,
-                    1 => Color::LightRed,
-                    2 => Color::Yellow,
-                    3 => Color::LightGreen,
-                    4 => Color::Green,
-                    5 => Color::Cyan,
-                    _ => Color::White,
+                    0 => theme.negative,
+                    1 => theme.caution,
+                    2 => theme.highlight,
+                    3 => theme.strong_positive,
+                    4 => theme.positive,
+                    5 => theme.accent,
+                    _ => theme.text,
                 };
                 Bar::default()
                     .value(*value)
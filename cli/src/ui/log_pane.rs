@@ -0,0 +1,99 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::event_log::{EventLog, LogKind};
+use crate::ui::theme::Theme;
+
+/// Scrollback pane under the main view; follows new entries until scrolled up
+pub struct LogPane {
+    scroll: usize, // Entries back from the newest (0 = following)
+    page: usize,   // Entries that fit, from the last render
+}
+
+impl LogPane {
+    pub fn new() -> Self {
+        Self { scroll: 0, page: 1 }
+    }
+
+    /// Rows for the pane on a terminal `height` rows tall
+    pub fn height(height: u16) -> u16 {
+        (height / 3).clamp(6, 14)
+    }
+
+    pub fn scroll_up(&mut self, entries: usize) {
+        self.scroll = self.scroll.saturating_add(entries);
+    }
+
+    pub fn scroll_down(&mut self, entries: usize) {
+        self.scroll = self.scroll.saturating_sub(entries);
+    }
+
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn scroll_to_oldest(&mut self) {
+        self.scroll = usize::MAX;
+    }
+
+    pub fn follow(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, log: &EventLog, theme: &Theme) {
+        let entries = log.entries();
+        self.page = (area.height as usize).saturating_sub(2).max(1);
+        self.scroll = self.scroll.min(entries.len().saturating_sub(self.page));
+        let end = entries.len() - self.scroll;
+        let start = end.saturating_sub(self.page);
+
+        let lines: Vec<Line> = entries
+            .range(start..end)
+            .map(|entry| {
+                let color = match entry.kind {
+                    LogKind::Connection => theme.accent,
+                    LogKind::Stream => theme.muted,
+                    LogKind::Trade => theme.positive,
+                    LogKind::Error => theme.negative,
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!(" {} ", entry.at.format("%H:%M:%S")),
+                        Style::default().fg(theme.faint),
+                    ),
+                    Span::styled(
+                        format!("{:<6}", entry.kind.label()),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(entry.message.clone(), Style::default().fg(color)),
+                ])
+            })
+            .collect();
+
+        let title = if self.scroll > 0 {
+            format!(" LOG ({}, {} newer below) ", entries.len(), self.scroll)
+        } else {
+            format!(" LOG ({}) ", entries.len())
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.faint))
+            .title(title)
+            .title_bottom(" [↑/↓ PgUp/PgDn] scroll │ [End] follow │ [l/ESC] close ");
+
+        let paragraph = if lines.is_empty() {
+            Paragraph::new(Line::from(Span::styled(
+                " Nothing logged yet",
+                Style::default().fg(theme.muted),
+            )))
+        } else {
+            Paragraph::new(lines)
+        };
+        frame.render_widget(paragraph.block(block), area);
+    }
+}
@@ -1,7 +1,68 @@
-pub mod signals;
+pub mod amend_dialog;
+pub mod archive;
+pub mod btc_chart;
+pub mod close_dialog;
+pub mod compare;
+pub mod detail;
+pub mod diagnostics;
+pub mod dialog;
+pub mod distribution;
+pub mod hedge_dialog;
+pub mod help_scroll;
+pub mod history;
 pub mod hourly_stats;
+pub mod log_pane;
+pub mod orders;
+pub mod override_dialog;
+pub mod plan_dialog;
+pub mod pnl;
+pub mod portfolio;
+pub mod positions;
+pub mod rules;
+pub mod search;
+pub mod settlement_watch;
+pub mod signals;
+pub mod stress;
+pub mod theme;
+pub mod trade_dialog;
+pub mod vol_chart;
 pub mod vol_skew;
 
-pub use signals::SignalsView;
+pub use amend_dialog::AmendDialog;
+pub use archive::ArchiveBrowser;
+pub use btc_chart::BtcChart;
+pub use compare::CompareView;
+pub use detail::DetailView;
+pub use diagnostics::DiagnosticsView;
+pub use dialog::{Dialog, DialogResponse};
+pub use distribution::DistributionView;
+pub use hedge_dialog::HedgeDialog;
+pub use help_scroll::HelpScroll;
+pub use history::HistoryView;
 pub use hourly_stats::HourlyStatsView;
+pub use log_pane::LogPane;
+pub use orders::OrdersView;
+pub use override_dialog::OverrideDialog;
+pub use plan_dialog::PlanDialog;
+pub use pnl::PnlView;
+pub use portfolio::PortfolioView;
+pub use positions::PositionsView;
+pub use rules::RulesView;
+pub use search::SearchOverlay;
+pub use settlement_watch::SettlementWatch;
+pub use signals::SignalsView;
+pub use stress::StressView;
+pub use theme::Theme;
+pub use trade_dialog::TradeDialog;
+pub use vol_chart::VolChart;
 pub use vol_skew::VolSkewView;
+
+use ratatui::layout::Rect;
+
+/// Index of the row under a click at `row` in a bordered table whose header
+/// has a one-line bottom margin; `offset` is the first row on screen
+pub fn table_row_at(area: Rect, offset: usize, row: u16) -> Option<usize> {
+    let first = area.y + 3;
+    let last = area.y + area.height.saturating_sub(1);
+    (row >= first && row < last).then(|| offset + (row - first) as usize)
+}
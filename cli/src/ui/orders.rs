@@ -0,0 +1,264 @@
+use chrono::Utc;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::api::models::{parse_timestamp, Contract, Order};
+use crate::ui::dialog::Dialog;
+use crate::ui::theme::Theme;
+
+/// Orders tab: limit orders still resting on the book, next to the live
+/// quote for their side
+pub struct OrdersView {
+    table_state: TableState,
+    visible_ids: Vec<String>, // Order IDs in display order, from the last render
+    selected_id: Option<String>, // Keeps the selection on the same order across refreshes
+    area: Rect,               // Where the table was last drawn, for mouse clicks
+}
+
+impl OrdersView {
+    pub fn new() -> Self {
+        Self {
+            table_state: TableState::default(),
+            visible_ids: Vec::new(),
+            selected_id: None,
+            area: Rect::default(),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.step(1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.step(-1);
+    }
+
+    /// Select the row under a mouse click, if any
+    pub fn click(&mut self, row: u16) {
+        let offset = self.table_state.offset();
+        match super::table_row_at(self.area, offset, row) {
+            Some(index) if index < self.visible_ids.len() => {
+                self.table_state.select(Some(index));
+                self.selected_id = Some(self.visible_ids[index].clone());
+            }
+            _ => {}
+        }
+    }
+
+    pub fn select_first(&mut self) {
+        self.step(isize::MIN / 2);
+    }
+
+    pub fn select_last(&mut self) {
+        self.step(isize::MAX / 2);
+    }
+
+    fn step(&mut self, delta: isize) {
+        if self.visible_ids.is_empty() {
+            return;
+        }
+        let last = self.visible_ids.len() as isize - 1;
+        let index = match self.table_state.selected() {
+            Some(i) => (i as isize + delta).clamp(0, last) as usize,
+            // Nothing selected yet: count from just above the first row
+            None => (delta - 1).clamp(0, last) as usize,
+        };
+        self.table_state.select(Some(index));
+        self.selected_id = Some(self.visible_ids[index].clone());
+    }
+
+    /// Order ID of the highlighted order
+    pub fn selected_id(&self) -> Option<&str> {
+        self.selected_id.as_deref()
+    }
+
+    /// `orders` should already be narrowed to the resting ones
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        orders: Option<&[Order]>,
+        contracts: &[Contract],
+        theme: &Theme,
+    ) {
+        let block = Block::default().borders(Borders::ALL);
+        self.area = area;
+        self.visible_ids = orders
+            .unwrap_or_default()
+            .iter()
+            .map(|o| o.order_id.clone())
+            .collect();
+        // Follow the selected order if it moved; fall back to the same row
+        let index = self
+            .selected_id
+            .as_ref()
+            .and_then(|id| self.visible_ids.iter().position(|v| v == id))
+            .or_else(|| {
+                let last = self.visible_ids.len().checked_sub(1)?;
+                Some(self.table_state.selected().unwrap_or(0).min(last))
+            });
+        self.table_state.select(index);
+        self.selected_id = index.map(|i| self.visible_ids[i].clone());
+
+        let Some(orders) = orders else {
+            let paragraph = Paragraph::new("Loading orders...")
+                .block(block.title(" RESTING ORDERS "))
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        };
+        if orders.is_empty() {
+            let paragraph = Paragraph::new("No resting orders.")
+                .block(block.title(" RESTING ORDERS "))
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let header = Row::new(vec![
+            "Order ID", "Ticker", "Dir", "Strike", "Limit", "Market", "Filled", "Age",
+        ])
+        .style(
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
+
+        let rows: Vec<Row> = orders
+            .iter()
+            .map(|order| {
+                let market = quote(order, contracts);
+                // A limit at or through the quote should have filled; flag it
+                let market_color = match (market, order.limit_price) {
+                    (Some(price), Some(cents)) if price * 100.0 <= cents as f64 => theme.caution,
+                    _ => theme.text,
+                };
+                let filled_color = if order.filled() > 0 {
+                    theme.positive
+                } else {
+                    theme.muted
+                };
+
+                Row::new(vec![
+                    Cell::from(short_id(&order.order_id)),
+                    Cell::from(order.ticker.clone()),
+                    Cell::from(order.direction.clone()),
+                    Cell::from(format!("${:.0}", order.strike)),
+                    Cell::from(order.limit_display()),
+                    Cell::from(
+                        market
+                            .map(|p| format!("${:.2}", p))
+                            .unwrap_or_else(|| "--".to_string()),
+                    )
+                    .style(Style::default().fg(market_color)),
+                    Cell::from(format!("{}/{}", order.filled(), order.contracts))
+                        .style(Style::default().fg(filled_color)),
+                    Cell::from(age(order)).style(Style::default().fg(theme.muted)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(14), // Order ID
+                Constraint::Length(24), // Ticker
+                Constraint::Length(5),  // Dir
+                Constraint::Length(10), // Strike
+                Constraint::Length(8),  // Limit
+                Constraint::Length(8),  // Market
+                Constraint::Length(8),  // Filled
+                Constraint::Length(8),  // Age
+            ],
+        )
+        .header(header)
+        .highlight_style(Style::default().bg(theme.selection))
+        .highlight_symbol("▶ ")
+        .block(
+            block
+                .title(format!(" RESTING ORDERS ({}) ", orders.len()))
+                .title_bottom(
+                    " Market: live quote for the order's side │ [x] cancel │ [i] amend selected ",
+                ),
+        );
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+}
+
+/// Confirmation before cancelling an order from the Orders tab
+pub fn confirm_cancel(order: &Order, theme: &Theme) -> Dialog {
+    let label = |text: &'static str| {
+        Span::styled(format!("  {:<14}", text), Style::default().fg(theme.muted))
+    };
+
+    let body = vec![
+        Line::from(vec![
+            label("Order"),
+            Span::styled(
+                order.order_id.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![label("Contract"), Span::raw(order.ticker.clone())]),
+        Line::from(vec![
+            label("Side"),
+            Span::raw(format!(
+                "{} x{} @ {}",
+                order.direction,
+                order.remaining,
+                order.limit_display()
+            )),
+            Span::styled(
+                format!("  ({} of {} filled)", order.filled(), order.contracts),
+                Style::default().fg(theme.muted),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Contracts already filled stay as a position.",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+
+    Dialog::confirm("CANCEL ORDER", body, "pull order")
+        .destructive()
+        .width(72)
+}
+
+/// Live quote for the order's side, when the contract is in the feed
+fn quote(order: &Order, contracts: &[Contract]) -> Option<f64> {
+    let contract = contracts.iter().find(|c| c.ticker == order.ticker)?;
+    if order.direction.eq_ignore_ascii_case("NO") {
+        contract.no_price
+    } else {
+        contract.yes_price
+    }
+}
+
+/// First 12 characters; `basilisk orders` prints the full ID
+fn short_id(order_id: &str) -> String {
+    match order_id.char_indices().nth(12) {
+        Some((end, _)) => format!("{}…", &order_id[..end]),
+        None => order_id.to_string(),
+    }
+}
+
+/// Time since the order was placed, "--" when the backend didn't say
+fn age(order: &Order) -> String {
+    let Some(created) = order.created_at.as_deref().and_then(parse_timestamp) else {
+        return "--".to_string();
+    };
+    let minutes = (Utc::now() - created).num_minutes().max(0);
+    if minutes < 60 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}h{:02}m", minutes / 60, minutes % 60)
+    }
+}
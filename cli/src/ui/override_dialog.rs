@@ -0,0 +1,126 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::model_override::{ModelOverride, VolSource};
+use crate::ui::theme::Theme;
+
+/// Rows of the popup, top to bottom
+const FIELDS: usize = 5;
+
+/// Settings popup for the "My%" model ('o' on the Signals tab)
+pub struct OverrideDialog {
+    /// Edited copy; only saved on Enter
+    pub draft: ModelOverride,
+    selected: usize,
+}
+
+impl OverrideDialog {
+    pub fn new(current: &ModelOverride) -> Self {
+        Self {
+            draft: current.clone(),
+            selected: 0,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(FIELDS - 1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Nudge the selected field one step (`delta` of +1 or -1)
+    pub fn adjust(&mut self, delta: i32) {
+        let step = delta as f64;
+        let draft = &mut self.draft;
+        match self.selected {
+            0 => draft.enabled = !draft.enabled,
+            1 => draft.vol_source = draft.vol_source.step(delta),
+            2 => draft.fixed_vol = (draft.fixed_vol + 0.01 * step).clamp(0.05, 3.0),
+            3 => draft.iv_bump = (draft.iv_bump + 0.01 * step).clamp(-1.0, 1.0),
+            _ => draft.drift_per_hour = (draft.drift_per_hour + 0.0005 * step).clamp(-0.05, 0.05),
+        }
+    }
+
+    /// `vol` is what the engine would use with the draft settings right now
+    pub fn render(&self, frame: &mut Frame, vol: Option<f64>, theme: &Theme) {
+        let area = frame.size();
+        let popup_width = 64.min(area.width);
+        let popup_height = 12.min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let draft = &self.draft;
+        let fixed_in_use = draft.vol_source == VolSource::Fixed;
+        let fields = [
+            (
+                "My% column",
+                if draft.enabled { "on" } else { "off" }.to_string(),
+            ),
+            ("Vol source", draft.vol_source.label().to_string()),
+            (
+                "Fixed vol",
+                format!(
+                    "{:.0}%{}",
+                    draft.fixed_vol * 100.0,
+                    if fixed_in_use { "" } else { "  (unused)" }
+                ),
+            ),
+            ("IV bump", format!("{:+.0} vol pts", draft.iv_bump * 100.0)),
+            (
+                "Drift",
+                format!("{:+.2}% per hour", draft.drift_per_hour * 100.0),
+            ),
+        ];
+
+        let mut lines = vec![Line::from("")];
+        for (i, (label, value)) in fields.iter().enumerate() {
+            let selected = i == self.selected;
+            let style = if selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::raw(if selected { "  ▶ " } else { "    " }),
+                Span::styled(format!("{:<14}", label), Style::default().fg(theme.muted)),
+                Span::styled(format!("◀ {} ▶", value), style),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            match vol {
+                Some(vol) => format!("    Engine vol now: {:.1}%", vol * 100.0),
+                None => "    Engine vol now: -- (no reading from this source)".to_string(),
+            },
+            Style::default().fg(theme.muted),
+        )));
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(" MY MODEL ")
+            .title_style(
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(" [↑/↓] field │ [←/→] change │ [Enter] save │ [ESC] cancel ");
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
@@ -0,0 +1,172 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::planner::AllocationPlan;
+use crate::ui::theme::Theme;
+
+/// Confirmation popup for the allocation planner's basket
+pub struct PlanDialog {
+    pub plan: AllocationPlan,
+    /// Outcome per executed leg, in plan order
+    results: Vec<Result<String, String>>,
+    done: bool,
+}
+
+impl PlanDialog {
+    pub fn new(plan: AllocationPlan) -> Self {
+        Self {
+            plan,
+            results: Vec::new(),
+            done: false,
+        }
+    }
+
+    pub fn can_submit(&self) -> bool {
+        !self.done && self.plan.can_execute()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    pub fn push_result(&mut self, result: Result<String, String>) {
+        self.results.push(result);
+    }
+
+    pub fn finish(&mut self) {
+        self.done = true;
+    }
+
+    pub fn render(&self, frame: &mut Frame, theme: &Theme) {
+        let area = frame.size();
+        let popup_width = 76.min(area.width);
+        let rows =
+            self.plan.legs.len().max(1) + self.results.len() + usize::from(self.plan.skipped > 0);
+        let popup_height = (7 + rows as u16).min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let label = |text: &'static str| {
+            Span::styled(format!("  {:<14}", text), Style::default().fg(theme.muted))
+        };
+
+        let mut lines = vec![
+            Line::from(vec![
+                label("Expiry"),
+                Span::styled(
+                    self.plan.expiry_label(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                label("Budget"),
+                Span::styled(
+                    format!("${:.2}", self.plan.budget),
+                    Style::default().fg(theme.highlight),
+                ),
+                Span::styled(
+                    format!("  (${:.2} already open this hour)", self.plan.committed),
+                    Style::default().fg(theme.muted),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        if self.plan.legs.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  Nothing to allocate: the budget is used up or too small for one contract.",
+                Style::default().fg(theme.highlight),
+            )));
+        }
+        for (i, leg) in self.plan.legs.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}. ", i + 1),
+                    Style::default().fg(theme.highlight),
+                ),
+                Span::styled(format!("{:<40}", leg.describe()), Style::default()),
+                Span::styled(
+                    format!("${:>7.2}", leg.cost()),
+                    Style::default().fg(theme.accent),
+                ),
+                Span::styled(
+                    format!(
+                        "  {:>3.0}%  EV {:+.1}%",
+                        leg.weight * 100.0,
+                        leg.contract.expected_value * 100.0
+                    ),
+                    Style::default().fg(theme.muted),
+                ),
+            ]));
+        }
+        if self.plan.skipped > 0 {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  + {} signal(s) too small a share for one contract",
+                    self.plan.skipped
+                ),
+                Style::default().fg(theme.muted),
+            )));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            label("Basket"),
+            Span::styled(
+                format!("${:.2}", self.plan.total_cost()),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  expected ${:+.2}", self.plan.expected_profit()),
+                Style::default().fg(theme.positive),
+            ),
+        ]));
+
+        for result in &self.results {
+            lines.push(match result {
+                Ok(message) => Line::from(Span::styled(
+                    format!("  ✅ {}", message),
+                    Style::default().fg(theme.positive),
+                )),
+                Err(message) => Line::from(Span::styled(
+                    format!("  ❌ {}", message),
+                    Style::default().fg(theme.negative),
+                )),
+            });
+        }
+
+        let hint = if self.done {
+            " [any key] close "
+        } else if self.plan.legs.is_empty() {
+            " [ESC] close "
+        } else {
+            " [Enter] place all legs │ [ESC] cancel "
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.special))
+            .title(" ALLOCATION PLAN ")
+            .title_style(
+                Style::default()
+                    .fg(theme.special)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(hint);
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
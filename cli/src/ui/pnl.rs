@@ -0,0 +1,110 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::api::models::PnLSummary;
+use crate::ui::theme::Theme;
+
+/// Periods shown side by side, as accepted by the P&L endpoint
+pub const PERIODS: [&str; 3] = ["today", "week", "all"];
+
+/// P&L tab: one summary card per period
+pub struct PnlView;
+
+impl PnlView {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `summaries` is in `PERIODS` order; missing entries are still loading or failed
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        summaries: &[Option<PnLSummary>],
+        theme: &Theme,
+    ) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+            ])
+            .split(area);
+
+        for (i, period) in PERIODS.iter().enumerate() {
+            let title = match *period {
+                "today" => " TODAY ",
+                "week" => " THIS WEEK ",
+                _ => " ALL TIME ",
+            };
+            let block = Block::default().borders(Borders::ALL).title(title);
+
+            let Some(summary) = summaries.get(i).and_then(Option::as_ref) else {
+                let paragraph = Paragraph::new("Loading P&L...")
+                    .block(block)
+                    .alignment(Alignment::Center);
+                frame.render_widget(paragraph, columns[i]);
+                continue;
+            };
+
+            let net_color = if summary.net_pnl >= 0.0 {
+                theme.positive
+            } else {
+                theme.negative
+            };
+            let label = |text: &'static str| {
+                Span::styled(format!("  {:<10}", text), Style::default().fg(theme.muted))
+            };
+
+            let lines = vec![
+                Line::from(""),
+                Line::from(vec![
+                    label("Net P&L"),
+                    Span::styled(
+                        format!("${:+.2}", summary.net_pnl),
+                        Style::default().fg(net_color).add_modifier(Modifier::BOLD),
+                    ),
+                ]),
+                Line::from(vec![
+                    label("Gross"),
+                    Span::raw(format!("${:+.2}", summary.total_pnl)),
+                ]),
+                Line::from(vec![
+                    label("Fees"),
+                    Span::raw(format!("${:.2}", summary.total_fees)),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    label("Trades"),
+                    Span::raw(summary.trade_count.to_string()),
+                ]),
+                Line::from(vec![
+                    label("Wins"),
+                    Span::styled(
+                        summary.wins.to_string(),
+                        Style::default().fg(theme.positive),
+                    ),
+                ]),
+                Line::from(vec![
+                    label("Losses"),
+                    Span::styled(
+                        summary.losses.to_string(),
+                        Style::default().fg(theme.negative),
+                    ),
+                ]),
+                Line::from(vec![
+                    label("Win Rate"),
+                    Span::raw(format!("{:.0}%", summary.win_rate * 100.0)),
+                ]),
+            ];
+
+            frame.render_widget(Paragraph::new(lines).block(block), columns[i]);
+        }
+    }
+}
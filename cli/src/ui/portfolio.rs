@@ -0,0 +1,256 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::exposure::{Concentration, ExposureBook, ExposureSummary};
+use crate::ui::theme::Theme;
+
+/// Width of the text meter in the exposure table
+const METER_WIDTH: usize = 24;
+
+/// Portfolio tab: open notional per expiry against `[exposure] max_per_expiry`,
+/// and the same positions by expiry and side
+pub struct PortfolioView;
+
+impl PortfolioView {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        exposure: Option<&ExposureBook>,
+        concentration: Option<&ExposureSummary>,
+        cap: Option<f64>,
+        theme: &Theme,
+    ) {
+        // Header, a row per expiry and side, a Both row where an expiry holds
+        // both sides, then a row per side when both are held and the total
+        let concentration_rows =
+            concentration
+                .filter(|summary| !summary.is_empty())
+                .map_or(0, |summary| {
+                    let rows: usize = summary
+                        .expiries()
+                        .map(|bucket| {
+                            let sides = bucket.sides().count();
+                            sides + usize::from(sides > 1)
+                        })
+                        .sum();
+                    let sides = summary.by_side().sides().count();
+                    rows + if sides > 1 { sides } else { 0 } + 1
+                });
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title
+                Constraint::Min(0),    // Exposure by expiry
+                Constraint::Length(if concentration_rows > 0 {
+                    concentration_rows as u16 + 4
+                } else {
+                    0
+                }), // By expiry and side
+            ])
+            .split(area);
+        if let Some(summary) = concentration.filter(|summary| !summary.is_empty()) {
+            render_concentration(frame, chunks[2], summary, theme);
+        }
+
+        let cap_text = match cap {
+            Some(cap) => format!("cap ${:.2} per expiry", cap),
+            None => "no cap set ([exposure] max_per_expiry)".to_string(),
+        };
+        let title = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "EXPOSURE BY EXPIRY",
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  {}", cap_text), Style::default().fg(theme.muted)),
+        ]))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Center);
+        frame.render_widget(title, chunks[0]);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Open Notional ");
+        let Some(exposure) = exposure else {
+            let paragraph = Paragraph::new("Loading positions...")
+                .block(block)
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, chunks[1]);
+            return;
+        };
+        if exposure.expiries().next().is_none() {
+            let paragraph = Paragraph::new("No open positions.")
+                .block(block)
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, chunks[1]);
+            return;
+        }
+
+        let header = Row::new(vec!["Expiry", "Positions", "Notional", "Meter"])
+            .style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .bottom_margin(1);
+
+        let rows: Vec<Row> = exposure
+            .expiries()
+            .map(|bucket| {
+                let meter = match bucket.utilization(cap) {
+                    Some(used) => {
+                        let color = if used >= 1.0 {
+                            theme.negative
+                        } else if used >= 0.75 {
+                            theme.highlight
+                        } else {
+                            theme.positive
+                        };
+                        let filled = ((used.min(1.0) * METER_WIDTH as f64).round() as usize)
+                            .min(METER_WIDTH);
+                        Cell::from(Line::from(vec![
+                            Span::styled("█".repeat(filled), Style::default().fg(color)),
+                            Span::styled(
+                                "░".repeat(METER_WIDTH - filled),
+                                Style::default().fg(theme.faint),
+                            ),
+                            Span::styled(
+                                format!(" {:>4.0}%", used * 100.0),
+                                Style::default().fg(color),
+                            ),
+                            if used >= 1.0 {
+                                Span::styled(
+                                    " AT CAP",
+                                    Style::default()
+                                        .fg(theme.negative)
+                                        .add_modifier(Modifier::BOLD),
+                                )
+                            } else {
+                                Span::raw("")
+                            },
+                        ]))
+                    }
+                    None => Cell::from(Span::styled("--", Style::default().fg(theme.faint))),
+                };
+
+                Row::new(vec![
+                    Cell::from(bucket.label()),
+                    Cell::from(bucket.positions.to_string()),
+                    Cell::from(format!("${:.2}", bucket.notional)),
+                    meter,
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(12),
+                Constraint::Length(10),
+                Constraint::Length(12),
+                Constraint::Min(METER_WIDTH as u16 + 14),
+            ],
+        )
+        .header(header)
+        .block(block);
+
+        frame.render_widget(table, chunks[1]);
+    }
+}
+
+/// Contracts, value at risk and max loss by expiry and side, with each row's
+/// share of the total max loss
+fn render_concentration(frame: &mut Frame, area: Rect, summary: &ExposureSummary, theme: &Theme) {
+    let total = summary.total();
+    let row = |label: String, side: &str, figures: &Concentration, bold: bool| {
+        let share = figures.share_of(&total);
+        let share_color = if share >= 0.75 && figures.positions < total.positions {
+            theme.caution
+        } else {
+            theme.text
+        };
+        let style = if bold {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        Row::new(vec![
+            Cell::from(label),
+            Cell::from(side.to_string()),
+            Cell::from(figures.positions.to_string()),
+            Cell::from(figures.contracts.to_string()),
+            Cell::from(format!("${:.2}", figures.at_risk)),
+            Cell::from(format!("${:.2}", figures.max_loss))
+                .style(Style::default().fg(theme.negative)),
+            Cell::from(format!("{:.0}%", share * 100.0)).style(Style::default().fg(share_color)),
+        ])
+        .style(style)
+    };
+
+    let mut rows = Vec::new();
+    for bucket in summary.expiries() {
+        let mut label = bucket.label();
+        for (side, figures) in bucket.sides() {
+            rows.push(row(std::mem::take(&mut label), side, figures, false));
+        }
+        if bucket.yes.positions > 0 && bucket.no.positions > 0 {
+            rows.push(row(String::new(), "Both", &bucket.total(), false));
+        }
+    }
+    let sides = summary.by_side();
+    let mut label = "All".to_string();
+    if sides.sides().count() > 1 {
+        for (side, figures) in sides.sides() {
+            rows.push(row(std::mem::take(&mut label), side, figures, true));
+        }
+    }
+    rows.push(row(label, "Total", &total, true));
+
+    let header = Row::new(vec![
+        "Expiry",
+        "Side",
+        "Pos",
+        "Contracts",
+        "At Risk",
+        "Max Loss",
+        "Share",
+    ])
+    .style(
+        Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(6),
+            Constraint::Length(5),
+            Constraint::Length(10),
+            Constraint::Length(11),
+            Constraint::Length(11),
+            Constraint::Length(7),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Concentration by Expiry and Side ")
+            .title_bottom(" At Risk: value at the live quote │ Max Loss: cost plus entry fees "),
+    );
+    frame.render_widget(table, area);
+}
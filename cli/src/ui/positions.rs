@@ -0,0 +1,313 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::api::models::{Contract, Position};
+use crate::spread::SpreadBook;
+use crate::ui::theme::Theme;
+
+/// Positions tab: open positions, marked to the live contract quotes
+pub struct PositionsView {
+    table_state: TableState,
+    visible_ids: Vec<i32>, // Trade IDs in display order, from the last render
+    selected_id: Option<i32>, // Keeps the selection on the same position across refreshes
+    area: Rect,            // Where the table was last drawn, for mouse clicks
+}
+
+/// Current price and unrealized P&L for one position
+#[derive(Debug, Clone, Copy)]
+pub struct Mark {
+    pub price: Option<f64>,
+    pub pnl: Option<f64>,
+    /// Taken from the streamed quote rather than the last positions fetch
+    pub live: bool,
+}
+
+/// Mark to the contract's latest quote for the position's side, falling
+/// back to the backend's figures when the contract isn't in the feed
+pub fn mark(position: &Position, contracts: &[Contract]) -> Mark {
+    let quote = contracts
+        .iter()
+        .find(|c| c.ticker == position.ticker)
+        .and_then(|c| {
+            if position.direction.eq_ignore_ascii_case("NO") {
+                c.no_price
+            } else {
+                c.yes_price
+            }
+        });
+    match quote {
+        Some(price) => Mark {
+            price: Some(price),
+            pnl: Some((price - position.entry_price) * position.contracts as f64),
+            live: true,
+        },
+        None => Mark {
+            price: position.current_price,
+            pnl: position.unrealized_pnl,
+            live: false,
+        },
+    }
+}
+
+impl PositionsView {
+    pub fn new() -> Self {
+        Self {
+            table_state: TableState::default(),
+            visible_ids: Vec::new(),
+            selected_id: None,
+            area: Rect::default(),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.step(1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.step(-1);
+    }
+
+    /// Select the row under a mouse click, if any
+    pub fn click(&mut self, row: u16) {
+        let offset = self.table_state.offset();
+        match super::table_row_at(self.area, offset, row) {
+            Some(index) if index < self.visible_ids.len() => {
+                self.table_state.select(Some(index));
+                self.selected_id = Some(self.visible_ids[index]);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn select_first(&mut self) {
+        self.step(isize::MIN / 2);
+    }
+
+    pub fn select_last(&mut self) {
+        self.step(isize::MAX / 2);
+    }
+
+    fn step(&mut self, delta: isize) {
+        if self.visible_ids.is_empty() {
+            return;
+        }
+        let last = self.visible_ids.len() as isize - 1;
+        let index = match self.table_state.selected() {
+            Some(i) => (i as isize + delta).clamp(0, last) as usize,
+            // Nothing selected yet: count from just above the first row
+            None => (delta - 1).clamp(0, last) as usize,
+        };
+        self.table_state.select(Some(index));
+        self.selected_id = Some(self.visible_ids[index]);
+    }
+
+    /// Highlight a position on the next render
+    pub fn select_id(&mut self, id: i32) {
+        self.selected_id = Some(id);
+    }
+
+    /// Trade ID of the highlighted position
+    pub fn selected_id(&self) -> Option<i32> {
+        self.selected_id
+    }
+
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        positions: Option<&[Position]>,
+        contracts: &[Contract],
+        spreads: &SpreadBook,
+        theme: &Theme,
+    ) {
+        let block = Block::default().borders(Borders::ALL);
+        let open_spreads = spreads.open(positions.unwrap_or_default());
+        let area = if open_spreads.is_empty() {
+            area
+        } else {
+            let split = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Min(6),
+                    Constraint::Length(open_spreads.len() as u16 + 2),
+                ])
+                .split(area);
+            let lines: Vec<Line> = open_spreads
+                .iter()
+                .map(|spread| {
+                    let pnl = spread.pnl(positions.unwrap_or_default(), |p| mark(p, contracts).pnl);
+                    Line::from(vec![
+                        Span::styled(
+                            format!(" ⇄{:<3}", spread.id),
+                            Style::default().fg(theme.highlight),
+                        ),
+                        Span::raw(format!(
+                            "{:<24} x{:<5}",
+                            spread.describe(),
+                            spread.legs.first().map_or(0, |leg| leg.contracts)
+                        )),
+                        Span::styled(
+                            format!(
+                                "P&L {:<10}",
+                                pnl.map_or("N/A".to_string(), |pnl| format!("${:+.2}", pnl))
+                            ),
+                            Style::default().fg(pnl_color(pnl, theme)),
+                        ),
+                        Span::styled(
+                            format!("Max loss ${:.2}  ", spread.max_loss()),
+                            Style::default().fg(theme.negative),
+                        ),
+                        Span::styled(
+                            format!("Max profit ${:.2}", spread.max_profit()),
+                            Style::default().fg(theme.positive),
+                        ),
+                    ])
+                })
+                .collect();
+            let paragraph = Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" SPREADS ({}) ", open_spreads.len())),
+            );
+            frame.render_widget(paragraph, split[1]);
+            split[0]
+        };
+        self.area = area;
+        self.visible_ids = positions
+            .unwrap_or_default()
+            .iter()
+            .map(|p| p.trade_id)
+            .collect();
+        // Follow the selected position if it moved; fall back to the same row
+        let index = self
+            .selected_id
+            .and_then(|id| self.visible_ids.iter().position(|v| *v == id))
+            .or_else(|| {
+                let last = self.visible_ids.len().checked_sub(1)?;
+                Some(self.table_state.selected().unwrap_or(0).min(last))
+            });
+        self.table_state.select(index);
+        self.selected_id = index.map(|i| self.visible_ids[i]);
+
+        let Some(positions) = positions else {
+            let paragraph = Paragraph::new("Loading positions...")
+                .block(block.title(" OPEN POSITIONS "))
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        };
+        if positions.is_empty() {
+            let paragraph = Paragraph::new("No open positions.")
+                .block(block.title(" OPEN POSITIONS "))
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let marks: Vec<Mark> = positions.iter().map(|p| mark(p, contracts)).collect();
+        let total = marks
+            .iter()
+            .filter_map(|m| m.pnl)
+            .fold(0.0, |total, pnl| total + pnl);
+
+        let header = Row::new(vec![
+            "ID", "Ticker", "Dir", "Strike", "Qty", "Entry", "Current", "P&L", "Left",
+        ])
+        .style(
+            Style::default()
+                .fg(theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
+
+        let rows: Vec<Row> = positions
+            .iter()
+            .zip(&marks)
+            .map(|(position, mark)| {
+                let price = match mark.price {
+                    Some(price) if mark.live => format!("${:.2}•", price),
+                    Some(price) => format!("${:.2}", price),
+                    None => "N/A".to_string(),
+                };
+                let pnl = match mark.pnl {
+                    Some(pnl) => format!("${:+.2}", pnl),
+                    None => "N/A".to_string(),
+                };
+                let left_color = if position.is_near_expiry() {
+                    theme.caution
+                } else {
+                    theme.text
+                };
+
+                let id = match spreads.spread_of(position.trade_id) {
+                    Some(spread) => format!("{} ⇄{}", position.trade_id, spread.id),
+                    None => position.trade_id.to_string(),
+                };
+
+                Row::new(vec![
+                    Cell::from(id),
+                    Cell::from(position.ticker.clone()),
+                    Cell::from(position.direction.clone()),
+                    Cell::from(format!("${:.0}", position.strike)),
+                    Cell::from(position.contracts.to_string()),
+                    Cell::from(format!("${:.2}", position.entry_price)),
+                    Cell::from(price),
+                    Cell::from(pnl).style(Style::default().fg(pnl_color(mark.pnl, theme))),
+                    Cell::from(position.time_left_display()).style(Style::default().fg(left_color)),
+                ])
+            })
+            .collect();
+
+        let title = Line::from(vec![
+            Span::raw(format!(
+                " OPEN POSITIONS ({})  Unrealized ",
+                positions.len()
+            )),
+            Span::styled(
+                format!("${:+.2} ", total),
+                Style::default()
+                    .fg(pnl_color(Some(total), theme))
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]);
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),  // ID
+                Constraint::Length(24), // Ticker
+                Constraint::Length(5),  // Dir
+                Constraint::Length(10), // Strike
+                Constraint::Length(6),  // Qty
+                Constraint::Length(9),  // Entry
+                Constraint::Length(10), // Current
+                Constraint::Length(10), // P&L
+                Constraint::Length(8),  // Left
+            ],
+        )
+        .header(header)
+        .highlight_style(Style::default().bg(theme.selection))
+        .highlight_symbol("▶ ")
+        .block(
+            block
+                .title(title)
+                .title_bottom(" • marked to the live quote │ ⇄ spread leg │ [x] close selected "),
+        );
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+}
+
+/// Green for gains, red for losses, gray when unknown or flat
+pub fn pnl_color(pnl: Option<f64>, theme: &Theme) -> Color {
+    match pnl {
+        Some(pnl) if pnl > 0.0 => theme.positive,
+        Some(pnl) if pnl < 0.0 => theme.negative,
+        _ => theme.muted,
+    }
+}
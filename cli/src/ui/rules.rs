@@ -0,0 +1,162 @@
+use chrono::Local;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::rules::{PriceRule, RuleStatus};
+use crate::ui::theme::Theme;
+
+/// Rules tab: price-triggered orders and what became of them
+pub struct RulesView {
+    table_state: TableState,
+    visible_ids: Vec<u32>,    // Rule IDs in display order, from the last render
+    selected_id: Option<u32>, // Keeps the selection on the same rule as statuses change
+    area: Rect,               // Where the table was last drawn, for mouse clicks
+}
+
+impl RulesView {
+    pub fn new() -> Self {
+        Self {
+            table_state: TableState::default(),
+            visible_ids: Vec::new(),
+            selected_id: None,
+            area: Rect::default(),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.step(1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.step(-1);
+    }
+
+    /// Select the row under a mouse click, if any
+    pub fn click(&mut self, row: u16) {
+        let offset = self.table_state.offset();
+        match super::table_row_at(self.area, offset, row) {
+            Some(index) if index < self.visible_ids.len() => {
+                self.table_state.select(Some(index));
+                self.selected_id = Some(self.visible_ids[index]);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn select_first(&mut self) {
+        self.step(isize::MIN / 2);
+    }
+
+    pub fn select_last(&mut self) {
+        self.step(isize::MAX / 2);
+    }
+
+    fn step(&mut self, delta: isize) {
+        if self.visible_ids.is_empty() {
+            return;
+        }
+        let last = self.visible_ids.len() as isize - 1;
+        let index = match self.table_state.selected() {
+            Some(i) => (i as isize + delta).clamp(0, last) as usize,
+            None => (delta - 1).clamp(0, last) as usize,
+        };
+        self.table_state.select(Some(index));
+        self.selected_id = Some(self.visible_ids[index]);
+    }
+
+    /// ID of the highlighted rule
+    pub fn selected_id(&self) -> Option<u32> {
+        self.selected_id
+    }
+
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        rules: &[&PriceRule],
+        btc_price: f64,
+        theme: &Theme,
+    ) {
+        let block = Block::default().borders(Borders::ALL);
+        self.area = area;
+        self.visible_ids = rules.iter().map(|r| r.id).collect();
+        let index = self
+            .selected_id
+            .and_then(|id| self.visible_ids.iter().position(|v| *v == id))
+            .or_else(|| {
+                let last = self.visible_ids.len().checked_sub(1)?;
+                Some(self.table_state.selected().unwrap_or(0).min(last))
+            });
+        self.table_state.select(index);
+        self.selected_id = index.map(|i| self.visible_ids[i]);
+
+        if rules.is_empty() {
+            let paragraph = Paragraph::new(
+                "No rules. Add one with: basilisk-cli rules add yes 98500 --size 3 --above 98300 --before 45",
+            )
+            .block(block.title(" PRICE RULES "))
+            .alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let header = Row::new(vec!["ID", "Rule", "Status", "Added", "Updated"])
+            .style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .bottom_margin(1);
+
+        let rows: Vec<Row> = rules
+            .iter()
+            .map(|rule| {
+                let color = match rule.status {
+                    RuleStatus::Armed => theme.accent,
+                    RuleStatus::Fired { .. } => theme.positive,
+                    RuleStatus::Failed { .. } => theme.negative,
+                    RuleStatus::Expired | RuleStatus::Cancelled => theme.faint,
+                };
+                let time = |at: Option<chrono::DateTime<chrono::Utc>>| {
+                    at.map(|at| at.with_timezone(&Local).format("%H:%M:%S").to_string())
+                        .unwrap_or_else(|| "--".to_string())
+                };
+                Row::new(vec![
+                    Cell::from(format!("#{}", rule.id)),
+                    Cell::from(rule.describe()),
+                    Cell::from(rule.status.label()).style(Style::default().fg(color)),
+                    Cell::from(time(Some(rule.created_at))),
+                    Cell::from(time(rule.updated_at)),
+                ])
+            })
+            .collect();
+
+        let armed = rules.iter().filter(|r| r.is_armed()).count();
+        let title = if btc_price > 0.0 {
+            format!(" PRICE RULES ({} armed)  BTC ${:.0} ", armed, btc_price)
+        } else {
+            format!(" PRICE RULES ({} armed) ", armed)
+        };
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(6),  // ID
+                Constraint::Length(52), // Rule
+                Constraint::Min(20),    // Status
+                Constraint::Length(10), // Added
+                Constraint::Length(10), // Updated
+            ],
+        )
+        .header(header)
+        .highlight_style(Style::default().bg(theme.selection))
+        .highlight_symbol("▶ ")
+        .block(block.title(title));
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+}
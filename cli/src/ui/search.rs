@@ -0,0 +1,253 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::collections::HashMap;
+
+use crate::api::models::{Position, TradeHistory};
+use crate::api::Contract;
+use crate::ui::theme::Theme;
+
+/// Where a search hit lives, so Enter can jump to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchTarget {
+    /// Contract ID in the signals table
+    Signal(i32),
+    /// Trade ID in the Positions tab
+    Position(i32),
+    /// Trade ID in the History tab
+    History(i32),
+}
+
+impl SearchTarget {
+    fn tab(self, theme: &Theme) -> (&'static str, Color) {
+        match self {
+            SearchTarget::Signal(_) => ("Signals", theme.positive),
+            SearchTarget::Position(_) => ("Positions", theme.secondary),
+            SearchTarget::History(_) => ("History", theme.accent),
+        }
+    }
+}
+
+pub struct SearchHit {
+    pub target: SearchTarget,
+    label: String,
+    /// Lower-cased text the query is matched against
+    haystack: String,
+}
+
+impl SearchHit {
+    fn new(target: SearchTarget, label: String, terms: &[&str]) -> Self {
+        let haystack = terms.join(" ").to_lowercase();
+        Self {
+            target,
+            label,
+            haystack,
+        }
+    }
+}
+
+/// Everything searchable: signals, open positions and trade history, with
+/// journal tags attached to the trades they were recorded for
+pub fn index(
+    contracts: &[Contract],
+    positions: &[Position],
+    history: &[TradeHistory],
+    tags: &HashMap<i32, String>,
+) -> Vec<SearchHit> {
+    let mut hits = Vec::new();
+
+    for contract in contracts {
+        let strike = contract
+            .strike_price
+            .map(|s| format!("{:.0}", s))
+            .unwrap_or_default();
+        hits.push(SearchHit::new(
+            SearchTarget::Signal(contract.id),
+            format!(
+                "{:<24} {:<9} {:<8} EV {}",
+                contract.ticker,
+                contract.strike_display(),
+                contract.signal_type,
+                contract.ev_display()
+            ),
+            &[&contract.ticker, &strike, &contract.signal_type],
+        ));
+    }
+
+    for position in positions {
+        let tag = tags
+            .get(&position.trade_id)
+            .map(String::as_str)
+            .unwrap_or_default();
+        let strike = format!("{:.0}", position.strike);
+        let id = format!("#{}", position.trade_id);
+        hits.push(SearchHit::new(
+            SearchTarget::Position(position.trade_id),
+            format!(
+                "{:<6} {:<24} {} x{}  {}",
+                id, position.ticker, position.direction, position.contracts, tag
+            ),
+            &[&id, &position.ticker, &strike, &position.direction, tag],
+        ));
+    }
+
+    for trade in history {
+        let tag = tags.get(&trade.id).map(String::as_str).unwrap_or_default();
+        let strike = format!("{:.0}", trade.strike);
+        let id = format!("#{}", trade.id);
+        hits.push(SearchHit::new(
+            SearchTarget::History(trade.id),
+            format!(
+                "{:<6} {:<24} {} x{}  {} {}  {}",
+                id,
+                trade.ticker,
+                trade.direction,
+                trade.contracts,
+                trade.status,
+                trade.pnl_display(),
+                tag
+            ),
+            &[
+                &id,
+                &trade.ticker,
+                &strike,
+                &trade.direction,
+                &trade.status,
+                tag,
+            ],
+        ));
+    }
+
+    hits
+}
+
+/// Ctrl-F search popup over every tab's rows
+pub struct SearchOverlay {
+    pub query: String,
+    hits: Vec<SearchHit>,
+    /// Indices into `hits` matching the query, in tab order
+    matches: Vec<usize>,
+    selected: usize,
+}
+
+impl SearchOverlay {
+    pub fn new(hits: Vec<SearchHit>) -> Self {
+        let mut overlay = Self {
+            query: String::new(),
+            hits,
+            matches: Vec::new(),
+            selected: 0,
+        };
+        overlay.refilter();
+        overlay
+    }
+
+    /// Every whitespace-separated term must appear somewhere in the hit
+    fn refilter(&mut self) {
+        let query = self.query.to_lowercase();
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        self.matches = self
+            .hits
+            .iter()
+            .enumerate()
+            .filter(|(_, hit)| terms.iter().all(|term| hit.haystack.contains(term)))
+            .map(|(i, _)| i)
+            .collect();
+        self.selected = 0;
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + 1).min(self.matches.len() - 1);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_target(&self) -> Option<SearchTarget> {
+        self.matches
+            .get(self.selected)
+            .map(|i| self.hits[*i].target)
+    }
+
+    pub fn render(&self, frame: &mut Frame, theme: &Theme) {
+        let area = frame.size();
+        let popup_width = 90.min(area.width);
+        let popup_height = 20.min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 4,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("  / ", Style::default().fg(theme.highlight)),
+                Span::styled(
+                    format!("{}_", self.query),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        // Keep the selection inside the visible window (borders + query take 4 lines)
+        let page = (popup_height as usize).saturating_sub(4).max(1);
+        let offset = self.selected.saturating_sub(page - 1);
+        for (row, i) in self.matches.iter().enumerate().skip(offset).take(page) {
+            let hit = &self.hits[*i];
+            let (tab, color) = hit.target.tab(theme);
+            let style = if row == self.selected {
+                Style::default().bg(theme.selection)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<10}", tab), style.fg(color)),
+                Span::styled(hit.label.clone(), style),
+            ]));
+        }
+        if self.matches.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No matches",
+                Style::default().fg(theme.muted),
+            )));
+        }
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.highlight))
+            .title(format!(
+                " SEARCH ({} of {}) ",
+                self.matches.len(),
+                self.hits.len()
+            ))
+            .title_style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(" ticker, strike, side, status, #id or tag │ [↑/↓] move │ [Enter] jump │ [ESC] close ");
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::api::Contract;
+use crate::replay::SETTLEMENT_WINDOW_SECS;
+use crate::ui::theme::Theme;
+
+/// Strikes listed, closest to the projected settlement first
+pub const MAX_STRIKES: usize = 4;
+
+/// Panel height for `strikes` rows (borders and the price line included)
+pub fn height(strikes: usize) -> u16 {
+    3 + strikes.min(MAX_STRIKES) as u16
+}
+
+/// BTC now and the mean of the ticks inside the settlement window so far
+#[derive(Debug, Clone, Copy)]
+pub struct SettlementPrices {
+    pub spot: f64,
+    pub average: Option<f64>,
+}
+
+/// The expiring hour's final minutes: BTC, the running settlement average,
+/// and which side each nearby strike is tracking
+pub struct SettlementWatch;
+
+impl SettlementWatch {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        expiry: DateTime<Utc>,
+        prices: SettlementPrices,
+        contracts: &[&Contract],
+        theme: &Theme,
+    ) {
+        let SettlementPrices { spot, average } = prices;
+        let now = Utc::now();
+        let left = (expiry - now).num_seconds().max(0);
+        let window_left = left.min(SETTLEMENT_WINDOW_SECS);
+        let in_window = left <= SETTLEMENT_WINDOW_SECS;
+
+        // Where the average ends up if BTC holds here for the rest of the window
+        let projected = match average {
+            Some(average) if in_window => {
+                let elapsed = (SETTLEMENT_WINDOW_SECS - window_left) as f64;
+                (average * elapsed + spot * window_left as f64) / SETTLEMENT_WINDOW_SECS as f64
+            }
+            _ => spot,
+        };
+
+        let mut price_line = vec![
+            Span::styled("  BTC ", Style::default().fg(theme.muted)),
+            Span::styled(
+                format!("${:.2}", spot),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("   │   "),
+        ];
+        match average {
+            Some(average) if in_window => {
+                price_line.push(Span::styled(
+                    "Settlement avg ",
+                    Style::default().fg(theme.muted),
+                ));
+                price_line.push(Span::styled(
+                    format!("${:.2}", average),
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ));
+                price_line.push(Span::raw(format!(
+                    " ({}s of {}s, projected ${:.2})",
+                    SETTLEMENT_WINDOW_SECS - window_left,
+                    SETTLEMENT_WINDOW_SECS,
+                    projected
+                )));
+            }
+            _ if in_window => price_line.push(Span::styled(
+                "Settlement window open, waiting for ticks",
+                Style::default().fg(theme.highlight),
+            )),
+            _ => {
+                let opens_in = left - SETTLEMENT_WINDOW_SECS;
+                price_line.push(Span::styled(
+                    format!(
+                        "Settlement window opens in {}:{:02}",
+                        opens_in / 60,
+                        opens_in % 60
+                    ),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+        }
+
+        let mut strikes: Vec<(f64, &Contract)> = contracts
+            .iter()
+            .filter_map(|c| c.strike_price.map(|s| (s, *c)))
+            .collect();
+        strikes.sort_by(|a, b| (a.0 - projected).abs().total_cmp(&(b.0 - projected).abs()));
+        strikes.truncate(MAX_STRIKES);
+        strikes.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let mut lines = vec![Line::from(price_line)];
+        for (strike, contract) in strikes {
+            let diff = projected - strike;
+            let (side, color) = if diff > 0.0 {
+                ("YES ▲", theme.positive)
+            } else {
+                ("NO  ▼", theme.negative)
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!("  ${:<9.0}", strike)),
+                Span::styled(
+                    format!("tracking {}", side),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("   {:+.0} ({:+.2}%)", diff, diff / strike * 100.0)),
+                Span::styled(
+                    format!("   signal {}", contract.signal_type),
+                    Style::default().fg(theme.faint),
+                ),
+            ]));
+        }
+
+        let border = if in_window {
+            theme.negative
+        } else {
+            theme.highlight
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border))
+            .title(format!(
+                " ⏳ SETTLEMENT WATCH │ {} │ {}:{:02} left ",
+                expiry.format("%H:%M UTC"),
+                left / 60,
+                left % 60
+            ));
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
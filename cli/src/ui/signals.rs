@@ -1,32 +1,368 @@
 use ratatui::{
     layout::{Constraint, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Row, Table, TableState},
+    widgets::{
+        Block, Borders, Cell, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
+        TableState,
+    },
     Frame,
 };
 
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
 use crate::api::Contract;
+use crate::config::{ExtremeConfig, LiquidityConfig, TableConfig};
+use crate::fees;
+use crate::momentum::{Drift, MomentumTracker};
+use crate::scripting::ScriptHooks;
+use crate::ui::theme::Theme;
+
+/// Where the My% column goes when the model override is on (after Mod%)
+const MY_MODEL_COLUMN: usize = 8;
+
+/// Where the YES/NO/Sprd columns go with `[table] quote_columns` (after Dist)
+const QUOTE_COLUMN: usize = 5;
+const QUOTE_COLUMN_COUNT: usize = 3;
+
+/// Columns dropped in the compact layout (Expiry and Current), highest first
+const COMPACT_HIDDEN: [usize; 2] = [3, 1];
+
+/// Column the signals table is ordered by ('s' cycles, 'S' reverses)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Backend,
+    Ev,
+    TimeLeft,
+    Distance,
+    Implied,
+    Model,
+    Score,
+}
+
+impl SortKey {
+    const CYCLE: [SortKey; 7] = [
+        SortKey::Backend,
+        SortKey::Ev,
+        SortKey::TimeLeft,
+        SortKey::Distance,
+        SortKey::Implied,
+        SortKey::Model,
+        SortKey::Score,
+    ];
+
+    fn next(self) -> Self {
+        let index = Self::CYCLE.iter().position(|key| *key == self).unwrap_or(0);
+        Self::CYCLE[(index + 1) % Self::CYCLE.len()]
+    }
+
+    /// Header column that carries the sort arrow
+    fn column(self) -> Option<usize> {
+        match self {
+            SortKey::Backend => None,
+            SortKey::TimeLeft => Some(2),
+            SortKey::Distance => Some(4),
+            SortKey::Implied => Some(5),
+            SortKey::Model => Some(7),
+            SortKey::Ev => Some(8),
+            SortKey::Score => Some(9),
+        }
+    }
+
+    /// Soonest expiry and nearest strike come first; everything else highest first
+    fn ascending(self) -> bool {
+        matches!(self, SortKey::TimeLeft | SortKey::Distance)
+    }
+
+    fn value(self, contract: &Contract, scores: &HashMap<i32, f64>, net_ev: bool) -> Option<f64> {
+        match self {
+            SortKey::Backend => None,
+            SortKey::Ev => Some(displayed_ev(contract, net_ev)),
+            SortKey::TimeLeft => contract.time_to_expiry_hours,
+            SortKey::Distance => Some(contract.distance_percent().abs()),
+            SortKey::Implied => contract.implied_probability,
+            SortKey::Model => contract.model_probability,
+            SortKey::Score => scores.get(&contract.id).copied(),
+        }
+    }
+}
+
+/// Single-key narrowing of the table on the Signals tab (Shift+1/2/3, Shift+0 clears)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickFilter {
+    All,
+    BuyYes,
+    BuyNo,
+    PositiveEv,
+}
+
+impl QuickFilter {
+    pub fn label(self) -> &'static str {
+        match self {
+            QuickFilter::All => "all signals",
+            QuickFilter::BuyYes => "BUY YES only",
+            QuickFilter::BuyNo => "BUY NO only",
+            QuickFilter::PositiveEv => "positive EV only",
+        }
+    }
+
+    fn matches(self, contract: &Contract, net_ev: bool) -> bool {
+        match self {
+            QuickFilter::All => true,
+            QuickFilter::BuyYes => contract.signal_type == "BUY YES",
+            QuickFilter::BuyNo => contract.signal_type == "BUY NO",
+            QuickFilter::PositiveEv => displayed_ev(contract, net_ev) > 0.0,
+        }
+    }
+}
 
 pub struct SignalsView {
     pub table_state: TableState,
+    visible_ids: Vec<i32>, // Contract IDs in display order, from the last render
+    visible_tickers: Vec<String>, // Tickers in the same order
+    selected_ticker: Option<String>, // Keeps the selection on the same contract across refreshes
+    offset: usize,         // First row in the viewport
+    anchor: Option<i32>,   // Contract to select once it's visible (after a rollover)
+    momentum: MomentumTracker,
+    muted: HashSet<i32>,       // Contract IDs currently muted (dimmed)
+    pinned: HashSet<i32>,      // Contract IDs pinned to the top
+    extreme_mode: bool,        // Only contracts past the extreme thresholds ('e')
+    marked: Vec<i32>,          // Contract IDs marked for the compare view
+    scores: HashMap<i32, f64>, // Composite score per contract ID
+    sort_key: SortKey,
+    sort_reversed: bool,                 // Flip the key's natural direction ('S')
+    extreme: ExtremeConfig,              // Thresholds for extreme mode
+    illiquid: HashSet<i32>, // Contract IDs where the default size would move the price too far
+    liquidity_scores: HashMap<i32, f64>, // Volume/open-interest score per contract ID
+    liquidity: LiquidityConfig, // Size and threshold behind the illiquid flag
+    my_model: Option<HashMap<i32, f64>>, // YES probability from the user's model override, when on
+    table: TableConfig,     // Optional quote columns and their spread thresholds
+    quick_filter: QuickFilter,
+    net_ev: bool,     // EV column after Kalshi's taker fee ('f')
+    compact: bool,    // Abbreviated cells, no Expiry/Current columns
+    area: Rect,       // Where the table was last drawn, for mouse clicks
+    page_rows: usize, // Table rows that fit, from the last render
 }
 
 impl SignalsView {
     pub fn new() -> Self {
         Self {
             table_state: TableState::default(),
+            visible_ids: Vec::new(),
+            visible_tickers: Vec::new(),
+            selected_ticker: None,
+            offset: 0,
+            anchor: None,
+            momentum: MomentumTracker::new(),
+            muted: HashSet::new(),
+            pinned: HashSet::new(),
+            extreme_mode: false,
+            marked: Vec::new(),
+            scores: HashMap::new(),
+            sort_key: SortKey::Backend,
+            sort_reversed: false,
+            extreme: ExtremeConfig::default(),
+            illiquid: HashSet::new(),
+            liquidity_scores: HashMap::new(),
+            liquidity: LiquidityConfig::default(),
+            my_model: None,
+            table: TableConfig::default(),
+            quick_filter: QuickFilter::All,
+            net_ev: false,
+            compact: false,
+            area: Rect::default(),
+            page_rows: 10,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.visible_ids.is_empty() {
+            return;
+        }
+        let next = match self.table_state.selected() {
+            Some(i) => (i + 1).min(self.visible_ids.len() - 1),
+            None => 0,
+        };
+        self.select_index(next);
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.visible_ids.is_empty() {
+            return;
+        }
+        let previous = match self.table_state.selected() {
+            Some(i) => i.saturating_sub(1),
+            None => 0,
+        };
+        self.select_index(previous);
+    }
+
+    pub fn page_down(&mut self) {
+        self.step(self.page_rows as isize);
+    }
+
+    pub fn page_up(&mut self) {
+        self.step(-(self.page_rows as isize));
+    }
+
+    pub fn select_first(&mut self) {
+        self.step(isize::MIN / 2);
+    }
+
+    pub fn select_last(&mut self) {
+        self.step(isize::MAX / 2);
+    }
+
+    fn step(&mut self, delta: isize) {
+        if self.visible_ids.is_empty() {
+            return;
+        }
+        let last = self.visible_ids.len() as isize - 1;
+        let current = self.table_state.selected().map_or(-1, |i| i as isize);
+        self.select_index((current + delta).clamp(0, last) as usize);
+    }
+
+    /// Select the row under a mouse click, if any
+    pub fn click(&mut self, row: u16) {
+        match super::table_row_at(self.area, self.offset, row) {
+            Some(index) if index < self.visible_ids.len() => self.select_index(index),
+            _ => {}
+        }
+    }
+
+    fn select_index(&mut self, index: usize) {
+        self.table_state.select(Some(index));
+        self.selected_ticker = self.visible_tickers.get(index).cloned();
+    }
+
+    /// Sample implied probabilities for the Δ column
+    pub fn record_quotes(&mut self, contracts: &[Contract]) {
+        self.momentum.record(contracts);
+    }
+
+    /// Contracts to dim as muted
+    pub fn set_muted(&mut self, ids: HashSet<i32>) {
+        self.muted = ids;
+    }
+
+    /// Contracts to keep at the top, marked with a pin
+    pub fn set_pinned(&mut self, ids: HashSet<i32>) {
+        self.pinned = ids;
+    }
+
+    pub fn set_extreme_mode(&mut self, enabled: bool) {
+        self.extreme_mode = enabled;
+    }
+
+    /// Flip the EV column to net of taker fees and back (sorting and the
+    /// positive-EV filter follow it); true when it's now net
+    pub fn toggle_net_ev(&mut self) -> bool {
+        self.net_ev = !self.net_ev;
+        self.net_ev
+    }
+
+    /// Contracts to highlight as marked for comparison
+    pub fn set_marked(&mut self, ids: Vec<i32>) {
+        self.marked = ids;
+    }
+
+    /// Composite scores for the Score column
+    pub fn set_scores(&mut self, scores: HashMap<i32, f64>) {
+        self.scores = scores;
+    }
+
+    pub fn set_extreme_thresholds(&mut self, extreme: ExtremeConfig) {
+        self.extreme = extreme;
+    }
+
+    pub fn set_liquidity_thresholds(&mut self, liquidity: LiquidityConfig) {
+        self.liquidity = liquidity;
+    }
+
+    pub fn set_table_config(&mut self, table: TableConfig) {
+        self.net_ev = table.net_ev;
+        self.table = table;
+    }
+
+    pub fn set_quick_filter(&mut self, filter: QuickFilter) {
+        self.quick_filter = filter;
+    }
+
+    pub fn set_compact(&mut self, compact: bool) {
+        self.compact = compact;
+    }
+
+    /// Probabilities for the My% column (None hides it)
+    pub fn set_my_model(&mut self, probabilities: Option<HashMap<i32, f64>>) {
+        self.my_model = probabilities;
+    }
+
+    /// Contracts to flag as too thin for the default size
+    pub fn set_illiquid(&mut self, ids: HashSet<i32>) {
+        self.illiquid = ids;
+    }
+
+    /// Liquidity scores for the Liq column (contracts without ticker data show --)
+    pub fn set_liquidity_scores(&mut self, scores: HashMap<i32, f64>) {
+        self.liquidity_scores = scores;
+    }
+
+    pub fn set_sort(&mut self, key: SortKey) {
+        self.sort_key = key;
+        self.sort_reversed = false;
+    }
+
+    /// Next sort column, in its natural direction
+    pub fn cycle_sort(&mut self) {
+        self.set_sort(self.sort_key.next());
+    }
+
+    pub fn reverse_sort(&mut self) {
+        if self.sort_key != SortKey::Backend {
+            self.sort_reversed = !self.sort_reversed;
         }
     }
 
-    pub fn render(&mut self, frame: &mut Frame, area: Rect, contracts: &[Contract], extreme_mode: bool, current_btc_price: f64) {
+    /// Move the selection to a contract on the next render
+    pub fn select_id(&mut self, id: i32) {
+        self.anchor = Some(id);
+    }
+
+    /// ID of the highlighted contract
+    pub fn selected_id(&self) -> Option<i32> {
+        self.table_state
+            .selected()
+            .and_then(|i| self.visible_ids.get(i).copied())
+    }
+
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        contracts: &[Contract],
+        current_btc_price: f64,
+        script: Option<&ScriptHooks>,
+        theme: &Theme,
+    ) {
+        let extreme_mode = self.extreme_mode;
+        let script_filter = script.filter(|s| s.has_filter());
+        self.area = area;
+
         // Filter contracts for extreme mode if enabled
-        let filtered_contracts: Vec<&Contract> = if extreme_mode {
+        let mut filtered_contracts: Vec<&Contract> = if extreme_mode {
             contracts
                 .iter()
                 .filter(|contract| {
-                    // Extreme mode criteria:
-                    // 1. Implied probability < 25% (market thinks unlikely)
-                    // 2. Requires move > 3%
+                    // A user script's filter() replaces the built-in criteria
+                    if let Some(passes) =
+                        script_filter.and_then(|s| s.filter(contract).ok().flatten())
+                    {
+                        return passes;
+                    }
+
+                    // Extreme mode criteria ([extreme] in config.toml):
+                    // 1. Implied probability below max_implied (market thinks unlikely)
+                    // 2. Requires a move of more than min_move_pct
                     // 3. Positive EV
                     let implied_prob = contract.implied_probability.unwrap_or(0.5);
                     let strike = contract.strike_price.unwrap_or(0.0);
@@ -36,85 +372,342 @@ impl SignalsView {
                         0.0
                     };
 
-                    implied_prob < 0.25
-                        && required_move_pct > 3.0
-                        && contract.expected_value > 0.0
+                    implied_prob < self.extreme.max_implied
+                        && required_move_pct > self.extreme.min_move_pct
+                        && displayed_ev(contract, self.net_ev) > 0.0
                 })
                 .collect()
         } else {
             contracts.iter().collect()
         };
+        filtered_contracts.retain(|contract| self.quick_filter.matches(contract, self.net_ev));
+
+        // Stable sort: ties keep backend order, contracts missing the value go last
+        if self.sort_key != SortKey::Backend {
+            let ascending = self.sort_key.ascending() != self.sort_reversed;
+            filtered_contracts.sort_by(|a, b| {
+                match (
+                    self.sort_key.value(a, &self.scores, self.net_ev),
+                    self.sort_key.value(b, &self.scores, self.net_ev),
+                ) {
+                    (Some(x), Some(y)) if ascending => x.total_cmp(&y),
+                    (Some(x), Some(y)) => y.total_cmp(&x),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            });
+        }
+        // Pinned contracts go first, in the order above
+        filtered_contracts.sort_by_key(|contract| !self.pinned.contains(&contract.id));
+
+        // Keep the selection on the same ticker as rows are added, removed or reordered,
+        // falling back to a valid row when it drops out of the list
+        self.visible_ids = filtered_contracts.iter().map(|c| c.id).collect();
+        self.visible_tickers = filtered_contracts
+            .iter()
+            .map(|c| c.ticker.clone())
+            .collect();
+        if let Some(id) = self.anchor.take() {
+            if let Some(index) = self.visible_ids.iter().position(|v| *v == id) {
+                self.select_index(index);
+            }
+        }
+        let by_ticker = self
+            .selected_ticker
+            .as_ref()
+            .and_then(|ticker| self.visible_tickers.iter().position(|t| t == ticker));
+        match (by_ticker, self.table_state.selected()) {
+            (Some(index), _) => self.table_state.select(Some(index)),
+            (None, Some(_)) if self.visible_ids.is_empty() => self.table_state.select(None),
+            (None, Some(i)) => self.select_index(i.min(self.visible_ids.len() - 1)),
+            (None, None) => {}
+        }
 
-        let header_cells = [
+        let mut header_cells = vec![
             "Strike",
             "Expiry",
             "Left",
             "Current",
             "Dist",
             "Imp%",
+            "Δ5m",
             "Mod%",
-            "EV",
+            if self.net_ev { "Net EV" } else { "EV" },
+            "Score",
+            "Liq",
             "Action",
         ];
+        // My% sits right after the backend's Mod%, pushing later columns along
+        if self.my_model.is_some() {
+            header_cells.insert(MY_MODEL_COLUMN, "My%");
+        }
+        // Quotes go in after, so they shift My% along with everything else
+        if self.table.quote_columns {
+            for (i, cell) in ["YES", "NO", "Sprd"].into_iter().enumerate() {
+                header_cells.insert(QUOTE_COLUMN + i, cell);
+            }
+        }
+        let sort_column = self.sort_key.column().map(|mut column| {
+            if self.my_model.is_some() && column >= MY_MODEL_COLUMN {
+                column += 1;
+            }
+            if self.table.quote_columns && column >= QUOTE_COLUMN {
+                column += QUOTE_COLUMN_COUNT;
+            }
+            column
+        });
+
+        let arrow = if self.sort_key.ascending() != self.sort_reversed {
+            "▲"
+        } else {
+            "▼"
+        };
+        let mut header_cells: Vec<String> = header_cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| match sort_column {
+                Some(column) if column == i => format!("{}{}", cell, arrow),
+                _ => cell.to_string(),
+            })
+            .collect();
+        if self.compact {
+            for column in COMPACT_HIDDEN {
+                header_cells.remove(column);
+            }
+        }
 
         let header = Row::new(header_cells)
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
             .bottom_margin(1);
 
-        let rows: Vec<Row> = filtered_contracts
+        // Only build rows for the visible window (borders + header take 4 lines)
+        let page = (area.height as usize).saturating_sub(4).max(1);
+        self.page_rows = page;
+        let total = filtered_contracts.len();
+        self.offset = self.offset.min(total.saturating_sub(page));
+        if let Some(selected) = self.table_state.selected() {
+            if selected < self.offset {
+                self.offset = selected;
+            } else if selected >= self.offset + page {
+                self.offset = selected + 1 - page;
+            }
+        }
+        let end = (self.offset + page).min(total);
+
+        let rows: Vec<Row> = filtered_contracts[self.offset..end]
             .iter()
             .map(|contract| {
-                let _ev_color = get_ev_color(contract.expected_value);
-                let _dist_color = if contract.is_above_strike() {
-                    Color::Green
+                let ev = displayed_ev(contract, self.net_ev);
+                let ev_color = get_ev_color(ev, theme);
+                let dist_color = if contract.is_above_strike() {
+                    theme.positive
                 } else {
-                    Color::Red
+                    theme.negative
+                };
+
+                let time_left_color = if contract.is_near_expiry() {
+                    theme.caution
+                } else {
+                    theme.text
+                };
+
+                // Green when the market is moving toward the model, red when away
+                let momentum_cell = match self.momentum.momentum(contract) {
+                    Some(m) => {
+                        let (arrow, color) = match m.drift {
+                            Drift::Toward => {
+                                (if m.delta > 0.0 { "▲" } else { "▼" }, theme.positive)
+                            }
+                            Drift::Away => (if m.delta > 0.0 { "▲" } else { "▼" }, theme.negative),
+                            Drift::Flat => ("·", theme.muted),
+                        };
+                        Cell::from(format!("{}{:+.1}", arrow, m.delta * 100.0))
+                            .style(Style::default().fg(color))
+                    }
+                    None => Cell::from("--").style(Style::default().fg(theme.muted)),
                 };
 
-                let _time_left_color = if contract.is_near_expiry() {
-                    Color::LightRed
+                let pin = if self.pinned.contains(&contract.id) {
+                    "📌"
+                } else {
+                    ""
+                };
+                let strike_cell = if self.illiquid.contains(&contract.id) {
+                    Cell::from(format!("{}{} ⚠", pin, contract.strike_display()))
+                        .style(Style::default().fg(theme.caution))
                 } else {
-                    Color::White
+                    Cell::from(format!("{}{}", pin, contract.strike_display()))
                 };
 
-                Row::new(vec![
-                    contract.strike_display(),
-                    contract.expiry_display(),
-                    contract.time_left_display(),
-                    contract.btc_price_display(),
-                    format_distance(contract.distance_dollars(), contract.distance_percent()),
-                    format_opt_percent(contract.implied_probability),
-                    format_opt_percent(contract.model_probability),
-                    contract.ev_display(),
-                    contract.signal_type.clone(),
-                ])
-                .style(Style::default().fg(Color::White))
-                .height(1)
+                let mut cells = vec![
+                    strike_cell,
+                    Cell::from(contract.expiry_display()),
+                    Cell::from(contract.time_left_display())
+                        .style(Style::default().fg(time_left_color)),
+                    Cell::from(contract.btc_price_display()),
+                    Cell::from(if self.compact {
+                        format_distance_percent(
+                            contract.distance_dollars(),
+                            contract.distance_percent(),
+                        )
+                    } else {
+                        format_distance(contract.distance_dollars(), contract.distance_percent())
+                    })
+                    .style(Style::default().fg(dist_color)),
+                    Cell::from(format_opt_percent(contract.implied_probability)),
+                    momentum_cell,
+                    Cell::from(format_opt_percent(contract.model_probability)),
+                    Cell::from(format!("{:+.1}%", ev * 100.0)).style(Style::default().fg(ev_color)),
+                    match self.scores.get(&contract.id) {
+                        Some(score) => Cell::from(format!("{:.0}", score))
+                            .style(Style::default().fg(get_score_color(*score, theme))),
+                        None => Cell::from("--").style(Style::default().fg(theme.muted)),
+                    },
+                    match self.liquidity_scores.get(&contract.id) {
+                        Some(score) => Cell::from(format!("{:.0}", score))
+                            .style(Style::default().fg(get_score_color(*score, theme))),
+                        None => Cell::from("--").style(Style::default().fg(theme.muted)),
+                    },
+                    Cell::from(if self.compact {
+                        abbreviate_action(&contract.signal_type).to_string()
+                    } else {
+                        contract.signal_type.clone()
+                    })
+                    .style(
+                        Style::default()
+                            .fg(get_action_color(&contract.signal_type, theme))
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                ];
+                if let Some(my_model) = &self.my_model {
+                    let cell = match my_model.get(&contract.id) {
+                        Some(p) => Cell::from(format!("{:.1}%", p * 100.0))
+                            .style(Style::default().fg(theme.accent)),
+                        None => Cell::from("--").style(Style::default().fg(theme.muted)),
+                    };
+                    cells.insert(MY_MODEL_COLUMN, cell);
+                }
+                if self.table.quote_columns {
+                    let style = match contract.spread() {
+                        Some(spread) => {
+                            Style::default().fg(spread_color(spread, &self.table, theme))
+                        }
+                        None => Style::default().fg(theme.muted),
+                    };
+                    let quote_cells = [
+                        format_cents(contract.yes_price),
+                        format_cents(contract.no_price),
+                        format_cents(contract.spread()),
+                    ];
+                    for (i, text) in quote_cells.into_iter().enumerate() {
+                        cells.insert(QUOTE_COLUMN + i, Cell::from(text).style(style));
+                    }
+                }
+                if self.compact {
+                    for column in COMPACT_HIDDEN {
+                        cells.remove(column);
+                    }
+                }
+
+                Row::new(cells)
+                    .style(if self.marked.contains(&contract.id) {
+                        Style::default()
+                            .fg(theme.accent)
+                            .add_modifier(Modifier::BOLD)
+                    } else if self.muted.contains(&contract.id) {
+                        Style::default()
+                            .fg(theme.faint)
+                            .add_modifier(Modifier::ITALIC)
+                    } else {
+                        Style::default().fg(theme.text)
+                    })
+                    .height(1)
             })
             .collect();
 
-        let widths = [
-            Constraint::Length(10), // Strike
-            Constraint::Length(22), // Expiry (now shows UTC + EST)
-            Constraint::Length(8),  // Left
-            Constraint::Length(10), // Current
-            Constraint::Length(15), // Dist
-            Constraint::Length(7),  // Imp%
-            Constraint::Length(7),  // Mod%
-            Constraint::Length(8),  // EV
-            Constraint::Length(10), // Action
-        ];
+        let mut widths = if self.compact {
+            vec![
+                Constraint::Length(9), // Strike
+                Constraint::Length(0), // Expiry (dropped below)
+                Constraint::Length(7), // Left
+                Constraint::Length(0), // Current (dropped below)
+                Constraint::Length(8), // Dist
+                Constraint::Length(6), // Imp%
+                Constraint::Length(6), // Δ5m
+                Constraint::Length(6), // Mod%
+                Constraint::Length(7), // EV
+                Constraint::Length(5), // Score
+                Constraint::Length(4), // Liq
+                Constraint::Length(4), // Action
+            ]
+        } else {
+            vec![
+                Constraint::Length(10), // Strike
+                Constraint::Length(22), // Expiry (now shows UTC + EST)
+                Constraint::Length(8),  // Left
+                Constraint::Length(10), // Current
+                Constraint::Length(15), // Dist
+                Constraint::Length(7),  // Imp%
+                Constraint::Length(7),  // Δ5m
+                Constraint::Length(7),  // Mod%
+                Constraint::Length(8),  // EV
+                Constraint::Length(6),  // Score
+                Constraint::Length(5),  // Liq
+                Constraint::Length(10), // Action
+            ]
+        };
+        if self.my_model.is_some() {
+            widths.insert(
+                MY_MODEL_COLUMN,
+                Constraint::Length(if self.compact { 6 } else { 7 }),
+            );
+        }
+        if self.table.quote_columns {
+            for i in 0..QUOTE_COLUMN_COUNT {
+                widths.insert(
+                    QUOTE_COLUMN + i,
+                    Constraint::Length(if self.compact { 4 } else { 5 }),
+                );
+            }
+        }
+        if self.compact {
+            for column in COMPACT_HIDDEN {
+                widths.remove(column);
+            }
+        }
 
-        let title = if extreme_mode {
-            " 🎲 EXTREME VOLATILITY OPPORTUNITIES (Implied <25% | Move >3%) "
+        let mut title = if extreme_mode && script_filter.is_some() {
+            " 🎲 EXTREME VOLATILITY OPPORTUNITIES (Script filter) ".to_string()
+        } else if extreme_mode {
+            format!(
+                " 🎲 EXTREME VOLATILITY OPPORTUNITIES (Implied <{:.0}% | Move >{}%) ",
+                self.extreme.max_implied * 100.0,
+                self.extreme.min_move_pct
+            )
+        } else if self.compact {
+            " SIGNALS ".to_string()
         } else {
-            " ACTIVE SIGNALS (Bitcoin Hourly Contracts) "
+            " ACTIVE SIGNALS (Bitcoin Hourly Contracts) ".to_string()
         };
+        if self.quick_filter != QuickFilter::All {
+            title.push_str(&format!("│ {} ", self.quick_filter.label()));
+        }
+        if !self.illiquid.is_empty() {
+            title.push_str(&format!(
+                "│ ⚠ {} contracts would move the price >{}% ",
+                self.liquidity.default_size, self.liquidity.max_impact_pct
+            ));
+        }
 
         let title_color = if extreme_mode {
-            Color::Red
+            theme.negative
         } else {
-            Color::White
+            theme.text
         };
 
         let table = Table::new(rows, widths)
@@ -125,23 +718,69 @@ impl SignalsView {
                     .title(title)
                     .border_style(Style::default().fg(title_color)),
             )
-            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_style(Style::default().bg(theme.selection))
             .highlight_symbol("▶ ");
 
-        frame.render_stateful_widget(table, area, &mut self.table_state);
+        let mut window_state = TableState::default()
+            .with_selected(self.table_state.selected().map(|i| i - self.offset));
+        frame.render_stateful_widget(table, area, &mut window_state);
+
+        if total > page {
+            // Thumb reaches the bottom once the last page is in view
+            let mut scrollbar_state = ScrollbarState::new(total - page + 1)
+                .viewport_content_length(page)
+                .position(self.offset);
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None);
+            let track = Rect {
+                y: area.y + 1,
+                height: area.height.saturating_sub(2),
+                ..area
+            };
+            frame.render_stateful_widget(scrollbar, track, &mut scrollbar_state);
+        }
+    }
+}
+
+/// EV for the column: the backend's, or net of the taker fee when that's on
+/// and can be worked out
+fn displayed_ev(contract: &Contract, net_ev: bool) -> f64 {
+    if net_ev {
+        fees::net_ev(contract).unwrap_or(contract.expected_value)
+    } else {
+        contract.expected_value
     }
 }
 
-fn get_ev_color(ev: f64) -> Color {
+fn get_ev_color(ev: f64, theme: &Theme) -> Color {
     let ev_percent = ev * 100.0;
     if ev_percent >= 5.0 {
-        Color::LightGreen
+        theme.strong_positive
     } else if ev_percent >= 3.0 {
-        Color::Green
+        theme.positive
     } else if ev_percent >= 1.0 {
-        Color::Yellow
+        theme.highlight
+    } else {
+        theme.muted
+    }
+}
+
+fn get_action_color(signal_type: &str, theme: &Theme) -> Color {
+    match signal_type {
+        "BUY YES" => theme.positive,
+        "BUY NO" => theme.negative,
+        _ => theme.highlight,
+    }
+}
+
+fn get_score_color(score: f64, theme: &Theme) -> Color {
+    if score >= 70.0 {
+        theme.strong_positive
+    } else if score >= 50.0 {
+        theme.highlight
     } else {
-        Color::Gray
+        theme.muted
     }
 }
 
@@ -153,9 +792,41 @@ fn format_distance(dollars: f64, percent: f64) -> String {
     format!("{}{:.0} ({}{:.2}%)", sign, dollars, sign, percent)
 }
 
+/// Compact Dist cell: the percentage only
+fn format_distance_percent(dollars: f64, percent: f64) -> String {
+    if dollars == 0.0 && percent == 0.0 {
+        return "N/A".to_string();
+    }
+    format!("{:+.2}%", percent)
+}
+
+/// Compact Action cell: "BUY YES" -> "YES", "BUY NO" -> "NO"
+fn abbreviate_action(signal_type: &str) -> &str {
+    signal_type.strip_prefix("BUY ").unwrap_or(signal_type)
+}
+
 fn format_opt_percent(prob: Option<f64>) -> String {
     match prob {
         Some(p) => format!("{:.1}%", p * 100.0),
         None => "N/A".to_string(),
     }
 }
+
+/// Dollar price as whole cents ("45¢")
+fn format_cents(price: Option<f64>) -> String {
+    match price {
+        Some(p) => format!("{:.0}¢", p * 100.0),
+        None => "--".to_string(),
+    }
+}
+
+/// Tight spreads green, wide ones red, per `[table]`
+fn spread_color(spread: f64, table: &TableConfig, theme: &Theme) -> Color {
+    if spread <= table.tight_spread {
+        theme.positive
+    } else if spread >= table.wide_spread {
+        theme.negative
+    } else {
+        theme.highlight
+    }
+}
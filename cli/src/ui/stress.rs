@@ -0,0 +1,124 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::stress::{StressReport, DEFAULT_MOVES, DEFAULT_VOL_SHOCKS};
+use crate::ui::theme::Theme;
+
+/// Popup grid of portfolio P&L under spot (rows) and vol (columns) shocks
+pub struct StressView;
+
+impl StressView {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, report: Option<&StressReport>, theme: &Theme) {
+        // Centered popup (70% width, 60% height)
+        let area = frame.size();
+        let popup_width = (area.width * 70) / 100;
+        let popup_height = (area.height * 60) / 100;
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.special))
+            .title(" STRESS TEST (P&L impact by spot move × vol shock) ")
+            .title_style(
+                Style::default()
+                    .fg(theme.special)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        let report = match report {
+            Some(report) if report.positions > 0 => report,
+            Some(_) => {
+                let paragraph = Paragraph::new("No priceable open positions.")
+                    .block(block)
+                    .alignment(ratatui::layout::Alignment::Center);
+                frame.render_widget(paragraph, popup_area);
+                return;
+            }
+            None => {
+                let paragraph = Paragraph::new("Loading positions...")
+                    .block(block)
+                    .alignment(ratatui::layout::Alignment::Center);
+                frame.render_widget(paragraph, popup_area);
+                return;
+            }
+        };
+
+        let mut header_cells = vec![Cell::from("Spot \\ Vol")];
+        header_cells.extend(
+            DEFAULT_VOL_SHOCKS
+                .iter()
+                .map(|vol| Cell::from(format!("{:+.0}%", vol * 100.0))),
+        );
+        let header = Row::new(header_cells)
+            .style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .bottom_margin(1);
+
+        let rows: Vec<Row> = DEFAULT_MOVES
+            .iter()
+            .map(|&spot_move| {
+                let mut cells = vec![Cell::from(format!("{:+.1}%", spot_move * 100.0))
+                    .style(Style::default().fg(theme.accent))];
+
+                for &vol_change in &DEFAULT_VOL_SHOCKS {
+                    let cell = match report.get(spot_move, vol_change) {
+                        Some(result) => {
+                            let color = if result.pnl_change > 0.005 {
+                                theme.positive
+                            } else if result.pnl_change < -0.005 {
+                                theme.negative
+                            } else {
+                                theme.muted
+                            };
+                            Cell::from(format!("${:+.2}", result.pnl_change))
+                                .style(Style::default().fg(color))
+                        }
+                        None => Cell::from("--"),
+                    };
+                    cells.push(cell);
+                }
+
+                Row::new(cells)
+            })
+            .collect();
+
+        let mut widths = vec![Constraint::Length(12)];
+        widths.extend(DEFAULT_VOL_SHOCKS.iter().map(|_| Constraint::Length(12)));
+
+        let title = Line::from(vec![
+            Span::raw(format!(" {} positions │ model value ", report.positions)),
+            Span::styled(
+                format!("${:.2}", report.base_value),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" │ [w/ESC] close "),
+        ]);
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(block.title_bottom(title));
+
+        frame.render_widget(table, popup_area);
+    }
+}
@@ -0,0 +1,126 @@
+use ratatui::style::Color;
+
+use crate::config::ThemeName;
+
+/// Colors the dashboard draws with, by role. Views take a `&Theme` rather
+/// than naming colors, so a light terminal or a high-contrast palette only
+/// changes this table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: ThemeName,
+    /// Values and plain text that should stand out from labels
+    pub text: Color,
+    /// Labels, secondary values, HOLD and neutral readings
+    pub muted: Color,
+    /// Borders, dividers, hints and anything greyed out
+    pub faint: Color,
+    /// Titles, key hints and the app's own chrome
+    pub accent: Color,
+    /// Headings, warnings and values that need attention
+    pub highlight: Color,
+    /// Gains, BUY YES, healthy states
+    pub positive: Color,
+    /// Exceptional edges and gains
+    pub strong_positive: Color,
+    /// Losses, BUY NO, errors
+    pub negative: Color,
+    /// Elevated risk, short of an error
+    pub caution: Color,
+    /// Second series on charts and comparisons
+    pub special: Color,
+    /// Informational badges (read-only, implied vol)
+    pub info: Color,
+    pub secondary: Color,
+    /// Background of the selected row
+    pub selection: Color,
+    /// Unfilled part of gauges
+    pub gauge_track: Color,
+}
+
+impl Theme {
+    pub fn new(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::HighContrast => Self::high_contrast(),
+        }
+    }
+
+    /// The original palette, for dark terminal backgrounds
+    pub fn dark() -> Self {
+        Self {
+            name: ThemeName::Dark,
+            text: Color::White,
+            muted: Color::Gray,
+            faint: Color::DarkGray,
+            accent: Color::Cyan,
+            highlight: Color::Yellow,
+            positive: Color::Green,
+            strong_positive: Color::LightGreen,
+            negative: Color::Red,
+            caution: Color::LightRed,
+            special: Color::Magenta,
+            info: Color::LightBlue,
+            secondary: Color::Blue,
+            selection: Color::DarkGray,
+            gauge_track: Color::Black,
+        }
+    }
+
+    /// Darker, saturated colors that read on white and pale backgrounds
+    pub fn light() -> Self {
+        Self {
+            name: ThemeName::Light,
+            text: Color::Black,
+            muted: Color::Rgb(80, 80, 80),
+            faint: Color::Rgb(140, 140, 140),
+            accent: Color::Rgb(0, 95, 175),
+            highlight: Color::Rgb(165, 95, 0),
+            positive: Color::Rgb(0, 130, 0),
+            strong_positive: Color::Rgb(0, 95, 0),
+            negative: Color::Rgb(190, 0, 0),
+            caution: Color::Rgb(205, 80, 0),
+            special: Color::Rgb(150, 0, 150),
+            info: Color::Rgb(0, 110, 190),
+            secondary: Color::Rgb(0, 60, 150),
+            selection: Color::Rgb(215, 215, 215),
+            gauge_track: Color::Rgb(225, 225, 225),
+        }
+    }
+
+    /// Bright colors only, white text and a blue selection bar
+    pub fn high_contrast() -> Self {
+        Self {
+            name: ThemeName::HighContrast,
+            text: Color::White,
+            muted: Color::White,
+            faint: Color::Gray,
+            accent: Color::LightCyan,
+            highlight: Color::LightYellow,
+            positive: Color::LightGreen,
+            strong_positive: Color::LightGreen,
+            negative: Color::LightRed,
+            caution: Color::LightMagenta,
+            special: Color::LightMagenta,
+            info: Color::LightBlue,
+            secondary: Color::LightBlue,
+            selection: Color::Blue,
+            gauge_track: Color::Black,
+        }
+    }
+
+    /// The next palette, for the `u` key
+    pub fn next(&self) -> Self {
+        Self::new(match self.name {
+            ThemeName::Dark => ThemeName::Light,
+            ThemeName::Light => ThemeName::HighContrast,
+            ThemeName::HighContrast => ThemeName::Dark,
+        })
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
@@ -0,0 +1,561 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::api::models::OrderBook;
+use crate::api::Contract;
+use crate::checklist::Checklist;
+use crate::config::ChecklistConfig;
+use crate::fees;
+use crate::order_guard::GuardVerdict;
+use crate::sizing::{self, SizePreset};
+use crate::ui::dialog::Dialog;
+use crate::ui::theme::Theme;
+
+/// Price levels shown either side of the quote on the ladder
+const LADDER_LEVELS: i32 = 3;
+
+/// Order ticket for the highlighted signal, with quick-size presets on keys 1-9
+/// and a price ladder around the quote for picking a limit
+pub struct TradeDialog {
+    pub contract: Contract,
+    pub size: i32,
+    /// Signal's entry price in cents, where the ladder starts
+    quote: Option<i32>,
+    /// Price picked on the ladder, in cents
+    price: Option<i32>,
+    /// Depth for the ladder (None when the backend has no book for it)
+    book: Option<OrderBook>,
+    presets: Vec<(SizePreset, Option<i32>)>, // Preset and the contracts it resolves to
+    bankroll: Option<f64>,
+    guard: GuardVerdict,
+    /// Pre-trade checklist, once Enter was pressed on a large enough order
+    pub checklist: Option<Checklist>,
+    result: Option<Result<String, String>>,
+}
+
+impl TradeDialog {
+    pub fn new(
+        contract: Contract,
+        presets: &[SizePreset],
+        bankroll: Option<f64>,
+        guard: GuardVerdict,
+        book: Option<OrderBook>,
+    ) -> Self {
+        let presets: Vec<(SizePreset, Option<i32>)> = presets
+            .iter()
+            .take(9)
+            .map(|preset| (*preset, preset.contracts(&contract, bankroll)))
+            .collect();
+
+        // Start on the first preset that resolves to a tradeable size
+        let size = presets
+            .iter()
+            .find_map(|(_, size)| size.filter(|n| *n > 0))
+            .unwrap_or(1);
+        let quote = sizing::entry_price(&contract).map(|p| (p * 100.0).round() as i32);
+
+        Self {
+            contract,
+            size,
+            quote,
+            price: quote,
+            book,
+            presets,
+            bankroll,
+            guard,
+            checklist: None,
+            result: None,
+        }
+    }
+
+    /// Switch to the checklist for the current size
+    pub fn start_checklist(&mut self, config: &ChecklistConfig) {
+        self.checklist = Some(Checklist::new(
+            config,
+            &self.contract,
+            self.size,
+            self.bankroll,
+        ));
+    }
+
+    /// Apply preset `index` (0-based); false if it can't be resolved
+    pub fn select_preset(&mut self, index: usize) -> bool {
+        match self.presets.get(index) {
+            Some((_, Some(size))) if *size > 0 => {
+                self.size = *size;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn adjust(&mut self, delta: i32) {
+        self.size = (self.size + delta).max(1);
+    }
+
+    /// Step the ladder price by `delta` cents, staying inside 1-99¢
+    pub fn move_price(&mut self, delta: i32) {
+        if let Some(price) = self.price.as_mut() {
+            *price = (*price + delta).clamp(1, 99);
+        }
+    }
+
+    /// Limit in cents when the ladder was moved off the quote; None sends the
+    /// order at the signal's price
+    pub fn limit_price(&self) -> Option<i32> {
+        self.price.filter(|price| Some(*price) != self.quote)
+    }
+
+    fn is_no(&self) -> bool {
+        self.contract.signal_type.contains("NO")
+    }
+
+    pub fn can_submit(&self) -> bool {
+        self.result.is_none() && !self.guard.blocked && self.size > 0
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// Last look at the order before it's sent; at `type_size_at` contracts or
+    /// more (0 = never) the size has to be typed in to confirm
+    pub fn confirmation(&self, type_size_at: i32, theme: &Theme) -> Dialog {
+        let side = self.contract.signal_type.trim_start_matches("BUY ");
+        let (price, kind) = match self.limit_price() {
+            Some(cents) => (Some(cents), "limit"),
+            None => (self.quote, "at the signal price"),
+        };
+        let cost = price
+            .map(|cents| format!("${:.2}", cents as f64 / 100.0 * self.size as f64))
+            .unwrap_or_else(|| "--".to_string());
+
+        let body = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!(
+                        "  Buy {} {} x{}",
+                        side,
+                        self.contract.strike_display(),
+                        self.size
+                    ),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  ({})", self.contract.ticker),
+                    Style::default().fg(theme.muted),
+                ),
+            ]),
+            Line::from(format!(
+                "  {} {}, costs about {}; pays ${} if {} wins",
+                price
+                    .map(|cents| format!("{}¢", cents))
+                    .unwrap_or_else(|| "--".to_string()),
+                kind,
+                cost,
+                self.size,
+                side
+            )),
+        ];
+
+        let dialog = if type_size_at > 0 && self.size >= type_size_at {
+            Dialog::input(
+                "CONFIRM ORDER",
+                body,
+                "send order",
+                "Type the size to confirm:",
+                Some(self.size.to_string()),
+            )
+        } else {
+            Dialog::confirm("CONFIRM ORDER", body, "send order")
+        };
+        dialog.width(76)
+    }
+
+    pub fn set_result(&mut self, result: Result<String, String>) {
+        self.result = Some(result);
+    }
+
+    pub fn render(&self, frame: &mut Frame, theme: &Theme) {
+        if let Some(checklist) = &self.checklist {
+            self.render_checklist(frame, checklist, theme);
+            return;
+        }
+
+        // Centered popup (76 columns, tall enough for nine presets and the ladder)
+        let area = frame.size();
+        let popup_width = 76.min(area.width);
+        let popup_height = (25 + self.presets.len() as u16).min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let label = |text: &'static str| {
+            Span::styled(format!("  {:<12}", text), Style::default().fg(theme.muted))
+        };
+        let price = self.price.map(|cents| cents as f64 / 100.0);
+
+        let signal_color = if self.contract.signal_type.contains("YES") {
+            theme.positive
+        } else {
+            theme.negative
+        };
+
+        let mut lines = vec![
+            Line::from(vec![
+                label("Contract"),
+                Span::styled(
+                    self.contract.ticker.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                label("Signal"),
+                Span::styled(
+                    self.contract.signal_type.clone(),
+                    Style::default()
+                        .fg(signal_color)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(
+                    "  @ {}  EV {}",
+                    self.contract.strike_display(),
+                    self.contract.ev_display()
+                )),
+            ]),
+            Line::from(vec![
+                label("Quote"),
+                Span::raw(
+                    self.quote
+                        .map(|cents| format!("{}¢", cents))
+                        .unwrap_or_else(|| "--".to_string()),
+                ),
+                Span::raw(format!("   Left: {}", self.contract.time_left_display())),
+            ]),
+            Line::from(vec![
+                label("Bankroll"),
+                Span::raw(
+                    self.bankroll
+                        .map(|b| format!("${:.2}", b))
+                        .unwrap_or_else(|| "--".to_string()),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        for (i, (preset, size)) in self.presets.iter().enumerate() {
+            let resolved = match size {
+                Some(n) if *n > 0 => format!("{} contracts", n),
+                Some(_) => "0 (no edge)".to_string(),
+                None => "n/a".to_string(),
+            };
+            let selected = *size == Some(self.size);
+            let style = if selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  [{}] ", i + 1),
+                    Style::default().fg(theme.highlight),
+                ),
+                Span::styled(format!("{:<18}", preset.label()), style),
+                Span::styled(format!("→ {}", resolved), style),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.extend(self.ladder_lines(theme));
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            label("Size"),
+            Span::styled(
+                format!("{} contracts", self.size),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(match price {
+                Some(p) => format!("  (cost ${:.2})", p * self.size as f64),
+                None => String::new(),
+            }),
+        ]));
+        lines.extend(self.ev_lines(price, theme));
+
+        for warning in &self.guard.warnings {
+            let color = if self.guard.blocked {
+                theme.negative
+            } else {
+                theme.highlight
+            };
+            lines.push(Line::from(Span::styled(
+                format!("  ⚠ {}", warning),
+                Style::default().fg(color),
+            )));
+        }
+
+        match &self.result {
+            Some(Ok(message)) => lines.push(Line::from(Span::styled(
+                format!("  ✅ {}", message),
+                Style::default().fg(theme.positive),
+            ))),
+            Some(Err(message)) => lines.push(Line::from(Span::styled(
+                format!("  ❌ {}", message),
+                Style::default().fg(theme.negative),
+            ))),
+            None => {}
+        }
+
+        let hint = if self.is_done() {
+            " [any key] close "
+        } else if self.guard.blocked {
+            " Blocked by order guard │ [ESC] cancel "
+        } else {
+            " [1-9] preset │ [+/-] size │ [↑/↓] price │ [Enter] submit │ [ESC] cancel "
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.highlight))
+            .title(" TRADE ")
+            .title_style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(hint);
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+
+    /// Levels around the quote, highest first, with the bid and offered depth
+    /// for the signal's side at each
+    fn ladder_lines(&self, theme: &Theme) -> Vec<Line<'static>> {
+        let (Some(quote), Some(price)) = (self.quote, self.price) else {
+            return vec![Line::from(Span::styled(
+                "  No quote to build a price ladder from",
+                Style::default().fg(theme.muted),
+            ))];
+        };
+
+        // Follow the selection if it walks off the default window
+        let center = quote.clamp(price - LADDER_LEVELS, price + LADDER_LEVELS);
+        let depth = |qty: i32| {
+            if qty > 0 {
+                qty.to_string()
+            } else {
+                "·".to_string()
+            }
+        };
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled(
+                format!("  {:<12}", "Ladder"),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(
+                match self.book {
+                    Some(_) => format!("{:>6} {:>8} {:>8}", "price", "bid", "offer"),
+                    None => format!("{:>6}   depth n/a", "price"),
+                },
+                Style::default().fg(theme.faint),
+            ),
+        ])];
+        for level in ((center - LADDER_LEVELS)..=(center + LADDER_LEVELS)).rev() {
+            if !(1..=99).contains(&level) {
+                continue;
+            }
+            let selected = level == price;
+            let style = if selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let book = match &self.book {
+                Some(book) => format!(
+                    " {:>8} {:>8}",
+                    depth(book.bid_at(self.is_no(), level)),
+                    depth(book.offered_at(self.is_no(), level))
+                ),
+                None => String::new(),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<12}", if selected { "▶" } else { "" }), style),
+                Span::styled(format!("{:>5}¢{}", level, book), style),
+                Span::styled(
+                    if level == quote { "  ← quote" } else { "" },
+                    Style::default().fg(theme.muted),
+                ),
+            ]));
+        }
+        lines
+    }
+
+    /// Expected value and breakeven at the ladder price against the model
+    fn ev_lines(&self, price: Option<f64>, theme: &Theme) -> Vec<Line<'static>> {
+        let label = |text: &'static str| {
+            Span::styled(format!("  {:<12}", text), Style::default().fg(theme.muted))
+        };
+        let (Some(price), Some(win)) = (price, sizing::win_probability(&self.contract)) else {
+            return vec![Line::from(vec![
+                label("EV"),
+                Span::raw("-- (no model probability)"),
+            ])];
+        };
+
+        // A binary contract bought at p pays 1 with the model's win probability
+        let ev = win - price;
+        let color = if ev > 0.0 {
+            theme.positive
+        } else {
+            theme.negative
+        };
+        let fee = fees::taker_fee(self.size, price);
+        let net = ev * self.size as f64 - fee;
+        let net_color = if net > 0.0 {
+            theme.positive
+        } else {
+            theme.negative
+        };
+        vec![
+            Line::from(vec![
+                label("EV"),
+                Span::styled(
+                    format!(
+                        "{:+.1}¢/contract (${:+.2} total) at {}¢",
+                        ev * 100.0,
+                        ev * self.size as f64,
+                        (price * 100.0).round()
+                    ),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                label("Fees"),
+                Span::raw(format!("${:.2} taker → ", fee)),
+                Span::styled(
+                    format!("${:+.2} net EV", net),
+                    Style::default().fg(net_color),
+                ),
+            ]),
+            Line::from(vec![
+                label("Breakeven"),
+                Span::raw(format!(
+                    "{:.0}% win rate, {:.1}% after fees (model {:.0}%)",
+                    price * 100.0,
+                    (price + fees::fee_per_contract(price)) * 100.0,
+                    win * 100.0
+                )),
+            ]),
+        ]
+    }
+
+    fn render_checklist(&self, frame: &mut Frame, checklist: &Checklist, theme: &Theme) {
+        let area = frame.size();
+        let popup_width = 64.min(area.width);
+        let popup_height = (10 + checklist.questions.len() as u16).min(area.height);
+        let popup_area = Rect {
+            x: (area.width - popup_width) / 2,
+            y: (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let marker = |selected: bool| if selected { "▶ " } else { "  " };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("  {} x{}", self.contract.ticker, self.size),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("  {}", self.contract.signal_type)),
+            ]),
+            Line::from(""),
+        ];
+
+        for (i, question) in checklist.questions.iter().enumerate() {
+            let (answer, color) = match question.answer {
+                Some(true) => ("[y]", theme.positive),
+                Some(false) => ("[n]", theme.negative),
+                None => ("[ ]", theme.muted),
+            };
+            let text_style = if i == checklist.selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::raw(marker(i == checklist.selected)),
+                Span::styled(format!("{} ", answer), Style::default().fg(color)),
+                Span::styled(format!("{:<26}", question.text), text_style),
+                Span::styled(
+                    question.hint.clone().unwrap_or_default(),
+                    Style::default().fg(theme.muted),
+                ),
+            ]));
+        }
+
+        if let Some(reason) = &checklist.reason {
+            let editing = checklist.editing_reason();
+            let cursor = if editing { "_" } else { "" };
+            lines.push(Line::from(vec![
+                Span::raw(marker(editing)),
+                Span::styled("Reason: ", Style::default().fg(theme.muted)),
+                Span::styled(
+                    format!("{}{}", reason, cursor),
+                    Style::default().fg(theme.accent),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        match checklist.blocker() {
+            Some(blocker) => lines.push(Line::from(Span::styled(
+                format!("  {}", blocker),
+                Style::default().fg(theme.highlight),
+            ))),
+            None => lines.push(Line::from(Span::styled(
+                "  Ready to submit",
+                Style::default().fg(theme.positive),
+            ))),
+        }
+
+        let hint = if checklist.editing_reason() {
+            " type reason │ [↑] back │ [Enter] submit │ [ESC] ticket "
+        } else {
+            " [y/n] answer │ [↑/↓] move │ [Enter] submit │ [ESC] ticket "
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.highlight))
+            .title(" PRE-TRADE CHECKLIST ")
+            .title_style(
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(hint);
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+    }
+}
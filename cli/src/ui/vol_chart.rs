@@ -0,0 +1,184 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::Style,
+    symbols::Marker,
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
+    Frame,
+};
+
+use crate::ui::theme::Theme;
+
+/// One vol reading: seconds before now, RV and IV in vol points
+#[derive(Debug, Clone, Copy)]
+pub struct VolPoint {
+    pub secs: f64,
+    pub realized: f64,
+    pub implied: f64,
+}
+
+/// Dots filling the gap between the two lines
+#[derive(Default)]
+struct Shading {
+    /// IV over RV
+    expensive: Vec<(f64, f64)>,
+    /// IV under RV
+    cheap: Vec<(f64, f64)>,
+}
+
+/// RV and IV on one axis, with the gap between them shaded by sign of the
+/// premium: yellow where IV is over RV (vol expensive), green where it is
+/// under (vol cheap)
+pub struct VolChart;
+
+impl VolChart {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `points` are oldest first. While the endpoint is empty (`available`
+    /// false) the older readings are still drawn, without the premium shading.
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        points: &[VolPoint],
+        available: bool,
+        theme: &Theme,
+    ) {
+        let title = match points.last() {
+            _ if !available => " IV vs RV │ unavailable ".to_string(),
+            Some(last) => format!(
+                " IV vs RV │ premium {:+.1}pp ",
+                last.implied - last.realized
+            ),
+            None => " IV vs RV ".to_string(),
+        };
+        let block = Block::default().borders(Borders::ALL).title(title);
+
+        if points.len() < 2 {
+            let message = if available {
+                "Collecting vol readings..."
+            } else {
+                "Volatility unavailable"
+            };
+            frame.render_widget(
+                Paragraph::new(message)
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(theme.faint))
+                    .block(block),
+                area,
+            );
+            return;
+        }
+
+        let start = points[0].secs.min(-60.0);
+        let low = points
+            .iter()
+            .map(|p| p.realized.min(p.implied))
+            .fold(f64::INFINITY, f64::min);
+        let high = points
+            .iter()
+            .map(|p| p.realized.max(p.implied))
+            .fold(f64::NEG_INFINITY, f64::max);
+        let pad = ((high - low) * 0.1).max(1.0);
+        let (low, high) = (low - pad, high + pad);
+
+        let inner = block.inner(area);
+        let shading = if available {
+            shade(points, start, low, high, inner)
+        } else {
+            Shading::default()
+        };
+        let realized: Vec<(f64, f64)> = points.iter().map(|p| (p.secs, p.realized)).collect();
+        let implied: Vec<(f64, f64)> = points.iter().map(|p| (p.secs, p.implied)).collect();
+
+        let datasets = vec![
+            Dataset::default()
+                .marker(Marker::Dot)
+                .graph_type(GraphType::Scatter)
+                .style(Style::default().fg(theme.highlight))
+                .data(&shading.expensive),
+            Dataset::default()
+                .marker(Marker::Dot)
+                .graph_type(GraphType::Scatter)
+                .style(Style::default().fg(theme.positive))
+                .data(&shading.cheap),
+            Dataset::default()
+                .name("RV")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(theme.caution))
+                .data(&realized),
+            Dataset::default()
+                .name("IV")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(theme.info))
+                .data(&implied),
+        ];
+
+        // The legend is only two lines; keep it on a short panel
+        let chart = Chart::new(datasets)
+            .block(block)
+            .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)))
+            .x_axis(
+                Axis::default()
+                    .style(Style::default().fg(theme.faint))
+                    .bounds([start, 0.0])
+                    .labels(vec![
+                        Span::raw(format!("-{:.0}m", -start / 60.0)),
+                        Span::raw("now"),
+                    ]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(theme.faint))
+                    .bounds([low, high])
+                    .labels(vec![
+                        Span::raw(format!("{:.0}%", low)),
+                        Span::raw(format!("{:.0}%", high)),
+                    ]),
+            );
+        frame.render_widget(chart, area);
+    }
+}
+
+/// Fill the gap between the two lines with one dot per cell
+fn shade(points: &[VolPoint], start: f64, low: f64, high: f64, area: Rect) -> Shading {
+    let mut shading = Shading::default();
+
+    let columns = (area.width as usize).max(1);
+    let dy = (high - low) / (area.height as f64).max(1.0);
+
+    for column in 0..columns {
+        let x = start + (column as f64 + 0.5) / columns as f64 * -start;
+        let Some(pair) = points
+            .windows(2)
+            .find(|pair| pair[0].secs <= x && x <= pair[1].secs)
+        else {
+            continue;
+        };
+        let span = pair[1].secs - pair[0].secs;
+        let t = if span > 0.0 {
+            (x - pair[0].secs) / span
+        } else {
+            0.0
+        };
+        let realized = pair[0].realized + (pair[1].realized - pair[0].realized) * t;
+        let implied = pair[0].implied + (pair[1].implied - pair[0].implied) * t;
+
+        let target = if implied > realized {
+            &mut shading.expensive
+        } else {
+            &mut shading.cheap
+        };
+        let mut y = realized.min(implied) + dy;
+        while y < realized.max(implied) {
+            target.push((x, y));
+            y += dy;
+        }
+    }
+
+    shading
+}
@@ -1,49 +1,57 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Sparkline},
     Frame,
 };
 
 use crate::api::VolatilitySkew;
+use crate::ui::theme::Theme;
 
-pub struct VolSkewView;
+pub struct VolSkewView {
+    iv_trend: Vec<f64>, // Recent implied vol readings (from the stream buffer)
+}
 
 impl VolSkewView {
     pub fn new() -> Self {
-        Self
+        Self {
+            iv_trend: Vec::new(),
+        }
+    }
+
+    /// Implied vol readings to chart under the smile, oldest first
+    pub fn set_iv_trend(&mut self, readings: Vec<f64>) {
+        self.iv_trend = readings;
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect, skew: &VolatilitySkew) {
+    pub fn render(&self, frame: &mut Frame, area: Rect, skew: &VolatilitySkew, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3),   // Title
-                Constraint::Length(10),  // Metrics cards
-                Constraint::Min(0),      // Interpretation & visual
+                Constraint::Length(3),  // Title
+                Constraint::Length(10), // Metrics cards
+                Constraint::Min(0),     // Interpretation & visual
             ])
             .split(area);
 
         // Title
-        self.render_title(frame, chunks[0]);
+        self.render_title(frame, chunks[0], theme);
 
         // Metrics
-        self.render_metrics(frame, chunks[1], skew);
+        self.render_metrics(frame, chunks[1], skew, theme);
 
         // Interpretation
-        self.render_interpretation(frame, chunks[2], skew);
+        self.render_interpretation(frame, chunks[2], skew, theme);
     }
 
-    fn render_title(&self, frame: &mut Frame, area: Rect) {
-        let title_text = Line::from(vec![
-            Span::styled(
-                "VOLATILITY SKEW ANALYSIS",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]);
+    fn render_title(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let title_text = Line::from(vec![Span::styled(
+            "VOLATILITY SKEW ANALYSIS",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )]);
 
         let paragraph = Paragraph::new(title_text)
             .block(Block::default().borders(Borders::ALL))
@@ -52,7 +60,7 @@ impl VolSkewView {
         frame.render_widget(paragraph, area);
     }
 
-    fn render_metrics(&self, frame: &mut Frame, area: Rect, skew: &VolatilitySkew) {
+    fn render_metrics(&self, frame: &mut Frame, area: Rect, skew: &VolatilitySkew, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
@@ -68,24 +76,24 @@ impl VolSkewView {
             Line::from(vec![Span::styled(
                 "At-The-Money",
                 Style::default()
-                    .fg(Color::Gray)
+                    .fg(theme.muted)
                     .add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Implied Vol:",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(theme.muted),
             )]),
             Line::from(vec![Span::styled(
                 format!("{:.1}%", skew.atm_iv * 100.0),
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "(Neutral strike)",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.faint),
             )]),
         ];
 
@@ -99,33 +107,29 @@ impl VolSkewView {
             Line::from(vec![Span::styled(
                 "Out-of-Money Call",
                 Style::default()
-                    .fg(Color::Gray)
+                    .fg(theme.muted)
                     .add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Implied Vol:",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(theme.muted),
             )]),
             Line::from(vec![Span::styled(
                 format!("{:.1}%", skew.otm_call_iv * 100.0),
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme.positive)
                     .add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "(Upside bets)",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.faint),
             )]),
         ];
 
         let call_para = Paragraph::new(call_text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" OTM Calls "),
-            )
+            .block(Block::default().borders(Borders::ALL).title(" OTM Calls "))
             .alignment(ratatui::layout::Alignment::Center);
         frame.render_widget(call_para, chunks[1]);
 
@@ -134,24 +138,24 @@ impl VolSkewView {
             Line::from(vec![Span::styled(
                 "Out-of-Money Put",
                 Style::default()
-                    .fg(Color::Gray)
+                    .fg(theme.muted)
                     .add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Implied Vol:",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(theme.muted),
             )]),
             Line::from(vec![Span::styled(
                 format!("{:.1}%", skew.otm_put_iv * 100.0),
                 Style::default()
-                    .fg(Color::Red)
+                    .fg(theme.negative)
                     .add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "(Downside protection)",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.faint),
             )]),
         ];
 
@@ -162,33 +166,29 @@ impl VolSkewView {
 
         // Skew metric
         let (skew_color, skew_arrow) = if skew.skew > 0.1 {
-            (Color::Red, "↑ PUT SKEW")
+            (theme.negative, "↑ PUT SKEW")
         } else if skew.skew < -0.1 {
-            (Color::Green, "↓ CALL SKEW")
+            (theme.positive, "↓ CALL SKEW")
         } else {
-            (Color::Yellow, "→ NEUTRAL")
+            (theme.highlight, "→ NEUTRAL")
         };
 
         let skew_text = vec![
             Line::from(vec![Span::styled(
                 "Skew Metric",
                 Style::default()
-                    .fg(Color::Gray)
+                    .fg(theme.muted)
                     .add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 format!("{:+.3}", skew.skew),
-                Style::default()
-                    .fg(skew_color)
-                    .add_modifier(Modifier::BOLD),
+                Style::default().fg(skew_color).add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 skew_arrow,
-                Style::default()
-                    .fg(skew_color)
-                    .add_modifier(Modifier::BOLD),
+                Style::default().fg(skew_color).add_modifier(Modifier::BOLD),
             )]),
         ];
 
@@ -198,7 +198,13 @@ impl VolSkewView {
         frame.render_widget(skew_para, chunks[3]);
     }
 
-    fn render_interpretation(&self, frame: &mut Frame, area: Rect, skew: &VolatilitySkew) {
+    fn render_interpretation(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        skew: &VolatilitySkew,
+        theme: &Theme,
+    ) {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
@@ -207,7 +213,7 @@ impl VolSkewView {
         // Left: Interpretation text
         let (sentiment_color, sentiment_text, description) = if skew.skew > 0.1 {
             (
-                Color::Red,
+                theme.negative,
                 "PUT SKEW - FEAR DOMINANT",
                 vec![
                     "Market is pricing higher downside volatility than upside.",
@@ -225,7 +231,7 @@ impl VolSkewView {
             )
         } else if skew.skew < -0.1 {
             (
-                Color::Green,
+                theme.positive,
                 "CALL SKEW - GREED DOMINANT",
                 vec![
                     "Market is pricing higher upside volatility than downside.",
@@ -243,7 +249,7 @@ impl VolSkewView {
             )
         } else {
             (
-                Color::Yellow,
+                theme.highlight,
                 "NEUTRAL - BALANCED MARKET",
                 vec![
                     "Market is pricing similar volatility for both directions.",
@@ -271,7 +277,7 @@ impl VolSkewView {
             Line::from(""),
             Line::from(vec![Span::styled(
                 "─".repeat(area.width.saturating_sub(4) as usize),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.faint),
             )]),
             Line::from(""),
         ];
@@ -282,26 +288,28 @@ impl VolSkewView {
 
         interpretation_lines.push(Line::from(""));
         interpretation_lines.push(Line::from(vec![
-            Span::styled("Raw Interpretation: ", Style::default().fg(Color::Gray)),
-            Span::styled(
-                &skew.skew_interpretation,
-                Style::default().fg(Color::Cyan),
-            ),
+            Span::styled("Raw Interpretation: ", Style::default().fg(theme.muted)),
+            Span::styled(&skew.skew_interpretation, Style::default().fg(theme.accent)),
         ]));
 
-        let interp_para = Paragraph::new(interpretation_lines)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Market Sentiment "),
-            );
+        let interp_para = Paragraph::new(interpretation_lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Market Sentiment "),
+        );
         frame.render_widget(interp_para, chunks[0]);
 
         // Right: Visual representation
-        self.render_skew_visual(frame, chunks[1], skew);
+        self.render_skew_visual(frame, chunks[1], skew, theme);
     }
 
-    fn render_skew_visual(&self, frame: &mut Frame, area: Rect, skew: &VolatilitySkew) {
+    fn render_skew_visual(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        skew: &VolatilitySkew,
+        theme: &Theme,
+    ) {
         // Create a simple visual representation of the IV curve
         // Generate mock data points for visualization (in real implementation, would use actual curve)
         let num_points = 20;
@@ -329,8 +337,32 @@ impl VolSkewView {
                     .title(" IV Smile Curve "),
             )
             .data(&curve_data)
-            .style(Style::default().fg(Color::Magenta));
+            .style(Style::default().fg(theme.special));
 
-        frame.render_widget(sparkline, area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(area);
+        frame.render_widget(sparkline, chunks[0]);
+
+        // Recent IV from the stream buffer, so the chart is filled as soon as the tab opens
+        let title = match (self.iv_trend.first(), self.iv_trend.last()) {
+            (Some(first), Some(last)) => format!(
+                " IV Last Hour: {:.1}% → {:.1}% ",
+                first * 100.0,
+                last * 100.0
+            ),
+            _ => " IV Last Hour (waiting for stream) ".to_string(),
+        };
+        let iv_data: Vec<u64> = self
+            .iv_trend
+            .iter()
+            .map(|iv| (iv * 1000.0) as u64)
+            .collect();
+        let iv_sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .data(&iv_data)
+            .style(Style::default().fg(theme.info));
+        frame.render_widget(iv_sparkline, chunks[1]);
     }
 }
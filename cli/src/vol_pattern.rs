@@ -0,0 +1,214 @@
+//! Intraday realized-vol pattern.
+//!
+//! The dashboard samples realized/implied vol into `vol_history.jsonl` every
+//! few minutes; `basilisk vol-pattern` compares today's path, hour by hour,
+//! with the average of previous days that fell on the same weekday.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use crate::api::VolatilityData;
+use crate::store;
+
+const VOL_HISTORY_FILE: &str = "vol_history.jsonl";
+
+/// Minimum gap between stored samples
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// UTC hours overlapping the US cash session (13:30–20:00 UTC in summer)
+const US_HOURS: std::ops::Range<u32> = 13..20;
+
+/// Width of the bars in the report
+const BAR_WIDTH: f64 = 30.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolSample {
+    pub timestamp: DateTime<Utc>,
+    pub realized_vol: f64,
+    pub implied_vol: f64,
+    pub regime: String,
+}
+
+/// Throttled writer for vol samples
+#[derive(Debug, Default)]
+pub struct VolRecorder {
+    last: Option<Instant>,
+}
+
+impl VolRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a sample if the last one is old enough (empty readings are skipped)
+    pub fn record(&mut self, volatility: &VolatilityData) -> Result<()> {
+        if volatility.realized_vol <= 0.0 {
+            return Ok(());
+        }
+        if self.last.is_some_and(|at| at.elapsed() < SAMPLE_INTERVAL) {
+            return Ok(());
+        }
+        self.last = Some(Instant::now());
+
+        let sample = VolSample {
+            timestamp: Utc::now(),
+            realized_vol: volatility.realized_vol,
+            implied_vol: volatility.implied_vol,
+            regime: volatility.regime.clone(),
+        };
+        store::append_jsonl(VOL_HISTORY_FILE, &sample)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Mean {
+    total: f64,
+    count: usize,
+}
+
+impl Mean {
+    fn add(&mut self, value: f64) {
+        self.total += value;
+        self.count += 1;
+    }
+
+    fn value(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.total / self.count as f64)
+    }
+}
+
+/// Average RV per UTC hour for one day
+fn hourly_path<'a>(samples: impl Iterator<Item = &'a VolSample>) -> [Mean; 24] {
+    let mut path = [Mean::default(); 24];
+    for sample in samples {
+        path[sample.timestamp.hour() as usize].add(sample.realized_vol);
+    }
+    path
+}
+
+fn bar(value: f64, scale: f64) -> String {
+    let width = ((value / scale) * BAR_WIDTH).round().clamp(0.0, BAR_WIDTH) as usize;
+    "█".repeat(width)
+}
+
+pub fn handle_vol_pattern_command(weekday: Option<Weekday>) -> Result<()> {
+    let samples: Vec<VolSample> =
+        store::read_jsonl(VOL_HISTORY_FILE).context("Failed to read vol history")?;
+
+    let today = Utc::now().date_naive();
+    let weekday = weekday.unwrap_or(today.weekday());
+
+    let mut by_day: BTreeMap<NaiveDate, Vec<&VolSample>> = BTreeMap::new();
+    for sample in &samples {
+        by_day
+            .entry(sample.timestamp.date_naive())
+            .or_default()
+            .push(sample);
+    }
+
+    let today_path = hourly_path(by_day.get(&today).into_iter().flatten().copied());
+
+    // Typical path: average of each prior matching day's hourly averages
+    let prior_days: Vec<&NaiveDate> = by_day
+        .keys()
+        .filter(|day| **day != today && day.weekday() == weekday)
+        .collect();
+    let mut typical = [Mean::default(); 24];
+    for day in &prior_days {
+        let path = hourly_path(by_day[*day].iter().copied());
+        for (hour, mean) in path.iter().enumerate() {
+            if let Some(value) = mean.value() {
+                typical[hour].add(value);
+            }
+        }
+    }
+
+    if prior_days.is_empty() {
+        println!(
+            "📭 No stored vol history for previous {} sessions yet.",
+            weekday
+        );
+        println!("   The dashboard records realized vol every 5 minutes while it runs.");
+        return Ok(());
+    }
+
+    let scale = today_path
+        .iter()
+        .chain(typical.iter())
+        .filter_map(Mean::value)
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON);
+
+    println!(
+        "📊 Realized Vol by Hour: today ({}) vs typical {} ({} prior days)",
+        today.format("%Y-%m-%d"),
+        weekday,
+        prior_days.len()
+    );
+    println!("{}", "─".repeat(96));
+    println!(
+        "{:<9} {:>8} {:>8} {:>7}  \x1b[36m█ today\x1b[0m  \x1b[90m█ typical\x1b[0m",
+        "Hour UTC", "Today", "Typical", "Δ"
+    );
+    println!("{}", "─".repeat(96));
+
+    // Average today-minus-typical, inside and outside US hours
+    let (mut us_delta, mut other_delta) = (Mean::default(), Mean::default());
+    for hour in 0..24u32 {
+        let (current, usual) = (
+            today_path[hour as usize].value(),
+            typical[hour as usize].value(),
+        );
+        if current.is_none() && usual.is_none() {
+            continue;
+        }
+
+        let marker = if US_HOURS.contains(&hour) { "US" } else { "" };
+        let delta = match (current, usual) {
+            (Some(current), Some(usual)) => {
+                let delta = (current - usual) * 100.0;
+                if US_HOURS.contains(&hour) {
+                    us_delta.add(delta);
+                } else {
+                    other_delta.add(delta);
+                }
+                let color = if delta > 0.0 { "\x1b[31m" } else { "\x1b[32m" };
+                format!("{}{:>+7.1}\x1b[0m", color, delta)
+            }
+            _ => format!("{:>7}", "--"),
+        };
+        let format_vol = |vol: Option<f64>| {
+            vol.map(|v| format!("{:.1}%", v * 100.0))
+                .unwrap_or_else(|| "--".to_string())
+        };
+
+        println!(
+            "{:02}:00 {:<2} {:>8} {:>8} {}  \x1b[36m{}\x1b[0m",
+            hour,
+            marker,
+            format_vol(current),
+            format_vol(usual),
+            delta,
+            current.map(|v| bar(v, scale)).unwrap_or_default()
+        );
+        if let Some(usual) = usual {
+            println!("{:<36}\x1b[90m{}\x1b[0m", "", bar(usual, scale));
+        }
+    }
+    println!("{}", "─".repeat(96));
+
+    if let Some(delta) = other_delta.value() {
+        println!(
+            "Outside US hours today runs {:+.1} vol points vs typical.",
+            delta
+        );
+    }
+    if let Some(delta) = us_delta.value() {
+        println!("US hours today run {:+.1} vol points vs typical.", delta);
+    }
+
+    Ok(())
+}